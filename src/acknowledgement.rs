@@ -0,0 +1,262 @@
+use anyhow::{bail, Context, Result};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Html,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use hmac::Mac;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use tokio::sync::mpsc;
+
+use crate::signing::hmac_sha256;
+
+/// Acknowledgements received but not yet recorded by the daemon
+const QUEUE_CAPACITY: usize = 100;
+
+const CONFIRMATION_PAGE: &str = "<!doctype html><html><head><meta charset=\"utf-8\"><title>LastSignal</title></head>\
+<body><h1>Thank you</h1><p>Your acknowledgement has been recorded. You don't need to do anything else with this link.</p></body></html>";
+
+const INVALID_LINK_PAGE: &str = "<!doctype html><html><head><meta charset=\"utf-8\"><title>LastSignal</title></head>\
+<body><h1>Link not recognised</h1><p>This acknowledgement link is incomplete or has been altered. Please open the link from the message exactly as it was sent.</p></body></html>";
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AcknowledgementsConfig {
+    /// Address the acknowledgement listener binds to, e.g. "127.0.0.1:8787"
+    pub listen: Option<String>,
+    /// Public base URL that reaches the listener, used to build `{ack_url}`
+    pub public_url: Option<String>,
+    /// Secret used to sign acknowledgement links with HMAC-SHA256
+    pub secret: Option<String>,
+}
+
+impl AcknowledgementsConfig {
+    pub fn validate(&self) -> Result<()> {
+        match (&self.listen, &self.public_url, &self.secret) {
+            (None, None, _) => Ok(()),
+            (Some(listen), Some(public_url), Some(secret)) => {
+                listen.parse::<SocketAddr>()
+                    .with_context(|| format!("Invalid acknowledgements listen address '{}'", listen))?;
+                let url = reqwest::Url::parse(public_url)
+                    .with_context(|| format!("Invalid acknowledgements public_url '{}'", public_url))?;
+                if !matches!(url.scheme(), "http" | "https") {
+                    bail!("Acknowledgements public_url must be an http(s) URL");
+                }
+                if secret.len() < 16 {
+                    bail!("Acknowledgements secret must be at least 16 characters");
+                }
+                Ok(())
+            }
+            _ => bail!("Acknowledgement links need 'listen', 'public_url' and 'secret' to all be set"),
+        }
+    }
+
+    /// Signs links for a last signal sent at `issued_at`, or `None` when links are not configured
+    pub fn links(&self, issued_at: DateTime<Utc>) -> Option<AckLinks> {
+        Some(AckLinks {
+            public_url: self.public_url.clone()?,
+            secret: self.secret.clone()?,
+            issued_at,
+        })
+    }
+}
+
+/// A recipient confirming they have seen the last signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Acknowledgement {
+    pub recipient_id: String,
+    /// When the link was sent. Links sent before the latest check-in are stale.
+    pub issued_at: DateTime<Utc>,
+}
+
+/// Builds the per-recipient `{ack_url}` for one firing of the last signal
+#[derive(Debug, Clone)]
+pub struct AckLinks {
+    public_url: String,
+    secret: String,
+    issued_at: DateTime<Utc>,
+}
+
+impl AckLinks {
+    pub fn url_for(&self, recipient_id: &str) -> String {
+        let issued = self.issued_at.timestamp();
+        format!(
+            "{}/ack?r={}&t={}&sig={}",
+            self.public_url.trim_end_matches('/'),
+            urlencoding::encode(recipient_id),
+            issued,
+            sign(&self.secret, recipient_id, issued)
+        )
+    }
+}
+
+fn sign(secret: &str, recipient_id: &str, issued: i64) -> String {
+    let mac = hmac_sha256(secret.as_bytes(), format!("{}\n{}", recipient_id, issued).as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Checks the query parameters of an acknowledgement link
+pub fn verify(secret: &str, params: &HashMap<String, String>) -> Result<Acknowledgement> {
+    let (Some(recipient_id), Some(issued), Some(signature)) = (params.get("r"), params.get("t"), params.get("sig")) else {
+        bail!("Acknowledgement link is missing parameters");
+    };
+    let issued: i64 = issued.parse().context("Invalid acknowledgement timestamp")?;
+    let signature = hex::decode(signature).context("Invalid acknowledgement signature")?;
+
+    let mac = hmac_sha256(secret.as_bytes(), format!("{}\n{}", recipient_id, issued).as_bytes());
+    mac.verify_slice(&signature).map_err(|_| anyhow::anyhow!("Acknowledgement signature does not match"))?;
+
+    Ok(Acknowledgement {
+        recipient_id: recipient_id.clone(),
+        issued_at: DateTime::from_timestamp(issued, 0).context("Acknowledgement timestamp out of range")?,
+    })
+}
+
+/// Extracts the bare, lowercased address from a sender such as `Jane <jane@example.com>`
+pub fn sender_address(from: &str) -> String {
+    let address = match (from.rfind('<'), from.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &from[start + 1..end],
+        _ => from,
+    };
+    address.trim().to_ascii_lowercase()
+}
+
+#[derive(Clone)]
+struct ServerState {
+    secret: String,
+    sender: mpsc::Sender<Acknowledgement>,
+}
+
+/// Starts the listener for acknowledgement links. Verified acknowledgements
+/// arrive on the returned channel for the daemon to record. `None` when
+/// acknowledgement links are not configured.
+pub async fn start_server(config: &AcknowledgementsConfig) -> Result<Option<mpsc::Receiver<Acknowledgement>>> {
+    let (Some(listen), Some(secret)) = (&config.listen, &config.secret) else {
+        return Ok(None);
+    };
+
+    let listener = tokio::net::TcpListener::bind(listen).await
+        .with_context(|| format!("Failed to bind acknowledgement listener to {}", listen))?;
+    let (sender, receiver) = mpsc::channel(QUEUE_CAPACITY);
+    let app = Router::new()
+        .route("/ack", get(handle_ack))
+        .with_state(ServerState { secret: secret.clone(), sender });
+
+    tracing::info!("Listening for acknowledgement links on {}", listen);
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            tracing::error!("Acknowledgement listener stopped: {}", e);
+        }
+    });
+
+    Ok(Some(receiver))
+}
+
+async fn handle_ack(
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> (StatusCode, Html<&'static str>) {
+    match verify(&state.secret, &params) {
+        Ok(acknowledgement) => {
+            if let Err(e) = state.sender.try_send(acknowledgement) {
+                tracing::warn!("Acknowledgement queue full or closed, dropping acknowledgement: {}", e);
+                return (StatusCode::SERVICE_UNAVAILABLE, Html(INVALID_LINK_PAGE));
+            }
+            (StatusCode::OK, Html(CONFIRMATION_PAGE))
+        }
+        Err(e) => {
+            tracing::warn!("Rejected acknowledgement link: {}", e);
+            (StatusCode::BAD_REQUEST, Html(INVALID_LINK_PAGE))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    fn links() -> AckLinks {
+        AcknowledgementsConfig {
+            listen: Some("127.0.0.1:0".to_string()),
+            public_url: Some("https://lastsignal.example.com/".to_string()),
+            secret: Some(SECRET.to_string()),
+        }
+        .links(DateTime::from_timestamp(1_750_000_000, 0).unwrap())
+        .unwrap()
+    }
+
+    fn query(url: &str) -> HashMap<String, String> {
+        reqwest::Url::parse(url).unwrap().query_pairs().into_owned().collect()
+    }
+
+    #[test]
+    fn test_signed_link_round_trips() {
+        let url = links().url_for("email:contact@example.com");
+        assert!(url.starts_with("https://lastsignal.example.com/ack?r=email%3Acontact%40example.com&t=1750000000&sig="));
+
+        let acknowledgement = verify(SECRET, &query(&url)).unwrap();
+        assert_eq!(acknowledgement.recipient_id, "email:contact@example.com");
+        assert_eq!(acknowledgement.issued_at.timestamp(), 1_750_000_000);
+    }
+
+    #[test]
+    fn test_tampered_links_are_rejected() {
+        let url = links().url_for("email:contact@example.com");
+
+        let mut other_recipient = query(&url);
+        other_recipient.insert("r".to_string(), "email:someone@example.com".to_string());
+        assert!(verify(SECRET, &other_recipient).is_err());
+
+        assert!(verify("a-different-secret", &query(&url)).is_err());
+
+        let mut missing = query(&url);
+        missing.remove("sig");
+        assert!(verify(SECRET, &missing).is_err());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(AcknowledgementsConfig::default().validate().is_ok());
+        assert!(AcknowledgementsConfig { public_url: Some("https://example.com".to_string()), ..Default::default() }.validate().is_err());
+        assert!(AcknowledgementsConfig {
+            listen: Some("127.0.0.1:8787".to_string()),
+            public_url: Some("https://example.com".to_string()),
+            secret: Some("short".to_string()),
+        }.validate().is_err());
+    }
+
+    #[test]
+    fn test_sender_address() {
+        assert_eq!(sender_address("Jane Doe <Jane@Example.com>"), "jane@example.com");
+        assert_eq!(sender_address("jane@example.com"), "jane@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_server_confirms_and_forwards_acknowledgements() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        let config = AcknowledgementsConfig {
+            listen: Some(address.to_string()),
+            public_url: Some(format!("http://{}", address)),
+            secret: Some(SECRET.to_string()),
+        };
+        let mut receiver = start_server(&config).await.unwrap().unwrap();
+        let url = config.links(Utc::now()).unwrap().url_for("slack:T0/B0");
+
+        let response = reqwest::get(&url).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(response.text().await.unwrap().contains("Thank you"));
+        assert_eq!(receiver.recv().await.unwrap().recipient_id, "slack:T0/B0");
+
+        let response = reqwest::get(format!("http://{}/ack?r=x&t=1&sig=00", address)).await.unwrap();
+        assert_eq!(response.status(), 400);
+    }
+}
@@ -1,13 +1,47 @@
 use anyhow::{Context, Result};
-use tokio::time::{sleep, Duration};
-
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::time::{sleep_until, Duration, Instant};
+
+use crate::acknowledgement::{self, Acknowledgement};
+use crate::audit::AuditLog;
+use crate::checkin_server::{self, CheckinServer, ServerRequest};
 use crate::config::Config;
-use crate::message_adapter::{MessageAdapter, MessageAdapterFactory};
+use crate::config_backup;
+use crate::connectivity;
+use crate::duration_parser::DurationStyle;
+use crate::events::{Event, EventPublisher};
+use crate::message_adapter::{MessageAdapter, MessageAdapterFactory, MessageContext};
+use crate::monitoring::Monitor;
+use crate::notifier::{Notifier, Warning, WarningCategory};
 use crate::outputs::{
-    process_last_signal_outputs, generate_recipient_id, Output, OutputFactory, OutputResult,
-    bidirectional::{BidirectionalOutput, BidirectionalOutputFactory, process_bidirectional_outputs_for_checkins, mark_all_processed_until}
+    process_last_signal_outputs, process_outputs_with_fallback, LastSignalRun, OutputContext, dispatch_order, generate_recipient_id, generate_recipient_ids, wrap_message, MessageKind, Output, OutputFactory, OutputResult,
+    chaos::{ChaosOutput, ChaosSpec},
+    rate_limit::{self, RateLimitedOutput, RateLimiter},
+    whoop_webhook::WhoopWebhook,
+    bidirectional::{BidirectionalOutput, BidirectionalOutputFactory, CheckinResponse, process_bidirectional_outputs_for_checkins, mark_all_processed_until}
 };
+use crate::redact;
+use crate::schedule::{self, CheckinRequest};
+use crate::shutdown::Shutdown;
 use crate::state::StateManager;
+use crate::status::{recipient_statuses, RecipientState, StatusReport, StatusView};
+
+const TEST_MESSAGE: &str = "This is a test message from LastSignal. No action is needed.";
+
+const CONFIG_CHANGE_NOTE: &str = "\n\nYour LastSignal configuration changed";
+
+const REVIEW_REMINDER_NOTE: &str = "\n\nIt has been a while since your LastSignal configuration was reviewed. Please check that the contacts, addresses and tokens are still current, then run `lastsignal reviewed`.";
+
+/// How a daemon cycle ended
+enum CycleOutcome {
+    Completed,
+    /// The network was down, so nothing was sent or recorded
+    Offline,
+    /// Shutdown was requested, so the rest of the cycle was skipped
+    Interrupted,
+}
 
 pub struct LastSignalApp {
     config: Config,
@@ -16,18 +50,33 @@ pub struct LastSignalApp {
     checkin_outputs: Vec<Box<dyn BidirectionalOutput>>,
     last_signal_outputs: Vec<Box<dyn Output>>,
     last_signal_output_configs: Vec<crate::config::OutputConfig>,
+    /// Sent the last signal by `lastsignal rehearse`, never by the daemon
+    canary_outputs: Vec<Box<dyn Output>>,
+    events: EventPublisher,
+    notifier: Notifier,
+    /// Acknowledgements from the link listener, while the daemon is running
+    acknowledgements: Option<mpsc::Receiver<Acknowledgement>>,
+    /// The HTTP check-in endpoint, while the daemon is running
+    checkin_server: Option<CheckinServer>,
+    rate_limiter: Arc<RateLimiter>,
+    /// Stops the daemon at the next safe point
+    shutdown: Shutdown,
+    /// Pinged around each daemon cycle, when `[monitoring]` is configured
+    monitor: Option<Monitor>,
 }
 
 impl LastSignalApp {
-    pub async fn new() -> Result<Self> {
-        tracing::debug!("Loading configuration...");
-        let config = Config::load()
-            .context("Failed to load configuration. Make sure config.toml exists in ~/.lastsignal/")?;
-        
-        Self::from_config(config).await
+    /// For one-shot commands, which don't start background tasks
+    pub async fn from_config(config: Config) -> Result<Self> {
+        Self::create(config, false).await
     }
 
-    pub async fn from_config(config: Config) -> Result<Self> {
+    /// For `lastsignal run`, whose outputs keep themselves up to date
+    pub async fn for_daemon(config: Config) -> Result<Self> {
+        Self::create(config, true).await
+    }
+
+    async fn create(config: Config, long_running: bool) -> Result<Self> {
 
         tracing::debug!("Getting data directory...");
         let data_directory = config.get_data_directory()
@@ -45,8 +94,22 @@ impl LastSignalApp {
         let message_adapter = MessageAdapterFactory::create_adapter(
             &config.last_signal.adapter_type,
             &message_file_path,
+            config.last_signal.format,
         ).context("Failed to create message adapter")?;
 
+        // Replies from last signal email recipients are acknowledgements, so they get past the sender check
+        let acknowledgement_senders: Vec<String> = config.recipient.last_signal_outputs.iter()
+            .filter(|output_config| matches!(output_config.output_type.as_str(), "email" | "email_mailgun" | "email_ses"))
+            .flat_map(generate_recipient_ids)
+            .filter_map(|recipient_id| recipient_id.split_once(':').map(|(_, to)| acknowledgement::sender_address(to)))
+            .collect();
+
+        let output_context = OutputContext {
+            max_time_since_last_checkin: config.recipient.max_time_since_last_checkin,
+            check_interval: config.app.check_interval,
+            long_running,
+        };
+
         tracing::debug!("Creating checkin outputs...");
         let mut checkin_outputs: Vec<Box<dyn BidirectionalOutput>> = Vec::new();
         for (i, output_config) in config.checkin.outputs.iter().enumerate() {
@@ -55,8 +118,10 @@ impl LastSignalApp {
                 &output_config.output_type, 
                 &output_config.config,
                 output_config.bidirectional,
+                &output_config.additional_imap_accounts,
+                &acknowledgement_senders,
                 Some(&data_directory),
-                config.recipient.max_time_since_last_checkin
+                output_context
             ).with_context(|| format!("Failed to create checkin output: {}", output_config.output_type))?;
             checkin_outputs.push(output);
             tracing::debug!("Successfully created checkin output {}", i + 1);
@@ -64,12 +129,47 @@ impl LastSignalApp {
 
         let mut last_signal_outputs: Vec<Box<dyn Output>> = Vec::new();
         for output_config in &config.recipient.last_signal_outputs {
-            let output = OutputFactory::create_output(&output_config.output_type, &output_config.config, Some(&data_directory), config.recipient.max_time_since_last_checkin)
+            let output = OutputFactory::create_output(&output_config.output_type, &output_config.config, MessageKind::LastSignal, Some(&data_directory), output_context)
                 .with_context(|| format!("Failed to create last signal output: {}", output_config.output_type))?;
             last_signal_outputs.push(output);
         }
 
+        let mut canary_outputs: Vec<Box<dyn Output>> = Vec::new();
+        for output_config in &config.recipient.canary_outputs {
+            let output = OutputFactory::create_output(&output_config.output_type, &output_config.config, MessageKind::LastSignal, Some(&data_directory), output_context)
+                .with_context(|| format!("Failed to create canary output: {}", output_config.output_type))?;
+            canary_outputs.push(output);
+        }
+
+        // Every output sending through the same provider shares its limit
+        let rate_limiter = Arc::new(RateLimiter::new(&config.app.rate_limit, &state_manager.get_state().provider_sends));
+        let checkin_outputs = checkin_outputs.into_iter()
+            .zip(&config.checkin.outputs)
+            .map(|(output, output_config)| match rate_limit::provider_key(output_config) {
+                Some(provider) => Box::new(RateLimitedOutput::new(output, provider, rate_limiter.clone())) as Box<dyn BidirectionalOutput>,
+                None => output,
+            })
+            .collect();
+        let last_signal_outputs = last_signal_outputs.into_iter()
+            .zip(&config.recipient.last_signal_outputs)
+            .map(|(output, output_config)| match rate_limit::provider_key(output_config) {
+                Some(provider) => Box::new(RateLimitedOutput::new(output, provider, rate_limiter.clone())) as Box<dyn Output>,
+                None => output,
+            })
+            .collect();
+        let canary_outputs = canary_outputs.into_iter()
+            .zip(&config.recipient.canary_outputs)
+            .map(|(output, output_config)| match rate_limit::provider_key(output_config) {
+                Some(provider) => Box::new(RateLimitedOutput::new(output, provider, rate_limiter.clone())) as Box<dyn Output>,
+                None => output,
+            })
+            .collect();
+
         let last_signal_output_configs = config.recipient.last_signal_outputs.clone();
+        let events = EventPublisher::new(&config.app.events);
+        let notifier = Notifier::new(&config.app.notifications, Some(&data_directory));
+
+        let monitor = config.monitoring.as_ref().map(Monitor::new).transpose()?;
 
         tracing::debug!("App initialization complete");
         Ok(LastSignalApp {
@@ -79,37 +179,208 @@ impl LastSignalApp {
             checkin_outputs,
             last_signal_outputs,
             last_signal_output_configs,
+            canary_outputs,
+            events,
+            notifier,
+            acknowledgements: None,
+            checkin_server: None,
+            rate_limiter,
+            shutdown: Shutdown::never(),
+            monitor,
         })
     }
 
-    pub async fn run(&mut self) -> Result<()> {
+    /// Runs the daemon until `shutdown` is requested
+    pub async fn run(&mut self, shutdown: Shutdown) -> Result<()> {
+        self.rate_limiter.set_shutdown(shutdown.clone());
+        self.shutdown = shutdown;
+        let started = Instant::now();
+        let mut cycles = 0;
         tracing::info!("Starting LastSignal application");
         tracing::info!("Configuration loaded: {} checkin outputs, {} last signal outputs", 
             self.checkin_outputs.len(), 
             self.last_signal_outputs.len());
 
+        self.track_config_changes()?;
+
+        let report = StatusReport::new(&self.config, self.state_manager.get_state());
+        tracing::info!("Startup status:\n{}", report.render_text(Utc::now()).trim_end());
+
+        for warning in self.config.audit_warnings() {
+            tracing::warn!("Config: {}", warning);
+            self.notify_warning(Warning::new(WarningCategory::Config, warning))?;
+        }
+
         // Check for unsent last signal recipients on startup
         self.check_for_pending_last_signal_recipients().await?;
 
+        self.acknowledgements = acknowledgement::start_server(&self.config.recipient.acknowledgements).await?;
+        let data_directory = self.config.get_data_directory()?;
+        let whoop_webhook = WhoopWebhook::from_checkin_outputs(&self.config.checkin.outputs, &data_directory)?;
+        self.checkin_server = checkin_server::start_server(self.config.server.as_ref(), &data_directory, whoop_webhook).await?;
+
         tracing::debug!("Entering main loop");
         loop {
             tracing::info!("About to run cycle");
-            if let Err(e) = self.run_cycle().await {
-                tracing::error!("Error in application cycle: {}", e);
-                sleep(Duration::from_secs(300)).await; // Wait 5 minutes before retrying
-                continue;
+            if let Some(monitor) = &self.monitor {
+                monitor.start().await;
+            }
+            let wait = match self.run_cycle().await {
+                Ok(CycleOutcome::Completed) => {
+                    cycles += 1;
+                    if let Some(monitor) = &self.monitor {
+                        monitor.success().await;
+                    }
+                    // Sleep for configured interval before next check
+                    let check_interval = self.config.app.check_interval;
+                    tracing::info!("Cycle complete, sleeping for {} seconds ({})", check_interval.as_secs(), check_interval);
+                    check_interval.as_duration()
+                }
+                Ok(CycleOutcome::Offline) => {
+                    let retry_interval = self.config.app.connectivity.offline_retry_interval;
+                    tracing::warn!("Network appears down, skipping this cycle and retrying in {}", retry_interval);
+                    retry_interval.as_duration()
+                }
+                Ok(CycleOutcome::Interrupted) => break,
+                Err(e) => {
+                    tracing::error!("Error in application cycle: {}", e);
+                    if let Some(monitor) = &self.monitor {
+                        monitor.failure(&format!("{:#}", e)).await;
+                    }
+                    Duration::from_secs(300) // Wait 5 minutes before retrying
+                }
+            };
+
+            if self.shutdown.is_requested() {
+                break;
+            }
+            self.idle(wait).await?;
+            if self.shutdown.is_requested() {
+                break;
+            }
+        }
+
+        self.shut_down(started.elapsed(), cycles).await
+    }
+
+    /// Saves state and delivers queued events before the daemon exits
+    async fn shut_down(&mut self, uptime: Duration, cycles: u32) -> Result<()> {
+        self.save_send_history()?;
+        self.state_manager.save().context("Failed to save state on shutdown")?;
+        self.events.close().await;
+
+        let last_checkin = self.state_manager.get_state().last_checkin
+            .map(|checkin| checkin.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| "never".to_string());
+        tracing::info!(
+            "LastSignal stopped after {} with {} completed cycle(s). Last check-in: {}",
+            DurationStyle::Verbose.format(chrono::Duration::from_std(uptime).unwrap_or_default()), cycles, last_checkin
+        );
+        Ok(())
+    }
+
+    /// Saves recent sends per provider so rate limits survive a restart
+    fn save_send_history(&mut self) -> Result<()> {
+        self.state_manager.record_provider_sends(self.rate_limiter.history(Utc::now()))
+            .context("Failed to record provider send history")
+    }
+
+    /// Waits between cycles, recording acknowledgements, answering the
+    /// check-in server and processing pushed replies as they arrive
+    async fn idle(&mut self, duration: Duration) -> Result<()> {
+        let until = Instant::now() + duration;
+        let reply_signals: Vec<_> = self.checkin_outputs.iter().filter_map(|output| output.reply_signal()).collect();
+        let mut shutdown = self.shutdown.clone();
+        loop {
+            let acknowledgement = async {
+                match &mut self.acknowledgements {
+                    Some(receiver) => receiver.recv().await,
+                    None => std::future::pending().await,
+                }
+            };
+            let server_request = async {
+                match &mut self.checkin_server {
+                    Some(server) => server.requests.recv().await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            let reply = async {
+                if reply_signals.is_empty() {
+                    std::future::pending::<()>().await;
+                }
+                futures_util::future::select_all(reply_signals.iter().map(|signal| Box::pin(signal.notified()))).await;
+            };
+
+            tokio::select! {
+                _ = reply => {
+                    tracing::info!("Reply pushed between cycles, checking for check-ins");
+                    self.process_bidirectional_checkins().await?;
+                }
+                received = acknowledgement => match received {
+                    Some(acknowledgement) => self.record_acknowledgement(acknowledgement, "link")?,
+                    None => self.acknowledgements = None,
+                },
+                received = server_request => match received {
+                    Some(request) => self.answer_server_request(request),
+                    None => self.checkin_server = None,
+                },
+                _ = sleep_until(until) => return Ok(()),
+                _ = shutdown.requested() => return Ok(()),
             }
+        }
+    }
 
-            // Sleep for configured interval before next check
-            let check_interval = self.config.app.check_interval.as_secs();
-            tracing::info!("Cycle complete, sleeping for {} seconds ({})", check_interval, self.config.app.check_interval);
-            sleep(Duration::from_secs(check_interval)).await;
+    fn answer_server_request(&mut self, request: ServerRequest) {
+        match request {
+            ServerRequest::Checkin { reply } => {
+                let result = self.record_checkin("http");
+                if let Err(e) = &result {
+                    tracing::error!("Failed to record check-in from the check-in server: {:#}", e);
+                } else {
+                    tracing::info!("Check-in recorded via the check-in server");
+                }
+                let _ = reply.send(result.map_err(|e| format!("{:#}", e)));
+            }
+            ServerRequest::Status { reply } => {
+                let _ = reply.send(StatusReport::new(&self.config, self.state_manager.get_state()));
+            }
         }
     }
 
-    async fn run_cycle(&mut self) -> Result<()> {
+    /// Records a recipient confirming they saw the last signal. Links sent
+    /// before the latest check-in belong to an earlier alarm and are ignored.
+    fn record_acknowledgement(&mut self, acknowledgement: Acknowledgement, source: &str) -> Result<()> {
+        let state = self.state_manager.get_state();
+        if state.last_checkin.is_some_and(|checkin| acknowledgement.issued_at < checkin) {
+            tracing::info!("Ignoring stale acknowledgement from {}, sent before the latest check-in", acknowledgement.recipient_id);
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        if self.state_manager.record_last_signal_acknowledged(&acknowledgement.recipient_id, now)
+            .context("Failed to record last signal acknowledgement")?
+        {
+            tracing::warn!("Last signal acknowledged by {} via {}", acknowledgement.recipient_id, source);
+            AuditLog::new(self.config.get_data_directory().ok().as_deref()).record(now, serde_json::json!({
+                "category": "last_signal_acknowledged",
+                "recipient_id": acknowledgement.recipient_id,
+                "source": source,
+            }));
+            self.events.publish(Event::LastSignalAcknowledged { recipient_id: acknowledgement.recipient_id });
+        }
+        Ok(())
+    }
+
+    async fn run_cycle(&mut self) -> Result<CycleOutcome> {
         tracing::info!("Running application cycle");
 
+        // With no network every output fails for the same reason. Don't count
+        // that against the outputs, record unanswerable requests, or fire.
+        if !connectivity::network_available(&self.config.app.connectivity).await {
+            return Ok(CycleOutcome::Offline);
+        }
+
         // First, check if all emergency notifications have been completed
         if self.all_recipients_already_notified().await? {
             tracing::info!("All {} recipient(s) already notified - emergency process complete", self.last_signal_output_configs.len());
@@ -132,50 +403,176 @@ impl LastSignalApp {
         self.process_bidirectional_checkins().await?;
         tracing::info!("Finished checking bidirectional responses");
 
+        // Between steps is a safe point to stop: nothing is half sent or half saved
+        if self.shutdown.is_requested() {
+            return Ok(CycleOutcome::Interrupted);
+        }
+
         // Check if we need to request a checkin
         tracing::info!("Checking if we should request checkin...");
-        if self.should_request_checkin().await? {
+        if let Some(request) = self.checkin_request_due() {
             tracing::info!("Time to request checkin");
-            self.request_checkin().await?;
+            self.request_checkin(request).await?;
         } else {
             tracing::info!("No checkin request needed");
         }
 
+        if self.shutdown.is_requested() {
+            return Ok(CycleOutcome::Interrupted);
+        }
+
+        if self.should_warn_before_last_signal() {
+            self.send_last_signal_warning().await?;
+        }
+
+        if self.shutdown.is_requested() {
+            return Ok(CycleOutcome::Interrupted);
+        }
+
         // Check if we need to fire the last signal
         tracing::info!("Checking if we should fire last signal...");
         if self.should_fire_last_signal().await? {
             tracing::warn!("Time to fire last signal");
-            self.fire_last_signal().await?;
+            self.fire_last_signal(false, true).await?;
         } else {
             tracing::info!("No last signal needed");
         }
 
+        self.check_for_problems().await?;
+        self.send_warning_digest().await?;
+        self.save_send_history()?;
+
         tracing::info!("Application cycle completed");
+        Ok(CycleOutcome::Completed)
+    }
+
+    fn notify_warning(&mut self, warning: Warning) -> Result<()> {
+        let notifier = &self.notifier;
+        self.state_manager.update_notifier_state(|state| notifier.warn(state, warning, Utc::now()))
+            .context("Failed to record warning")?;
         Ok(())
     }
 
-    async fn should_request_checkin(&self) -> Result<bool> {
-        let state = self.state_manager.get_state();
-        Ok(state.should_request_checkin(self.config.checkin.duration_between_checkins))
+    /// Looks for problems the admin should fix before the last signal is needed
+    async fn check_for_problems(&mut self) -> Result<()> {
+        let mut warnings = Vec::new();
+
+        for (output, output_config) in self.last_signal_outputs.iter().zip(&self.last_signal_output_configs) {
+            let healthy = match output.health_check().await {
+                Ok(healthy) => healthy,
+                Err(e) => {
+                    tracing::debug!("Health check error for {}: {}", output.get_name(), e);
+                    false
+                }
+            };
+            if !healthy {
+                warnings.push(Warning::new(
+                    WarningCategory::UnhealthyOutput,
+                    format!("Last signal output for {} is failing its health check", generate_recipient_id(output_config)),
+                ));
+            }
+        }
+
+        for output in &self.checkin_outputs {
+            match output.mailbox_usage().await {
+                Ok(Some(usage)) => {
+                    tracing::info!("{} mailbox usage: {}% ({})", output.get_name(), usage.percent, usage.resource);
+                    if usage.percent >= self.config.app.notifications.mailbox_usage_threshold {
+                        warnings.push(Warning::new(
+                            WarningCategory::UnhealthyOutput,
+                            format!("The {} mailbox that receives check-in replies is {}% full ({}). Once it is full your replies will bounce", output.get_name(), usage.percent, usage.resource),
+                        ));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::debug!("Mailbox usage check failed for {}: {}", output.get_name(), e),
+            }
+        }
+
+        for status in recipient_statuses(&self.last_signal_output_configs, self.state_manager.get_state()) {
+            if status.state == RecipientState::Orphaned {
+                warnings.push(Warning::new(
+                    WarningCategory::State,
+                    format!("State records a last signal to {}, which is no longer configured", status.recipient_id),
+                ));
+            }
+        }
+
+        for warning in warnings {
+            self.notify_warning(warning)?;
+        }
+        Ok(())
+    }
+
+    async fn send_warning_digest(&mut self) -> Result<()> {
+        let Some(digest) = self.notifier.digest(&self.state_manager.get_state().notifier, Utc::now()) else {
+            return Ok(());
+        };
+
+        let result = process_outputs_with_fallback(&self.checkin_outputs, &digest, &self.config.checkin.outputs, self.config.app.local_time(Utc::now())).await?;
+        let delivered = matches!(result, OutputResult::Success);
+        if !delivered {
+            tracing::error!("Failed to send warning digest: {:?}", result);
+        }
+
+        let notifier = &self.notifier;
+        self.state_manager.update_notifier_state(|state| notifier.record_digest(state, delivered, Utc::now()))
+            .context("Failed to record warning digest")?;
+        Ok(())
+    }
+
+    fn checkin_request_due(&self) -> Option<CheckinRequest> {
+        let checkin = &self.config.checkin;
+        self.state_manager.get_state()
+            .checkin_request_due(checkin.duration_between_checkins, checkin.output_retry_delay, &checkin.escalation, self.config.app.anchoring())
     }
 
     async fn should_fire_last_signal(&self) -> Result<bool> {
         let state = self.state_manager.get_state();
-        
-        // Don't fire if we've already fired recently
-        if state.has_fired_last_signal_recently(self.config.recipient.max_time_since_last_checkin) {
+        if !state.should_fire_last_signal(self.config.recipient.max_time_since_last_checkin, self.config.app.anchoring()) {
             return Ok(false);
         }
 
-        Ok(state.should_fire_last_signal(self.config.recipient.max_time_since_last_checkin))
+        // Until every recipient has it, the rest are retried each time their
+        // output_retry_delay passes
+        let recipient_ids: Vec<String> = self.last_signal_output_configs.iter().flat_map(generate_recipient_ids).collect();
+        let recipient = &self.config.recipient;
+        Ok(state.last_signal_retry_due(&recipient_ids, recipient.output_retry_delay, recipient.min_renotify_interval))
+    }
+
+    fn should_warn_before_last_signal(&self) -> bool {
+        let recipient = &self.config.recipient;
+        recipient.warn_before_last_signal.is_some_and(|warn_before| {
+            self.state_manager.get_state()
+                .should_warn_before_last_signal(recipient.max_time_since_last_checkin, warn_before, self.config.app.anchoring())
+        })
+    }
+
+    /// Tells the admin through the checkin outputs exactly when the last
+    /// signal fires. A failed warning is tried again next cycle.
+    async fn send_last_signal_warning(&mut self) -> Result<()> {
+        tracing::warn!("Warning admin that the last signal is about to fire");
+        let message = self.message_adapter.generate_warning_message(&self.message_context())
+            .context("Failed to generate last signal warning")?;
+
+        let result = process_outputs_with_fallback(&self.checkin_outputs, &message, &self.config.checkin.outputs, self.config.app.local_time(Utc::now())).await?;
+        match result {
+            OutputResult::Success => {
+                tracing::info!("Last signal warning sent successfully");
+                self.state_manager.record_last_signal_warning()
+                    .context("Failed to record last signal warning")?;
+            }
+            OutputResult::Failed(error) => tracing::error!("Failed to send last signal warning: {}", error),
+            OutputResult::Skipped(reason) => tracing::warn!("Last signal warning skipped: {}", reason),
+        }
+        Ok(())
     }
 
     async fn all_recipients_already_notified(&self) -> Result<bool> {
         let state = self.state_manager.get_state();
         
         // Check each recipient to see if they've already been notified
-        for output_config in &self.last_signal_output_configs {
-            let recipient_id = generate_recipient_id(output_config);
+        for recipient_id in self.last_signal_output_configs.iter().flat_map(generate_recipient_ids) {
             if !state.is_last_signal_recipient_already_notified(&recipient_id) {
                 return Ok(false); // Found at least one recipient not yet notified
             }
@@ -185,46 +582,87 @@ impl LastSignalApp {
         Ok(true)
     }
 
-    async fn request_checkin(&mut self) -> Result<()> {
-        tracing::info!("Requesting checkin from admin");
+    async fn request_checkin(&mut self, request: CheckinRequest) -> Result<()> {
+        let mut message = match request {
+            CheckinRequest::Initial => {
+                tracing::info!("Requesting checkin from admin");
+                self.message_adapter.generate_checkin_message(&self.message_context())
+                    .context("Failed to generate checkin message")?
+            }
+            CheckinRequest::Reminder(level) => {
+                tracing::warn!("Sending escalation reminder {} of {} to admin", level, self.config.checkin.escalation.len());
+                self.message_adapter.generate_reminder_message(&self.message_context(), level)
+                    .context("Failed to generate reminder message")?
+            }
+        };
+
+        let config_change = self.state_manager.get_state().unannounced_config_change.clone();
+        if let Some(change) = &config_change {
+            message.push_str(&format!("{} {}. If you didn't make this change, check the configuration now.", CONFIG_CHANGE_NOTE, change));
+        }
 
-        let message = self.message_adapter.generate_checkin_message()
-            .context("Failed to generate checkin message")?;
+        if self.review_due() {
+            message.push_str(REVIEW_REMINDER_NOTE);
+        }
 
-        let result = self.send_message_via_bidirectional_outputs(&message).await?;
+        let result = process_outputs_with_fallback(&self.checkin_outputs, &message, &self.config.checkin.outputs, self.config.app.local_time(Utc::now())).await?;
 
-        match result {
+        match &result {
             OutputResult::Success => {
                 tracing::info!("Checkin request sent successfully");
-                self.state_manager.record_checkin_request()
-                    .context("Failed to record checkin request")?;
+                self.record_checkin_request(request)?;
+                if config_change.is_some() {
+                    self.state_manager.clear_unannounced_config_change()
+                        .context("Failed to record config change announcement")?;
+                }
+                self.events.publish(Event::CheckinRequestSent);
             }
             OutputResult::Failed(error) => {
                 tracing::error!("Failed to send checkin request: {}", error);
-                self.state_manager.record_checkin_request()
-                    .context("Failed to send checkin request")?;
+                if result.is_persistent_failure() {
+                    self.notify_warning(Warning::new(WarningCategory::Config, format!("Check-in requests are being refused: {}", error)))?;
+                }
+                self.state_manager.record_checkin_request_failed()
+                    .context("Failed to record failed checkin request")?;
             }
             OutputResult::Skipped(reason) => {
                 tracing::info!("Checkin request skipped: {}", reason);
-                self.state_manager.record_checkin_request()
-                    .context("Failed to record checkin request")?;
+                self.record_checkin_request(request)?;
             }
         }
 
         Ok(())
     }
 
-    async fn fire_last_signal(&mut self) -> Result<()> {
+    fn record_checkin_request(&mut self, request: CheckinRequest) -> Result<()> {
+        match request {
+            CheckinRequest::Initial => self.state_manager.record_checkin_request(),
+            CheckinRequest::Reminder(level) => self.state_manager.record_escalation_reminder(level),
+        }
+        .context("Failed to record checkin request")
+    }
+
+    /// Sends the last signal to every recipient it hasn't reached. The daemon
+    /// passes `throttle_retries` so each of those is tried at most once per
+    /// `recipient.output_retry_delay`.
+    async fn fire_last_signal(&mut self, force_renotify: bool, throttle_retries: bool) -> Result<Vec<(String, String, OutputResult)>> {
         tracing::warn!("Firing last signal to recipients");
 
-        let message = self.message_adapter.generate_last_signal_message()
+        let message = self.message_adapter.generate_last_signal_message(&self.message_context())
             .context("Failed to generate last signal message")?;
 
         let results = process_last_signal_outputs(
             &self.last_signal_output_configs,
             &self.last_signal_outputs,
             &message,
+            self.message_adapter.last_signal_message_format(),
             &mut self.state_manager,
+            LastSignalRun::Live {
+                min_renotify_interval: self.config.recipient.min_renotify_interval,
+                force_renotify,
+                output_retry_delay: throttle_retries.then_some(self.config.recipient.output_retry_delay),
+            },
+            self.config.recipient.acknowledgements.links(Utc::now()).as_ref(),
         ).await?;
 
         let mut success_count = 0;
@@ -232,15 +670,22 @@ impl LastSignalApp {
         let mut skip_count = 0;
         let mut already_notified_count = 0;
 
-        for (output_name, recipient_id, result) in results {
+        for (output_name, recipient_id, result) in &results {
             match result {
                 OutputResult::Success => {
                     success_count += 1;
                     tracing::info!("Last signal sent successfully to {} ({})", output_name, recipient_id);
+                    self.events.publish(Event::LastSignalSent {
+                        output: output_name.clone(),
+                        recipient_id: recipient_id.clone(),
+                    });
                 }
                 OutputResult::Failed(error) => {
                     failure_count += 1;
                     tracing::error!("Failed to send last signal to {} ({}): {}", output_name, recipient_id, error);
+                    if result.is_persistent_failure() {
+                        self.notify_warning(Warning::new(WarningCategory::Config, format!("The last signal to {} was refused: {}", recipient_id, error)))?;
+                    }
                 }
                 OutputResult::Skipped(reason) => {
                     if reason.contains("already notified") {
@@ -261,13 +706,86 @@ impl LastSignalApp {
             if already_notified_count > 0 {
                 tracing::info!("{} recipient(s) already notified, skipped to prevent spam", already_notified_count);
             }
-            self.state_manager.record_last_signal_fired()
-                .context("Failed to record last signal fired")?;
+            // Retries that reach more recipients don't move when it first went out
+            if self.state_manager.get_state().last_signal_fired.is_none() {
+                self.state_manager.record_last_signal_fired()
+                    .context("Failed to record last signal fired")?;
+            }
         } else {
             let error_msg = format!("All {} last signal output(s) failed or were skipped", failure_count + skip_count);
             tracing::error!("{}", error_msg);
         }
 
+        Ok(results)
+    }
+
+    /// Manually fires the last signal now, regardless of check-in timing
+    pub async fn fire(&mut self, force_renotify: bool) -> Result<()> {
+        if force_renotify {
+            tracing::warn!("Manual last signal fire with --force-renotify, ignoring min_renotify_interval");
+        }
+
+        let results = self.fire_last_signal(force_renotify, false).await?;
+        self.save_send_history()?;
+        self.events.close().await;
+
+        println!("Last signal results:");
+        print_last_signal_results(&results);
+
+        Ok(())
+    }
+
+    /// Sends the real last signal to the canary outputs only. Live recipient
+    /// tracking and `last_signal_fired` are left untouched, and no events are published.
+    pub async fn rehearse(&mut self) -> Result<()> {
+        if self.canary_outputs.is_empty() {
+            anyhow::bail!("No canary outputs configured. Add [[recipient.canary_outputs]] to rehearse the last signal.");
+        }
+        tracing::warn!("Rehearsing the last signal to {} canary output(s)", self.canary_outputs.len());
+
+        let message = self.message_adapter.generate_last_signal_message(&self.message_context())
+            .context("Failed to generate last signal message")?;
+
+        self.state_manager.start_rehearsal()
+            .context("Failed to reset canary recipient tracking")?;
+        // Acknowledgement links would record against live tracking, so they are left out
+        let results = process_last_signal_outputs(
+            &self.config.recipient.canary_outputs,
+            &self.canary_outputs,
+            &message,
+            self.message_adapter.last_signal_message_format(),
+            &mut self.state_manager,
+            LastSignalRun::Rehearsal,
+            None,
+        ).await?;
+        self.save_send_history()?;
+
+        let delivered = results.iter().filter(|(_, _, result)| result.is_success()).count();
+        let succeeded = delivered == results.len();
+        if succeeded {
+            self.state_manager.record_successful_rehearsal()
+                .context("Failed to record successful rehearsal")?;
+        }
+
+        AuditLog::new(self.config.get_data_directory().ok().as_deref()).record(Utc::now(), serde_json::json!({
+            "category": "rehearsal",
+            "succeeded": succeeded,
+            "results": results.iter().map(|(output_name, recipient_id, result)| serde_json::json!({
+                "output": output_name,
+                "recipient_id": recipient_id,
+                "sent": result.is_success(),
+                "error": result.error_message(),
+            })).collect::<Vec<_>>(),
+        }));
+
+        println!("Rehearsal results (canary recipients only):");
+        print_last_signal_results(&results);
+        if succeeded {
+            println!("✅ Rehearsal reached all {} canary recipient(s)", results.len());
+        } else {
+            println!("⚠️  Rehearsal reached {} of {} canary recipient(s)", delivered, results.len());
+        }
+
         Ok(())
     }
 
@@ -282,7 +800,7 @@ impl LastSignalApp {
         // Generate list of all recipient IDs
         let all_recipient_ids: Vec<String> = self.last_signal_output_configs
             .iter()
-            .map(|config| generate_recipient_id(config))
+            .flat_map(generate_recipient_ids)
             .collect();
             
         let pending_recipients = state.get_pending_last_signal_recipients(&all_recipient_ids);
@@ -310,6 +828,15 @@ impl LastSignalApp {
 
     pub async fn checkin(&mut self) -> Result<()> {
         tracing::info!("Recording manual checkin");
+        self.record_checkin("manual")?;
+        self.events.close().await;
+
+        println!("Checkin recorded successfully!");
+        Ok(())
+    }
+
+    /// Records a check-in made by the user rather than found by an output
+    fn record_checkin(&mut self, source: &str) -> Result<()> {
         self.state_manager.record_checkin()
             .context("Failed to record checkin")?;
         
@@ -317,144 +844,308 @@ impl LastSignalApp {
         self.state_manager.clear_last_signal_recipient_tracking()
             .context("Failed to clear last signal recipient tracking")?;
         
-        println!("Checkin recorded successfully!");
+        self.events.publish(Event::CheckinRecorded { source: source.to_string() });
+        Ok(())
+    }
+
+    /// Prints checkin outputs in the order they would be tried at `at`
+    /// (default now), and the last signal outputs
+    pub fn list_outputs(&self, at: Option<&str>) -> Result<()> {
+        let timezone = self.config.app.timezone;
+        let at = match at {
+            Some(at) => schedule::parse_local_time(at, timezone, Utc::now())?,
+            None => Utc::now(),
+        };
+        let local_time = self.config.app.local_time(at);
+
+        println!("Checkin outputs in the order they are tried at {}:", at.with_timezone(&timezone).format("%Y-%m-%d %H:%M %Z"));
+        let output_configs = &self.config.checkin.outputs;
+        for (position, i) in dispatch_order(output_configs.len(), output_configs, local_time).into_iter().enumerate() {
+            let output_config = &output_configs[i];
+            let hours = match output_config.active_hours {
+                Some(active_hours) if active_hours.contains(local_time) => format!("active {}", active_hours),
+                Some(active_hours) => format!("active {}, out of hours: fallback only", active_hours),
+                None => "always active".to_string(),
+            };
+            println!("  {}. {} (priority {}, {})", position + 1, output_config.output_type, output_config.priority, hours);
+        }
+
+        println!("\nLast signal outputs (every recipient is sent the last signal):");
+        for output_config in &self.last_signal_output_configs {
+            println!("  - {}", generate_recipient_id(output_config));
+        }
+
         Ok(())
     }
 
-    pub async fn status(&self) -> Result<()> {
+    /// Schedule details for messages sent now. A check-in request about to be
+    /// sent starts the deadline if nothing has yet, so that is assumed here.
+    /// Once a request is due, reminders repeat every check interval.
+    fn message_context(&self) -> MessageContext {
+        let now = Utc::now();
         let state = self.state_manager.get_state();
-        
-        println!("LastSignal Status:");
-        println!("==================");
-        
-        match state.last_checkin {
-            Some(checkin_time) => {
-                let days_since = state.days_since_last_checkin().unwrap_or(0);
-                println!("Last checkin: {} ({} days ago)", checkin_time.format("%Y-%m-%d %H:%M:%S UTC"), days_since);
-            }
-            None => println!("Last checkin: Never"),
+        let max_time = self.config.recipient.max_time_since_last_checkin;
+        let anchoring = self.config.app.anchoring();
+
+        MessageContext {
+            now,
+            deadline: Some(schedule::last_signal_deadline(state, max_time, anchoring)
+                .unwrap_or_else(|| schedule::add_duration(now, max_time))),
+            next_reminder: self.next_reminder(now),
+            timezone: self.config.app.timezone,
         }
+    }
 
-        match state.last_checkin_request {
-            Some(request_time) => {
-                let days_since = state.days_since_last_checkin_request().unwrap_or(0);
-                println!("Last checkin request: {} ({} days ago)", request_time.format("%Y-%m-%d %H:%M:%S UTC"), days_since);
-            }
-            None => println!("Last checkin request: Never"),
+    /// When the admin hears from LastSignal next if they don't check in
+    fn next_reminder(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let state = self.state_manager.get_state();
+        let checkin = &self.config.checkin;
+        let anchoring = self.config.app.anchoring();
+        if !checkin.escalation.is_empty() {
+            let first_request = state.first_unanswered_request.unwrap_or(now);
+            return schedule::next_escalation(first_request, &checkin.escalation, &state.escalation_levels_sent, anchoring, now)
+                .map(|(_, due)| due);
         }
+        Some(schedule::next_request_due(state, checkin.duration_between_checkins, anchoring)
+            .filter(|due| !schedule::is_reached(*due, now))
+            .unwrap_or_else(|| schedule::add_duration(now, self.config.app.check_interval)))
+    }
 
-        match state.last_signal_fired {
-            Some(signal_time) => {
-                let days_since = state.days_since_last_signal_fired().unwrap_or(0);
-                println!("Last signal fired: {} ({} days ago)", signal_time.format("%Y-%m-%d %H:%M:%S UTC"), days_since);
-            }
-            None => println!("Last signal fired: Never"),
+    fn review_due(&self) -> bool {
+        let last_review = schedule::last_configuration_review(self.state_manager.get_state(), self.config.modified_at());
+        schedule::review_due(last_review, self.config.app.review_reminder_interval, Utc::now())
+    }
+
+    /// Backs up the config file if it changed since it was last loaded, and
+    /// queues a mention of the change for the next check-in request
+    fn track_config_changes(&mut self) -> Result<()> {
+        let Some(path) = &self.config.source_path else {
+            return Ok(());
+        };
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file for backup: {:?}", path))?;
+        let data_directory = self.config.get_data_directory()?;
+        let now = Utc::now();
+
+        let previous_hash = self.state_manager.get_state().config_hash.clone();
+        let Some(change) = config_backup::backup_if_changed(&content, previous_hash.as_deref(), &data_directory, self.config.app.config_backups, now)? else {
+            return Ok(());
+        };
+
+        let description = change.summary.as_ref().map(|summary| {
+            format!("at {} ({})", now.with_timezone(&self.config.app.timezone).format("%Y-%m-%d %H:%M %Z"), summary)
+        });
+        if let Some(description) = &description {
+            tracing::warn!("Configuration changed {}, backed up to {:?}", description, change.backup_path);
+        } else {
+            tracing::info!("Configuration backed up to {:?}", change.backup_path);
         }
 
-        println!("Checkin request count: {}", state.checkin_request_count);
-        println!();
-        
-        println!("Configuration:");
-        println!("  Duration between checkins: {}", self.config.checkin.duration_between_checkins);
-        println!("  Output retry delay (checkin): {}", self.config.checkin.output_retry_delay);
-        println!("  Max time since last checkin: {}", self.config.recipient.max_time_since_last_checkin);
-        println!("  Output retry delay (last signal): {}", self.config.recipient.output_retry_delay);
+        AuditLog::new(Some(&data_directory)).record(now, serde_json::json!({
+            "category": "config_changed",
+            "message": change.summary.as_deref().unwrap_or("first configuration seen"),
+            "backup": change.backup_path,
+        }));
+
+        self.state_manager.record_config_change(change.hash, description)
+            .context("Failed to record configuration hash")
+    }
+
+    pub fn reviewed(&mut self) -> Result<()> {
+        self.state_manager.record_reviewed()
+            .context("Failed to record configuration review")?;
+        println!("Configuration review recorded. The next reminder is due in {}.", self.config.app.review_reminder_interval);
+        Ok(())
+    }
+
+    /// Prints a check-in URL that works without the bearer token until it expires
+    pub fn generate_checkin_url(&self, valid_for: crate::duration_parser::ConfigDuration) -> Result<()> {
+        let server = self.config.server.as_ref()
+            .context("Signed check-in URLs are served by the check-in server: add a [server] section first")?;
+        let secret = checkin_server::load_or_create_url_secret(&self.config.get_data_directory()?)?;
+        let expires = Utc::now() + chrono::Duration::seconds(valid_for.as_secs() as i64);
+        println!("{}", server.signed_checkin_url(&secret, expires));
+        eprintln!("Valid until {}. Opening it records a check-in while the daemon runs.", expires.with_timezone(&self.config.app.timezone).format("%Y-%m-%d %H:%M %Z"));
+        Ok(())
+    }
+
+    pub fn validate(&mut self) -> Result<()> {
+        self.track_config_changes()?;
+        let warnings = self.config.audit_warnings();
+
+        println!("✅ Configuration is valid");
         println!("  Checkin outputs: {}", self.checkin_outputs.len());
         println!("  Last signal outputs: {}", self.last_signal_outputs.len());
-        
-        println!();
-        
-        // Show what actions would be taken
-        if self.state_manager.get_state().should_request_checkin(self.config.checkin.duration_between_checkins) {
-            println!("⚠️  Checkin request would be sent if running");
-        } else {
-            println!("✅ Checkin is up to date");
+        if !self.canary_outputs.is_empty() {
+            println!("  Canary outputs: {}", self.canary_outputs.len());
+        }
+
+        match std::fs::read_to_string(self.config.get_message_file_path()?) {
+            Ok(content) if content.trim().is_empty() => {
+                println!("⚠️  Last signal message file is empty, the built-in default message would be sent");
+            }
+            Ok(content) => {
+                let content = content.trim();
+                let preview: String = content.chars().take(80).collect();
+                let ellipsis = if content.chars().count() > 80 { "..." } else { "" };
+                println!("  Last signal message: {} characters, starting \"{}{}\"", content.chars().count(), preview.replace('\n', " "), ellipsis);
+            }
+            Err(_) => {
+                println!("  Last signal message file not found, the built-in default message would be created and sent");
+            }
         }
 
-        if self.state_manager.get_state().should_fire_last_signal(self.config.recipient.max_time_since_last_checkin) 
-            && !self.state_manager.get_state().has_fired_last_signal_recently(self.config.recipient.max_time_since_last_checkin) {
-            println!("🚨 Last signal would be fired if running");
+        for output_config in &self.last_signal_output_configs {
+            if output_config.config.contains_key("encrypt_to") {
+                println!("  🔒 Last signal to {} will be encrypted to its age key", generate_recipient_id(output_config));
+            }
+        }
+
+        if warnings.is_empty() {
+            println!("No warnings");
         } else {
-            println!("✅ Last signal not needed");
+            println!();
+            println!("⚠️  {} warning(s):", warnings.len());
+            for warning in &warnings {
+                println!("  - {}", warning);
+            }
         }
 
         Ok(())
     }
 
-    pub async fn test_outputs(&self) -> Result<()> {
+    pub async fn status(&self, view: StatusView) -> Result<()> {
+        let report = StatusReport::new(&self.config, self.state_manager.get_state());
+
+        match view {
+            StatusView::Json => {
+                let output = serde_json::to_string_pretty(&report)
+                    .context("Failed to serialize status report to JSON")?;
+                println!("{}", output);
+                return Ok(());
+            }
+            StatusView::Short => {
+                println!("{}", report.render_short(Utc::now()));
+                return Ok(());
+            }
+            StatusView::Template(template) => {
+                println!("{}", report.render_template(&template, Utc::now()));
+                return Ok(());
+            }
+            StatusView::Text => {}
+        }
+
+        print!("{}", report.render_text(Utc::now()));
+
+        Ok(())
+    }
+
+    /// Wraps every output in a fault injector for rehearsing failures. Only
+    /// `lastsignal test` takes a chaos spec, so the daemon never runs with one.
+    pub fn inject_chaos(&mut self, spec: &ChaosSpec) {
+        tracing::warn!("Injecting faults into outputs: {}", spec);
+        println!("🧪 Chaos: {}\n", spec);
+
+        self.checkin_outputs = std::mem::take(&mut self.checkin_outputs).into_iter()
+            .zip(&self.config.checkin.outputs)
+            .map(|(output, output_config)| {
+                Box::new(ChaosOutput::new(output, spec.faults_for(&output_config.output_type))) as Box<dyn BidirectionalOutput>
+            })
+            .collect();
+        self.last_signal_outputs = std::mem::take(&mut self.last_signal_outputs).into_iter()
+            .zip(&self.last_signal_output_configs)
+            .map(|(output, output_config)| {
+                Box::new(ChaosOutput::new(output, spec.faults_for(&output_config.output_type))) as Box<dyn Output>
+            })
+            .collect();
+    }
+
+    pub async fn test_outputs(&mut self, send: bool) -> Result<()> {
         println!("Testing checkin outputs...");
-        for (i, output) in self.checkin_outputs.iter().enumerate() {
+        let mut all_sent = true;
+        for (i, (output, output_config)) in self.checkin_outputs.iter().zip(&self.config.checkin.outputs).enumerate() {
             print!("  {} ({}): ", i + 1, output.get_name());
             match output.health_check().await {
                 Ok(true) => println!("✅ Healthy"),
                 Ok(false) => println!("❌ Unhealthy"),
                 Err(e) => println!("💥 Error: {}", e),
             }
+
+            if send {
+                let message = wrap_message(TEST_MESSAGE, output_config);
+                match output.send_message(&message).await {
+                    Ok(OutputResult::Success) => println!("      ✅ Test message sent"),
+                    Ok(OutputResult::Failed(error)) => {
+                        all_sent = false;
+                        println!("      ❌ Test message failed: {}", error);
+                    }
+                    Ok(OutputResult::Skipped(reason)) => {
+                        all_sent = false;
+                        println!("      ⏭️  Test message skipped: {}", reason);
+                    }
+                    Err(e) => {
+                        all_sent = false;
+                        println!("      💥 Test message error: {}", e);
+                    }
+                }
+            }
+        }
+
+        if send && all_sent {
+            self.state_manager.record_test_send()
+                .context("Failed to record test send")?;
         }
 
         println!("\nTesting last signal outputs...");
-        for (i, output) in self.last_signal_outputs.iter().enumerate() {
+        for (i, (output, output_config)) in self.last_signal_outputs.iter().zip(&self.last_signal_output_configs).enumerate() {
             print!("  {} ({}): ", i + 1, output.get_name());
             match output.health_check().await {
                 Ok(true) => println!("✅ Healthy"),
                 Ok(false) => println!("❌ Unhealthy"),
                 Err(e) => println!("💥 Error: {}", e),
             }
+
+            // Console outputs reach nobody, so they can show the test message safely
+            if send && output_config.output_type == "console" {
+                let message = wrap_message(TEST_MESSAGE, output_config);
+                if let Err(e) = output.send_message(&message).await {
+                    println!("      💥 Test message error: {}", e);
+                }
+            }
         }
 
         Ok(())
     }
 
-    async fn send_message_via_bidirectional_outputs(&self, message: &str) -> Result<OutputResult> {
-        if self.checkin_outputs.is_empty() {
-            return Ok(OutputResult::Failed("No checkin outputs configured".to_string()));
-        }
+    /// Once the last signal has fired, a reply from an email recipient of it
+    /// acknowledges it rather than counting as a check-in. Returns the rest.
+    fn take_acknowledgement_replies(&mut self, responses: Vec<CheckinResponse>) -> Result<Vec<CheckinResponse>> {
+        let Some(fired) = self.state_manager.get_state().last_signal_fired else {
+            return Ok(responses);
+        };
+
+        let recipients: Vec<(String, String)> = self.last_signal_output_configs.iter()
+            .filter(|output_config| matches!(output_config.output_type.as_str(), "email" | "email_mailgun" | "email_ses"))
+            .flat_map(generate_recipient_ids)
+            .filter_map(|recipient_id| recipient_id.split_once(':')
+                .map(|(_, to)| (acknowledgement::sender_address(to), recipient_id.clone())))
+            .collect();
 
-        for (i, output) in self.checkin_outputs.iter().enumerate() {
-            tracing::info!("Attempting to send message via {}", output.get_name());
-            
-            let health_ok = match output.health_check().await {
-                Ok(healthy) => {
-                    if !healthy {
-                        tracing::warn!("Health check failed for {}, skipping", output.get_name());
-                        false
-                    } else {
-                        true
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Health check error for {}: {}, skipping", output.get_name(), e);
-                    false
+        let mut remaining = Vec::new();
+        for response in responses {
+            let recipient_id = match &response {
+                CheckinResponse::Found { from, .. } => {
+                    let sender = acknowledgement::sender_address(from);
+                    recipients.iter().find(|(address, _)| *address == sender).map(|(_, id)| id.clone())
                 }
             };
 
-            if !health_ok {
-                continue;
-            }
-
-            match output.send_message(message).await {
-                Ok(OutputResult::Success) => {
-                    tracing::info!("Message sent successfully via {}", output.get_name());
-                    return Ok(OutputResult::Success);
-                }
-                Ok(OutputResult::Failed(error)) => {
-                    tracing::warn!("Failed to send message via {}: {}", output.get_name(), error);
-                }
-                Ok(OutputResult::Skipped(reason)) => {
-                    tracing::info!("Message sending skipped via {}: {}", output.get_name(), reason);
-                    return Ok(OutputResult::Skipped(reason));
-                }
-                Err(e) => {
-                    tracing::error!("Error sending message via {}: {}", output.get_name(), e);
-                }
-            }
-
-            if i < self.checkin_outputs.len() - 1 {
-                tracing::info!("Trying next output immediately due to failure");
+            match recipient_id {
+                Some(recipient_id) => self.record_acknowledgement(Acknowledgement { recipient_id, issued_at: fired }, "email reply")?,
+                None => remaining.push(response),
             }
         }
-
-        Ok(OutputResult::Failed("All checkin outputs failed".to_string()))
+        Ok(remaining)
     }
 
     async fn process_bidirectional_checkins(&mut self) -> Result<()> {
@@ -469,6 +1160,7 @@ impl LastSignalApp {
         
         match process_bidirectional_outputs_for_checkins(&self.checkin_outputs, since).await {
             Ok(responses) => {
+                let responses = self.take_acknowledgement_replies(responses)?;
                 if !responses.is_empty() {
                     tracing::info!("Found {} potential checkin responses", responses.len());
                     
@@ -477,26 +1169,30 @@ impl LastSignalApp {
                     sorted_responses.sort_by_key(|r| {
                         match r {
                             crate::outputs::bidirectional::CheckinResponse::Found { timestamp, .. } => *timestamp,
-                            crate::outputs::bidirectional::CheckinResponse::None => chrono::Utc::now(),
                         }
                     });
                     
-                    if let Some(latest_response) = sorted_responses.last() {
-                        if let crate::outputs::bidirectional::CheckinResponse::Found { timestamp, subject, from } = latest_response {
-                            tracing::info!("Processing checkin response from {} at {}: {}", from, timestamp, subject);
-                            
-                            // Record the checkin
-                            self.state_manager.record_checkin()
-                                .context("Failed to record checkin from bidirectional response")?;
-                            
-                            // Mark all responses as processed up to this timestamp
-                            mark_all_processed_until(&self.checkin_outputs, *timestamp).await?;
+                    if let Some(crate::outputs::bidirectional::CheckinResponse::Found { timestamp, subject, from }) = sorted_responses.last() {
+                        if redact::sensitive_payloads_enabled() {
+                            tracing::trace!("Processing checkin response from {} at {}: {}", from, timestamp, subject);
+                        } else {
+                            tracing::info!("Processing checkin response at {}", timestamp);
                         }
+                        
+                        self.record_checkin(from)
+                            .context("Failed to record checkin from bidirectional response")?;
+                        
+                        // Mark all responses as processed up to this timestamp
+                        mark_all_processed_until(&self.checkin_outputs, *timestamp).await?;
                     }
                 }
             }
             Err(e) => {
                 tracing::warn!("Error checking for bidirectional responses: {}", e);
+                self.notify_warning(Warning::new(
+                    WarningCategory::UnhealthyOutput,
+                    format!("Checking for check-in replies failed: {}", e),
+                ))?;
             }
         }
         
@@ -504,11 +1200,21 @@ impl LastSignalApp {
     }
 }
 
+fn print_last_signal_results(results: &[(String, String, OutputResult)]) {
+    for (output_name, recipient_id, result) in results {
+        match result {
+            OutputResult::Success => println!("  ✅ {} ({}): sent", output_name, recipient_id),
+            OutputResult::Failed(error) => println!("  ❌ {} ({}): failed - {}", output_name, recipient_id, error),
+            OutputResult::Skipped(reason) => println!("  ⏭️  {} ({}): skipped - {}", output_name, recipient_id, reason),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::AppState;
     use tempfile::tempdir;
-    use std::io::Write;
 
     async fn create_test_app() -> Result<LastSignalApp> {
         let temp_dir = tempdir()?;
@@ -545,11 +1251,7 @@ check_interval = "1h"
         let config_path = config_dir.join("config.toml");
         std::fs::write(&config_path, config_content.replace("{}", &config_dir.to_string_lossy()))?;
 
-        // Temporarily set the config path for testing
-        unsafe { std::env::set_var("HOME", temp_dir.path()); }
-        
-        let app = LastSignalApp::new().await?;
-        Ok(app)
+        LastSignalApp::from_config(Config::load_from_path(&config_path)?).await
     }
 
     #[tokio::test]
@@ -561,4 +1263,476 @@ check_interval = "1h"
         assert_eq!(app.checkin_outputs.len(), 1);
         assert_eq!(app.last_signal_outputs.len(), 1);
     }
+
+    /// An app whose outputs all print to the console, so whole cycles run without network access
+    fn console_app_config(data_directory: &std::path::Path) -> Result<Config> {
+        std::fs::write(data_directory.join("message.txt"), "Goodbye, and thanks for everything")?;
+        let config_content = format!(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+
+[[checkin.outputs]]
+type = "console"
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+
+[[recipient.last_signal_outputs]]
+type = "console"
+config = {{ name = "alice" }}
+
+[last_signal]
+adapter_type = "file"
+message_file = "{dir}/message.txt"
+
+[app]
+data_directory = "{dir}"
+log_level = "info"
+check_interval = "1h"
+
+[app.connectivity]
+probe_hosts = []
+"#, dir = data_directory.to_string_lossy());
+
+        let config_path = data_directory.join("config.toml");
+        std::fs::write(&config_path, config_content)?;
+        Config::load_from_path(&config_path)
+    }
+
+    #[tokio::test]
+    async fn test_run_cycle_with_console_outputs() {
+        let temp_dir = tempdir().unwrap();
+
+        // A fresh install asks for its first check-in and nothing else
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        assert!(matches!(app.run_cycle().await.unwrap(), CycleOutcome::Completed));
+        let state = app.state_manager.get_state();
+        assert!(state.last_checkin_request.is_some());
+        assert!(state.last_signal_fired.is_none());
+
+        // Once the deadline has passed, the last signal goes to every recipient
+        let mut state = state.clone();
+        state.last_checkin = Some(Utc::now() - chrono::Duration::days(30));
+        state.last_checkin_request = Some(Utc::now() - chrono::Duration::days(23));
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        assert!(matches!(app.run_cycle().await.unwrap(), CycleOutcome::Completed));
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_fired.is_some());
+        assert!(state.is_last_signal_recipient_already_notified("console:alice"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_checkin_request_is_retried_after_output_retry_delay() {
+        let temp_dir = tempdir().unwrap();
+        let failing_app = || async {
+            let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+            app.inject_chaos(&ChaosSpec::parse("console:fail", Utc::now()).unwrap());
+            app
+        };
+
+        // A request that fails everywhere isn't recorded as sent
+        let mut app = failing_app().await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        let failed_at = state.checkin_request_failed_at.unwrap();
+        assert!(state.last_checkin_request.is_none());
+
+        // Nor is it retried on every cycle
+        app.run_cycle().await.unwrap();
+        assert_eq!(app.state_manager.get_state().checkin_request_failed_at, Some(failed_at));
+
+        // Once output_retry_delay ("24h") has passed it's tried again, and
+        // again after each failure
+        let mut state = app.state_manager.get_state().clone();
+        state.checkin_request_failed_at = Some(Utc::now() - chrono::Duration::hours(25));
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = failing_app().await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.checkin_request_failed_at.unwrap() > Utc::now() - chrono::Duration::minutes(1));
+        assert!(state.last_checkin_request.is_none());
+
+        let mut state = state.clone();
+        state.checkin_request_failed_at = Some(Utc::now() - chrono::Duration::hours(23));
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        app.run_cycle().await.unwrap();
+        assert!(app.state_manager.get_state().last_checkin_request.is_none());
+
+        // The retry that gets through counts as the request
+        let mut state = app.state_manager.get_state().clone();
+        state.checkin_request_failed_at = Some(Utc::now() - chrono::Duration::hours(25));
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.last_checkin_request.is_some());
+        assert!(state.checkin_request_failed_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_escalation_reminders_are_sent_once_each() {
+        let temp_dir = tempdir().unwrap();
+        let escalating_app = || async {
+            let mut config = console_app_config(temp_dir.path()).unwrap();
+            config.checkin.escalation = ["2d", "4d", "6d"].iter().map(|offset| offset.parse().unwrap()).collect();
+            LastSignalApp::from_config(config).await.unwrap()
+        };
+        // Moves the first unanswered request back in time, as if `age` had passed
+        let requested_ago = |app: &LastSignalApp, age: chrono::Duration| {
+            let mut state = app.state_manager.get_state().clone();
+            state.first_unanswered_request = Some(Utc::now() - age);
+            state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        };
+
+        let state = AppState {
+            last_checkin: Some(Utc::now() - chrono::Duration::days(8)),
+            ..AppState::default()
+        };
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+
+        // The overdue check-in gets one request, and nothing more until the first level
+        let mut app = escalating_app().await;
+        app.run_cycle().await.unwrap();
+        app.run_cycle().await.unwrap();
+        assert_eq!(app.state_manager.get_state().checkin_request_count, 1);
+        assert!(app.state_manager.get_state().escalation_levels_sent.is_empty());
+
+        for (level, days) in [(1usize, 2), (2, 4), (3, 6)] {
+            requested_ago(&app, chrono::Duration::days(days) + chrono::Duration::minutes(1));
+            app = escalating_app().await;
+            app.run_cycle().await.unwrap();
+            app.run_cycle().await.unwrap();
+            let state = app.state_manager.get_state();
+            assert_eq!(state.checkin_request_count as usize, 1 + level, "level {}", level);
+            assert_eq!(state.escalation_levels_sent, (1..=level).collect());
+        }
+
+        // After the last level nothing more is sent before the last signal
+        requested_ago(&app, chrono::Duration::days(6) + chrono::Duration::hours(12));
+        let mut app = escalating_app().await;
+        app.run_cycle().await.unwrap();
+        assert_eq!(app.state_manager.get_state().checkin_request_count, 4);
+
+        // A check-in starts the next overdue period from scratch
+        app.state_manager.record_checkin().unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.first_unanswered_request.is_none());
+        assert!(state.escalation_levels_sent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_retries_recipients_it_missed() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("last_signal.txt");
+        let mut file_output = console_app_config(temp_dir.path()).unwrap().recipient.last_signal_outputs.remove(0);
+        file_output.output_type = "file".to_string();
+        file_output.config = [("path".to_string(), file_path.display().to_string())].into();
+        let file_recipient = generate_recipient_id(&file_output);
+        let data_directory = temp_dir.path();
+        let app_with = |chaos: Option<&'static str>| {
+            let file_output = file_output.clone();
+            async move {
+                let mut config = console_app_config(data_directory).unwrap();
+                config.recipient.last_signal_outputs.push(file_output);
+                let mut app = LastSignalApp::from_config(config).await.unwrap();
+                if let Some(chaos) = chaos {
+                    app.inject_chaos(&ChaosSpec::parse(chaos, Utc::now()).unwrap());
+                }
+                app
+            }
+        };
+        let last_attempted_ago = |app: &LastSignalApp, age: chrono::Duration| {
+            let mut state = app.state_manager.get_state().clone();
+            state.last_signal_recipient_attempts.insert(file_recipient.clone(), Utc::now() - age);
+            state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        };
+
+        let state = AppState {
+            last_checkin: Some(Utc::now() - chrono::Duration::days(30)),
+            last_checkin_request: Some(Utc::now() - chrono::Duration::days(23)),
+            ..AppState::default()
+        };
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+
+        // Alice is reached while the file output is down
+        let mut app = app_with(Some("file:fail")).await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        let fired = state.last_signal_fired.unwrap();
+        assert!(state.is_last_signal_recipient_already_notified("console:alice"));
+        assert!(!state.is_last_signal_recipient_already_notified(&file_recipient));
+        let attempted = state.last_signal_recipient_attempts[&file_recipient];
+
+        // The next cycle waits for output_retry_delay ("12h")
+        app.run_cycle().await.unwrap();
+        assert_eq!(app.state_manager.get_state().last_signal_recipient_attempts[&file_recipient], attempted);
+
+        // After it, only the missed recipient is tried again
+        last_attempted_ago(&app, chrono::Duration::hours(13));
+        let mut app = app_with(Some("file:fail")).await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_recipient_attempts[&file_recipient] > attempted);
+        assert!(!state.is_last_signal_recipient_already_notified(&file_recipient));
+        let alice_notified_at = state.last_signal_recipients_notified["console:alice"];
+
+        // Once the output recovers the retry gets through
+        last_attempted_ago(&app, chrono::Duration::hours(13));
+        let mut app = app_with(None).await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.is_last_signal_recipient_already_notified(&file_recipient));
+        assert_eq!(state.last_signal_recipients_notified["console:alice"], alice_notified_at);
+        assert!(std::fs::read_to_string(&file_path).unwrap().contains("Goodbye, and thanks for everything"));
+        // It still went out when Alice was reached
+        assert_eq!(state.last_signal_fired, Some(fired));
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_waits_out_min_renotify_interval_quietly() {
+        let temp_dir = tempdir().unwrap();
+        let notified_ago = |age: chrono::Duration| {
+            // Alice was sent the last time's last signal, then the admin checked in
+            let state = AppState {
+                last_checkin: Some(Utc::now() - chrono::Duration::days(15)),
+                last_checkin_request: Some(Utc::now() - chrono::Duration::days(8)),
+                last_signal_recipient_history: [("console:alice".to_string(), Utc::now() - age)].into(),
+                ..AppState::default()
+            };
+            state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        };
+
+        // Within min_renotify_interval ("24h") nothing is due, cycle after cycle
+        notified_ago(chrono::Duration::hours(1));
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        for _ in 0..3 {
+            assert!(!app.should_fire_last_signal().await.unwrap());
+            app.run_cycle().await.unwrap();
+        }
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_fired.is_none());
+        assert!(state.last_signal_recipient_attempts.is_empty());
+
+        // Once it has passed, Alice is sent the last signal again
+        notified_ago(chrono::Duration::hours(25));
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        assert!(app.should_fire_last_signal().await.unwrap());
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_fired.is_some());
+        assert!(state.is_last_signal_recipient_already_notified("console:alice"));
+    }
+
+    #[tokio::test]
+    async fn test_warning_before_last_signal_is_sent_once() {
+        let temp_dir = tempdir().unwrap();
+        let warning_app = || async {
+            let mut config = console_app_config(temp_dir.path()).unwrap();
+            config.recipient.warn_before_last_signal = Some("24h".parse().unwrap());
+            LastSignalApp::from_config(config).await.unwrap()
+        };
+        let checked_in_ago = |app: &LastSignalApp, ago: chrono::Duration| {
+            let mut state = app.state_manager.get_state().clone();
+            state.last_checkin = Some(Utc::now() - ago);
+            state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        };
+
+        // An hour into the warning window the admin is warned, once
+        let state = AppState {
+            last_checkin: Some(Utc::now() - chrono::Duration::days(13) - chrono::Duration::hours(1)),
+            ..AppState::default()
+        };
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = warning_app().await;
+        app.run_cycle().await.unwrap();
+        let warned_at = app.state_manager.get_state().last_signal_warning_sent.unwrap();
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert_eq!(state.last_signal_warning_sent, Some(warned_at));
+        assert!(state.last_signal_fired.is_none());
+
+        // At the fire time the last signal goes out and the warning isn't repeated
+        checked_in_ago(&app, chrono::Duration::days(14) + chrono::Duration::minutes(1));
+        let mut app = warning_app().await;
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_fired.is_some());
+        assert_eq!(state.last_signal_warning_sent, Some(warned_at));
+    }
+
+    #[tokio::test]
+    async fn test_warning_returns_after_a_checkin_by_reply() {
+        let temp_dir = tempdir().unwrap();
+        let notes = temp_dir.path().join("notes.md");
+        let (data_directory, notes_path) = (temp_dir.path(), notes.as_path());
+        let warning_app = move |activity: bool| {
+            async move {
+                let mut config = console_app_config(data_directory).unwrap();
+                config.recipient.warn_before_last_signal = Some("24h".parse().unwrap());
+                if activity {
+                    let mut file_activity = config.checkin.outputs[0].clone();
+                    file_activity.output_type = "file_activity".to_string();
+                    file_activity.config = [("paths".to_string(), notes_path.display().to_string())].into();
+                    config.checkin.outputs.push(file_activity);
+                }
+                LastSignalApp::from_config(config).await.unwrap()
+            }
+        };
+
+        let state = AppState {
+            last_checkin: Some(Utc::now() - chrono::Duration::days(15)),
+            ..AppState::default()
+        };
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = warning_app(false).await;
+        app.run_cycle().await.unwrap();
+        assert!(app.state_manager.get_state().last_signal_fired.is_some());
+
+        // The admin turns up through a check-in output rather than `lastsignal checkin`
+        std::fs::write(&notes, "back from the mountains").unwrap();
+        let mut app = warning_app(true).await;
+        app.process_bidirectional_checkins().await.unwrap();
+        let mut state = app.state_manager.get_state().clone();
+        assert!(state.last_checkin.is_some_and(|checkin| checkin > Utc::now() - chrono::Duration::minutes(1)));
+        assert!(state.last_signal_fired.is_none());
+
+        // So the next overdue period is warned about again
+        state.last_checkin = Some(Utc::now() - chrono::Duration::days(13) - chrono::Duration::hours(1));
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+        let mut app = warning_app(false).await;
+        app.run_cycle().await.unwrap();
+        assert!(app.state_manager.get_state().last_signal_warning_sent.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_no_warning_once_the_last_signal_is_due() {
+        let temp_dir = tempdir().unwrap();
+        let mut config = console_app_config(temp_dir.path()).unwrap();
+        config.recipient.warn_before_last_signal = Some("24h".parse().unwrap());
+        // The daemon was down for the whole warning window
+        let state = AppState {
+            last_checkin: Some(Utc::now() - chrono::Duration::days(15)),
+            ..AppState::default()
+        };
+        state.save_to_path(temp_dir.path().join("state.json")).unwrap();
+
+        let mut app = LastSignalApp::from_config(config).await.unwrap();
+        app.run_cycle().await.unwrap();
+        let state = app.state_manager.get_state();
+        assert!(state.last_signal_fired.is_some());
+        assert!(state.last_signal_warning_sent.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_during_sleep_exits_promptly() {
+        let temp_dir = tempdir().unwrap();
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        let (trigger, shutdown) = Shutdown::channel();
+
+        // The first cycle finishes in moments, then the daemon sleeps for check_interval ("1h")
+        let started = Instant::now();
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(app.run(shutdown), async {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                trigger.send(true).unwrap();
+            })
+        }).await.expect("the daemon should stop without waiting out its sleep");
+        result.unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        // The first cycle's request was sent and saved before stopping
+        let state = AppState::load_from_path(temp_dir.path().join("state.json")).unwrap();
+        assert!(state.last_checkin_request.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_daemon_pings_monitoring_after_each_cycle() {
+        use axum::{extract::State, http::Uri, Router};
+        use std::sync::Mutex;
+
+        let pings: Arc<Mutex<Vec<String>>> = Arc::default();
+        let service = Router::new()
+            .fallback(|State(pings): State<Arc<Mutex<Vec<String>>>>, uri: Uri| async move {
+                pings.lock().unwrap().push(uri.to_string());
+            })
+            .with_state(pings.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, service).await.unwrap() });
+
+        let temp_dir = tempdir().unwrap();
+        let mut config = console_app_config(temp_dir.path()).unwrap();
+        config.monitoring = Some(crate::monitoring::MonitoringConfig {
+            ping_url: format!("http://{}/ping/abc", address),
+            timeout: "5s".parse().unwrap(),
+            ping_start: true,
+        });
+        let mut app = LastSignalApp::from_config(config).await.unwrap();
+        let (trigger, shutdown) = Shutdown::channel();
+
+        let (result, ()) = tokio::join!(app.run(shutdown), async {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            trigger.send(true).unwrap();
+        });
+        result.unwrap();
+        assert_eq!(*pings.lock().unwrap(), ["/ping/abc/start", "/ping/abc"]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_skips_the_rest_of_a_cycle() {
+        let temp_dir = tempdir().unwrap();
+        let mut app = LastSignalApp::from_config(console_app_config(temp_dir.path()).unwrap()).await.unwrap();
+        let (trigger, shutdown) = Shutdown::channel();
+        app.shutdown = shutdown;
+        trigger.send(true).unwrap();
+
+        assert!(matches!(app.run_cycle().await.unwrap(), CycleOutcome::Interrupted));
+        assert!(app.state_manager.get_state().last_checkin_request.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_checkin_server_records_checkins() {
+        let temp_dir = tempdir().unwrap();
+        let mut config = console_app_config(temp_dir.path()).unwrap();
+        config.server = Some(checkin_server::ServerConfig {
+            bind: "127.0.0.1".to_string(),
+            port: 0,
+            token: "0123456789abcdef".to_string(),
+            public_url: None,
+        });
+
+        let mut app = LastSignalApp::from_config(config).await.unwrap();
+        app.state_manager.record_last_signal_recipient_notified("console:alice").unwrap();
+        app.checkin_server = checkin_server::start_server(app.config.server.as_ref(), temp_dir.path(), None).await.unwrap();
+        let address = app.checkin_server.as_ref().unwrap().local_addr;
+
+        let client = async {
+            let client = reqwest::Client::new();
+            let checkin = client.post(format!("http://{}/checkin", address))
+                .bearer_auth("0123456789abcdef")
+                .send().await.unwrap();
+            let status: serde_json::Value = client.get(format!("http://{}/status", address))
+                .bearer_auth("0123456789abcdef")
+                .send().await.unwrap()
+                .json().await.unwrap();
+            let unauthorized = client.get(format!("http://{}/status", address)).send().await.unwrap();
+            (checkin.status(), status, unauthorized.status())
+        };
+        let (idle, (checkin, status, unauthorized)) = tokio::join!(app.idle(Duration::from_millis(500)), client);
+        idle.unwrap();
+
+        assert_eq!(checkin, reqwest::StatusCode::OK);
+        assert!(status["last_checkin"].is_string());
+        assert_eq!(unauthorized, reqwest::StatusCode::UNAUTHORIZED);
+
+        // Like `lastsignal checkin`, recipients are forgotten so a future last signal reaches them again
+        let state = app.state_manager.get_state();
+        assert!(state.last_checkin.is_some());
+        assert!(!state.is_last_signal_recipient_already_notified("console:alice"));
+    }
 }
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Append-only JSON lines record of notable events in the data directory.
+/// Writing is best effort: a failure is logged but never stops the caller.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: Option<PathBuf>,
+}
+
+impl AuditLog {
+    pub fn new(data_directory: Option<&Path>) -> Self {
+        Self {
+            path: data_directory.map(|dir| dir.join("audit.log")),
+        }
+    }
+
+    /// Appends `fields` (a JSON object) with a `timestamp` added
+    pub fn record(&self, now: DateTime<Utc>, mut fields: Value) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        if let Some(object) = fields.as_object_mut() {
+            object.insert("timestamp".to_string(), serde_json::json!(now));
+        }
+
+        let result = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", fields));
+        if let Err(e) = result {
+            tracing::error!("Failed to write to audit log {:?}: {}", path, e);
+        }
+    }
+}
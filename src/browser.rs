@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Opens a URL in a browser, for auth commands
+pub trait BrowserOpener {
+    fn open(&self, url: &str) -> Result<()>;
+}
+
+/// The desktop's default browser, via `open`, `xdg-open` or `start`
+pub struct SystemBrowser;
+
+impl BrowserOpener for SystemBrowser {
+    fn open(&self, url: &str) -> Result<()> {
+        // Without a display, xdg-open falls back to a terminal browser that
+        // would take over the terminal the auth command is prompting in
+        if cfg!(all(unix, not(target_os = "macos"))) && !has_display(|name| std::env::var_os(name).is_some()) {
+            anyhow::bail!("no graphical display");
+        }
+
+        let (program, args): (&str, &[&str]) = if cfg!(target_os = "macos") {
+            ("open", &[])
+        } else if cfg!(windows) {
+            // The empty argument is start's window title
+            ("cmd", &["/C", "start", ""])
+        } else {
+            ("xdg-open", &[])
+        };
+        let mut child = Command::new(program)
+            .args(args)
+            .arg(url)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .with_context(|| format!("Failed to run {}", program))?;
+
+        // Some openers only return once the browser closes, so don't wait here
+        tokio::spawn(async move {
+            match child.wait().await {
+                Ok(status) if status.success() => tracing::debug!("{} opened the browser", program),
+                Ok(status) => tracing::warn!("{} couldn't open a browser ({}), open the URL yourself", program, status),
+                Err(e) => tracing::warn!("Failed to wait for {}: {}", program, e),
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Whether an X11 or Wayland display is available, given a check for
+/// whether an environment variable is set
+fn has_display(is_set: impl Fn(&str) -> bool) -> bool {
+    is_set("DISPLAY") || is_set("WAYLAND_DISPLAY")
+}
+
+/// Tries to open `url`, logging rather than failing when it can't, since
+/// the URL has been printed too. Returns whether the browser was launched.
+pub fn try_open(opener: &dyn BrowserOpener, url: &str) -> bool {
+    match opener.open(url) {
+        Ok(()) => {
+            tracing::info!("Opened the authorization URL in your browser");
+            true
+        }
+        Err(e) => {
+            tracing::info!("Couldn't open a browser ({}), open the URL above yourself", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingBrowser {
+        opened: Mutex<Vec<String>>,
+    }
+
+    impl BrowserOpener for RecordingBrowser {
+        fn open(&self, url: &str) -> Result<()> {
+            self.opened.lock().unwrap().push(url.to_string());
+            Ok(())
+        }
+    }
+
+    struct MissingBrowser;
+
+    impl BrowserOpener for MissingBrowser {
+        fn open(&self, _url: &str) -> Result<()> {
+            anyhow::bail!("xdg-open not found")
+        }
+    }
+
+    #[test]
+    fn test_try_open() {
+        let browser = RecordingBrowser::default();
+        assert!(try_open(&browser, "https://api.prod.whoop.com/oauth/oauth2/auth?state=abc"));
+        assert_eq!(*browser.opened.lock().unwrap(), vec!["https://api.prod.whoop.com/oauth/oauth2/auth?state=abc"]);
+
+        // A failed launch is only logged
+        assert!(!try_open(&MissingBrowser, "https://api.prod.whoop.com/oauth/oauth2/auth"));
+    }
+
+    #[test]
+    fn test_has_display() {
+        assert!(has_display(|name| name == "DISPLAY"));
+        assert!(has_display(|name| name == "WAYLAND_DISPLAY"));
+        // e.g. an SSH session or a container
+        assert!(!has_display(|_| false));
+    }
+}
@@ -0,0 +1,457 @@
+use anyhow::{bail, Context, Result};
+use axum::{
+    body::Bytes,
+    extract::{Path as UrlPath, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::{get, post},
+    Router,
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::net::SocketAddr;
+use std::path::Path;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::outputs::whoop_webhook::WhoopWebhook;
+use crate::signing::hmac_sha256;
+use crate::status::StatusReport;
+
+/// Requests received but not yet answered by the daemon
+const QUEUE_CAPACITY: usize = 16;
+
+/// Signs check-in URLs, created in the data directory on first use
+const URL_SECRET_FILE: &str = "checkin_url_secret";
+
+/// The `[server]` section: an HTTP endpoint for checking in from a phone or script
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServerConfig {
+    /// Address to bind, default 127.0.0.1. Use 0.0.0.0 behind a reverse proxy or VPN.
+    #[serde(default = "default_bind")]
+    pub bind: String,
+    pub port: u16,
+    /// Sent by clients as `Authorization: Bearer <token>`
+    pub token: String,
+    /// Base URL that reaches the server from outside, used by
+    /// `generate-checkin-url`. Default `http://<bind>:<port>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_url: Option<String>,
+}
+
+fn default_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+impl ServerConfig {
+    pub fn validate(&self) -> Result<()> {
+        self.address()?;
+        if self.token.len() < 16 {
+            bail!("Server token must be at least 16 characters");
+        }
+        if let Some(public_url) = &self.public_url {
+            let url = reqwest::Url::parse(public_url)
+                .with_context(|| format!("Invalid server public_url '{}'", public_url))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                bail!("Server public_url must be an http(s) URL");
+            }
+        }
+        Ok(())
+    }
+
+    /// A URL that checks in with a single GET until `expires`
+    pub fn signed_checkin_url(&self, secret: &str, expires: DateTime<Utc>) -> String {
+        let base = match &self.public_url {
+            Some(public_url) => public_url.trim_end_matches('/').to_string(),
+            None => format!("http://{}:{}", self.bind, self.port),
+        };
+        format!("{}/checkin/{}", base, sign_checkin_token(secret, expires))
+    }
+
+    fn address(&self) -> Result<SocketAddr> {
+        format!("{}:{}", self.bind, self.port)
+            .parse()
+            .with_context(|| format!("Invalid server bind address '{}'", self.bind))
+    }
+}
+
+/// What the server asks of the daemon, which owns the state
+#[derive(Debug)]
+pub enum ServerRequest {
+    /// Record a check-in, as `lastsignal checkin` does
+    Checkin { reply: oneshot::Sender<Result<(), String>> },
+    Status { reply: oneshot::Sender<StatusReport> },
+}
+
+/// A running server. Dropping it stops the server.
+pub struct CheckinServer {
+    pub requests: mpsc::Receiver<ServerRequest>,
+    /// Where it ended up listening, for tests that bind port 0
+    #[cfg(test)]
+    pub local_addr: SocketAddr,
+    _shutdown: oneshot::Sender<()>,
+}
+
+#[derive(Clone)]
+struct ServerState {
+    token: String,
+    url_secret: String,
+    sender: mpsc::Sender<ServerRequest>,
+    whoop_webhook: Option<WhoopWebhook>,
+}
+
+/// The secret signing check-in URLs, generated the first time it is needed
+pub fn load_or_create_url_secret(data_directory: &Path) -> Result<String> {
+    let path = data_directory.join(URL_SECRET_FILE);
+    if path.exists() {
+        let secret = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        return Ok(secret.trim().to_string());
+    }
+
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let secret = hex::encode(bytes);
+    std::fs::create_dir_all(data_directory)
+        .with_context(|| format!("Failed to create data directory {}", data_directory.display()))?;
+    std::fs::write(&path, &secret).with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("Failed to restrict permissions of {}", path.display()))?;
+    }
+    Ok(secret)
+}
+
+fn checkin_mac(secret: &str, expires: i64) -> Hmac<Sha256> {
+    hmac_sha256(secret.as_bytes(), format!("checkin\n{}", expires).as_bytes())
+}
+
+/// `<expiry as unix seconds>.<hex HMAC-SHA256 of the expiry>`
+fn sign_checkin_token(secret: &str, expires: DateTime<Utc>) -> String {
+    let expires = expires.timestamp();
+    format!("{}.{}", expires, hex::encode(checkin_mac(secret, expires).finalize().into_bytes()))
+}
+
+/// Checks a check-in URL token, returning when it expires
+fn verify_checkin_token(secret: &str, token: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let (expires, signature) = token.split_once('.').context("Malformed check-in token")?;
+    let expires: i64 = expires.parse().context("Invalid check-in token expiry")?;
+    let signature = hex::decode(signature).context("Invalid check-in token signature")?;
+    checkin_mac(secret, expires)
+        .verify_slice(&signature)
+        .map_err(|_| anyhow::anyhow!("Check-in token signature does not match"))?;
+
+    let expires = DateTime::from_timestamp(expires, 0).context("Check-in token expiry out of range")?;
+    if now >= expires {
+        bail!("Check-in token expired at {}", expires);
+    }
+    Ok(expires)
+}
+
+/// Starts the check-in server. `None` when there is no `[server]` section.
+/// With `whoop_webhook`, it also takes WHOOP's webhook events at `/webhooks/whoop`.
+pub async fn start_server(config: Option<&ServerConfig>, data_directory: &Path, whoop_webhook: Option<WhoopWebhook>) -> Result<Option<CheckinServer>> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+
+    let url_secret = load_or_create_url_secret(data_directory)?;
+    let address = config.address()?;
+    let listener = tokio::net::TcpListener::bind(address).await
+        .with_context(|| format!("Failed to bind check-in server to {}", address))?;
+    let local_addr = listener.local_addr().context("Failed to read check-in server address")?;
+
+    let (sender, requests) = mpsc::channel(QUEUE_CAPACITY);
+    let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+    let mut app = Router::new()
+        .route("/checkin", post(handle_checkin))
+        .route("/checkin/:token", get(handle_signed_checkin))
+        .route("/status", get(handle_status));
+    if whoop_webhook.is_some() {
+        app = app.route("/webhooks/whoop", post(handle_whoop_webhook));
+    }
+    let app = app.with_state(ServerState { token: config.token.clone(), url_secret, sender, whoop_webhook });
+
+    tracing::info!("Check-in server listening on {}", local_addr);
+    tokio::spawn(async move {
+        let shutdown = async {
+            // Resolves when the `CheckinServer` is dropped
+            let _ = shutdown_signal.await;
+        };
+        if let Err(e) = axum::serve(listener, app).with_graceful_shutdown(shutdown).await {
+            tracing::error!("Check-in server stopped: {}", e);
+        }
+        tracing::debug!("Check-in server shut down");
+    });
+
+    Ok(Some(CheckinServer {
+        requests,
+        #[cfg(test)]
+        local_addr,
+        _shutdown: shutdown,
+    }))
+}
+
+/// Compares the bearer token without stopping at the first differing byte
+fn authorized(headers: &HeaderMap, token: &str) -> bool {
+    let Some(presented) = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return false;
+    };
+    presented.len() == token.len()
+        && presented.bytes().zip(token.bytes()).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+/// Hands a request to the daemon and waits for its answer
+async fn ask<T>(state: &ServerState, request: impl FnOnce(oneshot::Sender<T>) -> ServerRequest) -> Result<T, Response> {
+    let (reply, answer) = oneshot::channel();
+    if let Err(e) = state.sender.try_send(request(reply)) {
+        tracing::warn!("Check-in server queue full or closed: {}", e);
+        return Err(error(StatusCode::SERVICE_UNAVAILABLE, "LastSignal is busy, try again shortly"));
+    }
+    answer.await.map_err(|_| error(StatusCode::SERVICE_UNAVAILABLE, "LastSignal is shutting down"))
+}
+
+async fn checkin(state: &ServerState) -> Response {
+    match ask(state, |reply| ServerRequest::Checkin { reply }).await {
+        Ok(Ok(())) => Json(serde_json::json!({ "status": "checked_in" })).into_response(),
+        Ok(Err(e)) => error(StatusCode::INTERNAL_SERVER_ERROR, &e),
+        Err(response) => response,
+    }
+}
+
+async fn handle_checkin(State(state): State<ServerState>, headers: HeaderMap) -> Response {
+    if !authorized(&headers, &state.token) {
+        tracing::warn!("Rejected check-in request with a missing or wrong token");
+        return error(StatusCode::UNAUTHORIZED, "Missing or wrong bearer token");
+    }
+
+    checkin(&state).await
+}
+
+/// `GET /checkin/<token>`, for a single tap in Shortcuts or Tasker without the bearer token
+async fn handle_signed_checkin(State(state): State<ServerState>, UrlPath(token): UrlPath<String>) -> Response {
+    if let Err(e) = verify_checkin_token(&state.url_secret, &token, Utc::now()) {
+        tracing::warn!("Rejected signed check-in URL: {}", e);
+        return error(StatusCode::FORBIDDEN, "Invalid or expired check-in URL");
+    }
+
+    checkin(&state).await
+}
+
+async fn handle_status(State(state): State<ServerState>, headers: HeaderMap) -> Response {
+    if !authorized(&headers, &state.token) {
+        return error(StatusCode::UNAUTHORIZED, "Missing or wrong bearer token");
+    }
+
+    match ask(&state, |reply| ServerRequest::Status { reply }).await {
+        Ok(report) => Json(report).into_response(),
+        Err(response) => response,
+    }
+}
+
+/// `POST /webhooks/whoop`, authenticated by WHOOP's signature rather than the bearer token
+async fn handle_whoop_webhook(State(state): State<ServerState>, headers: HeaderMap, body: Bytes) -> Response {
+    let Some(webhook) = &state.whoop_webhook else {
+        return error(StatusCode::NOT_FOUND, "WHOOP webhook not enabled");
+    };
+
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    match webhook.receive(header("x-whoop-signature-timestamp"), header("x-whoop-signature"), &body, Utc::now()) {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            tracing::warn!("Rejected WHOOP webhook event: {:#}", e);
+            error(StatusCode::FORBIDDEN, "Invalid WHOOP webhook event")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "0123456789abcdef";
+
+    fn config() -> ServerConfig {
+        ServerConfig {
+            bind: "127.0.0.1".to_string(),
+            port: 0,
+            token: TOKEN.to_string(),
+            public_url: None,
+        }
+    }
+
+    #[test]
+    fn test_server_config_validation() {
+        assert!(config().validate().is_ok());
+        assert!(ServerConfig { token: "short".to_string(), ..config() }.validate().is_err());
+        assert!(ServerConfig { bind: "localhost:80".to_string(), ..config() }.validate().is_err());
+        assert!(ServerConfig { public_url: Some("ftp://example.com".to_string()), ..config() }.validate().is_err());
+    }
+
+    #[test]
+    fn test_checkin_token_round_trips() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let expires = now + chrono::Duration::days(90);
+        let token = sign_checkin_token(TOKEN, expires);
+        assert!(token.starts_with(&format!("{}.", expires.timestamp())));
+        assert_eq!(verify_checkin_token(TOKEN, &token, now).unwrap(), expires);
+
+        let url = ServerConfig { public_url: Some("https://home.example.com/lastsignal/".to_string()), ..config() }
+            .signed_checkin_url(TOKEN, expires);
+        assert_eq!(url, format!("https://home.example.com/lastsignal/checkin/{}", token));
+        assert_eq!(config().signed_checkin_url(TOKEN, expires), format!("http://127.0.0.1:0/checkin/{}", token));
+    }
+
+    #[test]
+    fn test_checkin_token_expiry() {
+        let expires: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let token = sign_checkin_token(TOKEN, expires);
+        assert!(verify_checkin_token(TOKEN, &token, expires - chrono::Duration::seconds(1)).is_ok());
+        assert!(verify_checkin_token(TOKEN, &token, expires).is_err());
+        assert!(verify_checkin_token(TOKEN, &token, expires + chrono::Duration::days(1)).is_err());
+    }
+
+    #[test]
+    fn test_tampered_checkin_tokens_are_rejected() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let token = sign_checkin_token(TOKEN, now + chrono::Duration::days(1));
+        let (_, signature) = token.split_once('.').unwrap();
+
+        // Pushing the expiry out invalidates the signature
+        let extended = format!("{}.{}", (now + chrono::Duration::days(365)).timestamp(), signature);
+        assert!(verify_checkin_token(TOKEN, &extended, now).is_err());
+        assert!(verify_checkin_token("a-different-secret", &token, now).is_err());
+        assert!(verify_checkin_token(TOKEN, signature, now).is_err());
+        assert!(verify_checkin_token(TOKEN, "", now).is_err());
+    }
+
+    #[test]
+    fn test_url_secret_is_created_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let secret = load_or_create_url_secret(temp_dir.path()).unwrap();
+        assert_eq!(secret.len(), 64);
+        assert_eq!(load_or_create_url_secret(temp_dir.path()).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_authorized() {
+        let mut headers = HeaderMap::new();
+        assert!(!authorized(&headers, TOKEN));
+        headers.insert(header::AUTHORIZATION, format!("Bearer {}", TOKEN).parse().unwrap());
+        assert!(authorized(&headers, TOKEN));
+        headers.insert(header::AUTHORIZATION, "Bearer 0123456789abcdeX".parse().unwrap());
+        assert!(!authorized(&headers, TOKEN));
+        headers.insert(header::AUTHORIZATION, TOKEN.parse().unwrap());
+        assert!(!authorized(&headers, TOKEN));
+    }
+
+    #[tokio::test]
+    async fn test_not_configured() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert!(start_server(None, temp_dir.path(), None).await.unwrap().is_none());
+        assert!(!temp_dir.path().join(URL_SECRET_FILE).exists());
+    }
+
+    #[tokio::test]
+    async fn test_checkin_is_passed_to_the_daemon() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut server = start_server(Some(&config()), temp_dir.path(), None).await.unwrap().unwrap();
+        let url = format!("http://{}/checkin", server.local_addr);
+        let client = reqwest::Client::new();
+
+        let response = client.post(&url).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+        assert!(server.requests.try_recv().is_err());
+
+        let daemon = tokio::spawn(async move {
+            match server.requests.recv().await {
+                Some(ServerRequest::Checkin { reply }) => reply.send(Ok(())).unwrap(),
+                other => panic!("expected a check-in, got {:?}", other),
+            }
+            server
+        });
+        let response = client.post(&url).bearer_auth(TOKEN).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        let server = daemon.await.unwrap();
+
+        // Dropping the server stops it
+        let address = server.local_addr;
+        drop(server);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let fresh_client = reqwest::Client::new();
+        assert!(fresh_client.post(format!("http://{}/checkin", address)).bearer_auth(TOKEN).send().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_whoop_webhook_route() {
+        use base64::Engine;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let body = r#"{"user_id":10129,"id":10235,"type":"sleep.updated","trace_id":"d3c1e3a0"}"#;
+        let timestamp = Utc::now().timestamp_millis().to_string();
+        let mut mac = hmac_sha256(b"whoop_client_secret", timestamp.as_bytes());
+        mac.update(body.as_bytes());
+        let signature = base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+        let client = reqwest::Client::new();
+        let post = |address: SocketAddr, signature: &str| {
+            client.post(format!("http://{}/webhooks/whoop", address))
+                .header("X-WHOOP-Signature-Timestamp", &timestamp)
+                .header("X-WHOOP-Signature", signature)
+                .body(body)
+                .send()
+        };
+
+        // Only served when a WHOOP output opts in
+        let server = start_server(Some(&config()), temp_dir.path(), None).await.unwrap().unwrap();
+        assert_eq!(post(server.local_addr, &signature).await.unwrap().status(), reqwest::StatusCode::NOT_FOUND);
+
+        let webhook = WhoopWebhook::new("whoop_client_secret", temp_dir.path());
+        let mut server = start_server(Some(&config()), temp_dir.path(), Some(webhook)).await.unwrap().unwrap();
+        assert_eq!(post(server.local_addr, "Zm9yZ2Vk").await.unwrap().status(), reqwest::StatusCode::FORBIDDEN);
+        assert_eq!(crate::outputs::whoop_webhook::last_event(temp_dir.path()).unwrap(), None);
+
+        assert_eq!(post(server.local_addr, &signature).await.unwrap().status(), reqwest::StatusCode::NO_CONTENT);
+        let event = crate::outputs::whoop_webhook::last_event(temp_dir.path()).unwrap().unwrap();
+        assert_eq!(event.event_type, "sleep.updated");
+
+        // Events don't go through the daemon's queue
+        assert!(server.requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_signed_checkin_url() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut server = start_server(Some(&config()), temp_dir.path(), None).await.unwrap().unwrap();
+        let secret = load_or_create_url_secret(temp_dir.path()).unwrap();
+        let config = ServerConfig { port: server.local_addr.port(), ..config() };
+
+        // Expired and forged URLs are refused without reaching the daemon
+        let expired = config.signed_checkin_url(&secret, Utc::now() - chrono::Duration::seconds(1));
+        assert_eq!(reqwest::get(&expired).await.unwrap().status(), reqwest::StatusCode::FORBIDDEN);
+        let forged = config.signed_checkin_url("not-the-secret", Utc::now() + chrono::Duration::days(1));
+        assert_eq!(reqwest::get(&forged).await.unwrap().status(), reqwest::StatusCode::FORBIDDEN);
+        assert!(server.requests.try_recv().is_err());
+
+        let daemon = tokio::spawn(async move {
+            match server.requests.recv().await {
+                Some(ServerRequest::Checkin { reply }) => reply.send(Ok(())).unwrap(),
+                other => panic!("expected a check-in, got {:?}", other),
+            }
+        });
+        let url = config.signed_checkin_url(&secret, Utc::now() + chrono::Duration::days(90));
+        assert_eq!(reqwest::get(&url).await.unwrap().status(), reqwest::StatusCode::OK);
+        daemon.await.unwrap();
+    }
+}
@@ -1,9 +1,24 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::acknowledgement::AcknowledgementsConfig;
+use crate::checkin_server::ServerConfig;
+use crate::monitoring::MonitoringConfig;
+use crate::secrets::EncryptionConfig;
+use crate::connectivity::ConnectivityConfig;
 use crate::duration_parser::ConfigDuration;
+use crate::encryption;
+use crate::events::EventsConfig;
+use crate::formatting::MessageFormat;
+use crate::notifier::NotificationsConfig;
+use crate::outputs::{OutputFactory, COMMON_CONFIG_KEYS};
+use crate::outputs::email_bidirectional::ImapAccount;
+use crate::outputs::rate_limit::RateLimitConfig;
+use crate::schedule::{ActiveHours, Anchor, Anchoring};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Config {
@@ -11,12 +26,31 @@ pub struct Config {
     pub recipient: RecipientConfig,
     pub last_signal: LastSignalConfig,
     pub app: AppConfig,
+    /// The HTTP check-in endpoint, absent unless configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<ServerConfig>,
+    /// Passphrase for token files, which are plaintext without it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionConfig>,
+    /// A dead man's switch the daemon pings after every cycle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitoring: Option<MonitoringConfig>,
+    /// Keys present in the config file that no setting reads
+    #[serde(skip)]
+    pub unknown_keys: Vec<String>,
+    /// The file this config was loaded from
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CheckinConfig {
     pub duration_between_checkins: ConfigDuration,
     pub output_retry_delay: ConfigDuration,
+    /// Times after the first unanswered check-in request at which further,
+    /// increasingly urgent reminders are sent, e.g. `["2d", "4d", "6d"]`
+    #[serde(default)]
+    pub escalation: Vec<ConfigDuration>,
     pub outputs: Vec<OutputConfig>,
 }
 
@@ -24,26 +58,57 @@ pub struct CheckinConfig {
 pub struct RecipientConfig {
     pub max_time_since_last_checkin: ConfigDuration,
     pub output_retry_delay: ConfigDuration,
+    /// Minimum time between two last signal sends to the same recipient,
+    /// enforced for every send path (only `fire --force-renotify` bypasses it)
+    #[serde(default = "default_min_renotify_interval")]
+    pub min_renotify_interval: ConfigDuration,
+    /// How long before the last signal fires to warn you through the
+    /// checkin outputs, e.g. "24h". No warning is sent without it.
+    #[serde(default)]
+    pub warn_before_last_signal: Option<ConfigDuration>,
     pub last_signal_outputs: Vec<OutputConfig>,
+    /// Your own secondary addresses, sent the real last signal by `lastsignal rehearse`
+    #[serde(default)]
+    pub canary_outputs: Vec<OutputConfig>,
+    /// Signed links recipients can open to confirm they have seen the last signal
+    #[serde(default)]
+    pub acknowledgements: AcknowledgementsConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OutputConfig {
     #[serde(rename = "type")]
     pub output_type: String,
+    /// Optional for outputs that need no settings, such as `console`
+    #[serde(default)]
     pub config: HashMap<String, String>,
     #[serde(default = "default_false")]
     pub bidirectional: bool,
+    /// Checkin outputs with lower numbers are tried first
+    #[serde(default)]
+    pub priority: i32,
+    /// Local times a checkin output may be used. Outside them it is only a last resort.
+    #[serde(default)]
+    pub active_hours: Option<ActiveHours>,
+    /// Further mailboxes a bidirectional email checkin output polls for replies
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_imap_accounts: Vec<ImapAccount>,
 }
 
 fn default_false() -> bool {
     false
 }
 
+fn default_min_renotify_interval() -> ConfigDuration {
+    ConfigDuration::from_hours(24)
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LastSignalConfig {
     pub adapter_type: String,
     pub message_file: String,
+    #[serde(default)]
+    pub format: MessageFormat,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -52,12 +117,70 @@ pub struct AppConfig {
     pub log_level: String,
     #[serde(default = "default_check_interval")]
     pub check_interval: ConfigDuration,
+    #[serde(default)]
+    pub events: EventsConfig,
+    /// How long the configuration can go unreviewed before LastSignal asks for a review
+    #[serde(default = "default_review_reminder_interval")]
+    pub review_reminder_interval: ConfigDuration,
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    #[serde(default)]
+    pub connectivity: ConnectivityConfig,
+    /// Log full API responses and check-in reply details at trace level.
+    /// Off by default because they can contain health data and email metadata.
+    #[serde(default)]
+    pub log_sensitive_payloads: bool,
+    /// Time zone for `active_hours`, as an IANA name like "Europe/London"
+    #[serde(default = "default_timezone")]
+    pub timezone: Tz,
+    /// Whether day-length durations keep the local time of day across DST changes
+    #[serde(default)]
+    pub anchor: Anchor,
+    /// How many backups of changed config files to keep in the data directory
+    #[serde(default = "default_config_backups")]
+    pub config_backups: usize,
+    /// Caps on how many messages go through one provider per hour
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
 }
 
 fn default_check_interval() -> ConfigDuration {
     ConfigDuration::from_hours(1)
 }
 
+fn default_review_reminder_interval() -> ConfigDuration {
+    ConfigDuration::from_days(180)
+}
+
+fn default_config_backups() -> usize {
+    20
+}
+
+fn default_timezone() -> Tz {
+    Tz::UTC
+}
+
+impl AppConfig {
+    /// How the check-in and last signal durations are applied
+    pub fn anchoring(&self) -> Anchoring {
+        Anchoring {
+            anchor: self.anchor,
+            timezone: self.timezone,
+        }
+    }
+
+    /// Wall-clock time in the configured time zone
+    pub fn local_time(&self, at: DateTime<Utc>) -> NaiveTime {
+        at.with_timezone(&self.timezone).time()
+    }
+}
+
+/// Whether `host` is this machine, where an unencrypted connection is safe
+fn is_local_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<std::net::IpAddr>().is_ok_and(|address| address.is_loopback())
+}
+
 impl Config {
     pub fn load() -> Result<Self> {
         let config_path = Self::get_config_path()?;
@@ -68,13 +191,81 @@ impl Config {
         let content = std::fs::read_to_string(path.as_ref())
             .with_context(|| format!("Failed to read config file: {:?}", path.as_ref()))?;
         
-        let config: Config = toml::from_str(&content)
-            .with_context(|| "Failed to parse config file as TOML")?;
+        let mut config = Self::parse(&content)?;
+        config.source_path = Some(path.as_ref().to_path_buf());
         
         config.validate()?;
         Ok(config)
     }
 
+    /// Parses the TOML, remembering any keys that were ignored along the way
+    fn parse(content: &str) -> Result<Self> {
+        let mut unknown_keys = Vec::new();
+        let mut config: Config = serde_ignored::deserialize(
+            toml::Deserializer::new(content),
+            |path| unknown_keys.push(path.to_string()),
+        ).with_context(|| "Failed to parse config file as TOML")?;
+
+        config.unknown_keys = unknown_keys;
+        Ok(config)
+    }
+
+    /// Lists config mistakes that don't stop LastSignal from running but are
+    /// almost certainly not what was intended, such as misspelt keys.
+    pub fn audit_warnings(&self) -> Vec<String> {
+        let mut warnings: Vec<String> = self.unknown_keys
+            .iter()
+            .map(|key| format!("Unknown config key '{}' is ignored", key))
+            .collect();
+
+        let sections = [
+            ("checkin.outputs", &self.checkin.outputs),
+            ("recipient.last_signal_outputs", &self.recipient.last_signal_outputs),
+            ("recipient.canary_outputs", &self.recipient.canary_outputs),
+        ];
+        for (section, outputs) in sections {
+            for (i, output) in outputs.iter().enumerate() {
+                let Some(known_keys) = OutputFactory::config_keys(&output.output_type, output.bidirectional) else {
+                    continue;
+                };
+
+                let mut unused: Vec<&String> = output.config
+                    .keys()
+                    .filter(|key| !known_keys.contains(&key.as_str()) && !COMMON_CONFIG_KEYS.contains(&key.as_str()))
+                    .filter(|key| !OutputFactory::accepts_prefixed_key(&output.output_type, key))
+                    .collect();
+                unused.sort();
+
+                for key in unused {
+                    warnings.push(format!(
+                        "Unused key '{}' in {} output ({}[{}]) is ignored",
+                        key, output.output_type, section, i
+                    ));
+                }
+
+                if output.output_type == "email"
+                    && output.config.get("smtp_security").is_some_and(|security| security == "none")
+                    && let Some(host) = output.config.get("smtp_host")
+                    && !is_local_host(host)
+                {
+                    warnings.push(format!(
+                        "email output ({}[{}]) sends mail unencrypted to {} with smtp_security = none",
+                        section, i, host
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// When the config file was last modified, if known
+    pub fn modified_at(&self) -> Option<DateTime<Utc>> {
+        let path = self.source_path.as_ref()?;
+        let modified = std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+        Some(modified.into())
+    }
+
     pub fn get_config_path() -> Result<PathBuf> {
         let home_dir = dirs::home_dir()
             .context("Could not determine home directory")?;
@@ -130,6 +321,17 @@ impl Config {
             anyhow::bail!("recipient output_retry_delay must be greater than 0");
         }
 
+        if self.checkin.escalation.first().is_some_and(|first| first.as_secs() == 0)
+            || self.checkin.escalation.windows(2).any(|pair| pair[0].as_secs() >= pair[1].as_secs()) {
+            anyhow::bail!("checkin escalation must be increasing durations greater than 0, e.g. [\"2d\", \"4d\", \"6d\"]");
+        }
+
+        if let Some(warn_before) = self.recipient.warn_before_last_signal
+            && (warn_before.as_secs() == 0 || warn_before.as_secs() >= self.recipient.max_time_since_last_checkin.as_secs())
+        {
+            anyhow::bail!("warn_before_last_signal must be greater than 0 and less than max_time_since_last_checkin");
+        }
+
         if self.app.check_interval.as_secs() == 0 {
             anyhow::bail!("app check_interval must be greater than 0");
         }
@@ -150,6 +352,22 @@ impl Config {
             self.validate_output(output, "last_signal")?;
         }
 
+        for output in &self.recipient.canary_outputs {
+            self.validate_output(output, "canary")?;
+        }
+
+        self.recipient.acknowledgements.validate()?;
+
+        if let Some(encryption) = &self.encryption {
+            encryption.validate()?;
+        }
+        if let Some(monitoring) = &self.monitoring {
+            monitoring.validate()?;
+        }
+        if let Some(server) = &self.server {
+            server.validate()?;
+        }
+
         let valid_log_levels = ["trace", "debug", "info", "warn", "error"];
         if !valid_log_levels.contains(&self.app.log_level.as_str()) {
             anyhow::bail!("Invalid log level: {}. Must be one of: {}", 
@@ -161,6 +379,20 @@ impl Config {
 
     fn validate_output(&self, output: &OutputConfig, context: &str) -> Result<()> {
         match output.output_type.as_str() {
+            "apprise" => {
+                if !output.config.contains_key("urls") {
+                    anyhow::bail!("apprise output in {} missing 'urls'", context);
+                }
+                crate::outputs::apprise::AppriseOutput::new(&output.config)
+                    .with_context(|| format!("Invalid apprise output in {}", context))?;
+            }
+            "console" => {}
+            "discord" => {
+                let webhook_url = output.config.get("webhook_url")
+                    .with_context(|| format!("discord output in {} missing 'webhook_url'", context))?;
+                crate::outputs::discord::parse_webhook_url(webhook_url)
+                    .with_context(|| format!("Invalid discord output in {}", context))?;
+            }
             "facebook_messenger" => {
                 if !output.config.contains_key("user_id") {
                     anyhow::bail!("facebook_messenger output in {} missing 'user_id'", context);
@@ -168,9 +400,18 @@ impl Config {
                 if !output.config.contains_key("access_token") {
                     anyhow::bail!("facebook_messenger output in {} missing 'access_token'", context);
                 }
+                if output.bidirectional && !output.config.contains_key("verify_token") {
+                    anyhow::bail!("bidirectional facebook_messenger output in {} missing 'verify_token'", context);
+                }
+                if let Some(port) = output.config.get("webhook_port") {
+                    port.parse::<u16>()
+                        .with_context(|| format!("Invalid 'webhook_port' in facebook_messenger output in {}", context))?;
+                }
+                crate::outputs::facebook_messenger::FacebookMessengerOutput::new(&output.config, self.get_data_directory().ok().as_deref())
+                    .with_context(|| format!("Invalid facebook_messenger output in {}", context))?;
             }
             "email" => {
-                let required_fields = ["to", "smtp_host", "smtp_port", "username", "password"];
+                let required_fields = ["to", "smtp_host", "smtp_port", "username"];
                 for field in &required_fields {
                     if !output.config.contains_key(*field) {
                         anyhow::bail!("email output in {} missing '{}'", context, field);
@@ -181,15 +422,205 @@ impl Config {
                     port_str.parse::<u16>()
                         .with_context(|| format!("Invalid SMTP port '{}' in {} output", port_str, context))?;
                 }
+                crate::outputs::email::SmtpSecurity::from_config(&output.config)
+                    .with_context(|| format!("Invalid email output in {}", context))?;
+                crate::outputs::email::MailTls::from_config(&output.config)
+                    .with_context(|| format!("Invalid email output in {}", context))?;
+                crate::outputs::email::MailAuth::from_config(&output.config)
+                    .with_context(|| format!("Invalid email output in {}", context))?;
 
                 // Validate IMAP settings for bidirectional email
+                if output.bidirectional
+                    && let Some(imap_port_str) = output.config.get("imap_port") {
+                    imap_port_str.parse::<u16>()
+                        .with_context(|| format!("Invalid IMAP port '{}' in {} output", imap_port_str, context))?;
+                }
                 if output.bidirectional {
-                    if let Some(imap_port_str) = output.config.get("imap_port") {
-                        imap_port_str.parse::<u16>()
-                            .with_context(|| format!("Invalid IMAP port '{}' in {} output", imap_port_str, context))?;
+                    crate::outputs::email_bidirectional::BidirectionalEmailOutput::new(&output.config)
+                        .with_context(|| format!("Invalid bidirectional email output in {}", context))?;
+                } else {
+                    crate::outputs::email::EmailOutput::new(&output.config, crate::outputs::MessageKind::LastSignal)
+                        .with_context(|| format!("Invalid email output in {}", context))?;
+                }
+            }
+            "email_mailgun" => {
+                for field in ["api_key", "domain", "from", "to"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("email_mailgun output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::email_mailgun::EmailMailgunOutput::new(&output.config)
+                    .with_context(|| format!("Invalid email_mailgun output in {}", context))?;
+            }
+            "email_ses" => {
+                for field in ["access_key_id", "secret_access_key", "region", "from", "to"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("email_ses output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::email_ses::EmailSesOutput::new(&output.config)
+                    .with_context(|| format!("Invalid email_ses output in {}", context))?;
+            }
+            "exec" => {
+                if !output.config.contains_key("command") {
+                    anyhow::bail!("exec output in {} missing 'command'", context);
+                }
+                crate::outputs::exec::ExecOutput::new(&output.config)
+                    .with_context(|| format!("Invalid exec output in {}", context))?;
+            }
+            "file" => {
+                if !output.config.contains_key("path") {
+                    anyhow::bail!("file output in {} missing 'path'", context);
+                }
+                if let Some(max_size) = output.config.get("max_size") {
+                    crate::outputs::file::parse_size(max_size)
+                        .with_context(|| format!("Invalid 'max_size' in {} file output", context))?;
+                }
+            }
+            "file_activity" => {
+                if !output.config.contains_key("paths") {
+                    anyhow::bail!("file_activity output in {} missing 'paths'", context);
+                }
+            }
+            "fitbit" => {
+                // Tokens come from `lastsignal fitbit-auth`, but refreshing them needs the client credentials
+                for field in ["client_id", "client_secret"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("fitbit output in {} missing '{}'", context, field);
+                    }
+                }
+            }
+            "garmin" => {
+                // Tokens come from `lastsignal garmin-auth`, but every request is signed with the consumer
+                for field in ["consumer_key", "consumer_secret"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("garmin output in {} missing '{}'", context, field);
                     }
                 }
             }
+            "git_activity" => {
+                crate::outputs::git_activity::GitActivityOutput::new(&output.config, crate::duration_parser::ConfigDuration::from_hours(24))
+                    .with_context(|| format!("Invalid git_activity output in {}", context))?;
+            }
+            "irc" => {
+                for field in ["server", "target"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("irc output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::irc::IrcOutput::new(&output.config)
+                    .with_context(|| format!("Invalid irc output in {}", context))?;
+            }
+            "lastfm" => {
+                for field in ["api_key", "username"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("lastfm output in {} missing '{}'", context, field);
+                    }
+                }
+            }
+            "local_activity" => {
+                crate::outputs::local_activity::LocalActivityOutput::new(&output.config, crate::duration_parser::ConfigDuration::from_hours(24))
+                    .with_context(|| format!("Invalid local_activity output in {}", context))?;
+            }
+            "matrix" => {
+                for field in ["homeserver_url", "access_token", "room_id"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("matrix output in {} missing '{}'", context, field);
+                    }
+                }
+                if output.bidirectional && !output.config.contains_key("admin_user_id") {
+                    anyhow::bail!("bidirectional matrix output in {} needs 'admin_user_id' to know whose messages are check-ins", context);
+                }
+            }
+            "mqtt_presence" => {
+                crate::outputs::mqtt_presence::MqttSettings::parse(&output.config)
+                    .with_context(|| format!("Invalid mqtt_presence output in {}", context))?;
+            }
+            "ntfy" => {
+                for field in ["server_url", "topic"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("ntfy output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::ntfy::NtfyOutput::new(&output.config, None)
+                    .with_context(|| format!("Invalid ntfy output in {}", context))?;
+            }
+            "pushover" => {
+                for field in ["user_key", "api_token"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("pushover output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::pushover::PushoverOutput::new(&output.config)
+                    .with_context(|| format!("Invalid pushover output in {}", context))?;
+            }
+            "rocketchat" => {
+                if !output.config.contains_key("webhook_url") {
+                    anyhow::bail!("rocketchat output in {} missing 'webhook_url'", context);
+                }
+                crate::outputs::rocketchat::RocketChatOutput::new(&output.config)
+                    .with_context(|| format!("Invalid rocketchat output in {}", context))?;
+            }
+            "slack" => {
+                let webhook_url = output.config.get("webhook_url")
+                    .with_context(|| format!("slack output in {} missing 'webhook_url'", context))?;
+                crate::outputs::slack::parse_webhook_url(webhook_url)
+                    .with_context(|| format!("Invalid slack output in {}", context))?;
+            }
+            "sms_twilio" => {
+                for field in ["account_sid", "auth_token", "from_number", "to_number"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("sms_twilio output in {} missing '{}'", context, field);
+                    }
+                }
+            }
+            "sns" => {
+                for field in ["access_key_id", "secret_access_key", "region", "topic_arn"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("sns output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::sns::SnsOutput::new(&output.config)
+                    .with_context(|| format!("Invalid sns output in {}", context))?;
+            }
+            "steam" => {
+                crate::outputs::steam::SteamOutput::new(&output.config, crate::duration_parser::ConfigDuration::from_hours(24))
+                    .with_context(|| format!("Invalid steam output in {}", context))?;
+            }
+            "syslog" => {
+                crate::outputs::syslog::SyslogOutput::new(&output.config, crate::outputs::MessageKind::LastSignal)
+                    .with_context(|| format!("Invalid syslog output in {}", context))?;
+            }
+            "voice_twilio" => {
+                for field in ["account_sid", "auth_token", "from_number", "to_number"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("voice_twilio output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::voice_twilio::VoiceTwilioOutput::new(&output.config)
+                    .with_context(|| format!("Invalid voice_twilio output in {}", context))?;
+            }
+            "webhook" => {
+                let url = output.config.get("url")
+                    .with_context(|| format!("webhook output in {} missing 'url'", context))?;
+                crate::outputs::webhook::parse_url(url)
+                    .with_context(|| format!("Invalid 'url' in {} webhook output", context))?;
+                crate::outputs::webhook::WebhookOutput::new(&output.config)
+                    .with_context(|| format!("Invalid webhook output in {}", context))?;
+            }
+            "whatsapp" => {
+                let provider = output.config.get("provider")
+                    .with_context(|| format!("whatsapp output in {} missing 'provider'", context))?;
+                let required_fields = crate::outputs::whatsapp::WhatsAppOutput::required_keys(provider)
+                    .with_context(|| format!("Unknown whatsapp provider '{}' in {}: use twilio or cloud_api", provider, context))?;
+                for field in std::iter::once(&"to_number").chain(required_fields) {
+                    if !output.config.contains_key(*field) {
+                        anyhow::bail!("whatsapp output in {} missing '{}' for provider {}", context, field, provider);
+                    }
+                }
+                crate::outputs::whatsapp::WhatsAppOutput::new(&output.config)
+                    .with_context(|| format!("Invalid whatsapp output in {}", context))?;
+            }
             "whoop" => {
                 // No access_token required in config since we use OAuth tokens
                 if let Some(max_hours_str) = output.config.get("max_hours_since_activity") {
@@ -199,11 +630,74 @@ impl Config {
                         anyhow::bail!("max_hours_since_activity must be greater than 0 in {} output", context);
                     }
                 }
+                for key in ["activity_window", "cache_ttl"] {
+                    if let Some(value) = output.config.get(key) {
+                        value.parse::<ConfigDuration>()
+                            .with_context(|| format!("Invalid {} '{}' in {} output", key, value, context))?;
+                    }
+                }
+                if let Some(value) = output.config.get("include_workouts").filter(|value| !matches!(value.as_str(), "true" | "false")) {
+                    anyhow::bail!("Invalid include_workouts '{}' in {} output, expected true or false", value, context);
+                }
+                if crate::outputs::whoop_webhook::webhook_enabled(&output.config)
+                    .with_context(|| format!("Invalid whoop output in {}", context))?
+                {
+                    if !output.config.contains_key("client_secret") {
+                        anyhow::bail!("whoop output in {} has 'webhook' on but no 'client_secret' to check signatures with", context);
+                    }
+                    if self.server.is_none() {
+                        anyhow::bail!("whoop output in {} has 'webhook' on, which needs a [server] section to receive events", context);
+                    }
+                }
+                if let Some(api_base_url) = output.config.get("api_base_url") {
+                    crate::outputs::whoop::parse_base_url(api_base_url)
+                        .with_context(|| format!("Invalid whoop output in {}", context))?;
+                }
+            }
+            "withings" => {
+                // Tokens come from `lastsignal withings-auth`, but refreshing them needs the client credentials
+                for field in ["client_id", "client_secret"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("withings output in {} missing '{}'", context, field);
+                    }
+                }
+            }
+            "xmpp" => {
+                for field in ["jid", "password", "to_jid"] {
+                    if !output.config.contains_key(field) {
+                        anyhow::bail!("xmpp output in {} missing '{}'", context, field);
+                    }
+                }
+                crate::outputs::xmpp::XmppOutput::new(&output.config, None)
+                    .with_context(|| format!("Invalid xmpp output in {}", context))?;
             }
             _ => {
                 anyhow::bail!("Unknown output type '{}' in {}", output.output_type, context);
             }
         }
+
+        if !output.additional_imap_accounts.is_empty()
+            && (context != "checkin" || output.output_type != "email" || !output.bidirectional)
+        {
+            anyhow::bail!("additional_imap_accounts is only supported on bidirectional email checkin outputs, found in {} {} output", context, output.output_type);
+        }
+
+        if let Some(recipient) = output.config.get("encrypt_to") {
+            if context == "checkin" {
+                anyhow::bail!("encrypt_to is only supported on last signal outputs, found in {} {} output", context, output.output_type);
+            }
+            encryption::parse_recipient(recipient)
+                .with_context(|| format!("Invalid encrypt_to in {} {} output", context, output.output_type))?;
+        }
+
+        if let Some(format) = output.config.get("format") {
+            if context == "checkin" {
+                anyhow::bail!("format is only supported on last signal outputs, found in {} {} output", context, output.output_type);
+            }
+            format.parse::<MessageFormat>()
+                .with_context(|| format!("Invalid format in {} {} output", context, output.output_type))?;
+        }
+
         Ok(())
     }
 }
@@ -249,9 +743,78 @@ check_interval = "1h"
         let config = Config::load_from_path(temp_file.path()).unwrap();
         assert_eq!(config.checkin.duration_between_checkins.as_days(), 7);
         assert_eq!(config.recipient.max_time_since_last_checkin.as_days(), 14);
+        assert_eq!(config.recipient.min_renotify_interval.as_hours(), 24);
         assert_eq!(config.app.check_interval.as_hours(), 1);
     }
 
+    #[test]
+    fn test_escalation_must_increase() {
+        let mut config: Config = toml::from_str(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+escalation = ["2d", "4d", "6d"]
+
+[[checkin.outputs]]
+type = "console"
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+
+[[recipient.last_signal_outputs]]
+type = "console"
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+
+[app]
+data_directory = "/tmp"
+log_level = "info"
+"#).unwrap();
+        assert_eq!(config.checkin.escalation, vec![ConfigDuration::from_days(2), ConfigDuration::from_days(4), ConfigDuration::from_days(6)]);
+        config.validate().unwrap();
+
+        config.checkin.escalation = vec![ConfigDuration::from_days(4), ConfigDuration::from_days(2)];
+        assert!(config.validate().unwrap_err().to_string().contains("escalation"));
+        config.checkin.escalation = vec![ConfigDuration::from_seconds(0)];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_warn_before_last_signal_must_fit_the_deadline() {
+        let mut config: Config = toml::from_str(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+
+[[checkin.outputs]]
+type = "console"
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+warn_before_last_signal = "24h"
+
+[[recipient.last_signal_outputs]]
+type = "console"
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+
+[app]
+data_directory = "/tmp"
+log_level = "info"
+"#).unwrap();
+        assert_eq!(config.recipient.warn_before_last_signal, Some(ConfigDuration::from_hours(24)));
+        config.validate().unwrap();
+
+        config.recipient.warn_before_last_signal = Some(ConfigDuration::from_days(14));
+        assert!(config.validate().unwrap_err().to_string().contains("warn_before_last_signal"));
+    }
+
     #[test]
     fn test_config_duration_formats() {
         // Test various valid formats
@@ -290,12 +853,12 @@ check_interval = "30m"
         assert_eq!(config.checkin.duration_between_checkins.as_hours(), 168);
         assert_eq!(config.checkin.duration_between_checkins.as_days(), 7);
         // 30 minutes
-        assert_eq!(config.checkin.output_retry_delay.as_minutes(), 30);
+        assert_eq!(config.checkin.output_retry_delay.as_secs(), 30 * 60);
         // 336 hours = 14 days  
         assert_eq!(config.recipient.max_time_since_last_checkin.as_hours(), 336);
         assert_eq!(config.recipient.max_time_since_last_checkin.as_days(), 14);
         // 30 minutes
-        assert_eq!(config.app.check_interval.as_minutes(), 30);
+        assert_eq!(config.app.check_interval.as_secs(), 30 * 60);
     }
 
     #[test]
@@ -378,4 +941,164 @@ check_interval = "3600s"
         // 3600 seconds = 1 hour
         assert_eq!(config.app.check_interval.as_hours(), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_audit_warnings_report_unknown_and_unused_keys() {
+        let config_content = r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+snooze = "1d"
+
+[[checkin.outputs]]
+type = "email"
+config = { to = "admin@example.com", smpt_host = "typo", smtp_host = "smtp.gmail.com", smtp_port = "587", username = "sender@example.com", password = "password" }
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+
+[[recipient.last_signal_outputs]]
+type = "file"
+config = { path = "sent.log" }
+
+[[recipient.last_signal_outputs]]
+type = "webhook"
+config = { url = "https://hooks.example.com/x", header_Authorization = "Bearer abc" }
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+
+[app]
+data_directory = "~/.lastsignal/"
+log_level = "info"
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_content.as_bytes()).unwrap();
+
+        let config = Config::load_from_path(temp_file.path()).unwrap();
+        assert_eq!(config.unknown_keys, vec!["checkin.snooze".to_string()]);
+
+        let warnings = config.audit_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("'checkin.snooze'"));
+        assert!(warnings[1].contains("'smpt_host'"));
+        assert!(warnings[1].contains("checkin.outputs[0]"));
+    }
+
+    #[test]
+    fn test_is_local_host() {
+        for host in ["localhost", "LOCALHOST", "127.0.0.1", "::1"] {
+            assert!(is_local_host(host), "{}", host);
+        }
+        for host in ["smtp.example.com", "192.168.1.10", "localhost.example.com"] {
+            assert!(!is_local_host(host), "{}", host);
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_email_imap_keys_are_known() {
+        let mut config = HashMap::new();
+        config.insert("imap_host".to_string(), "imap.gmail.com".to_string());
+
+        let known = OutputFactory::config_keys("email", true).unwrap();
+        assert!(config.keys().all(|key| known.contains(&key.as_str())));
+        assert!(!OutputFactory::config_keys("email", false).unwrap().contains(&"imap_host"));
+    }
+
+    #[test]
+    fn test_additional_imap_accounts_only_on_bidirectional_email_checkins() {
+        let config_content = |bidirectional: bool| format!(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+
+[[checkin.outputs]]
+type = "email"
+bidirectional = {}
+config = {{ to = "admin@example.com", smtp_host = "smtp.gmail.com", smtp_port = "587", username = "me@gmail.com", password = "password" }}
+
+[[checkin.outputs.additional_imap_accounts]]
+host = "imap.work.example.com"
+username = "me@work.example.com"
+password = "work-password"
+folder = "Replies"
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+
+[[recipient.last_signal_outputs]]
+type = "file"
+config = {{ path = "sent.log" }}
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+
+[app]
+data_directory = "~/.lastsignal/"
+log_level = "info"
+"#, bidirectional);
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_content(true).as_bytes()).unwrap();
+        let config = Config::load_from_path(temp_file.path()).unwrap();
+        let accounts = &config.checkin.outputs[0].additional_imap_accounts;
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].port, 993);
+        assert_eq!(accounts[0].folder, "Replies");
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_content(false).as_bytes()).unwrap();
+        let error = Config::load_from_path(temp_file.path()).unwrap_err();
+        assert!(format!("{:#}", error).contains("additional_imap_accounts"));
+    }
+
+    #[test]
+    fn test_output_format_is_validated() {
+        let config_content = |checkin_config: &str, last_signal_format: &str| format!(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+
+[[checkin.outputs]]
+type = "console"
+config = {{ {} }}
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+
+[[recipient.last_signal_outputs]]
+type = "file"
+config = {{ path = "sent.log", format = "{}" }}
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+format = "markdown"
+
+[app]
+data_directory = "~/.lastsignal/"
+log_level = "info"
+"#, checkin_config, last_signal_format);
+        let load = |content: String| {
+            let mut temp_file = NamedTempFile::new().unwrap();
+            temp_file.write_all(content.as_bytes()).unwrap();
+            Config::load_from_path(temp_file.path())
+        };
+
+        let config = load(config_content("", "plain")).unwrap();
+        assert_eq!(config.recipient.last_signal_outputs[0].config["format"], "plain");
+
+        let error = load(config_content("", "html")).unwrap_err();
+        assert!(format!("{:#}", error).contains("Unknown format 'html'"), "{:#}", error);
+
+        // Check-in requests are always plain text
+        let error = load(config_content(r#"format = "markdown""#, "plain")).unwrap_err();
+        assert!(format!("{:#}", error).contains("only supported on last signal outputs"), "{:#}", error);
+    }
+}
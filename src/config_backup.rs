@@ -0,0 +1,163 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Directory inside the data directory that holds config backups
+const BACKUP_DIRECTORY: &str = "config-backups";
+
+/// A config file whose content differs from the last one seen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub hash: String,
+    pub backup_path: PathBuf,
+    /// Line counts such as "3 lines added, 1 removed". `None` the first time
+    /// a config is seen, when there is nothing to compare against.
+    pub summary: Option<String>,
+}
+
+pub fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Backs the config up if its content hash differs from `previous_hash`,
+/// keeping the `keep` most recent backups. Returns `None` if unchanged.
+pub fn backup_if_changed(
+    content: &str,
+    previous_hash: Option<&str>,
+    data_directory: &Path,
+    keep: usize,
+    now: DateTime<Utc>,
+) -> Result<Option<ConfigChange>> {
+    let hash = content_hash(content);
+    if previous_hash == Some(hash.as_str()) {
+        return Ok(None);
+    }
+
+    let backup_directory = data_directory.join(BACKUP_DIRECTORY);
+    std::fs::create_dir_all(&backup_directory)
+        .with_context(|| format!("Failed to create config backup directory: {:?}", backup_directory))?;
+
+    let summary = match previous_hash {
+        Some(_) => {
+            let previous = latest_backup(&backup_directory)?
+                .map(|path| std::fs::read_to_string(&path).with_context(|| format!("Failed to read config backup: {:?}", path)))
+                .transpose()?
+                .unwrap_or_default();
+            Some(diff_summary(&previous, content))
+        }
+        None => None,
+    };
+
+    let backup_path = backup_directory.join(format!("config-{}.toml", now.format("%Y%m%dT%H%M%S%.3fZ")));
+    std::fs::write(&backup_path, content)
+        .with_context(|| format!("Failed to write config backup: {:?}", backup_path))?;
+    prune_backups(&backup_directory, keep)?;
+
+    Ok(Some(ConfigChange {
+        hash,
+        backup_path,
+        summary,
+    }))
+}
+
+/// Backups sorted oldest first. The timestamped names sort chronologically.
+fn list_backups(backup_directory: &Path) -> Result<Vec<PathBuf>> {
+    let mut backups: Vec<PathBuf> = std::fs::read_dir(backup_directory)
+        .with_context(|| format!("Failed to list config backups in {:?}", backup_directory))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("config-") && name.ends_with(".toml"))
+        })
+        .collect();
+    backups.sort();
+    Ok(backups)
+}
+
+fn latest_backup(backup_directory: &Path) -> Result<Option<PathBuf>> {
+    Ok(list_backups(backup_directory)?.pop())
+}
+
+fn prune_backups(backup_directory: &Path, keep: usize) -> Result<()> {
+    let backups = list_backups(backup_directory)?;
+    let excess = backups.len().saturating_sub(keep.max(1));
+    for path in &backups[..excess] {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove old config backup: {:?}", path))?;
+    }
+    Ok(())
+}
+
+/// Counts lines added and removed between two versions, ignoring order
+fn diff_summary(previous: &str, current: &str) -> String {
+    let mut counts: HashMap<&str, i64> = HashMap::new();
+    for line in previous.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        *counts.entry(line).or_default() -= 1;
+    }
+    for line in current.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        *counts.entry(line).or_default() += 1;
+    }
+
+    let added: i64 = counts.values().filter(|count| **count > 0).sum();
+    let removed: i64 = -counts.values().filter(|count| **count < 0).sum::<i64>();
+    match (added, removed) {
+        (0, 0) => "whitespace only".to_string(),
+        _ => format!(
+            "{} line{} added, {} removed",
+            added,
+            if added == 1 { "" } else { "s" },
+            removed
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_first_config_is_backed_up_without_summary() {
+        let temp_dir = tempdir().unwrap();
+        let change = backup_if_changed("a = 1\n", None, temp_dir.path(), 5, Utc::now()).unwrap().unwrap();
+
+        assert_eq!(change.hash, content_hash("a = 1\n"));
+        assert!(change.summary.is_none());
+        assert_eq!(std::fs::read_to_string(&change.backup_path).unwrap(), "a = 1\n");
+    }
+
+    #[test]
+    fn test_unchanged_config_is_not_backed_up() {
+        let temp_dir = tempdir().unwrap();
+        let hash = content_hash("a = 1\n");
+        assert!(backup_if_changed("a = 1\n", Some(&hash), temp_dir.path(), 5, Utc::now()).unwrap().is_none());
+        assert!(!temp_dir.path().join(BACKUP_DIRECTORY).exists());
+    }
+
+    #[test]
+    fn test_change_is_summarised_and_old_backups_pruned() {
+        let temp_dir = tempdir().unwrap();
+        let start = Utc::now();
+        let mut hash = None;
+
+        for (i, content) in ["a = 1\nb = 2\n", "a = 1\nb = 3\nc = 4\n", "a = 1\n"].iter().enumerate() {
+            let change = backup_if_changed(content, hash.as_deref(), temp_dir.path(), 2, start + Duration::seconds(i as i64))
+                .unwrap()
+                .unwrap();
+            match i {
+                0 => assert!(change.summary.is_none()),
+                1 => assert_eq!(change.summary.as_deref(), Some("2 lines added, 1 removed")),
+                _ => assert_eq!(change.summary.as_deref(), Some("0 lines added, 2 removed")),
+            }
+            hash = Some(change.hash);
+        }
+
+        let backups = list_backups(&temp_dir.path().join(BACKUP_DIRECTORY)).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(std::fs::read_to_string(&backups[1]).unwrap(), "a = 1\n");
+    }
+}
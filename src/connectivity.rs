@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio::time::{timeout, Duration};
+
+use crate::duration_parser::ConfigDuration;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConnectivityConfig {
+    /// `host:port` pairs tried before each cycle. The network counts as up if
+    /// any of them accepts a TCP connection. An empty list disables the check.
+    #[serde(default = "default_probe_hosts")]
+    pub probe_hosts: Vec<String>,
+    /// How soon to try again after a cycle was skipped because the network was down
+    #[serde(default = "default_offline_retry_interval")]
+    pub offline_retry_interval: ConfigDuration,
+}
+
+impl Default for ConnectivityConfig {
+    fn default() -> Self {
+        Self {
+            probe_hosts: default_probe_hosts(),
+            offline_retry_interval: default_offline_retry_interval(),
+        }
+    }
+}
+
+fn default_probe_hosts() -> Vec<String> {
+    vec!["1.1.1.1:443".to_string(), "8.8.8.8:443".to_string()]
+}
+
+fn default_offline_retry_interval() -> ConfigDuration {
+    ConfigDuration::from_minutes(5)
+}
+
+/// Whether this machine can reach the internet at all. When it can't, every
+/// output would fail for the same reason, and those failures say nothing
+/// about the outputs themselves.
+pub async fn network_available(config: &ConnectivityConfig) -> bool {
+    if config.probe_hosts.is_empty() {
+        return true;
+    }
+
+    for host in &config.probe_hosts {
+        match timeout(PROBE_TIMEOUT, TcpStream::connect(host.as_str())).await {
+            Ok(Ok(_)) => return true,
+            Ok(Err(e)) => tracing::debug!("Connectivity probe to {} failed: {}", host, e),
+            Err(_) => tracing::debug!("Connectivity probe to {} timed out", host),
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probing(probe_hosts: Vec<String>) -> ConnectivityConfig {
+        ConnectivityConfig {
+            probe_hosts,
+            ..ConnectivityConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reachable_probe_means_online() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let reachable = listener.local_addr().unwrap().to_string();
+
+        // The first probe fails, the second succeeds
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().to_string()
+        };
+
+        assert!(network_available(&probing(vec![closed, reachable])).await);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_probes_mean_offline() {
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().to_string()
+        };
+
+        assert!(!network_available(&probing(vec![closed, "not a host".to_string()])).await);
+    }
+
+    #[tokio::test]
+    async fn test_no_probes_disables_check() {
+        assert!(network_available(&probing(Vec::new())).await);
+    }
+}
@@ -16,6 +16,7 @@ impl ConfigDuration {
         self.0.as_secs()
     }
 
+    #[allow(dead_code)]
     pub fn as_days(&self) -> u64 {
         self.0.as_secs() / (24 * 60 * 60)
     }
@@ -24,10 +25,6 @@ impl ConfigDuration {
         self.0.as_secs() / (60 * 60)
     }
 
-    pub fn as_minutes(&self) -> u64 {
-        self.0.as_secs() / 60
-    }
-
     pub fn from_days(days: u64) -> Self {
         Self(Duration::from_secs(days * 24 * 60 * 60))
     }
@@ -108,11 +105,11 @@ impl fmt::Display for ConfigDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let secs = self.0.as_secs();
         
-        if secs % (24 * 60 * 60) == 0 {
+        if secs.is_multiple_of(24 * 60 * 60) {
             write!(f, "{}d", secs / (24 * 60 * 60))
-        } else if secs % (60 * 60) == 0 {
+        } else if secs.is_multiple_of(60 * 60) {
             write!(f, "{}h", secs / (60 * 60))
-        } else if secs % 60 == 0 {
+        } else if secs.is_multiple_of(60) {
             write!(f, "{}m", secs / 60)
         } else {
             write!(f, "{}s", secs)
@@ -120,6 +117,65 @@ impl fmt::Display for ConfigDuration {
     }
 }
 
+/// How a duration is written for people to read, as opposed to the config
+/// syntax that `ConfigDuration`'s `Display` produces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// "2d 4h"
+    Compact,
+    /// "2 days and 4 hours"
+    Verbose,
+}
+
+impl DurationStyle {
+    /// The two largest non-zero units of the duration's magnitude, so "1d 2h"
+    /// rather than "1d 2h 5m". The sign is ignored.
+    pub fn format(self, duration: chrono::Duration) -> String {
+        let duration = duration.abs();
+        let units = [
+            (duration.num_days(), "d", "day"),
+            (duration.num_hours() % 24, "h", "hour"),
+            (duration.num_minutes() % 60, "m", "minute"),
+        ];
+        let parts: Vec<String> = units.iter()
+            .skip_while(|(value, _, _)| *value == 0)
+            .take(2)
+            .filter(|(value, _, _)| *value > 0)
+            .map(|(value, short, long)| match self {
+                DurationStyle::Compact => format!("{}{}", value, short),
+                DurationStyle::Verbose => format!("{} {}{}", value, long, if *value == 1 { "" } else { "s" }),
+            })
+            .collect();
+
+        match (self, parts.is_empty()) {
+            (DurationStyle::Compact, true) => "<1m".to_string(),
+            (DurationStyle::Compact, false) => parts.join(" "),
+            (DurationStyle::Verbose, true) => "less than a minute".to_string(),
+            (DurationStyle::Verbose, false) => parts.join(" and "),
+        }
+    }
+
+    /// "in 36h" for a time `offset` from now, "3 days ago" for one in the past
+    pub fn relative(self, offset: chrono::Duration) -> String {
+        if offset.num_minutes() == 0 {
+            "just now".to_string()
+        } else if offset > chrono::Duration::zero() {
+            format!("in {}", self.format(offset))
+        } else {
+            format!("{} ago", self.format(offset))
+        }
+    }
+
+    /// "in 2 days" for a deadline `offset` from now, "overdue by 6 hours" once it has passed
+    pub fn until(self, offset: chrono::Duration) -> String {
+        if offset > chrono::Duration::zero() {
+            format!("in {}", self.format(offset))
+        } else {
+            format!("overdue by {}", self.format(offset))
+        }
+    }
+}
+
 impl Serialize for ConfigDuration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,6 +231,7 @@ impl<'de> Deserialize<'de> for ConfigDuration {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_parse_seconds() {
@@ -247,7 +304,6 @@ mod tests {
         let dur = ConfigDuration::from_days(2);
         assert_eq!(dur.as_days(), 2);
         assert_eq!(dur.as_hours(), 48);
-        assert_eq!(dur.as_minutes(), 2880);
         assert_eq!(dur.as_secs(), 172800);
     }
 
@@ -262,4 +318,62 @@ mod tests {
         let deserialized: ConfigDuration = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized, duration);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_duration_styles() {
+        let duration = chrono::Duration::hours(52) + chrono::Duration::minutes(5);
+        assert_eq!(DurationStyle::Compact.format(duration), "2d 4h");
+        assert_eq!(DurationStyle::Verbose.format(duration), "2 days and 4 hours");
+        assert_eq!(DurationStyle::Verbose.format(chrono::Duration::minutes(61)), "1 hour and 1 minute");
+        assert_eq!(DurationStyle::Verbose.format(chrono::Duration::days(1) + chrono::Duration::minutes(5)), "1 day");
+        assert_eq!(DurationStyle::Compact.format(chrono::Duration::seconds(20)), "<1m");
+        assert_eq!(DurationStyle::Verbose.format(chrono::Duration::seconds(20)), "less than a minute");
+    }
+
+    #[test]
+    fn test_relative_and_until_handle_negative_offsets() {
+        assert_eq!(DurationStyle::Verbose.relative(chrono::Duration::days(-3)), "3 days ago");
+        assert_eq!(DurationStyle::Compact.relative(chrono::Duration::hours(36)), "in 1d 12h");
+        assert_eq!(DurationStyle::Compact.relative(chrono::Duration::seconds(-30)), "just now");
+        assert_eq!(DurationStyle::Verbose.until(chrono::Duration::hours(-6)), "overdue by 6 hours");
+        assert_eq!(DurationStyle::Compact.until(chrono::Duration::days(2)), "in 2d");
+    }
+
+    proptest! {
+        #[test]
+        fn prop_single_unit_compact_matches_display(value in 1..1000u64, unit in 0..3usize) {
+            // Below the next unit up, or an exact multiple of days, both forms use one unit
+            let duration = match unit {
+                0 => ConfigDuration::from_minutes(value % 60 + 1),
+                1 => ConfigDuration::from_hours(value % 24 + 1),
+                _ => ConfigDuration::from_days(value),
+            };
+            let secs = duration.as_secs();
+            prop_assume!(unit == 2 || (secs % 86400 != 0 && (unit == 1 || secs % 3600 != 0)));
+            let compact = DurationStyle::Compact.format(chrono::Duration::seconds(secs as i64));
+            prop_assert_eq!(compact, duration.to_string());
+        }
+
+        #[test]
+        fn prop_compact_parts_parse_back_within_a_unit(secs in 60..(10 * 365 * 86400u64)) {
+            let compact = DurationStyle::Compact.format(chrono::Duration::seconds(secs as i64));
+            let parts: Vec<ConfigDuration> = compact.split(' ')
+                .map(|part| part.parse::<ConfigDuration>().unwrap())
+                .collect();
+            let total: u64 = parts.iter().map(ConfigDuration::as_secs).sum();
+
+            prop_assert!(total <= secs);
+            // Keeping two units drops at most the minutes after a day count
+            prop_assert!(secs - total < 60 * 60);
+        }
+
+        #[test]
+        fn prop_negative_offsets_mirror_positive(secs in 60..(10 * 365 * 86400i64)) {
+            let style = DurationStyle::Verbose;
+            let ahead = style.relative(chrono::Duration::seconds(secs));
+            let behind = style.relative(chrono::Duration::seconds(-secs));
+            prop_assert_eq!(ahead.trim_start_matches("in "), behind.trim_end_matches(" ago"));
+            prop_assert_eq!(style.until(chrono::Duration::seconds(-secs)), format!("overdue by {}", style.format(chrono::Duration::seconds(secs))));
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use std::io::Write;
+
+/// Sent in the clear above the ciphertext so the recipient knows what to do with it
+const PREAMBLE: &str = "This message from LastSignal is encrypted to your age key, so only you can read it.
+
+To decrypt it, save everything from the BEGIN line to the END line to a file, then run:
+
+    age --decrypt --identity <your key file> message.age
+
+age is available from https://age-encryption.org.
+";
+
+/// Parses an `encrypt_to` value. Only age X25519 recipients (`age1...`) are supported.
+pub fn parse_recipient(recipient: &str) -> Result<age::x25519::Recipient> {
+    let recipient = recipient.trim();
+    if recipient.contains("BEGIN PGP") || recipient.ends_with(".asc") || recipient.ends_with(".gpg") {
+        anyhow::bail!("PGP keys are not supported for encrypt_to, use an age recipient (age1...)");
+    }
+
+    recipient.parse::<age::x25519::Recipient>()
+        .map_err(|e| anyhow::anyhow!("Invalid age recipient '{}': {}", recipient, e))
+}
+
+/// Encrypts a message to an age recipient, returning the plaintext preamble
+/// followed by the ASCII-armored ciphertext
+pub fn encrypt_message(message: &str, recipient: &str) -> Result<String> {
+    let recipient = parse_recipient(recipient)?;
+    let encryptor = age::Encryptor::with_recipients(std::iter::once(&recipient as &dyn age::Recipient))
+        .context("Failed to set up age encryption")?;
+
+    let mut ciphertext = Vec::new();
+    let armored = age::armor::ArmoredWriter::wrap_output(&mut ciphertext, age::armor::Format::AsciiArmor)
+        .context("Failed to armor encrypted message")?;
+    let mut writer = encryptor.wrap_output(armored)
+        .context("Failed to encrypt message")?;
+    writer.write_all(message.as_bytes())
+        .context("Failed to encrypt message")?;
+    writer.finish()
+        .and_then(|armored| armored.finish())
+        .context("Failed to finish encrypted message")?;
+
+    let ciphertext = String::from_utf8(ciphertext).context("Armored message is not UTF-8")?;
+    Ok(format!("{}\n{}", PREAMBLE, ciphertext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn decrypt(armored: &str, identity: &age::x25519::Identity) -> String {
+        let start = armored.find("-----BEGIN AGE ENCRYPTED FILE-----").unwrap();
+        let reader = age::armor::ArmoredReader::new(&armored.as_bytes()[start..]);
+        let decryptor = age::Decryptor::new(reader).unwrap();
+        let mut plaintext = String::new();
+        decryptor.decrypt(std::iter::once(identity as &dyn age::Identity))
+            .unwrap()
+            .read_to_string(&mut plaintext)
+            .unwrap();
+        plaintext
+    }
+
+    #[test]
+    fn test_encrypt_round_trip() {
+        let identity = age::x25519::Identity::generate();
+        let recipient = identity.to_public().to_string();
+
+        let encrypted = encrypt_message("I'm sorry to tell you this way", &recipient).unwrap();
+
+        assert!(encrypted.starts_with(PREAMBLE));
+        assert!(!encrypted.contains("sorry"));
+        assert_eq!(decrypt(&encrypted, &identity), "I'm sorry to tell you this way");
+    }
+
+    #[test]
+    fn test_parse_recipient_rejects_invalid_keys() {
+        assert!(parse_recipient("age1notakey").is_err());
+        let pgp = parse_recipient("-----BEGIN PGP PUBLIC KEY BLOCK-----").unwrap_err();
+        assert!(pgp.to_string().contains("PGP keys are not supported"));
+    }
+}
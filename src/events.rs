@@ -0,0 +1,229 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::Mac;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout, Duration};
+
+use crate::signing::hmac_sha256;
+
+/// Pending events kept in memory before new ones are dropped
+const QUEUE_CAPACITY: usize = 100;
+/// Delivery attempts per event before giving up
+const MAX_ATTEMPTS: u32 = 3;
+/// How long one-shot commands wait for queued events before exiting
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct EventsConfig {
+    /// URL that receives a JSON POST for every state transition
+    pub webhook_url: Option<String>,
+    /// Optional secret used to sign each body with HMAC-SHA256
+    pub secret: Option<String>,
+}
+
+/// A notable state transition mirrored to the events webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    CheckinRecorded { source: String },
+    CheckinRequestSent,
+    LastSignalSent { output: String, recipient_id: String },
+    LastSignalAcknowledged { recipient_id: String },
+}
+
+#[derive(Debug, Serialize)]
+struct EventPayload<'a> {
+    #[serde(flatten)]
+    event: &'a Event,
+    timestamp: DateTime<Utc>,
+    version: &'static str,
+}
+
+/// Publishes events to the configured webhook from a background task, so
+/// a slow or broken endpoint never holds up check-ins or notifications.
+pub struct EventPublisher {
+    sender: Option<mpsc::Sender<Event>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl EventPublisher {
+    pub fn new(config: &EventsConfig) -> Self {
+        let Some(webhook_url) = config.webhook_url.clone() else {
+            return Self::disabled();
+        };
+
+        let (sender, receiver) = mpsc::channel(QUEUE_CAPACITY);
+        let worker = tokio::spawn(deliver_events(receiver, Client::new(), webhook_url, config.secret.clone()));
+
+        Self {
+            sender: Some(sender),
+            worker: Some(worker),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            sender: None,
+            worker: None,
+        }
+    }
+
+    /// Queues an event for delivery without waiting for it to be sent
+    pub fn publish(&self, event: Event) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+
+        if let Err(e) = sender.try_send(event) {
+            tracing::warn!("Events webhook queue full or closed, dropping event: {}", e);
+        }
+    }
+
+    /// Stops accepting events and waits briefly for queued ones to be delivered.
+    /// One-shot commands call this before exiting so their events aren't lost.
+    pub async fn close(&mut self) {
+        self.sender.take();
+
+        if let Some(worker) = self.worker.take()
+            && timeout(CLOSE_TIMEOUT, worker).await.is_err()
+        {
+            tracing::warn!("Timed out delivering queued events to the events webhook");
+        }
+    }
+}
+
+async fn deliver_events(
+    mut receiver: mpsc::Receiver<Event>,
+    client: Client,
+    webhook_url: String,
+    secret: Option<String>,
+) {
+    while let Some(event) = receiver.recv().await {
+        let body = match payload_body(&event, Utc::now()) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("Failed to serialize event {:?}: {}", event, e);
+                continue;
+            }
+        };
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            match post_event(&client, &webhook_url, secret.as_deref(), &body).await {
+                Ok(()) => {
+                    tracing::debug!("Delivered event {:?} to events webhook", event);
+                    break;
+                }
+                Err(e) if attempt < MAX_ATTEMPTS => {
+                    tracing::debug!("Events webhook attempt {} failed: {}, retrying", attempt, e);
+                    sleep(Duration::from_secs(2u64.pow(attempt))).await;
+                }
+                Err(e) => {
+                    tracing::warn!("Giving up on event {:?} after {} attempts: {}", event, MAX_ATTEMPTS, e);
+                }
+            }
+        }
+    }
+}
+
+fn payload_body(event: &Event, timestamp: DateTime<Utc>) -> Result<String> {
+    let payload = EventPayload {
+        event,
+        timestamp,
+        version: env!("CARGO_PKG_VERSION"),
+    };
+    serde_json::to_string(&payload).context("Failed to serialize event payload")
+}
+
+/// Computes the `X-LastSignal-Signature` header value for a request body
+pub fn sign(secret: &str, body: &str) -> String {
+    let mac = hmac_sha256(secret.as_bytes(), body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+async fn post_event(client: &Client, webhook_url: &str, secret: Option<&str>, body: &str) -> Result<()> {
+    let mut request = client
+        .post(webhook_url)
+        .header("Content-Type", "application/json")
+        .timeout(std::time::Duration::from_secs(10))
+        .body(body.to_string());
+
+    if let Some(secret) = secret {
+        request = request.header("X-LastSignal-Signature", sign(secret, body));
+    }
+
+    let response = request.send().await.context("Events webhook request failed")?;
+    if !response.status().is_success() {
+        anyhow::bail!("Events webhook returned HTTP {}", response.status());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_matches_known_hmac_sha256() {
+        assert_eq!(
+            sign("key", "The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn test_payload_body_is_tagged() {
+        let event = Event::LastSignalSent {
+            output: "email".to_string(),
+            recipient_id: "email:a@example.com".to_string(),
+        };
+        let body = payload_body(&event, Utc::now()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(json["event"], "last_signal_sent");
+        assert_eq!(json["recipient_id"], "email:a@example.com");
+        assert!(json["timestamp"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_publisher_is_a_no_op() {
+        let mut publisher = EventPublisher::new(&EventsConfig::default());
+        publisher.publish(Event::CheckinRequestSent);
+        publisher.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_publisher_delivers_signed_events() {
+        use axum::{extract::State, http::HeaderMap, routing::post, Router};
+        use std::sync::{Arc, Mutex};
+
+        type Received = Arc<Mutex<Vec<(String, String)>>>;
+        let received: Received = Arc::default();
+
+        let app = Router::new()
+            .route("/events", post(|State(received): State<Received>, headers: HeaderMap, body: String| async move {
+                let signature = headers["x-lastsignal-signature"].to_str().unwrap().to_string();
+                received.lock().unwrap().push((signature, body));
+            }))
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut publisher = EventPublisher::new(&EventsConfig {
+            webhook_url: Some(format!("http://{}/events", address)),
+            secret: Some("s3cret".to_string()),
+        });
+        publisher.publish(Event::CheckinRecorded { source: "manual".to_string() });
+        publisher.close().await;
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (signature, body) = &received[0];
+        assert_eq!(*signature, sign("s3cret", body));
+        assert!(body.contains("\"event\":\"checkin_recorded\""));
+    }
+}
@@ -0,0 +1,285 @@
+use pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+/// The format the message file is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    #[default]
+    Plain,
+    Markdown,
+}
+
+impl std::str::FromStr for MessageFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "plain" => Ok(MessageFormat::Plain),
+            "markdown" => Ok(MessageFormat::Markdown),
+            other => anyhow::bail!("Unknown format '{}', expected plain or markdown", other),
+        }
+    }
+}
+
+fn markdown_options() -> Options {
+    Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES
+}
+
+/// Renders a message for channels that can only display plain text.
+/// Markdown is stripped of its markup and links are unwrapped to `text (url)`.
+pub fn to_plain_text(message: &str, format: MessageFormat) -> String {
+    match format {
+        MessageFormat::Plain => message.to_string(),
+        MessageFormat::Markdown => render_text(message, TextDialect::Plain),
+    }
+}
+
+/// Renders a message as Slack mrkdwn: `*bold*`, `_italic_`, `<url|text>`
+/// links and bullet lists, with `&`, `<` and `>` escaped as Slack requires.
+/// Plain messages are sent as written.
+pub fn to_slack_mrkdwn(message: &str, format: MessageFormat) -> String {
+    match format {
+        MessageFormat::Plain => message.to_string(),
+        MessageFormat::Markdown => render_text(message, TextDialect::Slack),
+    }
+}
+
+/// Renders a message as an HTML fragment. Plain messages are escaped and
+/// keep their line breaks.
+pub fn to_html(message: &str, format: MessageFormat) -> String {
+    match format {
+        MessageFormat::Plain => {
+            let mut escaped = String::new();
+            // Writing into a String cannot fail
+            html::push_html(&mut escaped, std::iter::once(Event::Text(message.into())));
+            format!("<p>{}</p>", escaped.replace('\n', "<br>\n"))
+        }
+        MessageFormat::Markdown => {
+            let mut rendered = String::new();
+            html::push_html(&mut rendered, Parser::new_ext(message, markdown_options()));
+            rendered
+        }
+    }
+}
+
+/// Splits a message into chunks of at most `max_chars` characters for channels
+/// with a length limit, preferring to break at whitespace.
+pub fn split_message(message: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut remaining = message.trim();
+
+    while remaining.chars().count() > max_chars {
+        // Byte offset just past the first `max_chars` characters
+        let limit = remaining.char_indices().nth(max_chars).map(|(i, _)| i).unwrap_or(remaining.len());
+        let split_at = remaining[..limit]
+            .rfind(char::is_whitespace)
+            .filter(|&i| i > 0)
+            .unwrap_or(limit);
+
+        chunks.push(remaining[..split_at].trim_end().to_string());
+        remaining = remaining[split_at..].trim_start();
+    }
+
+    if !remaining.is_empty() || chunks.is_empty() {
+        chunks.push(remaining.to_string());
+    }
+    chunks
+}
+
+/// The text-based formats Markdown is rendered into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextDialect {
+    Plain,
+    Slack,
+}
+
+impl TextDialect {
+    fn push_text(self, output: &mut String, text: &str) {
+        match self {
+            TextDialect::Plain => output.push_str(text),
+            TextDialect::Slack => output.push_str(&text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")),
+        }
+    }
+
+    /// Markup around bold, italic and struck-through text, if the dialect has any
+    fn marker(self, tag: &TagEnd) -> &'static str {
+        match (self, tag) {
+            (TextDialect::Slack, TagEnd::Strong | TagEnd::Heading(_)) => "*",
+            (TextDialect::Slack, TagEnd::Emphasis) => "_",
+            (TextDialect::Slack, TagEnd::Strikethrough) => "~",
+            _ => "",
+        }
+    }
+}
+
+fn render_text(markdown: &str, dialect: TextDialect) -> String {
+    let mut output = String::new();
+    // Stack of ordered-list counters (None for bullet lists)
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // Link destinations and the output offset where the link text started
+    let mut links: Vec<(String, usize)> = Vec::new();
+
+    for event in Parser::new_ext(markdown, markdown_options()) {
+        match event {
+            Event::Start(Tag::Paragraph) => {}
+            Event::Start(Tag::Heading { level, .. }) => output.push_str(dialect.marker(&TagEnd::Heading(level))),
+            Event::End(tag @ (TagEnd::Paragraph | TagEnd::Heading(_))) => {
+                output.push_str(dialect.marker(&tag));
+                output.push_str(if lists.is_empty() { "\n\n" } else { "\n" });
+            }
+            Event::Start(Tag::Strong) => output.push_str(dialect.marker(&TagEnd::Strong)),
+            Event::Start(Tag::Emphasis) => output.push_str(dialect.marker(&TagEnd::Emphasis)),
+            Event::Start(Tag::Strikethrough) => output.push_str(dialect.marker(&TagEnd::Strikethrough)),
+            Event::End(tag @ (TagEnd::Strong | TagEnd::Emphasis | TagEnd::Strikethrough)) => {
+                output.push_str(dialect.marker(&tag));
+            }
+            Event::Start(Tag::List(start)) => {
+                if lists.is_empty() && !output.is_empty() && !output.ends_with("\n\n") {
+                    output.push('\n');
+                }
+                lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+                if lists.is_empty() {
+                    output.push('\n');
+                }
+            }
+            Event::Start(Tag::Item) => {
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                let depth = lists.len().saturating_sub(1);
+                output.push_str(&"  ".repeat(depth));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        output.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ if dialect == TextDialect::Slack => output.push_str("• "),
+                    _ => output.push_str("- "),
+                }
+            }
+            Event::End(TagEnd::Item) if !output.ends_with('\n') => output.push('\n'),
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                links.push((dest_url.to_string(), output.len()));
+            }
+            Event::End(TagEnd::Link) => {
+                let Some((url, text_start)) = links.pop() else { continue };
+                match dialect {
+                    TextDialect::Plain if output[text_start..] != url && !url.is_empty() => {
+                        output.push_str(&format!(" ({})", url));
+                    }
+                    TextDialect::Plain => {}
+                    TextDialect::Slack => {
+                        let text = output.split_off(text_start);
+                        if text == url || text.is_empty() {
+                            output.push_str(&format!("<{}>", url));
+                        } else {
+                            output.push_str(&format!("<{}|{}>", url, text));
+                        }
+                    }
+                }
+            }
+            Event::Start(Tag::CodeBlock(_)) if dialect == TextDialect::Slack => output.push_str("```\n"),
+            Event::Start(Tag::CodeBlock(_)) => {}
+            Event::End(TagEnd::CodeBlock) if dialect == TextDialect::Slack => output.push_str("```\n"),
+            Event::End(TagEnd::CodeBlock) => output.push('\n'),
+            Event::Code(code) if dialect == TextDialect::Slack => {
+                output.push('`');
+                dialect.push_text(&mut output, &code);
+                output.push('`');
+            }
+            Event::Text(text) | Event::Code(text) => dialect.push_text(&mut output, &text),
+            Event::SoftBreak | Event::HardBreak => output.push('\n'),
+            Event::Rule => output.push_str("----------\n\n"),
+            Event::Html(raw) | Event::InlineHtml(raw) => dialect.push_text(&mut output, &raw),
+            _ => {}
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_format_is_untouched() {
+        let message = "Call me at *555-0100* or [email](mailto:me@example.com)";
+        assert_eq!(to_plain_text(message, MessageFormat::Plain), message);
+    }
+
+    #[test]
+    fn test_markdown_to_plain_strips_emphasis_and_headings() {
+        let message = "# Emergency\n\nPlease call **555-0100** _now_.";
+        let plain = to_plain_text(message, MessageFormat::Markdown);
+        assert_eq!(plain, "Emergency\n\nPlease call 555-0100 now.");
+    }
+
+    #[test]
+    fn test_markdown_to_plain_unwraps_links() {
+        let message = "See [my instructions](https://example.com/plan) or <https://example.com>";
+        let plain = to_plain_text(message, MessageFormat::Markdown);
+        assert_eq!(plain, "See my instructions (https://example.com/plan) or https://example.com");
+    }
+
+    #[test]
+    fn test_markdown_to_plain_keeps_list_structure() {
+        let message = "Steps:\n\n1. Call me\n2. Call my sister\n\n- keys are under the mat";
+        let plain = to_plain_text(message, MessageFormat::Markdown);
+        assert_eq!(plain, "Steps:\n\n1. Call me\n2. Call my sister\n\n- keys are under the mat");
+    }
+
+    #[test]
+    fn test_markdown_to_slack_mrkdwn() {
+        let message = "# Emergency\n\nCall **555-0100** _now_, see [my plan](https://example.com/plan) or <https://example.com>.\n\n- keys < mat & door\n- ~old~ `code`\n\n1. first";
+        assert_eq!(
+            to_slack_mrkdwn(message, MessageFormat::Markdown),
+            "*Emergency*\n\nCall *555-0100* _now_, see <https://example.com/plan|my plan> or <https://example.com>.\n\n• keys &lt; mat &amp; door\n• ~old~ `code`\n\n1. first"
+        );
+        assert_eq!(to_slack_mrkdwn("Call *me*", MessageFormat::Plain), "Call *me*");
+    }
+
+    #[test]
+    fn test_markdown_to_html() {
+        let html = to_html("Call **555-0100**", MessageFormat::Markdown);
+        assert!(html.contains("<strong>555-0100</strong>"));
+    }
+
+    #[test]
+    fn test_plain_to_html_escapes_and_keeps_line_breaks() {
+        let html = to_html("a < b\nc", MessageFormat::Plain);
+        assert_eq!(html, "<p>a &lt; b<br>\nc</p>");
+    }
+
+    #[test]
+    fn test_split_message_short_is_single_chunk() {
+        assert_eq!(split_message("hello world", 20), vec!["hello world"]);
+    }
+
+    #[test]
+    fn test_split_message_breaks_at_whitespace() {
+        let chunks = split_message("one two three four", 9);
+        assert_eq!(chunks, vec!["one two", "three", "four"]);
+    }
+
+    #[test]
+    fn test_split_message_hard_splits_long_words_by_char() {
+        let chunks = split_message("ééééé", 2);
+        assert_eq!(chunks, vec!["éé", "éé", "é"]);
+    }
+
+    #[test]
+    fn test_format_deserializes_lowercase() {
+        let format: MessageFormat = serde_json::from_str("\"markdown\"").unwrap();
+        assert_eq!(format, MessageFormat::Markdown);
+        let format: MessageFormat = serde_json::from_str("\"plain\"").unwrap();
+        assert_eq!(format, MessageFormat::Plain);
+
+        assert_eq!("markdown".parse::<MessageFormat>().unwrap(), MessageFormat::Markdown);
+        assert!("html".parse::<MessageFormat>().is_err());
+    }
+}
@@ -0,0 +1,483 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::redact;
+use crate::secrets;
+
+const SSO_URL: &str = "https://sso.garmin.com/sso";
+const SSO_EMBED_URL: &str = "https://sso.garmin.com/sso/embed";
+const CONNECT_API_URL: &str = "https://connectapi.garmin.com";
+const USER_AGENT: &str = concat!("lastsignal/", env!("CARGO_PKG_VERSION"));
+pub const TOKEN_FILE: &str = "garmin_tokens.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GarminTokens {
+    pub oauth1_token: String,
+    pub oauth1_token_secret: String,
+    pub access_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The OAuth 1.0a consumer Garmin issued for signing requests, from the
+/// `garmin` output's config. Nothing is fetched at runtime.
+#[derive(Debug, Clone)]
+pub struct OAuthConsumer {
+    pub consumer_key: String,
+    pub consumer_secret: String,
+}
+
+impl OAuthConsumer {
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        let field = |name: &str| {
+            config
+                .get(name)
+                .cloned()
+                .with_context(|| format!("Missing '{}' field in garmin config", name))
+        };
+        Ok(Self {
+            consumer_key: field("consumer_key")?,
+            consumer_secret: field("consumer_secret")?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExchangeResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Percent-encoding as OAuth 1.0a signatures require: everything but unreserved characters
+fn encode(value: &str) -> String {
+    urlencoding::encode(value).into_owned()
+}
+
+/// The `Authorization` header for an OAuth 1.0a HMAC-SHA1 request.
+/// `params` are the query and form parameters, which are signed too.
+fn oauth1_header(
+    method: &str,
+    url: &str,
+    params: &[(&str, &str)],
+    consumer: (&str, &str),
+    token: Option<(&str, &str)>,
+    nonce: &str,
+    timestamp: i64,
+) -> String {
+    let timestamp = timestamp.to_string();
+    let mut oauth_params = vec![
+        ("oauth_consumer_key", consumer.0),
+        ("oauth_nonce", nonce),
+        ("oauth_signature_method", "HMAC-SHA1"),
+        ("oauth_timestamp", timestamp.as_str()),
+        ("oauth_version", "1.0"),
+    ];
+    if let Some((token, _)) = token {
+        oauth_params.push(("oauth_token", token));
+    }
+
+    let mut signed: Vec<(String, String)> = params
+        .iter()
+        .chain(oauth_params.iter())
+        .map(|(key, value)| (encode(key), encode(value)))
+        .collect();
+    signed.sort();
+    let parameter_string = signed
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let base_string = format!("{}&{}&{}", method.to_uppercase(), encode(url), encode(&parameter_string));
+    let signing_key = format!("{}&{}", encode(consumer.1), encode(token.map_or("", |(_, secret)| secret)));
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(signing_key.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(base_string.as_bytes());
+    let signature = {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    };
+
+    oauth_params.push(("oauth_signature", signature.as_str()));
+    let fields = oauth_params
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, encode(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("OAuth {}", fields)
+}
+
+fn oauth1_nonce() -> String {
+    hex::encode(rand::random::<[u8; 16]>())
+}
+
+/// The `_csrf` value in the SSO sign-in form
+fn find_csrf_token(html: &str) -> Option<&str> {
+    let start = html.find("name=\"_csrf\"")?;
+    let rest = &html[start..];
+    let value = &rest[rest.find("value=\"")? + 7..];
+    Some(&value[..value.find('"')?])
+}
+
+fn find_title(html: &str) -> Option<&str> {
+    let start = html.find("<title>")? + 7;
+    Some(html[start..][..html[start..].find("</title>")?].trim())
+}
+
+/// The service ticket in the page SSO shows after a successful sign-in
+fn find_ticket(html: &str) -> Option<&str> {
+    let start = html.find("embed?ticket=")? + 13;
+    let rest = &html[start..];
+    Some(&rest[..rest.find('"')?])
+}
+
+/// Remembers SSO session cookies between requests
+#[derive(Debug, Default)]
+struct Cookies(HashMap<String, String>);
+
+impl Cookies {
+    fn store(&mut self, response: &reqwest::Response) {
+        for cookie in response.headers().get_all(header::SET_COOKIE) {
+            let Ok(cookie) = cookie.to_str() else { continue };
+            let pair = cookie.split(';').next().unwrap_or_default();
+            if let Some((name, value)) = pair.split_once('=') {
+                self.0.insert(name.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    fn header(&self) -> String {
+        self.0.iter().map(|(name, value)| format!("{}={}", name, value)).collect::<Vec<_>>().join("; ")
+    }
+}
+
+/// Signs in to Garmin Connect the way its mobile app does, since Garmin's
+/// Health API is only open to businesses: an SSO ticket is traded for a
+/// long-lived OAuth 1.0a token, which is exchanged for the short-lived bearer
+/// tokens the Connect API takes. Refreshing is another exchange, which works
+/// until the OAuth 1.0a token expires after about a year.
+#[derive(Debug)]
+pub struct GarminAuth {
+    client: Client,
+    consumer: OAuthConsumer,
+    data_directory: std::path::PathBuf,
+}
+
+impl GarminAuth {
+    pub fn new(consumer: OAuthConsumer, data_directory: std::path::PathBuf) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .context("Failed to create Garmin HTTP client")?;
+        Ok(Self {
+            client,
+            consumer,
+            data_directory,
+        })
+    }
+
+    pub fn tokens_file(&self) -> std::path::PathBuf {
+        self.data_directory.join(TOKEN_FILE)
+    }
+
+    /// Signs in with a Garmin account's email and password, returning a new set of tokens
+    pub async fn login(&self, email: &str, password: &str) -> Result<GarminTokens> {
+        let ticket = self.sso_ticket(email, password).await?;
+        let (oauth1_token, oauth1_token_secret) = self.preauthorize(&ticket).await?;
+
+        let mut tokens = GarminTokens {
+            oauth1_token,
+            oauth1_token_secret,
+            access_token: String::new(),
+            expires_at: Utc::now(),
+        };
+        self.exchange(&mut tokens).await?;
+        Ok(tokens)
+    }
+
+    async fn sso_ticket(&self, email: &str, password: &str) -> Result<String> {
+        let signin_url = format!("{}/signin", SSO_URL);
+        let signin_params = [
+            ("id", "gauth-widget"),
+            ("embedWidget", "true"),
+            ("gauthHost", SSO_EMBED_URL),
+            ("service", SSO_EMBED_URL),
+            ("source", SSO_EMBED_URL),
+            ("redirectAfterAccountLoginUrl", SSO_EMBED_URL),
+            ("redirectAfterAccountCreationUrl", SSO_EMBED_URL),
+        ];
+        let mut cookies = Cookies::default();
+
+        // Sets the session cookies the sign-in form expects
+        let response = self.client
+            .get(SSO_EMBED_URL)
+            .query(&[("id", "gauth-widget"), ("embedWidget", "true"), ("gauthHost", SSO_URL)])
+            .send()
+            .await
+            .context("Failed to reach Garmin SSO")?;
+        cookies.store(&response);
+
+        let response = self.client
+            .get(&signin_url)
+            .query(&signin_params)
+            .header(header::REFERER, SSO_EMBED_URL)
+            .header(header::COOKIE, cookies.header())
+            .send()
+            .await
+            .context("Failed to load the Garmin sign-in form")?;
+        cookies.store(&response);
+        let html = response.text().await.context("Failed to read the Garmin sign-in form")?;
+        let csrf = find_csrf_token(&html).context("Garmin sign-in form has no CSRF token")?.to_string();
+
+        let response = self.client
+            .post(&signin_url)
+            .query(&signin_params)
+            .header(header::REFERER, &signin_url)
+            .header(header::COOKIE, cookies.header())
+            .form(&[("username", email), ("password", password), ("embed", "true"), ("_csrf", csrf.as_str())])
+            .send()
+            .await
+            .context("Failed to submit the Garmin sign-in form")?;
+        let html = response.text().await.context("Failed to read the Garmin sign-in response")?;
+
+        match find_title(&html) {
+            Some("Success") => {}
+            Some(title) if title.contains("MFA") => {
+                anyhow::bail!("This Garmin account uses two-factor authentication, which garmin-auth doesn't support yet")
+            }
+            Some(title) => anyhow::bail!("Garmin sign-in failed: {}. Check the email and password", title),
+            None => anyhow::bail!("Garmin sign-in failed: unexpected response"),
+        }
+
+        find_ticket(&html)
+            .map(str::to_string)
+            .context("Garmin sign-in succeeded but returned no ticket")
+    }
+
+    /// Trades an SSO ticket for the long-lived OAuth 1.0a token
+    async fn preauthorize(&self, ticket: &str) -> Result<(String, String)> {
+        let url = format!("{}/oauth-service/oauth/preauthorized", CONNECT_API_URL);
+        let params = [
+            ("ticket", ticket),
+            ("login-url", SSO_EMBED_URL),
+            ("accepts-mfa-tokens", "true"),
+        ];
+        let authorization = oauth1_header(
+            "GET",
+            &url,
+            &params,
+            (&self.consumer.consumer_key, &self.consumer.consumer_secret),
+            None,
+            &oauth1_nonce(),
+            Utc::now().timestamp(),
+        );
+
+        let response = self.client
+            .get(&url)
+            .query(&params)
+            .header(header::AUTHORIZATION, authorization)
+            .send()
+            .await
+            .context("Failed to request a Garmin OAuth token")?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            anyhow::bail!("Garmin OAuth token request failed ({}): {}", status, redact::redact(&body));
+        }
+
+        let fields: HashMap<String, String> = body
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .filter_map(|(key, value)| Some((key.to_string(), urlencoding::decode(value).ok()?.into_owned())))
+            .collect();
+        match (fields.get("oauth_token"), fields.get("oauth_token_secret")) {
+            (Some(token), Some(secret)) => Ok((token.clone(), secret.clone())),
+            _ => anyhow::bail!("Garmin OAuth token response is missing the token"),
+        }
+    }
+
+    /// Exchanges the OAuth 1.0a token for a fresh bearer token
+    pub async fn exchange(&self, tokens: &mut GarminTokens) -> Result<()> {
+        let url = format!("{}/oauth-service/oauth/exchange/user/2.0", CONNECT_API_URL);
+        let authorization = oauth1_header(
+            "POST",
+            &url,
+            &[],
+            (&self.consumer.consumer_key, &self.consumer.consumer_secret),
+            Some((&tokens.oauth1_token, &tokens.oauth1_token_secret)),
+            &oauth1_nonce(),
+            Utc::now().timestamp(),
+        );
+
+        let response = self.client
+            .post(&url)
+            .header(header::AUTHORIZATION, authorization)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .send()
+            .await
+            .context("Failed to exchange Garmin OAuth token")?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        redact::log_payload("Garmin token exchange response", status, &body);
+        if !status.is_success() {
+            anyhow::bail!(
+                "Garmin token exchange failed ({}). If this keeps happening, run 'lastsignal garmin-auth' again",
+                status
+            );
+        }
+
+        let exchange: ExchangeResponse = serde_json::from_str(&body).context("Failed to parse Garmin token exchange response")?;
+        tokens.access_token = exchange.access_token;
+        tokens.expires_at = crate::oauth::expires_at_from_expires_in(exchange.expires_in, Utc::now());
+        Ok(())
+    }
+
+    pub fn save_tokens(&self, tokens: &GarminTokens) -> Result<()> {
+        let tokens_file = self.tokens_file();
+        if let Some(parent) = tokens_file.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+
+        let tokens_json = serde_json::to_string_pretty(tokens).context("Failed to serialize tokens")?;
+        secrets::write(&tokens_file, &tokens_json)?;
+
+        tracing::info!("Saved Garmin tokens to: {:?}", tokens_file);
+        Ok(())
+    }
+
+    pub fn load_tokens(&self) -> Result<GarminTokens> {
+        let tokens_file = self.tokens_file();
+        if !tokens_file.exists() {
+            anyhow::bail!("No Garmin tokens found. Please run 'lastsignal garmin-auth' first.");
+        }
+
+        let tokens_json = secrets::read(&tokens_file)?;
+        serde_json::from_str(&tokens_json).context("Failed to parse tokens file")
+    }
+
+    /// Exchanges for a new bearer token if the stored one expires within `buffer`.
+    /// Returns whether it did.
+    pub async fn refresh_if_expiring(&self, buffer: chrono::Duration) -> Result<bool> {
+        let mut tokens = self.load_tokens()?;
+        if tokens.expires_at > Utc::now() + buffer {
+            return Ok(false);
+        }
+        self.exchange(&mut tokens).await?;
+        self.save_tokens(&tokens)?;
+        Ok(true)
+    }
+
+    pub async fn get_valid_access_token(&self) -> Result<String> {
+        if self.refresh_if_expiring(chrono::Duration::minutes(5)).await? {
+            tracing::info!("Garmin access token expired or expiring soon, refreshed");
+        }
+        Ok(self.load_tokens()?.access_token)
+    }
+}
+
+pub async fn run_garmin_authentication(
+    consumer: OAuthConsumer,
+    email: String,
+    password: Option<String>,
+    data_directory: std::path::PathBuf,
+) -> Result<()> {
+    let password = match password {
+        Some(password) => password,
+        None => {
+            print!("Garmin Connect password for {}: ", email);
+            std::io::stdout().flush()?;
+            let mut password = String::new();
+            std::io::stdin().read_line(&mut password).context("Failed to read password")?;
+            password.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    let auth = GarminAuth::new(consumer, data_directory)?;
+    println!("🔄 Signing in to Garmin Connect...");
+    let tokens = auth.login(&email, &password).await?;
+    auth.save_tokens(&tokens)?;
+
+    println!("✅ Successfully authenticated with Garmin Connect!");
+    println!("📁 Tokens saved to: {:?}", auth.tokens_file());
+    println!("\nAdd a garmin output with the same consumer_key and consumer_secret to your LastSignal configuration.");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_oauth1_signature() {
+        // The worked example from Twitter's "Creating a signature" guide
+        let header = oauth1_header(
+            "POST",
+            "https://api.twitter.com/1.1/statuses/update.json",
+            &[("include_entities", "true"), ("status", "Hello Ladies + Gentlemen, a signed OAuth request!")],
+            ("xvz1evFS4wEEPTGEFPHBog", "kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw"),
+            Some(("370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb", "LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE")),
+            "kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg",
+            1318622958,
+        );
+        assert!(header.starts_with("OAuth oauth_consumer_key=\"xvz1evFS4wEEPTGEFPHBog\", "));
+        assert!(header.contains("oauth_token=\"370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb\""));
+        assert!(header.ends_with("oauth_signature=\"hCtSmYh%2BiHYCEqBWrE7C7hYmtUk%3D\""), "{}", header);
+    }
+
+    #[test]
+    fn test_sso_page_parsing() {
+        let form = r#"<form><input type="hidden" name="_csrf" value="2A8B7C"/></form>"#;
+        assert_eq!(find_csrf_token(form), Some("2A8B7C"));
+        assert_eq!(find_csrf_token("<form></form>"), None);
+
+        let success = r#"<html><head><title>Success</title></head><body><script>var url = "https://sso.garmin.com/sso/embed?ticket=ST-0123-abc-cas";</script></body></html>"#;
+        assert_eq!(find_title(success), Some("Success"));
+        assert_eq!(find_ticket(success), Some("ST-0123-abc-cas"));
+
+        assert_eq!(find_title("<title> GARMIN > Enter MFA code for login </title>"), Some("GARMIN > Enter MFA code for login"));
+    }
+
+    fn consumer() -> OAuthConsumer {
+        OAuthConsumer { consumer_key: "consumer".to_string(), consumer_secret: "consumer-secret".to_string() }
+    }
+
+    #[test]
+    fn test_consumer_from_config() {
+        let config: HashMap<String, String> = [("consumer_key", "key"), ("consumer_secret", "secret")]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let consumer = OAuthConsumer::from_config(&config).unwrap();
+        assert_eq!((consumer.consumer_key.as_str(), consumer.consumer_secret.as_str()), ("key", "secret"));
+
+        let error = OAuthConsumer::from_config(&HashMap::new()).unwrap_err();
+        assert!(error.to_string().contains("consumer_key"), "{}", error);
+    }
+
+    #[test]
+    fn test_tokens_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let auth = GarminAuth::new(consumer(), temp_dir.path().to_path_buf()).unwrap();
+        assert!(auth.load_tokens().unwrap_err().to_string().contains("garmin-auth"));
+
+        let tokens = GarminTokens {
+            oauth1_token: "token".to_string(),
+            oauth1_token_secret: "token-secret".to_string(),
+            access_token: "bearer".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+        };
+        auth.save_tokens(&tokens).unwrap();
+        assert_eq!(auth.tokens_file(), temp_dir.path().join("garmin_tokens.json"));
+        assert_eq!(auth.load_tokens().unwrap().oauth1_token, "token");
+    }
+}
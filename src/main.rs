@@ -1,16 +1,36 @@
 use anyhow::Result;
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, ArgMatches, Command};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
+mod acknowledgement;
 mod app;
+mod audit;
+mod browser;
+mod checkin_server;
 mod config;
+mod config_backup;
+mod connectivity;
 mod duration_parser;
+mod encryption;
+mod events;
+mod formatting;
+mod garmin_auth;
 mod message_adapter;
+mod monitoring;
+mod notifier;
 mod oauth;
 mod outputs;
+mod redact;
+mod schedule;
+mod secrets;
+mod shutdown;
+mod signing;
 mod state;
+mod status;
 
 use app::LastSignalApp;
+use outputs::chaos::ChaosSpec;
+use status::StatusView;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -29,12 +49,92 @@ async fn main() -> Result<()> {
         .subcommand(
             Command::new("status")
                 .about("Show current status and configuration")
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Print the status report as JSON")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("short")
+                        .long("short")
+                        .help("Print a one-line summary for terminal dashboards")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("json")
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("TEMPLATE")
+                        .help("Print a custom line, e.g. \"{icon} {last_checkin_rel} {deadline_rel}\"")
+                        .conflicts_with_all(["json", "short"])
+                )
+        )
+        .subcommand(
+            Command::new("fire")
+                .about("Send the last signal to all recipients now")
+                .arg(
+                    Arg::new("force-renotify")
+                        .long("force-renotify")
+                        .help("Send even to recipients notified within min_renotify_interval")
+                        .action(ArgAction::SetTrue)
+                )
+        )
+        .subcommand(
+            Command::new("rehearse")
+                .about("Send the real last signal to the canary outputs only, leaving the real recipients untouched")
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Check the configuration and report ignored or misspelt keys")
         )
         .subcommand(
             Command::new("test")
                 .about("Test all configured outputs")
+                .arg(
+                    Arg::new("send")
+                        .long("send")
+                        .help("Also send a test message through every checkin output")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("chaos")
+                        .long("chaos")
+                        .value_name("SPEC")
+                        .help("Inject faults into outputs, e.g. \"email:fail_until=+72h,file:latency=20s\"")
+                )
         )
         .subcommand(
+            Command::new("outputs")
+                .about("Inspect configured outputs")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("list")
+                        .about("Show the order checkin outputs are tried in")
+                        .arg(
+                            Arg::new("at")
+                                .long("at")
+                                .value_name("TIME")
+                                .help("Show the order at this time instead of now: HH:MM in the configured timezone, or RFC 3339")
+                        )
+                )
+        )
+        .subcommand(
+            Command::new("reviewed")
+                .about("Record that the configuration has been reviewed and is up to date")
+        )
+        .subcommand(
+            Command::new("generate-checkin-url")
+                .about("Print a URL that records a check-in when opened, for phone shortcuts")
+                .arg(
+                    Arg::new("valid-for")
+                        .long("valid-for")
+                        .value_name("DURATION")
+                        .help("How long the URL works, e.g. 90d")
+                        .default_value("90d")
+                )
+        )
+        .subcommand(oauth_flow_args(
             Command::new("whoop-auth")
                 .about("Authenticate with WHOOP API")
                 .arg(
@@ -42,15 +142,124 @@ async fn main() -> Result<()> {
                         .long("client-id")
                         .value_name("CLIENT_ID")
                         .help("WHOOP OAuth client ID")
-                        .required(true)
+                        .required_unless_present_any(["status", "refresh"])
                 )
                 .arg(
                     Arg::new("client-secret")
                         .long("client-secret")
                         .value_name("CLIENT_SECRET")
                         .help("WHOOP OAuth client secret")
+                        .required_unless_present_any(["status", "refresh"])
+                )
+                .arg(
+                    Arg::new("status")
+                        .long("status")
+                        .help("Show the stored tokens' expiry and try a refresh, failing if you need to authorize again")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with_all(["refresh", "manual"])
+                )
+                .arg(
+                    Arg::new("refresh")
+                        .long("refresh")
+                        .help("Refresh the stored tokens now")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("manual")
+                )
+                .arg(
+                    Arg::new("token-file")
+                        .long("token-file")
+                        .value_name("FILE")
+                        .help("Where to save the tokens, relative to the data directory (matches the output's token_file)")
+                )
+        ))
+        .subcommand(
+            Command::new("whoop-status")
+                .about("Show WHOOP token expiry and latest activity, and whether it counts as a check-in")
+        )
+        .subcommand(oauth_flow_args(
+            Command::new("fitbit-auth")
+                .about("Authenticate with Fitbit Web API")
+                .arg(
+                    Arg::new("client-id")
+                        .long("client-id")
+                        .value_name("CLIENT_ID")
+                        .help("Fitbit OAuth 2.0 client ID")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("client-secret")
+                        .long("client-secret")
+                        .value_name("CLIENT_SECRET")
+                        .help("Fitbit client secret")
+                        .required(true)
+                )
+        ))
+        .subcommand(oauth_flow_args(
+            Command::new("withings-auth")
+                .about("Authenticate with Withings API")
+                .arg(
+                    Arg::new("client-id")
+                        .long("client-id")
+                        .value_name("CLIENT_ID")
+                        .help("Withings OAuth client ID")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("client-secret")
+                        .long("client-secret")
+                        .value_name("CLIENT_SECRET")
+                        .help("Withings client secret")
+                        .required(true)
+                )
+        ))
+        .subcommand(oauth_flow_args(
+            Command::new("gmail-auth")
+                .about("Authorize Gmail for email outputs with auth_method = \"xoauth2\"")
+                .arg(
+                    Arg::new("client-id")
+                        .long("client-id")
+                        .value_name("CLIENT_ID")
+                        .help("Google OAuth client ID")
                         .required(true)
                 )
+                .arg(
+                    Arg::new("client-secret")
+                        .long("client-secret")
+                        .value_name("CLIENT_SECRET")
+                        .help("Google OAuth client secret")
+                        .required(true)
+                )
+        ))
+        .subcommand(
+            Command::new("garmin-auth")
+                .about("Sign in to Garmin Connect")
+                .arg(
+                    Arg::new("consumer-key")
+                        .long("consumer-key")
+                        .value_name("CONSUMER_KEY")
+                        .help("Garmin OAuth consumer key")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("consumer-secret")
+                        .long("consumer-secret")
+                        .value_name("CONSUMER_SECRET")
+                        .help("Garmin OAuth consumer secret")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("email")
+                        .long("email")
+                        .value_name("EMAIL")
+                        .help("Garmin Connect account email")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("password")
+                        .long("password")
+                        .value_name("PASSWORD")
+                        .help("Garmin Connect password, prompted for if not given")
+                )
         )
         .subcommand(
             Command::new("facebook-auth")
@@ -62,6 +271,24 @@ async fn main() -> Result<()> {
                         .help("Facebook Page access token")
                         .required(true)
                 )
+                .arg(
+                    Arg::new("app-id")
+                        .long("app-id")
+                        .value_name("APP_ID")
+                        .help("Facebook app ID, to exchange the token for a long-lived one")
+                        .requires("app-secret")
+                )
+                .arg(
+                    Arg::new("app-secret")
+                        .long("app-secret")
+                        .value_name("APP_SECRET")
+                        .help("Facebook app secret, to exchange the token for a long-lived one")
+                        .requires("app-id")
+                )
+        )
+        .subcommand(
+            Command::new("migrate-secrets")
+                .about("Encrypt plaintext token files with the [encryption] passphrase")
         )
         .arg(
             Arg::new("config")
@@ -80,6 +307,11 @@ async fn main() -> Result<()> {
         crate::config::Config::load()?
     };
     
+    crate::redact::set_log_sensitive_payloads(config.app.log_sensitive_payloads);
+    if let Some(encryption) = &config.encryption {
+        crate::secrets::set_passphrase(encryption.passphrase()?);
+    }
+
     // Initialize logging with config log level
     let filter = EnvFilter::try_from_default_env()
         .or_else(|_| EnvFilter::try_new(&config.app.log_level))
@@ -94,34 +326,148 @@ async fn main() -> Result<()> {
     match matches.subcommand() {
         Some(("run", _)) => {
             tracing::debug!("About to create LastSignalApp...");
-            let mut app = LastSignalApp::from_config(config).await?;
+            let mut app = LastSignalApp::for_daemon(config).await?;
             tracing::debug!("LastSignalApp created successfully, starting run...");
-            app.run().await?;
+            app.run(shutdown::Shutdown::on_signals()?).await?;
         }
         Some(("checkin", _)) => {
             let mut app = LastSignalApp::from_config(config).await?;
             app.checkin().await?;
         }
-        Some(("status", _)) => {
+        Some(("status", sub_matches)) => {
             let app = LastSignalApp::from_config(config).await?;
-            app.status().await?;
+            let view = if let Some(template) = sub_matches.get_one::<String>("format") {
+                StatusView::Template(template.clone())
+            } else if sub_matches.get_flag("short") {
+                StatusView::Short
+            } else if sub_matches.get_flag("json") {
+                StatusView::Json
+            } else {
+                StatusView::Text
+            };
+            app.status(view).await?;
+        }
+        Some(("fire", sub_matches)) => {
+            let mut app = LastSignalApp::from_config(config).await?;
+            app.fire(sub_matches.get_flag("force-renotify")).await?;
+        }
+        Some(("rehearse", _)) => {
+            let mut app = LastSignalApp::from_config(config).await?;
+            app.rehearse().await?;
+        }
+        Some(("validate", _)) => {
+            let mut app = LastSignalApp::from_config(config).await?;
+            app.validate()?;
+        }
+        Some(("test", sub_matches)) => {
+            let mut app = LastSignalApp::from_config(config).await?;
+            if let Some(spec) = sub_matches.get_one::<String>("chaos") {
+                app.inject_chaos(&ChaosSpec::parse(spec, chrono::Utc::now())?);
+            }
+            app.test_outputs(sub_matches.get_flag("send")).await?;
         }
-        Some(("test", _)) => {
+        Some(("outputs", sub_matches)) => {
+            if let Some(("list", list_matches)) = sub_matches.subcommand() {
+                let app = LastSignalApp::from_config(config).await?;
+                app.list_outputs(list_matches.get_one::<String>("at").map(String::as_str))?;
+            }
+        }
+        Some(("reviewed", _)) => {
+            let mut app = LastSignalApp::from_config(config).await?;
+            app.reviewed()?;
+        }
+        Some(("generate-checkin-url", sub_matches)) => {
+            let valid_for: duration_parser::ConfigDuration = sub_matches.get_one::<String>("valid-for").unwrap().parse()?;
             let app = LastSignalApp::from_config(config).await?;
-            app.test_outputs().await?;
+            app.generate_checkin_url(valid_for)?;
+        }
+        Some(("whoop-auth", sub_matches)) if sub_matches.get_flag("status") || sub_matches.get_flag("refresh") => {
+            let token_file = sub_matches.get_one::<String>("token-file");
+            let (client_id, client_secret) = whoop_credentials(&config, sub_matches, token_file)?;
+            let mut oauth_client = oauth::OAuthClient::new(oauth::WHOOP, client_id, client_secret, String::new(), config.get_data_directory()?);
+            if let Some(token_file) = token_file {
+                oauth_client = oauth_client.with_token_file(token_file);
+            }
+
+            if sub_matches.get_flag("status") {
+                oauth::run_token_status(&oauth_client).await?;
+            } else {
+                oauth::run_token_refresh(&oauth_client).await?;
+            }
         }
         Some(("whoop-auth", sub_matches)) => {
             let client_id = sub_matches.get_one::<String>("client-id").unwrap().clone();
             let client_secret = sub_matches.get_one::<String>("client-secret").unwrap().clone();
             let data_directory = config.get_data_directory()?;
             
-            oauth::run_whoop_authentication(client_id, client_secret, data_directory).await?;
+            let options = oauth::AuthOptions {
+                token_file: sub_matches.get_one::<String>("token-file").cloned(),
+                ..auth_options(sub_matches)
+            };
+
+            oauth::run_whoop_authentication(client_id, client_secret, data_directory, options).await?;
+        }
+        Some(("whoop-status", _)) => {
+            outputs::whoop::run_whoop_status(&config).await?;
+        }
+        Some(("fitbit-auth", sub_matches)) => {
+            let client_id = sub_matches.get_one::<String>("client-id").unwrap().clone();
+            let client_secret = sub_matches.get_one::<String>("client-secret").unwrap().clone();
+            let data_directory = config.get_data_directory()?;
+            
+            oauth::run_authentication(oauth::FITBIT, client_id, client_secret, data_directory, auth_options(sub_matches)).await?;
+        }
+        Some(("withings-auth", sub_matches)) => {
+            let client_id = sub_matches.get_one::<String>("client-id").unwrap().clone();
+            let client_secret = sub_matches.get_one::<String>("client-secret").unwrap().clone();
+            let data_directory = config.get_data_directory()?;
+            
+            oauth::run_authentication(oauth::WITHINGS, client_id, client_secret, data_directory, auth_options(sub_matches)).await?;
+        }
+        Some(("gmail-auth", sub_matches)) => {
+            let client_id = sub_matches.get_one::<String>("client-id").unwrap().clone();
+            let client_secret = sub_matches.get_one::<String>("client-secret").unwrap().clone();
+            let data_directory = config.get_data_directory()?;
+            
+            oauth::run_authentication(oauth::GMAIL, client_id, client_secret, data_directory, auth_options(sub_matches)).await?;
+        }
+        Some(("garmin-auth", sub_matches)) => {
+            let consumer = garmin_auth::OAuthConsumer {
+                consumer_key: sub_matches.get_one::<String>("consumer-key").unwrap().clone(),
+                consumer_secret: sub_matches.get_one::<String>("consumer-secret").unwrap().clone(),
+            };
+            let email = sub_matches.get_one::<String>("email").unwrap().clone();
+            let password = sub_matches.get_one::<String>("password").cloned();
+            let data_directory = config.get_data_directory()?;
+            
+            garmin_auth::run_garmin_authentication(consumer, email, password, data_directory).await?;
         }
         Some(("facebook-auth", sub_matches)) => {
             let access_token = sub_matches.get_one::<String>("access-token").unwrap().clone();
+            let app_credentials = sub_matches.get_one::<String>("app-id").cloned()
+                .zip(sub_matches.get_one::<String>("app-secret").cloned());
             let data_directory = config.get_data_directory()?;
             
-            oauth::run_facebook_authentication(access_token, data_directory).await?;
+            oauth::run_facebook_authentication(access_token, app_credentials, data_directory).await?;
+        }
+        Some(("migrate-secrets", _)) => {
+            let data_directory = config.get_data_directory()?;
+            let mut files: Vec<_> = [oauth::WHOOP, oauth::FITBIT, oauth::WITHINGS, oauth::GMAIL]
+                .iter()
+                .map(|provider| provider.token_file)
+                .chain([oauth::FACEBOOK_TOKEN_FILE, garmin_auth::TOKEN_FILE])
+                .map(|file| data_directory.join(file))
+                .collect();
+            // and token files outputs were pointed at
+            let outputs = config.checkin.outputs.iter().chain(&config.recipient.last_signal_outputs);
+            for token_file in outputs.filter_map(|output| output.config.get("token_file")) {
+                let path = data_directory.join(token_file);
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+
+            secrets::run_migrate_secrets(config.encryption.as_ref(), &files)?;
         }
         _ => {
             println!("LastSignal - Automated Safety Check-in System");
@@ -131,9 +477,21 @@ async fn main() -> Result<()> {
             println!("  run           Start the LastSignal daemon");
             println!("  checkin       Record a manual check-in");
             println!("  status        Show current status and configuration");
+            println!("  fire          Send the last signal to all recipients now");
+            println!("  rehearse      Send the last signal to the canary outputs only");
+            println!("  validate      Check the configuration for mistakes");
             println!("  test          Test all configured outputs");
+            println!("  outputs list  Show the order checkin outputs are tried in");
+            println!("  reviewed      Record that the configuration has been reviewed");
+            println!("  generate-checkin-url  Print a signed URL that records a check-in");
             println!("  whoop-auth    Authenticate with WHOOP API");
+            println!("  whoop-status  Show whether WHOOP activity would check you in");
+            println!("  fitbit-auth   Authenticate with Fitbit Web API");
+            println!("  withings-auth Authenticate with Withings API");
+            println!("  gmail-auth    Authorize Gmail for XOAUTH2 email");
+            println!("  garmin-auth   Sign in to Garmin Connect");
             println!("  facebook-auth Set up Facebook Messenger integration");
+            println!("  migrate-secrets  Encrypt plaintext token files");
             println!();
             println!("Use 'lastsignal <command> --help' for more information on a command.");
             println!();
@@ -143,3 +501,56 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// The arguments every OAuth `*-auth` subcommand takes for its callback
+fn oauth_flow_args(command: Command) -> Command {
+    command
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .value_name("PORT")
+                .help("Port for the OAuth callback, otherwise the first free of 3000, 3001, 8976 or any")
+                .value_parser(clap::value_parser!(u16))
+        )
+        .arg(
+            Arg::new("manual")
+                .long("manual")
+                .help("Don't listen for the redirect, paste it from the browser instead (for headless machines)")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("redirect-uri")
+                .long("redirect-uri")
+                .value_name("URI")
+                .help("Redirect URI registered with the provider, in --manual mode")
+                .requires("manual")
+        )
+        .arg(
+            Arg::new("no-browser")
+                .long("no-browser")
+                .help("Don't try to open the authorization URL in a browser")
+                .action(ArgAction::SetTrue)
+        )
+}
+
+/// The client ID and secret from the command line, otherwise from the whoop
+/// checkin output using the same token file
+fn whoop_credentials(config: &config::Config, sub_matches: &ArgMatches, token_file: Option<&String>) -> Result<(String, String)> {
+    if let (Some(client_id), Some(client_secret)) = (sub_matches.get_one::<String>("client-id"), sub_matches.get_one::<String>("client-secret")) {
+        return Ok((client_id.clone(), client_secret.clone()));
+    }
+    config.checkin.outputs.iter()
+        .filter(|output| output.output_type == "whoop" && output.config.get("token_file") == token_file)
+        .find_map(|output| output.config.get("client_id").cloned().zip(output.config.get("client_secret").cloned()))
+        .ok_or_else(|| anyhow::anyhow!("Pass --client-id and --client-secret, or set them on the whoop checkin output"))
+}
+
+fn auth_options(sub_matches: &ArgMatches) -> oauth::AuthOptions {
+    oauth::AuthOptions {
+        token_file: None,
+        port: sub_matches.get_one::<u16>("port").copied(),
+        manual: sub_matches.get_flag("manual"),
+        redirect_uri: sub_matches.get_one::<String>("redirect-uri").cloned(),
+        no_browser: sub_matches.get_flag("no-browser"),
+    }
+}
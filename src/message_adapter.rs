@@ -1,20 +1,90 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use std::path::Path;
 
+use crate::duration_parser::DurationStyle;
+use crate::formatting::MessageFormat;
+
 pub trait MessageAdapter: Send + Sync {
-    fn generate_checkin_message(&self) -> Result<String>;
-    fn generate_last_signal_message(&self) -> Result<String>;
+    fn generate_checkin_message(&self, context: &MessageContext) -> Result<String>;
+    fn generate_last_signal_message(&self, context: &MessageContext) -> Result<String>;
+    /// An escalating reminder after an unanswered check-in request. `level`
+    /// counts from 1 and each level is more urgent.
+    fn generate_reminder_message(&self, context: &MessageContext, level: usize) -> Result<String>;
+    /// The last warning to the admin that the last signal fires at
+    /// `context.deadline` unless they check in
+    fn generate_warning_message(&self, context: &MessageContext) -> Result<String>;
+    /// Format the last signal message is written in
+    fn last_signal_message_format(&self) -> MessageFormat;
+}
+
+/// Schedule details a message can refer to, computed by the app when the message is sent
+#[derive(Debug, Clone)]
+pub struct MessageContext {
+    pub now: DateTime<Utc>,
+    /// When the last signal fires if nobody checks in
+    pub deadline: Option<DateTime<Utc>>,
+    /// When the next check-in reminder is due
+    pub next_reminder: Option<DateTime<Utc>>,
+    /// Time zone for the `_local` variables
+    pub timezone: Tz,
+}
+
+impl MessageContext {
+    fn local(&self, time: Option<DateTime<Utc>>) -> String {
+        match time {
+            Some(time) => time.with_timezone(&self.timezone).format("%A %-d %B, %H:%M %Z").to_string(),
+            None => "the configured time".to_string(),
+        }
+    }
+
+    fn relative(&self, time: Option<DateTime<Utc>>) -> String {
+        match time {
+            Some(time) => DurationStyle::Verbose.until(time - self.now),
+            None => "soon".to_string(),
+        }
+    }
+
+    /// Expands `{timestamp}`, `{deadline_local}`, `{deadline_relative}`,
+    /// `{next_reminder_local}` and `{next_reminder_relative}`
+    pub fn render(&self, template: &str) -> String {
+        render_template_variables(template)
+            .replace("{deadline_local}", &self.local(self.deadline))
+            .replace("{deadline_relative}", &self.relative(self.deadline))
+            .replace("{next_reminder_local}", &self.local(self.next_reminder))
+            .replace("{next_reminder_relative}", &self.relative(self.next_reminder))
+    }
+}
+
+/// Expands the template variables available in every message (`{timestamp}`)
+pub fn render_template_variables(template: &str) -> String {
+    let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
+    template.replace("{timestamp}", &timestamp.to_string())
+}
+
+/// Returns the trimmed message, or the default if it is empty. A blank
+/// emergency message (e.g. a file truncated by a failed sync) is worse than
+/// the generic one.
+pub fn non_empty_or_default(content: &str, source: &Path, default: impl FnOnce() -> String) -> String {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        tracing::warn!("Message file {:?} is empty, falling back to the default message", source);
+        return default();
+    }
+    trimmed.to_string()
 }
 
 pub struct FileMessageAdapter {
     message_file_path: std::path::PathBuf,
+    format: MessageFormat,
 }
 
 impl FileMessageAdapter {
-    pub fn new<P: AsRef<Path>>(message_file_path: P) -> Self {
+    pub fn new<P: AsRef<Path>>(message_file_path: P, format: MessageFormat) -> Self {
         Self {
             message_file_path: message_file_path.as_ref().to_path_buf(),
+            format,
         }
     }
 
@@ -37,7 +107,7 @@ impl FileMessageAdapter {
         let content = std::fs::read_to_string(&self.message_file_path)
             .with_context(|| format!("Failed to read message file: {:?}", self.message_file_path))?;
         
-        Ok(content.trim().to_string())
+        Ok(non_empty_or_default(&content, &self.message_file_path, || self.get_default_message()))
     }
 
     fn get_default_message(&self) -> String {
@@ -60,18 +130,41 @@ LastSignal - Automated Safety System"#.to_string()
 }
 
 impl MessageAdapter for FileMessageAdapter {
-    fn generate_checkin_message(&self) -> Result<String> {
-        let base_message = "Hello! This is your scheduled check-in reminder from LastSignal.\n\nPlease respond to confirm you're okay. If you don't respond within the configured timeframe, the emergency contacts will be notified.\n\nTo check in, you can reply to this message or use any of the configured response methods.";
-        Ok(base_message.to_string())
+    fn generate_checkin_message(&self, context: &MessageContext) -> Result<String> {
+        let template = if context.deadline.is_some() {
+            "Hello! This is your scheduled check-in reminder from LastSignal.\n\nPlease respond to confirm you're okay. Check in before {deadline_local} ({deadline_relative}), otherwise the emergency contacts will be notified.\n\nTo check in, you can reply to this message or use any of the configured response methods."
+        } else {
+            "Hello! This is your scheduled check-in reminder from LastSignal.\n\nPlease respond to confirm you're okay. If you don't respond within the configured timeframe, the emergency contacts will be notified.\n\nTo check in, you can reply to this message or use any of the configured response methods."
+        };
+        Ok(context.render(template))
     }
 
-    fn generate_last_signal_message(&self) -> Result<String> {
+    fn generate_last_signal_message(&self, context: &MessageContext) -> Result<String> {
         let template = self.load_message_from_file()?;
-        let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
-        
-        let message = template.replace("{timestamp}", &timestamp.to_string());
-        
-        Ok(message)
+        Ok(context.render(&template))
+    }
+
+    fn generate_reminder_message(&self, context: &MessageContext, level: usize) -> Result<String> {
+        let opening = match level {
+            1 => "Reminder: you haven't checked in with LastSignal yet.",
+            2 => "Second reminder: you still haven't checked in with LastSignal.",
+            _ => "URGENT: you still haven't checked in with LastSignal. Your emergency contacts will be notified soon unless you respond.",
+        };
+        let deadline = if context.deadline.is_some() {
+            "Check in before {deadline_local} ({deadline_relative}), otherwise the emergency contacts will be notified."
+        } else {
+            "If you don't respond within the configured timeframe, the emergency contacts will be notified."
+        };
+        let template = format!("{}\n\n{}\n\nTo check in, you can reply to this message or use any of the configured response methods.", opening, deadline);
+        Ok(context.render(&template))
+    }
+
+    fn generate_warning_message(&self, context: &MessageContext) -> Result<String> {
+        Ok(context.render("⚠️ FINAL WARNING: LastSignal will send your last signal to your emergency contacts at {deadline_local} ({deadline_relative}) unless you check in.\n\nThis is the last message before they are contacted. To check in, reply to this message or use any of the configured response methods."))
+    }
+
+    fn last_signal_message_format(&self) -> MessageFormat {
+        self.format
     }
 }
 
@@ -81,10 +174,11 @@ impl MessageAdapterFactory {
     pub fn create_adapter(
         adapter_type: &str,
         message_file_path: &Path,
+        format: MessageFormat,
     ) -> Result<Box<dyn MessageAdapter>> {
         match adapter_type {
             "file" => {
-                let adapter = FileMessageAdapter::new(message_file_path);
+                let adapter = FileMessageAdapter::new(message_file_path, format);
                 Ok(Box::new(adapter))
             }
             _ => anyhow::bail!("Unknown message adapter type: {}", adapter_type),
@@ -95,19 +189,29 @@ impl MessageAdapterFactory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
     use tempfile::{tempdir, NamedTempFile};
     use std::io::Write;
 
+    fn unscheduled() -> MessageContext {
+        MessageContext {
+            now: Utc::now(),
+            deadline: None,
+            next_reminder: None,
+            timezone: Tz::UTC,
+        }
+    }
+
     #[test]
     fn test_file_message_adapter_default_message() {
         let temp_dir = tempdir().unwrap();
         let message_path = temp_dir.path().join("message.txt");
         
-        let adapter = FileMessageAdapter::new(&message_path);
-        let message = adapter.generate_last_signal_message().unwrap();
+        let adapter = FileMessageAdapter::new(&message_path, MessageFormat::Plain);
+        let message = adapter.generate_last_signal_message(&unscheduled()).unwrap();
         
         assert!(message.contains("LastSignal"));
-        assert!(message.contains("{timestamp}") == false); // Should be replaced
+        assert!(!message.contains("{timestamp}")); // Should be replaced
         assert!(std::fs::exists(&message_path).unwrap());
     }
 
@@ -116,11 +220,24 @@ mod tests {
         let mut temp_file = NamedTempFile::new().unwrap();
         temp_file.write_all(b"Custom message with {timestamp}").unwrap();
         
-        let adapter = FileMessageAdapter::new(temp_file.path());
-        let message = adapter.generate_last_signal_message().unwrap();
+        let adapter = FileMessageAdapter::new(temp_file.path(), MessageFormat::Plain);
+        let message = adapter.generate_last_signal_message(&unscheduled()).unwrap();
         
         assert!(message.contains("Custom message"));
-        assert!(message.contains("{timestamp}") == false); // Should be replaced with actual timestamp
+        assert!(!message.contains("{timestamp}")); // Should be replaced with actual timestamp
+    }
+
+    #[test]
+    fn test_file_message_adapter_empty_file_uses_default() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(b"  \n\t\n").unwrap();
+
+        let adapter = FileMessageAdapter::new(temp_file.path(), MessageFormat::Plain);
+        let message = adapter.generate_last_signal_message(&unscheduled()).unwrap();
+
+        assert!(message.contains("This is an automated message from LastSignal"));
+        // The file itself is left alone so the truncation can be investigated
+        assert_eq!(std::fs::read_to_string(temp_file.path()).unwrap(), "  \n\t\n");
     }
 
     #[test]
@@ -128,8 +245,8 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let message_path = temp_dir.path().join("message.txt");
         
-        let adapter = FileMessageAdapter::new(&message_path);
-        let message = adapter.generate_checkin_message().unwrap();
+        let adapter = FileMessageAdapter::new(&message_path, MessageFormat::Plain);
+        let message = adapter.generate_checkin_message(&unscheduled()).unwrap();
         
         assert!(message.contains("check-in reminder"));
         assert!(message.contains("LastSignal"));
@@ -140,8 +257,8 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let message_path = temp_dir.path().join("message.txt");
         
-        let adapter = MessageAdapterFactory::create_adapter("file", &message_path).unwrap();
-        let message = adapter.generate_checkin_message().unwrap();
+        let adapter = MessageAdapterFactory::create_adapter("file", &message_path, MessageFormat::Plain).unwrap();
+        let message = adapter.generate_checkin_message(&unscheduled()).unwrap();
         
         assert!(message.contains("check-in reminder"));
     }
@@ -151,7 +268,7 @@ mod tests {
         let temp_dir = tempdir().unwrap();
         let message_path = temp_dir.path().join("message.txt");
         
-        let result = MessageAdapterFactory::create_adapter("unknown", &message_path);
+        let result = MessageAdapterFactory::create_adapter("unknown", &message_path, MessageFormat::Plain);
         assert!(result.is_err());
     }
 
@@ -160,11 +277,76 @@ mod tests {
         let mut temp_file = NamedTempFile::new().unwrap();
         temp_file.write_all(b"Message sent at: {timestamp}").unwrap();
         
-        let adapter = FileMessageAdapter::new(temp_file.path());
-        let message = adapter.generate_last_signal_message().unwrap();
+        let adapter = FileMessageAdapter::new(temp_file.path(), MessageFormat::Plain);
+        let message = adapter.generate_last_signal_message(&unscheduled()).unwrap();
         
         assert!(message.contains("Message sent at: "));
         assert!(message.contains("UTC"));
         assert!(!message.contains("{timestamp}"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_checkin_message_states_deadline() {
+        let temp_dir = tempdir().unwrap();
+        let adapter = FileMessageAdapter::new(temp_dir.path().join("message.txt"), MessageFormat::Plain);
+        let now = DateTime::parse_from_rfc3339("2025-06-18T13:00:00Z").unwrap().with_timezone(&Utc);
+        let context = MessageContext {
+            now,
+            deadline: Some(now + Duration::days(3) + Duration::hours(4)),
+            next_reminder: None,
+            timezone: chrono_tz::Europe::London,
+        };
+
+        let message = adapter.generate_checkin_message(&context).unwrap();
+        assert!(message.contains("Check in before Saturday 21 June, 18:00 BST (in 3 days and 4 hours)"));
+    }
+
+    #[test]
+    fn test_reminder_messages_escalate() {
+        let temp_dir = tempdir().unwrap();
+        let adapter = FileMessageAdapter::new(temp_dir.path().join("message.txt"), MessageFormat::Plain);
+        let now = Utc::now();
+        let context = MessageContext {
+            now,
+            deadline: Some(now + Duration::days(1)),
+            next_reminder: None,
+            timezone: Tz::UTC,
+        };
+
+        let first = adapter.generate_reminder_message(&context, 1).unwrap();
+        assert!(first.starts_with("Reminder:"));
+        assert!(first.contains("(in 1 day)"));
+        assert!(adapter.generate_reminder_message(&context, 2).unwrap().starts_with("Second reminder:"));
+        assert!(adapter.generate_reminder_message(&context, 3).unwrap().starts_with("URGENT:"));
+    }
+
+    #[test]
+    fn test_warning_message_states_fire_time() {
+        let temp_dir = tempdir().unwrap();
+        let adapter = FileMessageAdapter::new(temp_dir.path().join("message.txt"), MessageFormat::Plain);
+        let now = DateTime::parse_from_rfc3339("2025-06-18T13:00:00Z").unwrap().with_timezone(&Utc);
+        let context = MessageContext {
+            now,
+            deadline: Some(now + Duration::hours(24)),
+            next_reminder: None,
+            timezone: chrono_tz::Europe::London,
+        };
+
+        let message = adapter.generate_warning_message(&context).unwrap();
+        assert!(message.contains("at Thursday 19 June, 14:00 BST (in 1 day) unless you check in"), "{}", message);
+    }
+
+    #[test]
+    fn test_context_renders_overdue_and_missing_times() {
+        let now = Utc::now();
+        let context = MessageContext {
+            now,
+            deadline: Some(now - Duration::hours(6)),
+            next_reminder: None,
+            timezone: Tz::UTC,
+        };
+
+        assert_eq!(context.render("{deadline_relative}"), "overdue by 6 hours");
+        assert_eq!(context.render("{next_reminder_local}"), "the configured time");
+    }
+}
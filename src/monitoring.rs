@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::duration_parser::ConfigDuration;
+
+/// Longest error message sent with a failure ping. healthchecks.io keeps
+/// the first 100 kB of a body, other services far less.
+const MAX_FAILURE_BODY: usize = 10_000;
+
+/// Pings a dead man's switch such as healthchecks.io or Cronitor after every
+/// cycle, so someone notices if the daemon itself stops
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MonitoringConfig {
+    /// Fetched after each successful cycle, e.g. `https://hc-ping.com/<uuid>`.
+    /// Failures go to `<ping_url>/fail`.
+    pub ping_url: String,
+    /// How long a ping may take before it's abandoned
+    #[serde(default = "default_timeout")]
+    pub timeout: ConfigDuration,
+    /// Also ping `<ping_url>/start` as each cycle begins, so the service can
+    /// time cycles and notice one that hangs
+    #[serde(default)]
+    pub ping_start: bool,
+}
+
+fn default_timeout() -> ConfigDuration {
+    ConfigDuration::from_seconds(10)
+}
+
+impl MonitoringConfig {
+    pub fn validate(&self) -> Result<()> {
+        let url = reqwest::Url::parse(&self.ping_url)
+            .with_context(|| format!("Invalid [monitoring] ping_url '{}'", self.ping_url))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            anyhow::bail!("[monitoring] ping_url must be an http or https URL");
+        }
+        if self.timeout.as_secs() == 0 {
+            anyhow::bail!("[monitoring] timeout must be greater than 0");
+        }
+        Ok(())
+    }
+}
+
+/// Sends the pings. A ping that fails is logged and otherwise ignored, so
+/// the monitoring service being down never affects check-ins.
+pub struct Monitor {
+    client: Client,
+    config: MonitoringConfig,
+}
+
+impl Monitor {
+    pub fn new(config: &MonitoringConfig) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(config.timeout.as_duration())
+            .build()
+            .context("Failed to create monitoring HTTP client")?;
+        Ok(Self { client, config: config.clone() })
+    }
+
+    /// A cycle is starting, if `ping_start` is set
+    pub async fn start(&self) {
+        if self.config.ping_start {
+            self.ping(self.client.get(ping_url_with(&self.config.ping_url, "start")), "start").await;
+        }
+    }
+
+    /// A cycle completed
+    pub async fn success(&self) {
+        self.ping(self.client.get(&self.config.ping_url), "success").await;
+    }
+
+    /// A cycle failed with `error`
+    pub async fn failure(&self, error: &str) {
+        let body: String = error.chars().take(MAX_FAILURE_BODY).collect();
+        self.ping(self.client.post(ping_url_with(&self.config.ping_url, "fail")).body(body), "failure").await;
+    }
+
+    async fn ping(&self, request: reqwest::RequestBuilder, kind: &str) {
+        match request.send().await {
+            Ok(response) if response.status().is_success() => tracing::debug!("Sent monitoring {} ping", kind),
+            Ok(response) => tracing::warn!("Monitoring {} ping returned HTTP {}", kind, response.status()),
+            Err(e) => tracing::warn!("Monitoring {} ping failed: {}", kind, e),
+        }
+    }
+}
+
+/// Appends a path segment such as `fail`, keeping any query string at the end
+fn ping_url_with(ping_url: &str, segment: &str) -> String {
+    let (path, query) = match ping_url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (ping_url, None),
+    };
+    let url = format!("{}/{}", path.trim_end_matches('/'), segment);
+    match query {
+        Some(query) => format!("{}?{}", url, query),
+        None => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::State, http::Method, http::Uri, Router};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    type Received = Arc<Mutex<Vec<(Method, String, String)>>>;
+
+    /// A monitoring service that records every ping, and takes 2s to answer `/slow`
+    async fn mock_ping_server() -> (String, Received) {
+        let received: Received = Arc::default();
+        let app = Router::new()
+            .fallback(|State(received): State<Received>, method: Method, uri: Uri, body: String| async move {
+                if uri.path().starts_with("/slow") {
+                    tokio::time::sleep(Duration::from_secs(2)).await;
+                }
+                received.lock().unwrap().push((method, uri.to_string(), body));
+                "OK"
+            })
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{}", address), received)
+    }
+
+    fn monitor(ping_url: String, ping_start: bool) -> Monitor {
+        Monitor::new(&MonitoringConfig { ping_url, timeout: ConfigDuration::from_seconds(1), ping_start }).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_success_and_failure_pings() {
+        let (base, received) = mock_ping_server().await;
+        let monitor = monitor(format!("{}/ping/abc", base), true);
+
+        monitor.start().await;
+        monitor.success().await;
+        monitor.failure("Failed to send checkin request: SMTP refused").await;
+
+        let received = received.lock().unwrap();
+        assert_eq!(*received, vec![
+            (Method::GET, "/ping/abc/start".to_string(), String::new()),
+            (Method::GET, "/ping/abc".to_string(), String::new()),
+            (Method::POST, "/ping/abc/fail".to_string(), "Failed to send checkin request: SMTP refused".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_start_ping_is_opt_in() {
+        let (base, received) = mock_ping_server().await;
+        monitor(format!("{}/ping/abc", base), false).start().await;
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_slow_or_missing_service_is_ignored() {
+        let (base, received) = mock_ping_server().await;
+
+        let started = Instant::now();
+        monitor(format!("{}/slow", base), false).success().await;
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(received.lock().unwrap().is_empty());
+
+        // Nothing listening at all
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let closed = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        monitor(closed, false).failure("error").await;
+    }
+
+    #[test]
+    fn test_ping_url_with() {
+        assert_eq!(ping_url_with("https://hc-ping.com/uuid", "fail"), "https://hc-ping.com/uuid/fail");
+        assert_eq!(ping_url_with("https://hc-ping.com/uuid/", "start"), "https://hc-ping.com/uuid/start");
+        assert_eq!(ping_url_with("https://status.example.com/ping/job?env=prod", "fail"), "https://status.example.com/ping/job/fail?env=prod");
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = |ping_url: &str| MonitoringConfig { ping_url: ping_url.to_string(), timeout: default_timeout(), ping_start: false };
+        config("https://hc-ping.com/uuid").validate().unwrap();
+        assert!(config("hc-ping.com/uuid").validate().is_err());
+        assert!(config("ftp://example.com/ping").validate().is_err());
+    }
+}
@@ -0,0 +1,311 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::audit::AuditLog;
+use crate::duration_parser::ConfigDuration;
+use crate::schedule;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationsConfig {
+    /// Minimum time between warnings of the same category reaching the checkin outputs
+    #[serde(default = "default_cooldown")]
+    pub cooldown: ConfigDuration,
+    /// Per-category overrides of `cooldown`
+    #[serde(default)]
+    pub cooldowns: HashMap<WarningCategory, ConfigDuration>,
+    /// Warnings are batched and sent as one digest at most this often
+    #[serde(default = "default_digest_interval")]
+    pub digest_interval: ConfigDuration,
+    /// Warn when the mailbox receiving check-in replies is at least this full, in percent
+    #[serde(default = "default_mailbox_usage_threshold")]
+    pub mailbox_usage_threshold: u64,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            cooldown: default_cooldown(),
+            cooldowns: HashMap::new(),
+            digest_interval: default_digest_interval(),
+            mailbox_usage_threshold: default_mailbox_usage_threshold(),
+        }
+    }
+}
+
+fn default_cooldown() -> ConfigDuration {
+    ConfigDuration::from_days(1)
+}
+
+fn default_digest_interval() -> ConfigDuration {
+    ConfigDuration::from_days(1)
+}
+
+fn default_mailbox_usage_threshold() -> u64 {
+    90
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCategory {
+    Config,
+    UnhealthyOutput,
+    State,
+}
+
+impl WarningCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WarningCategory::Config => "config",
+            WarningCategory::UnhealthyOutput => "unhealthy_output",
+            WarningCategory::State => "state",
+        }
+    }
+}
+
+/// A problem LastSignal noticed about itself that the admin should hear about
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Warning {
+    pub category: WarningCategory,
+    pub message: String,
+}
+
+impl Warning {
+    pub fn new(category: WarningCategory, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            message: message.into(),
+        }
+    }
+}
+
+/// What happened to a warning handed to the notifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Disposition {
+    /// Waiting for the next digest
+    Queued,
+    /// An identical warning is already waiting for the next digest
+    Duplicate,
+    /// A warning of this category was delivered within its cooldown
+    CoolingDown,
+}
+
+impl Disposition {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Disposition::Queued => "queued",
+            Disposition::Duplicate => "duplicate",
+            Disposition::CoolingDown => "cooling_down",
+        }
+    }
+}
+
+/// Notifier bookkeeping, persisted with the rest of the app state so
+/// cooldowns survive restarts
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct NotifierState {
+    #[serde(default)]
+    pub pending: Vec<Warning>,
+    #[serde(default)]
+    pub last_delivered: HashMap<WarningCategory, DateTime<Utc>>,
+    #[serde(default)]
+    pub last_digest: Option<DateTime<Utc>>,
+}
+
+/// Single route for LastSignal's warnings about itself. Deduplicates and
+/// throttles them, batches what is left into a periodic digest for the
+/// checkin outputs, and writes every warning to the audit log whether or
+/// not it is delivered.
+pub struct Notifier {
+    config: NotificationsConfig,
+    audit_log: AuditLog,
+}
+
+impl Notifier {
+    pub fn new(config: &NotificationsConfig, data_directory: Option<&Path>) -> Self {
+        Self {
+            config: config.clone(),
+            audit_log: AuditLog::new(data_directory),
+        }
+    }
+
+    fn cooldown(&self, category: WarningCategory) -> ConfigDuration {
+        self.config.cooldowns.get(&category).copied().unwrap_or(self.config.cooldown)
+    }
+
+    pub fn warn(&self, state: &mut NotifierState, warning: Warning, now: DateTime<Utc>) -> Disposition {
+        let disposition = if state.pending.contains(&warning) {
+            Disposition::Duplicate
+        } else if state.last_delivered.get(&warning.category)
+            .is_some_and(|delivered| !schedule::is_reached(schedule::add_duration(*delivered, self.cooldown(warning.category)), now))
+        {
+            Disposition::CoolingDown
+        } else {
+            Disposition::Queued
+        };
+
+        tracing::debug!("Warning [{}] {}: {}", warning.category.as_str(), disposition.as_str(), warning.message);
+        self.audit(&warning, disposition.as_str(), now);
+
+        if disposition == Disposition::Queued {
+            state.pending.push(warning);
+        }
+        disposition
+    }
+
+    /// The digest message to send now, if anything is pending and the digest interval has passed
+    pub fn digest(&self, state: &NotifierState, now: DateTime<Utc>) -> Option<String> {
+        if state.pending.is_empty() {
+            return None;
+        }
+
+        if state.last_digest
+            .is_some_and(|sent| !schedule::is_reached(schedule::add_duration(sent, self.config.digest_interval), now))
+        {
+            return None;
+        }
+
+        let mut message = String::from("LastSignal needs your attention:\n");
+        for warning in &state.pending {
+            message.push_str(&format!("\n- [{}] {}", warning.category.as_str(), warning.message));
+        }
+        Some(message)
+    }
+
+    /// Records the outcome of sending the digest. Pending warnings are kept for the next attempt if it failed.
+    pub fn record_digest(&self, state: &mut NotifierState, delivered: bool, now: DateTime<Utc>) {
+        let outcome = if delivered { "delivered" } else { "delivery_failed" };
+        for warning in &state.pending {
+            self.audit(warning, outcome, now);
+        }
+
+        if delivered {
+            for warning in state.pending.drain(..) {
+                state.last_delivered.insert(warning.category, now);
+            }
+            state.last_digest = Some(now);
+        }
+    }
+
+    fn audit(&self, warning: &Warning, outcome: &str, now: DateTime<Utc>) {
+        self.audit_log.record(now, serde_json::json!({
+            "category": warning.category,
+            "message": warning.message,
+            "outcome": outcome,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use tempfile::tempdir;
+
+    fn unhealthy(name: &str) -> Warning {
+        Warning::new(WarningCategory::UnhealthyOutput, format!("{} is unhealthy", name))
+    }
+
+    #[test]
+    fn test_identical_warnings_are_deduplicated() {
+        let notifier = Notifier::new(&NotificationsConfig::default(), None);
+        let mut state = NotifierState::default();
+        let now = Utc::now();
+
+        assert_eq!(notifier.warn(&mut state, unhealthy("email"), now), Disposition::Queued);
+        assert_eq!(notifier.warn(&mut state, unhealthy("email"), now), Disposition::Duplicate);
+        assert_eq!(notifier.warn(&mut state, unhealthy("file"), now), Disposition::Queued);
+        assert_eq!(state.pending.len(), 2);
+    }
+
+    #[test]
+    fn test_category_cooldown_after_delivery() {
+        let mut config = NotificationsConfig::default();
+        config.cooldowns.insert(WarningCategory::Config, ConfigDuration::from_hours(1));
+        let notifier = Notifier::new(&config, None);
+        let mut state = NotifierState::default();
+        let now = Utc::now();
+
+        notifier.warn(&mut state, unhealthy("email"), now);
+        notifier.warn(&mut state, Warning::new(WarningCategory::Config, "unknown key"), now);
+        notifier.record_digest(&mut state, true, now);
+
+        let later = now + Duration::hours(2);
+        assert_eq!(notifier.warn(&mut state, unhealthy("email"), later), Disposition::CoolingDown);
+        assert_eq!(notifier.warn(&mut state, Warning::new(WarningCategory::Config, "unknown key"), later), Disposition::Queued);
+
+        let next_day = now + Duration::days(1);
+        assert_eq!(notifier.warn(&mut state, unhealthy("email"), next_day), Disposition::Queued);
+    }
+
+    #[test]
+    fn test_digest_batches_at_most_once_per_interval() {
+        let notifier = Notifier::new(&NotificationsConfig::default(), None);
+        let mut state = NotifierState::default();
+        let now = Utc::now();
+
+        assert!(notifier.digest(&state, now).is_none());
+
+        notifier.warn(&mut state, unhealthy("email"), now);
+        notifier.warn(&mut state, Warning::new(WarningCategory::State, "orphaned recipient"), now);
+        let digest = notifier.digest(&state, now).unwrap();
+        assert!(digest.contains("[unhealthy_output] email is unhealthy"));
+        assert!(digest.contains("[state] orphaned recipient"));
+
+        notifier.record_digest(&mut state, true, now);
+        assert!(state.pending.is_empty());
+
+        notifier.warn(&mut state, Warning::new(WarningCategory::Config, "unknown key"), now + Duration::hours(1));
+        assert!(notifier.digest(&state, now + Duration::hours(1)).is_none());
+        assert!(notifier.digest(&state, now + Duration::days(1)).is_some());
+    }
+
+    #[test]
+    fn test_failed_digest_keeps_warnings_pending() {
+        let notifier = Notifier::new(&NotificationsConfig::default(), None);
+        let mut state = NotifierState::default();
+        let now = Utc::now();
+
+        notifier.warn(&mut state, unhealthy("email"), now);
+        notifier.record_digest(&mut state, false, now);
+
+        assert_eq!(state.pending.len(), 1);
+        assert!(state.last_digest.is_none());
+        assert!(notifier.digest(&state, now).is_some());
+    }
+
+    #[test]
+    fn test_audit_log_records_suppressed_warnings() {
+        let temp_dir = tempdir().unwrap();
+        let notifier = Notifier::new(&NotificationsConfig::default(), Some(temp_dir.path()));
+        let mut state = NotifierState::default();
+        let now = Utc::now();
+
+        notifier.warn(&mut state, unhealthy("email"), now);
+        notifier.warn(&mut state, unhealthy("email"), now);
+        notifier.record_digest(&mut state, true, now);
+        notifier.warn(&mut state, unhealthy("email"), now);
+
+        let log = std::fs::read_to_string(temp_dir.path().join("audit.log")).unwrap();
+        let outcomes: Vec<String> = log.lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["outcome"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(outcomes, ["queued", "duplicate", "delivered", "cooling_down"]);
+    }
+
+    #[test]
+    fn test_category_cooldowns_parse_from_toml() {
+        let config: NotificationsConfig = toml::from_str(r#"
+cooldown = "12h"
+digest_interval = "6h"
+
+[cooldowns]
+unhealthy_output = "3d"
+"#).unwrap();
+
+        assert_eq!(config.cooldown, ConfigDuration::from_hours(12));
+        assert_eq!(config.cooldowns[&WarningCategory::UnhealthyOutput], ConfigDuration::from_days(3));
+    }
+}
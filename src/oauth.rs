@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use axum::{
-    extract::{Json, Query},
+    extract::{Json, Query, State},
     http::StatusCode,
     response::{Html, IntoResponse},
     routing::{get, post},
@@ -9,11 +9,18 @@ use axum::{
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{mpsc, RwLock};
 use tower_http::cors::CorsLayer;
 
+use crate::browser::{self, BrowserOpener, SystemBrowser};
+use crate::redact;
+use crate::secrets;
+
 #[derive(Debug, Serialize, Deserialize)]
-pub struct WhoopTokens {
+pub struct OAuthTokens {
     pub access_token: String,
     pub refresh_token: String,
     pub expires_at: DateTime<Utc>,
@@ -21,177 +28,392 @@ pub struct WhoopTokens {
 }
 
 #[derive(Debug, Deserialize)]
-struct WhoopTokenResponse {
+struct TokenResponse {
     access_token: String,
+    /// Left out of refresh responses by providers that keep the refresh token
+    #[serde(default)]
     refresh_token: String,
-    expires_in: u64,
+    #[serde(default)]
+    expires_in: Option<u64>,
     token_type: String,
 }
 
+/// Shortest token lifetime we accept from a provider before clamping
+const MIN_TOKEN_LIFETIME_SECS: u64 = 60;
+/// Longest token lifetime we accept from a provider before clamping (90 days)
+const MAX_TOKEN_LIFETIME_SECS: u64 = 90 * 24 * 60 * 60;
+/// Persisted expiry times further out than this are assumed to be corrupt
+const MAX_PERSISTED_EXPIRY_DAYS: i64 = 365;
+
+/// Converts a provider's `expires_in` into an absolute expiry time.
+/// A missing or zero value means the token should be refreshed immediately;
+/// anything else is clamped to a sane range so a misbehaving provider cannot
+/// cause refresh loops or keep a dead token alive.
+pub(crate) fn expires_at_from_expires_in(expires_in: Option<u64>, now: DateTime<Utc>) -> DateTime<Utc> {
+    match expires_in {
+        None | Some(0) => {
+            tracing::warn!("OAuth token response has no usable expires_in, treating token as already expired");
+            now
+        }
+        Some(seconds) => {
+            let clamped = seconds.clamp(MIN_TOKEN_LIFETIME_SECS, MAX_TOKEN_LIFETIME_SECS);
+            if clamped != seconds {
+                tracing::warn!("OAuth token expires_in of {}s is out of range, clamping to {}s", seconds, clamped);
+            }
+            now + chrono::Duration::seconds(clamped as i64)
+        }
+    }
+}
+
+impl OAuthTokens {
+    fn from_response(token_response: TokenResponse, now: DateTime<Utc>) -> Self {
+        Self {
+            access_token: token_response.access_token,
+            refresh_token: token_response.refresh_token,
+            expires_at: expires_at_from_expires_in(token_response.expires_in, now),
+            token_type: token_response.token_type,
+        }
+    }
+
+    /// Treats an implausibly distant `expires_at` (e.g. from a hand-edited
+    /// token file) as expired so the token gets refreshed.
+    fn sanitize_expiry(&mut self, now: DateTime<Utc>) {
+        if self.expires_at > now + chrono::Duration::days(MAX_PERSISTED_EXPIRY_DAYS) {
+            tracing::warn!(
+                "Stored token expiry {} is more than {} days away, treating token as expired",
+                self.expires_at,
+                MAX_PERSISTED_EXPIRY_DAYS
+            );
+            self.expires_at = now;
+        }
+    }
+}
+
+/// How long an auth command waits for the browser to come back with a code
+const AUTH_CODE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Callback ports tried in order when an auth command isn't given `--port`,
+/// ending with any free port
+const CALLBACK_PORTS: &[u16] = &[3000, 3001, 8976, 0];
+
+/// How long `facebook-auth` waits for a message to the page
+const PSID_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
 #[derive(Debug, Deserialize)]
 struct OAuthCallbackQuery {
     code: Option<String>,
+    state: Option<String>,
     error: Option<String>,
     error_description: Option<String>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct FacebookWebhookEntry {
     messaging: Vec<FacebookMessagingEvent>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct FacebookMessagingEvent {
     sender: FacebookMessageSender,
     message: Option<FacebookMessage>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct FacebookMessageSender {
     id: String,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct FacebookMessage {
     text: Option<String>,
 }
 
+/// How a provider expects the client ID and secret on token requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientAuthentication {
+    /// As `client_id` and `client_secret` form fields
+    RequestBody,
+    /// As an HTTP Basic `Authorization` header
+    BasicAuth,
+}
+
+/// How a provider's token endpoint is called and answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenFormat {
+    /// RFC 6749: the tokens are the response's top-level fields
+    Standard,
+    /// Withings: the tokens come back in `body` next to a `status` that is
+    /// nonzero on errors, even with HTTP 200
+    Withings,
+}
+
+/// Everything that differs between the OAuth 2.0 services LastSignal reads activity from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OAuthProvider {
+    /// For messages, e.g. "WHOOP"
+    pub name: &'static str,
+    pub auth_url: &'static str,
+    pub token_url: &'static str,
+    pub scopes: &'static [&'static str],
+    /// Joins `scopes` in the authorization URL, a space unless the provider differs
+    pub scope_separator: &'static str,
+    /// In the data directory
+    pub token_file: &'static str,
+    /// The subcommand that creates the token file
+    pub auth_command: &'static str,
+    pub callback_path: &'static str,
+    pub client_authentication: ClientAuthentication,
+    /// Whether the provider requires a PKCE code challenge
+    pub pkce: bool,
+    pub token_format: TokenFormat,
+    /// Extra query parameters for the authorization URL
+    pub authorization_params: &'static [(&'static str, &'static str)],
+    /// Extra form fields for every token request
+    pub token_params: &'static [(&'static str, &'static str)],
+}
+
+pub const WHOOP: OAuthProvider = OAuthProvider {
+    name: "WHOOP",
+    auth_url: "https://api.prod.whoop.com/oauth/oauth2/auth",
+    token_url: "https://api.prod.whoop.com/oauth/oauth2/token",
+    scopes: &["read:cycles", "read:sleep", "read:recovery", "read:workout", "read:profile", "offline"],
+    scope_separator: " ",
+    token_file: "whoop_tokens.json",
+    auth_command: "whoop-auth",
+    callback_path: "/auth/whoop/callback",
+    client_authentication: ClientAuthentication::RequestBody,
+    pkce: true,
+    token_format: TokenFormat::Standard,
+    authorization_params: &[],
+    token_params: &[],
+};
+
+pub const FITBIT: OAuthProvider = OAuthProvider {
+    name: "Fitbit",
+    auth_url: "https://www.fitbit.com/oauth2/authorize",
+    token_url: "https://api.fitbit.com/oauth2/token",
+    scopes: &["activity", "heartrate", "profile"],
+    scope_separator: " ",
+    token_file: "fitbit_tokens.json",
+    auth_command: "fitbit-auth",
+    callback_path: "/auth/fitbit/callback",
+    client_authentication: ClientAuthentication::BasicAuth,
+    pkce: true,
+    token_format: TokenFormat::Standard,
+    authorization_params: &[],
+    token_params: &[],
+};
+
+pub const WITHINGS: OAuthProvider = OAuthProvider {
+    name: "Withings",
+    auth_url: "https://account.withings.com/oauth2_user/authorize2",
+    token_url: "https://wbsapi.withings.net/v2/oauth2",
+    scopes: &["user.metrics", "user.activity"],
+    scope_separator: ",",
+    token_file: "withings_tokens.json",
+    auth_command: "withings-auth",
+    callback_path: "/auth/withings/callback",
+    client_authentication: ClientAuthentication::RequestBody,
+    pkce: false,
+    token_format: TokenFormat::Withings,
+    authorization_params: &[],
+    token_params: &[("action", "requesttoken")],
+};
+
+/// For XOAUTH2 logins to Gmail's SMTP and IMAP servers
+pub const GMAIL: OAuthProvider = OAuthProvider {
+    name: "Gmail",
+    auth_url: "https://accounts.google.com/o/oauth2/v2/auth",
+    token_url: "https://oauth2.googleapis.com/token",
+    scopes: &["https://mail.google.com/"],
+    scope_separator: " ",
+    token_file: "gmail_tokens.json",
+    auth_command: "gmail-auth",
+    callback_path: "/auth/gmail/callback",
+    client_authentication: ClientAuthentication::RequestBody,
+    pkce: true,
+    token_format: TokenFormat::Standard,
+    // Google only issues a refresh token for offline access, and only on
+    // the first consent unless asked again
+    authorization_params: &[("access_type", "offline"), ("prompt", "consent")],
+    token_params: &[],
+};
+
+/// Reads the tokens out of a successful token endpoint response
+fn parse_token_response(format: TokenFormat, response_text: &str) -> Result<TokenResponse> {
+    match format {
+        TokenFormat::Standard => serde_json::from_str(response_text).context("Failed to parse token response"),
+        TokenFormat::Withings => {
+            let response: serde_json::Value = serde_json::from_str(response_text).context("Failed to parse token response")?;
+            let status = response.get("status").and_then(serde_json::Value::as_i64).context("Withings token response has no status")?;
+            if status != 0 {
+                let error = response.get("error").and_then(serde_json::Value::as_str).unwrap_or("unknown error");
+                anyhow::bail!("Withings token request failed with status {}: {}", status, error);
+            }
+            let body = response.get("body").context("Withings token response has no body")?;
+            serde_json::from_value(body.clone()).context("Failed to parse token response body")
+        }
+    }
+}
+
+/// The authorization code flow and token storage for one `OAuthProvider`
 #[derive(Debug)]
-pub struct WhoopOAuth {
+pub struct OAuthClient {
+    provider: OAuthProvider,
     client: Client,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
     data_directory: std::path::PathBuf,
+    /// The provider's token file unless overridden, relative to the data directory
+    token_file: String,
+    /// The `state` the callback must return for this client's authorization attempt
+    state: String,
+    /// PKCE verifier for this client's authorization attempt
+    code_verifier: String,
 }
 
-impl WhoopOAuth {
+impl OAuthClient {
     pub fn new(
+        provider: OAuthProvider,
         client_id: String,
         client_secret: String,
         redirect_uri: String,
         data_directory: std::path::PathBuf,
     ) -> Self {
         Self {
+            provider,
             client: Client::new(),
             client_id,
             client_secret,
             redirect_uri,
             data_directory,
+            token_file: provider.token_file.to_string(),
+            state: generate_state(),
+            code_verifier: generate_code_verifier(),
+        }
+    }
+
+    /// Keeps tokens in `token_file` instead of the provider's default, so
+    /// several accounts can share a data directory
+    pub fn with_token_file(mut self, token_file: &str) -> Self {
+        self.token_file = token_file.to_string();
+        self
+    }
+
+    pub fn tokens_file(&self) -> std::path::PathBuf {
+        self.data_directory.join(&self.token_file)
+    }
+
+    /// The command that authorizes this client's token file
+    fn auth_command(&self) -> String {
+        if self.token_file == self.provider.token_file {
+            self.provider.auth_command.to_string()
+        } else {
+            format!("{} --token-file {}", self.provider.auth_command, self.token_file)
+        }
+    }
+
+    /// What the callback handler needs to check that a redirect belongs to
+    /// this client's authorization attempt, and to pass its code on
+    fn callback_state(&self, code_sender: mpsc::Sender<String>) -> CallbackState {
+        CallbackState {
+            provider_name: self.provider.name,
+            expected_state: self.state.clone(),
+            code_sender,
         }
     }
 
     pub fn get_authorization_url(&self) -> String {
-        let scopes = vec![
-            "read:cycles",
-            "read:sleep",
-            "read:recovery",
-            "read:profile",
-            "offline",
-        ];
-
-        format!(
-            "https://api.prod.whoop.com/oauth/oauth2/auth?response_type=code&client_id={}&redirect_uri={}&scope={}&state={}",
+        let mut url = format!(
+            "{}?response_type=code&client_id={}&redirect_uri={}&scope={}&state={}",
+            self.provider.auth_url,
             self.client_id,
             urlencoding::encode(&self.redirect_uri),
-            urlencoding::encode(&scopes.join(" ")),
-            "lastsignal_auth" // Simple state parameter
-        )
+            urlencoding::encode(&self.provider.scopes.join(self.provider.scope_separator)),
+            self.state
+        );
+        if self.provider.pkce {
+            url.push_str(&format!("&code_challenge={}&code_challenge_method=S256", code_challenge(&self.code_verifier)));
+        }
+        for (name, value) in self.provider.authorization_params {
+            url.push_str(&format!("&{}={}", name, value));
+        }
+        url
     }
 
-    pub async fn exchange_code_for_token(&self, code: &str) -> Result<WhoopTokens> {
-        let mut form_data = HashMap::new();
-        form_data.insert("grant_type", "authorization_code");
-        form_data.insert("client_id", &self.client_id);
-        form_data.insert("client_secret", &self.client_secret);
-        form_data.insert("redirect_uri", &self.redirect_uri);
-        form_data.insert("code", code);
+    /// Posts a token request, authenticating the client the way the provider expects
+    async fn request_tokens(&self, mut form_data: HashMap<&str, &str>, action: &str) -> Result<TokenResponse> {
+        let mut request = self.client.post(self.provider.token_url);
+        form_data.extend(self.provider.token_params.iter().copied());
+        match self.provider.client_authentication {
+            ClientAuthentication::RequestBody => {
+                form_data.insert("client_id", &self.client_id);
+                form_data.insert("client_secret", &self.client_secret);
+            }
+            ClientAuthentication::BasicAuth => {
+                form_data.insert("client_id", &self.client_id);
+                request = request.basic_auth(&self.client_id, Some(&self.client_secret));
+            }
+        }
 
-        let response = self
-            .client
-            .post("https://api.prod.whoop.com/oauth/oauth2/token")
+        let response = request
             .form(&form_data)
             .send()
             .await
-            .context("Failed to exchange authorization code for token")?;
+            .with_context(|| format!("Failed to {}", action))?;
 
-        let is_success = response.status().is_success();
+        let status = response.status();
         let response_text = response.text().await.unwrap_or_default();
-        
-        if !is_success {
-            tracing::debug!("WHOOP token exchange failed response: {}", response_text);
-            anyhow::bail!("Token exchange failed: {}", response_text);
-        }
+        redact::log_payload(&format!("{} token response", self.provider.name), status, &response_text);
 
-        tracing::debug!("WHOOP token exchange successful response: {}", response_text);
+        if !status.is_success() {
+            anyhow::bail!("{} token request failed: {}", self.provider.name, redact::redact(&response_text));
+        }
 
-        let token_response: WhoopTokenResponse = serde_json::from_str(&response_text)
-            .context("Failed to parse token response")?;
+        parse_token_response(self.provider.token_format, &response_text)
+    }
 
-        // With offline scope, refresh_token should always be present
-        if token_response.refresh_token.is_empty() {
-            anyhow::bail!("No refresh token received despite requesting offline scope");
+    pub async fn exchange_code_for_token(&self, code: &str) -> Result<OAuthTokens> {
+        let mut form_data = HashMap::new();
+        form_data.insert("grant_type", "authorization_code");
+        form_data.insert("redirect_uri", self.redirect_uri.as_str());
+        form_data.insert("code", code);
+        if self.provider.pkce {
+            form_data.insert("code_verifier", self.code_verifier.as_str());
         }
 
-        let expires_at = Utc::now() + chrono::Duration::seconds(token_response.expires_in as i64);
+        let token_response = self.request_tokens(form_data, "exchange authorization code for token").await?;
 
-        let tokens = WhoopTokens {
-            access_token: token_response.access_token,
-            refresh_token: token_response.refresh_token,
-            expires_at,
-            token_type: token_response.token_type,
-        };
+        // With offline access, refresh_token should always be present
+        if token_response.refresh_token.is_empty() {
+            anyhow::bail!("No refresh token received despite requesting offline access");
+        }
 
-        Ok(tokens)
+        Ok(OAuthTokens::from_response(token_response, Utc::now()))
     }
 
-    pub async fn refresh_token(&self, refresh_token: &str) -> Result<WhoopTokens> {
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<OAuthTokens> {
         let mut form_data = HashMap::new();
         form_data.insert("grant_type", "refresh_token");
-        form_data.insert("client_id", &self.client_id);
-        form_data.insert("client_secret", &self.client_secret);
         form_data.insert("refresh_token", refresh_token);
 
-        let response = self
-            .client
-            .post("https://api.prod.whoop.com/oauth/oauth2/token")
-            .form(&form_data)
-            .send()
-            .await
-            .context("Failed to refresh token")?;
-
-        let is_success = response.status().is_success();
-        let response_text = response.text().await.unwrap_or_default();
-        
-        if !is_success {
-            tracing::debug!("WHOOP token refresh failed response: {}", response_text);
-            anyhow::bail!("Token refresh failed: {}", response_text);
-        }
-
-        tracing::debug!("WHOOP token refresh successful response: {}", response_text);
+        let mut token_response = self.request_tokens(form_data, "refresh token").await?;
 
-        let token_response: WhoopTokenResponse = serde_json::from_str(&response_text)
-            .context("Failed to parse refresh token response")?;
-
-        // Refresh token response should always include a new refresh token
+        // Providers that don't rotate refresh tokens, like Google, leave it out
         if token_response.refresh_token.is_empty() {
-            anyhow::bail!("No refresh token received in refresh response");
+            token_response.refresh_token = refresh_token.to_string();
         }
 
-        let expires_at = Utc::now() + chrono::Duration::seconds(token_response.expires_in as i64);
-
-        let tokens = WhoopTokens {
-            access_token: token_response.access_token,
-            refresh_token: token_response.refresh_token,
-            expires_at,
-            token_type: token_response.token_type,
-        };
-
-        Ok(tokens)
+        Ok(OAuthTokens::from_response(token_response, Utc::now()))
     }
 
-    pub fn save_tokens(&self, tokens: &WhoopTokens) -> Result<()> {
-        let tokens_file = self.data_directory.join("whoop_tokens.json");
+    pub fn save_tokens(&self, tokens: &OAuthTokens) -> Result<()> {
+        let tokens_file = self.tokens_file();
         
         // Ensure the directory exists
         if let Some(parent) = tokens_file.parent() {
@@ -202,26 +424,34 @@ impl WhoopOAuth {
         let tokens_json = serde_json::to_string_pretty(tokens)
             .context("Failed to serialize tokens")?;
 
-        std::fs::write(&tokens_file, tokens_json)
-            .with_context(|| format!("Failed to write tokens file: {:?}", tokens_file))?;
+        secrets::write(&tokens_file, &tokens_json)?;
 
-        tracing::info!("Saved WHOOP tokens to: {:?}", tokens_file);
+        tracing::info!("Saved {} tokens to: {:?}", self.provider.name, tokens_file);
         Ok(())
     }
 
-    pub fn load_tokens(&self) -> Result<WhoopTokens> {
-        let tokens_file = self.data_directory.join("whoop_tokens.json");
+    pub fn load_tokens(&self) -> Result<OAuthTokens> {
+        let tokens_file = self.tokens_file();
         
         if !tokens_file.exists() {
-            anyhow::bail!("No WHOOP tokens found. Please run 'lastsignal whoop-auth' first.");
+            anyhow::bail!("No {} tokens found. Please run 'lastsignal {}' first.", self.provider.name, self.auth_command());
         }
 
-        let tokens_json = std::fs::read_to_string(&tokens_file)
-            .with_context(|| format!("Failed to read tokens file: {:?}", tokens_file))?;
+        let tokens_json = secrets::read(&tokens_file)?;
 
-        let tokens: WhoopTokens = serde_json::from_str(&tokens_json)
+        let mut tokens: OAuthTokens = serde_json::from_str(&tokens_json)
             .context("Failed to parse tokens file")?;
+        tokens.sanitize_expiry(Utc::now());
+
+        Ok(tokens)
+    }
 
+    /// Refreshes the stored tokens now, whether or not they're expiring, and
+    /// saves the result, since providers like WHOOP rotate the refresh token
+    pub async fn refresh_stored_tokens(&self) -> Result<OAuthTokens> {
+        let tokens = self.load_tokens()?;
+        let tokens = self.refresh_token(&tokens.refresh_token).await?;
+        self.save_tokens(&tokens)?;
         Ok(tokens)
     }
 
@@ -242,10 +472,91 @@ impl WhoopOAuth {
     }
 }
 
+/// 16 random bytes as hex, so a forged redirect can't guess it
+fn generate_state() -> String {
+    hex::encode(rand::random::<[u8; 16]>())
+}
+
+/// 32 random bytes as hex, within PKCE's 43 to 128 unreserved characters
+fn generate_code_verifier() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+/// The S256 PKCE challenge for `verifier`
+fn code_challenge(verifier: &str) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// Refreshes the stored tokens every 30 minutes when they are close to
+/// expiring, so a check-only output never finds them expired
+pub fn spawn_background_refresh(oauth_client: Arc<RwLock<OAuthClient>>, has_real_credentials: bool) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60 * 30)); // 30 minutes
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        
+        loop {
+            interval.tick().await;
+            
+            let oauth_client = oauth_client.read().await;
+            let name = oauth_client.provider.name;
+
+            // Skip refresh attempts if using dummy credentials
+            if !has_real_credentials {
+                tracing::debug!("{}: Skipping background token refresh - dummy credentials in use. Add client_id and client_secret to config for automatic refresh.", name);
+                continue;
+            }
+            
+            // Attempt to refresh the token
+            match oauth_client.load_tokens() {
+                Ok(tokens) => {
+                    // Check if token needs refreshing (expires within next 35 minutes)
+                    let now = Utc::now();
+                    let buffer = chrono::Duration::minutes(35);
+                    
+                    if tokens.expires_at <= now + buffer {
+                        tracing::info!("{}: Proactively refreshing access token in background", name);
+                        
+                        match oauth_client.refresh_token(&tokens.refresh_token).await {
+                            Ok(new_tokens) => {
+                                if let Err(e) = oauth_client.save_tokens(&new_tokens) {
+                                    tracing::error!("{}: Failed to save refreshed tokens: {}", name, e);
+                                } else {
+                                    tracing::info!("{}: Successfully refreshed access token in background", name);
+                                }
+                            }
+                            Err(e) => {
+                                tracing::error!("{}: Failed to refresh token in background: {}", name, e);
+                            }
+                        }
+                    } else {
+                        tracing::debug!("{}: Token still valid, no refresh needed", name);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("{}: Could not load tokens for background refresh: {}", name, e);
+                }
+            }
+        }
+    })
+}
+
+/// Shared with the callback handler for one authorization attempt
+#[derive(Debug, Clone)]
+struct CallbackState {
+    provider_name: &'static str,
+    expected_state: String,
+    /// Hands the authorization code to the waiting auth command
+    code_sender: mpsc::Sender<String>,
+}
+
 // OAuth callback handler
 async fn oauth_callback(
+    State(callback_state): State<Arc<CallbackState>>,
     Query(query): Query<OAuthCallbackQuery>,
 ) -> impl IntoResponse {
+    let provider_name = callback_state.provider_name;
+
     if let Some(error) = query.error {
         let error_desc = query.error_description.unwrap_or_default();
         return (
@@ -253,7 +564,7 @@ async fn oauth_callback(
             Html(format!(
                 r#"
                 <html>
-                <head><title>WHOOP Authentication Failed</title></head>
+                <head><title>{} Authentication Failed</title></head>
                 <body>
                     <h1>Authentication Failed</h1>
                     <p>Error: {}</p>
@@ -262,68 +573,92 @@ async fn oauth_callback(
                 </body>
                 </html>
                 "#,
-                error, error_desc
+                provider_name, error, error_desc
+            )),
+        );
+    }
+
+    // A redirect we didn't start, e.g. a forged link carrying someone else's code
+    if query.state.as_deref() != Some(callback_state.expected_state.as_str()) {
+        tracing::warn!("{} callback state does not match this authorization attempt, ignoring it", provider_name);
+        return (
+            StatusCode::BAD_REQUEST,
+            Html(format!(
+                r#"
+                <html>
+                <head><title>{name} Authentication Error</title></head>
+                <body>
+                    <h1>Authentication Error</h1>
+                    <p>This sign-in didn't come from the authorization link lastsignal printed, so it was ignored.</p>
+                    <p>Please close this window and open the link from the terminal again.</p>
+                </body>
+                </html>
+                "#,
+                name = provider_name
             )),
         );
     }
 
     if let Some(code) = query.code {
-        // Store the code for the main application to retrieve
-        if let Err(e) = std::fs::write("/tmp/whoop_auth_code.txt", &code) {
-            tracing::error!("Failed to store auth code: {}", e);
+        // Hand the code to the waiting auth command. Only the first gets through.
+        if let Err(e) = callback_state.code_sender.try_send(code) {
+            tracing::error!("Failed to pass on auth code: {}", e);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Html(
+                Html(format!(
                     r#"
                     <html>
-                    <head><title>WHOOP Authentication Error</title></head>
+                    <head><title>{name} Authentication Error</title></head>
                     <body>
                         <h1>Authentication Error</h1>
-                        <p>Failed to store authorization code. Please try again.</p>
+                        <p>Failed to pass on the authorization code. Please try again.</p>
                         <p>You can close this window now.</p>
                     </body>
                     </html>
-                    "#.to_string(),
-                ),
+                    "#,
+                    name = provider_name
+                )),
             );
         }
 
         return (
             StatusCode::OK,
-            Html(
+            Html(format!(
                 r#"
                 <html>
-                <head><title>WHOOP Authentication Success</title></head>
+                <head><title>{name} Authentication Success</title></head>
                 <body>
                     <h1>Authentication Successful!</h1>
-                    <p>You have successfully authenticated with WHOOP.</p>
+                    <p>You have successfully authenticated with {name}.</p>
                     <p>You can now close this window and return to the terminal.</p>
                     <script>
-                        setTimeout(() => {
+                        setTimeout(() => {{
                             window.close();
-                        }, 3000);
+                        }}, 3000);
                     </script>
                 </body>
                 </html>
-                "#.to_string(),
-            ),
+                "#,
+                name = provider_name
+            )),
         );
     }
 
     (
         StatusCode::BAD_REQUEST,
-        Html(
+        Html(format!(
             r#"
             <html>
-            <head><title>WHOOP Authentication Error</title></head>
+            <head><title>{name} Authentication Error</title></head>
             <body>
                 <h1>Authentication Error</h1>
                 <p>No authorization code received. Please try again.</p>
                 <p>You can close this window now.</p>
             </body>
             </html>
-            "#.to_string(),
-        ),
+            "#,
+            name = provider_name
+        )),
     )
 }
 
@@ -337,10 +672,9 @@ async fn facebook_webhook_verify(
         params.get("hub.mode"),
         params.get("hub.verify_token"),
         params.get("hub.challenge"),
-    ) {
-        if mode == "subscribe" && token == verify_token {
-            return challenge.clone();
-        }
+    )
+        && mode == "subscribe" && token == verify_token {
+        return challenge.clone();
     }
     
     "Forbidden".to_string()
@@ -348,26 +682,25 @@ async fn facebook_webhook_verify(
 
 // Facebook webhook message handler
 async fn facebook_webhook_message(
+    State(psid_sender): State<mpsc::Sender<String>>,
     Json(payload): Json<serde_json::Value>,
 ) -> impl IntoResponse {
-    tracing::debug!("Facebook webhook payload: {}", serde_json::to_string_pretty(&payload).unwrap_or_default());
+    redact::log_payload("Facebook webhook payload", "received", &serde_json::to_string_pretty(&payload).unwrap_or_default());
     
     // Parse the webhook payload to extract PSIDs
     if let Some(entry) = payload.get("entry").and_then(|e| e.as_array()) {
         for entry_item in entry {
             if let Some(messaging) = entry_item.get("messaging").and_then(|m| m.as_array()) {
                 for message_event in messaging {
-                    if let Some(sender) = message_event.get("sender").and_then(|s| s.get("id")).and_then(|id| id.as_str()) {
-                        if let Some(message) = message_event.get("message") {
-                            // Store the PSID for the main application to retrieve
-                            let psid_data = format!("{{\"psid\": \"{}\", \"message\": \"Received message from user\"}}", sender);
-                            if let Err(e) = std::fs::write("/tmp/facebook_psid.txt", psid_data) {
-                                tracing::error!("Failed to store PSID: {}", e);
-                            } else {
-                                tracing::info!("Captured PSID: {}", sender);
-                            }
-                            break;
+                    if let Some(sender) = message_event.get("sender").and_then(|s| s.get("id")).and_then(|id| id.as_str())
+                        && let Some(_message) = message_event.get("message") {
+                        // Hand the PSID to the waiting facebook-auth command
+                        if let Err(e) = psid_sender.try_send(sender.to_string()) {
+                            tracing::error!("Failed to pass on PSID: {}", e);
+                        } else {
+                            tracing::info!("Captured PSID: {}", sender);
                         }
+                        break;
                     }
                 }
             }
@@ -377,16 +710,38 @@ async fn facebook_webhook_message(
     StatusCode::OK
 }
 
-pub async fn start_oauth_server(port: u16) -> Result<()> {
-    let app = Router::new()
-        .route("/auth/whoop/callback", get(oauth_callback))
-        .layer(CorsLayer::permissive());
+fn callback_router(provider: OAuthProvider, callback_state: CallbackState) -> Router {
+    Router::new()
+        .route(provider.callback_path, get(oauth_callback))
+        .layer(CorsLayer::permissive())
+        .with_state(Arc::new(callback_state))
+}
 
-    let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port))
-        .await
-        .with_context(|| format!("Failed to bind to port {}", port))?;
+/// Binds the callback server to the first of `ports` that's free
+async fn bind_first_free(ports: &[u16]) -> Result<tokio::net::TcpListener> {
+    for port in ports {
+        match tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port)).await {
+            Ok(listener) => return Ok(listener),
+            Err(e) => tracing::debug!("OAuth callback port {} unavailable: {}", port, e),
+        }
+    }
+    anyhow::bail!("None of ports {:?} are free for the OAuth callback server", ports)
+}
+
+/// Binds `port` if given, otherwise the first free port of `CALLBACK_PORTS`
+async fn bind_callback_listener(port: Option<u16>) -> Result<tokio::net::TcpListener> {
+    match port {
+        Some(port) => tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port))
+            .await
+            .with_context(|| format!("Failed to bind to port {}", port)),
+        None => bind_first_free(CALLBACK_PORTS).await,
+    }
+}
 
-    tracing::info!("OAuth server listening on http://127.0.0.1:{}", port);
+async fn start_oauth_server(listener: tokio::net::TcpListener, provider: OAuthProvider, callback_state: CallbackState) -> Result<()> {
+    let app = callback_router(provider, callback_state);
+
+    tracing::info!("OAuth server listening on http://{}", listener.local_addr()?);
 
     axum::serve(listener, app)
         .await
@@ -395,11 +750,12 @@ pub async fn start_oauth_server(port: u16) -> Result<()> {
     Ok(())
 }
 
-pub async fn start_facebook_webhook_server(port: u16) -> Result<()> {
+async fn start_facebook_webhook_server(port: u16, psid_sender: mpsc::Sender<String>) -> Result<()> {
     let app = Router::new()
         .route("/webhook", get(facebook_webhook_verify))
         .route("/webhook", post(facebook_webhook_message))
-        .layer(CorsLayer::permissive());
+        .layer(CorsLayer::permissive())
+        .with_state(psid_sender);
 
     let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port))
         .await
@@ -418,70 +774,280 @@ pub async fn run_whoop_authentication(
     client_id: String,
     client_secret: String,
     data_directory: std::path::PathBuf,
+    options: AuthOptions,
+) -> Result<()> {
+    run_authentication(WHOOP, client_id, client_secret, data_directory, options).await
+}
+
+/// How an auth command runs the authorization code flow
+#[derive(Debug, Default)]
+pub struct AuthOptions {
+    /// Where to save the tokens in the data directory, instead of the provider's default
+    pub token_file: Option<String>,
+    /// The callback port, otherwise the first free of `CALLBACK_PORTS`
+    pub port: Option<u16>,
+    /// Read the redirect the user pastes instead of running a callback server
+    pub manual: bool,
+    /// The redirect URI for manual mode, otherwise the callback URL on `port`
+    pub redirect_uri: Option<String>,
+    /// Only print the authorization URL, without trying to open a browser
+    pub no_browser: bool,
+}
+
+/// The interactive authorization code flow for a provider's `*-auth` subcommand
+pub async fn run_authentication(
+    provider: OAuthProvider,
+    client_id: String,
+    client_secret: String,
+    data_directory: std::path::PathBuf,
+    options: AuthOptions,
 ) -> Result<()> {
-    let port = 3000; // Default port for OAuth redirect
-    let redirect_uri = format!("http://127.0.0.1:{}/auth/whoop/callback", port);
+    // Manual mode starts no server, so nothing needs to answer on the redirect URI
+    let listener = if options.manual {
+        None
+    } else {
+        Some(bind_callback_listener(options.port).await?)
+    };
+    let redirect_uri = match (&listener, options.redirect_uri) {
+        (Some(listener), _) => format!("http://127.0.0.1:{}{}", listener.local_addr()?.port(), provider.callback_path),
+        (None, Some(redirect_uri)) => redirect_uri,
+        (None, None) => format!("http://127.0.0.1:{}{}", options.port.unwrap_or(CALLBACK_PORTS[0]), provider.callback_path),
+    };
+    println!("\n↩️  Redirect URI: {}", redirect_uri);
+    println!("If {} rejects it, add it to your app's redirect URIs, or pass --port to use one already registered.", provider.name);
     
-    let oauth_client = WhoopOAuth::new(client_id, client_secret, redirect_uri, data_directory);
+    let mut oauth_client = OAuthClient::new(provider, client_id, client_secret, redirect_uri, data_directory);
+    if let Some(token_file) = &options.token_file {
+        oauth_client = oauth_client.with_token_file(token_file);
+    }
+
+    let browser: Option<&dyn BrowserOpener> = if options.no_browser { None } else { Some(&SystemBrowser) };
+    let auth_code = match listener {
+        Some(listener) => wait_for_callback(&oauth_client, listener, browser).await?,
+        None => read_pasted_code(&oauth_client, browser)?,
+    };
+
+    // Exchange code for tokens
+    println!("🔄 Exchanging authorization code for access token...");
+    let tokens = oauth_client.exchange_code_for_token(&auth_code).await?;
+    
+    // Save tokens
+    oauth_client.save_tokens(&tokens)?;
+    
+    println!("✅ Successfully authenticated with {}!", provider.name);
+    println!("📁 Tokens saved to: {:?}", oauth_client.tokens_file());
+    println!("\nYou can now use the {} adapter in your LastSignal configuration.", provider.name);
+    
+    Ok(())
+}
+
+/// Serves the callback on `listener` until the browser comes back with a code
+async fn wait_for_callback(oauth_client: &OAuthClient, listener: tokio::net::TcpListener, browser: Option<&dyn BrowserOpener>) -> Result<String> {
+    let provider = oauth_client.provider;
 
     // Start the OAuth server in the background
+    let (code_sender, mut code_receiver) = mpsc::channel(1);
+    let callback_state = oauth_client.callback_state(code_sender);
     let server_handle = tokio::spawn(async move {
-        if let Err(e) = start_oauth_server(port).await {
+        if let Err(e) = start_oauth_server(listener, provider, callback_state).await {
             tracing::error!("OAuth server error: {}", e);
         }
     });
 
     // Generate and display authorization URL
     let auth_url = oauth_client.get_authorization_url();
-    println!("\n🔗 Please open the following URL in your browser to authenticate with WHOOP:");
+    println!("\n🔗 Please open the following URL in your browser to authenticate with {}:", provider.name);
     println!("{}", auth_url);
+    if let Some(browser) = browser {
+        browser::try_open(browser, &auth_url);
+    }
     println!("\nAfter authentication, the browser will redirect to localhost and you should see a success message.");
     println!("Waiting for authentication...\n");
 
-    // Wait for the authorization code
-    let mut attempts = 0;
-    let max_attempts = 120; // 2 minutes timeout
-    let auth_code = loop {
-        if std::path::Path::new("/tmp/whoop_auth_code.txt").exists() {
-            match std::fs::read_to_string("/tmp/whoop_auth_code.txt") {
-                Ok(code) => {
-                    // Clean up the temporary file
-                    let _ = std::fs::remove_file("/tmp/whoop_auth_code.txt");
-                    break code.trim().to_string();
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to read auth code file: {}", e);
-                }
-            }
-        }
+    // Wait for the callback handler to pass on the authorization code
+    let auth_code = tokio::time::timeout(AUTH_CODE_TIMEOUT, code_receiver.recv()).await;
+    server_handle.abort();
+    match auth_code {
+        Ok(Some(code)) => Ok(code),
+        // The server dropped its sender, so it failed to start
+        Ok(None) => anyhow::bail!("OAuth callback server stopped before authentication finished, see the error above."),
+        Err(_) => anyhow::bail!("Timeout waiting for authentication. Please try again."),
+    }
+}
 
-        attempts += 1;
-        if attempts >= max_attempts {
-            server_handle.abort();
-            anyhow::bail!("Timeout waiting for authentication. Please try again.");
-        }
+/// Has the user open the authorization URL anywhere and paste back where
+/// the browser ended up
+fn read_pasted_code(oauth_client: &OAuthClient, browser: Option<&dyn BrowserOpener>) -> Result<String> {
+    use std::io::Write;
+
+    let auth_url = oauth_client.get_authorization_url();
+    println!("\n🔗 Open the following URL in a browser on any machine to authenticate with {}:", oauth_client.provider.name);
+    println!("{}", auth_url);
+    if let Some(browser) = browser {
+        browser::try_open(browser, &auth_url);
+    }
+    println!("\nAfter you approve, the browser is sent to the redirect URI, which probably won't load.");
+    println!("Copy the whole URL from the address bar, or just its code parameter.\n");
+    print!("Redirect URL or code: ");
+    std::io::stdout().flush()?;
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).context("Failed to read redirect URL")?;
+    parse_pasted_code(&input, &oauth_client.state)
+}
 
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+/// The authorization code in a pasted redirect URL, whose `state` must
+/// match this attempt's, or a pasted bare code
+fn parse_pasted_code(input: &str, expected_state: &str) -> Result<String> {
+    let input = input.trim();
+    // Fragments, like the `#_=_` some providers append, aren't sent to the redirect URI
+    let input = input.split('#').next().unwrap_or_default();
+    if input.is_empty() {
+        anyhow::bail!("Nothing was pasted");
+    }
+
+    let Some((_, query)) = input.split_once('?') else {
+        if input.contains("://") {
+            anyhow::bail!("The pasted URL has no query string. Copy the whole URL the browser was redirected to.");
+        }
+        return Ok(input.to_string());
     };
 
-    server_handle.abort();
+    let mut params = HashMap::new();
+    for pair in query.split('&') {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = urlencoding::decode(&value.replace('+', " ")).with_context(|| format!("Invalid {} in pasted URL", name))?.into_owned();
+        params.insert(name, value);
+    }
 
-    // Exchange code for tokens
-    println!("🔄 Exchanging authorization code for access token...");
-    let tokens = oauth_client.exchange_code_for_token(&auth_code).await?;
-    
-    // Save tokens
-    oauth_client.save_tokens(&tokens)?;
-    
-    println!("✅ Successfully authenticated with WHOOP!");
-    println!("📁 Tokens saved to: {:?}", oauth_client.data_directory.join("whoop_tokens.json"));
-    println!("\nYou can now use the WHOOP adapter in your LastSignal configuration.");
-    
+    if let Some(error) = params.get("error") {
+        anyhow::bail!("Authorization failed: {} {}", error, params.get("error_description").map(String::as_str).unwrap_or_default());
+    }
+    if params.get("state").map(String::as_str) != Some(expected_state) {
+        anyhow::bail!("The pasted URL isn't from this authorization attempt (its state doesn't match). Open the URL printed above and try again.");
+    }
+    params.remove("code").filter(|code| !code.is_empty()).context("The pasted URL has no code parameter")
+}
+
+/// `*-auth --status`: whether the tokens exist, when the access token
+/// expires, and whether a live refresh works. Fails when the provider needs
+/// authorizing again, so it can run from cron.
+pub async fn run_token_status(oauth_client: &OAuthClient) -> Result<()> {
+    let name = oauth_client.provider.name;
+    println!("{} ({})", name, oauth_client.tokens_file().display());
+    let tokens = oauth_client.load_tokens()?;
+    println!("  Access token expires {}", tokens.expires_at.format("%Y-%m-%d %H:%M UTC"));
+
+    match oauth_client.refresh_stored_tokens().await {
+        Ok(tokens) => {
+            println!("  ✅ Refresh works, the new access token expires {}", tokens.expires_at.format("%Y-%m-%d %H:%M UTC"));
+            Ok(())
+        }
+        Err(e) => {
+            println!("  ❌ Refresh failed: {:#}", e);
+            anyhow::bail!("{} token refresh failed. If the refresh token was revoked, run 'lastsignal {}' again.", name, oauth_client.auth_command())
+        }
+    }
+}
+
+/// `*-auth --refresh`: refreshes the tokens now
+pub async fn run_token_refresh(oauth_client: &OAuthClient) -> Result<()> {
+    let tokens = oauth_client.refresh_stored_tokens().await
+        .with_context(|| format!("Failed to refresh {} tokens. If the refresh token was revoked, run 'lastsignal {}' again.", oauth_client.provider.name, oauth_client.auth_command()))?;
+    println!("✅ Refreshed {} tokens, the access token expires {}", oauth_client.provider.name, tokens.expires_at.format("%Y-%m-%d %H:%M UTC"));
+    println!("📁 Tokens saved to: {:?}", oauth_client.tokens_file());
     Ok(())
 }
 
+/// Where `facebook-auth` keeps the long-lived page token, for outputs with
+/// `access_token = "stored"`
+pub const FACEBOOK_TOKEN_FILE: &str = "facebook_tokens.json";
+
+/// How long before the page token expires to start warning about it
+pub const FACEBOOK_EXPIRY_WARNING_DAYS: i64 = 7;
+
+/// A long-lived Facebook page token from `facebook-auth`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacebookPageToken {
+    pub access_token: String,
+    /// `None` for a token that doesn't expire
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl FacebookPageToken {
+    /// The token from an `fb_exchange_token` response. `expires_in` is
+    /// missing for tokens that don't expire.
+    fn from_exchange(response: &serde_json::Value, now: DateTime<Utc>) -> Result<Self> {
+        if let Some(error) = response.get("error") {
+            anyhow::bail!("Facebook API error: {}", error["message"].as_str().unwrap_or("Unknown error"));
+        }
+        let access_token = response
+            .get("access_token")
+            .and_then(|token| token.as_str())
+            .context("Facebook token exchange response has no access_token")?
+            .to_string();
+        let expires_at = response
+            .get("expires_in")
+            .and_then(|seconds| seconds.as_i64())
+            .filter(|&seconds| seconds > 0)
+            .map(|seconds| now + chrono::Duration::seconds(seconds));
+        Ok(FacebookPageToken { access_token, expires_at })
+    }
+
+    pub fn load(data_directory: &std::path::Path) -> Result<Self> {
+        let token_file = data_directory.join(FACEBOOK_TOKEN_FILE);
+        if !token_file.exists() {
+            anyhow::bail!("No Facebook page token found. Please run 'lastsignal facebook-auth' with --app-id and --app-secret first.");
+        }
+        let token_json = secrets::read(&token_file)?;
+        serde_json::from_str(&token_json).context("Failed to parse Facebook tokens file")
+    }
+
+    pub fn save(&self, data_directory: &std::path::Path) -> Result<()> {
+        std::fs::create_dir_all(data_directory)
+            .with_context(|| format!("Failed to create directory: {:?}", data_directory))?;
+        let token_file = data_directory.join(FACEBOOK_TOKEN_FILE);
+        let token_json = serde_json::to_string_pretty(self).context("Failed to serialize Facebook token")?;
+        secrets::write(&token_file, &token_json)?;
+        tracing::info!("Saved Facebook page token to: {:?}", token_file);
+        Ok(())
+    }
+
+    /// Whether the token has expired or will within `FACEBOOK_EXPIRY_WARNING_DAYS` of `now`
+    pub fn expires_soon(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at - now <= chrono::Duration::days(FACEBOOK_EXPIRY_WARNING_DAYS))
+    }
+}
+
+/// Exchanges a short-lived token from the Graph API Explorer for a
+/// long-lived one, using the app's credentials
+async fn exchange_facebook_token(client: &Client, access_token: &str, app_id: &str, app_secret: &str) -> Result<FacebookPageToken> {
+    let response = client
+        .get("https://graph.facebook.com/v18.0/oauth/access_token")
+        .query(&[
+            ("grant_type", "fb_exchange_token"),
+            ("client_id", app_id),
+            ("client_secret", app_secret),
+            ("fb_exchange_token", access_token),
+        ])
+        .send()
+        .await
+        // reqwest errors include the URL, which carries the tokens
+        .map_err(|e| anyhow::anyhow!("Failed to exchange Facebook token: {}", redact::redact(&e.to_string())))?;
+    let status = response.status();
+    let text = response.text().await.context("Failed to read Facebook token exchange response")?;
+    redact::log_payload("Facebook token exchange response", status, &text);
+    let json: serde_json::Value = serde_json::from_str(&text)
+        .with_context(|| format!("Failed to parse Facebook token exchange response (HTTP {})", status))?;
+    FacebookPageToken::from_exchange(&json, Utc::now())
+}
+
+/// Checks `access_token`, then captures the PSID of whoever messages the
+/// page. With the app's credentials, the token is first exchanged for a
+/// long-lived one and kept in the data directory.
 pub async fn run_facebook_authentication(
     access_token: String,
+    app_credentials: Option<(String, String)>,
     data_directory: std::path::PathBuf,
 ) -> Result<()> {
     let port = 3001; // Different port from WHOOP OAuth
@@ -490,7 +1056,7 @@ pub async fn run_facebook_authentication(
     println!("🔍 Validating Facebook access token...");
     let client = Client::new();
     let test_response = client
-        .get(&format!("https://graph.facebook.com/v18.0/me?access_token={}", access_token))
+        .get(format!("https://graph.facebook.com/v18.0/me?access_token={}", access_token))
         .send()
         .await
         .context("Failed to test Facebook access token")?;
@@ -512,10 +1078,29 @@ pub async fn run_facebook_authentication(
         .unwrap_or("Your Facebook Page");
     
     println!("✅ Access token is valid for page: {}", page_name);
+
+    match app_credentials {
+        Some((app_id, app_secret)) => {
+            println!("🔄 Exchanging it for a long-lived page token...");
+            let token = exchange_facebook_token(&client, &access_token, &app_id, &app_secret).await?;
+            token.save(&data_directory)?;
+            match token.expires_at {
+                Some(expires_at) => println!("✅ Long-lived token saved, valid until {}", expires_at.format("%Y-%m-%d %H:%M UTC")),
+                None => println!("✅ Long-lived token saved, it doesn't expire"),
+            }
+            println!("📁 Token saved to: {:?}", data_directory.join(FACEBOOK_TOKEN_FILE));
+            println!("   Set access_token = \"stored\" in your facebook_messenger config to use it.");
+        }
+        None => {
+            println!("⚠️  Tokens from the Graph API Explorer expire within hours. Run again with");
+            println!("   --app-id and --app-secret to store a long-lived token instead.");
+        }
+    }
     
     // Start the webhook server in the background
+    let (psid_sender, mut psid_receiver) = mpsc::channel(1);
     let server_handle = tokio::spawn(async move {
-        if let Err(e) = start_facebook_webhook_server(port).await {
+        if let Err(e) = start_facebook_webhook_server(port, psid_sender).await {
             tracing::error!("Facebook webhook server error: {}", e);
         }
     });
@@ -538,41 +1123,14 @@ pub async fn run_facebook_authentication(
     println!("⏳ Waiting for message (press Ctrl+C to cancel)...");
     println!();
     
-    // Wait for the PSID to be captured
-    let mut attempts = 0;
-    let max_attempts = 300; // 5 minutes timeout
-    let psid_data = loop {
-        if std::path::Path::new("/tmp/facebook_psid.txt").exists() {
-            match std::fs::read_to_string("/tmp/facebook_psid.txt") {
-                Ok(data) => {
-                    // Clean up the temporary file
-                    let _ = std::fs::remove_file("/tmp/facebook_psid.txt");
-                    break data;
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to read PSID file: {}", e);
-                }
-            }
-        }
-
-        attempts += 1;
-        if attempts >= max_attempts {
-            server_handle.abort();
-            anyhow::bail!("Timeout waiting for message. Please ensure your webhook is properly configured and try again.");
-        }
-
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-    };
-
+    // Wait for the webhook handler to pass on the PSID
+    let psid = tokio::time::timeout(PSID_TIMEOUT, psid_receiver.recv()).await;
     server_handle.abort();
-    
-    // Parse the PSID data
-    let psid_json: serde_json::Value = serde_json::from_str(&psid_data)
-        .context("Failed to parse PSID data")?;
-    
-    let psid = psid_json.get("psid")
-        .and_then(|p| p.as_str())
-        .context("PSID not found in data")?;
+    let psid = match psid {
+        Ok(Some(psid)) => psid,
+        Ok(None) => anyhow::bail!("Facebook webhook server stopped before a message arrived, see the error above."),
+        Err(_) => anyhow::bail!("Timeout waiting for message. Please ensure your webhook is properly configured and try again."),
+    };
     
     println!("✅ Successfully captured your PSID!");
     println!();
@@ -598,7 +1156,8 @@ mod tests {
     #[test]
     fn test_whoop_oauth_initialization() {
         let temp_dir = tempdir().unwrap();
-        let oauth_client = WhoopOAuth::new(
+        let oauth_client = OAuthClient::new(
+            WHOOP,
             "test_client_id".to_string(),
             "test_client_secret".to_string(),
             "http://localhost:3000/callback".to_string(),
@@ -613,7 +1172,8 @@ mod tests {
     #[test]
     fn test_authorization_url_generation() {
         let temp_dir = tempdir().unwrap();
-        let oauth_client = WhoopOAuth::new(
+        let oauth_client = OAuthClient::new(
+            WHOOP,
             "test_client_id".to_string(),
             "test_client_secret".to_string(),
             "http://localhost:3000/callback".to_string(),
@@ -630,12 +1190,221 @@ mod tests {
         assert!(auth_url.contains("read%3Asleep"));
         assert!(auth_url.contains("read%3Arecovery"));
         assert!(auth_url.contains("offline"));
-        assert!(auth_url.contains("state=lastsignal_auth"));
+        assert!(auth_url.contains(&format!("&state={}", oauth_client.state)));
+        assert!(auth_url.contains(&format!("&code_challenge={}&code_challenge_method=S256", code_challenge(&oauth_client.code_verifier))));
+
+        // Each attempt gets its own state and verifier
+        let another = OAuthClient::new(WHOOP, "test_client_id".to_string(), "s".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        assert_eq!(another.state.len(), 32);
+        assert_ne!(another.state, oauth_client.state);
+        assert_ne!(another.code_verifier, oauth_client.code_verifier);
+    }
+
+    fn query_param<'a>(url: &'a str, name: &str) -> &'a str {
+        let start = url.find(&format!("&{}=", name)).unwrap() + name.len() + 2;
+        url[start..].split('&').next().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_callback_rejects_mismatched_state() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(WHOOP, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        let (code_sender, mut code_receiver) = mpsc::channel(1);
+        let callback_state = Arc::new(oauth_client.callback_state(code_sender));
+        let callback = |state: Option<&str>| OAuthCallbackQuery {
+            code: Some("injected_code".to_string()),
+            state: state.map(str::to_string),
+            error: None,
+            error_description: None,
+        };
+
+        for state in [None, Some("lastsignal_auth"), Some(&oauth_client.state[1..])] {
+            let response = oauth_callback(State(callback_state.clone()), Query(callback(state))).await.into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            assert!(String::from_utf8_lossy(&body).contains("didn't come from the authorization link"));
+        }
+        assert!(code_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_callback_server_passes_code_over_channel() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(WHOOP, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        let (code_sender, mut code_receiver) = mpsc::channel(1);
+        let app = callback_router(WHOOP, oauth_client.callback_state(code_sender));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let callback_url = format!("http://{}{}", listener.local_addr().unwrap(), WHOOP.callback_path);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = Client::new();
+        let response = client.get(&callback_url).query(&[("code", "the_code"), ("state", oauth_client.state.as_str())]).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(code_receiver.recv().await.unwrap(), "the_code");
+
+        // A second redirect can't replace it
+        let response = client.get(&callback_url).query(&[("code", "another_code"), ("state", oauth_client.state.as_str())]).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        let response = client.get(&callback_url).query(&[("code", "third_code"), ("state", oauth_client.state.as_str())]).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(code_receiver.recv().await.unwrap(), "another_code");
+    }
+
+    #[test]
+    fn test_parse_pasted_code() {
+        let state = "4f1c2d9e8a7b6c5d4e3f2a1b0c9d8e7f";
+        let url = format!("http://127.0.0.1:3000/auth/whoop/callback?code=abc%2F123&scope=offline%20read%3Acycles&state={}", state);
+        assert_eq!(parse_pasted_code(&url, state).unwrap(), "abc/123");
+        assert_eq!(parse_pasted_code(&format!("  {}\n", url), state).unwrap(), "abc/123");
+        assert_eq!(parse_pasted_code(&format!("{}#_=_", url), state).unwrap(), "abc/123");
+        assert_eq!(parse_pasted_code(&format!("https://example.com/cb?state={}&code=xyz#fragment\r\n", state), state).unwrap(), "xyz");
+
+        // A bare code, from the address bar or a provider's display page
+        assert_eq!(parse_pasted_code(" abc123\n", state).unwrap(), "abc123");
+        assert_eq!(parse_pasted_code("abc123#", state).unwrap(), "abc123");
+
+        assert!(parse_pasted_code(" \n", state).is_err());
+        assert!(parse_pasted_code("http://127.0.0.1:3000/auth/whoop/callback", state).is_err());
+        assert!(parse_pasted_code("http://127.0.0.1:3000/auth/whoop/callback#code=abc", state).is_err());
+        assert!(parse_pasted_code(&format!("http://localhost/cb?state={}", state), state).is_err());
+        let error = parse_pasted_code("http://localhost/cb?code=abc&state=lastsignal_auth", state).unwrap_err();
+        assert!(error.to_string().contains("state"), "{}", error);
+        let error = parse_pasted_code("http://localhost/cb?error=access_denied&error_description=The+user+denied", state).unwrap_err();
+        assert!(error.to_string().contains("access_denied The user denied"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_callback_port_falls_back_when_taken() {
+        let taken = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let taken_port = taken.local_addr().unwrap().port();
+
+        let listener = bind_first_free(&[taken_port, 0]).await.unwrap();
+        assert_ne!(listener.local_addr().unwrap().port(), taken_port);
+        assert!(bind_first_free(&[taken_port]).await.is_err());
+
+        // An explicit --port doesn't fall back
+        let error = bind_callback_listener(Some(taken_port)).await.unwrap_err();
+        assert!(error.to_string().contains(&taken_port.to_string()), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_state_and_verifier_round_trip() {
+        use axum::extract::Form;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let token_url = format!("http://{}/token", listener.local_addr().unwrap());
+        let temp_dir = tempdir().unwrap();
+        let provider = OAuthProvider { token_url: Box::leak(token_url.into_boxed_str()), ..WHOOP };
+        let oauth_client = OAuthClient::new(provider, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+
+        // The state the provider echoes back is the one the callback expects
+        let auth_url = oauth_client.get_authorization_url();
+        assert_eq!(query_param(&auth_url, "state"), oauth_client.callback_state(mpsc::channel(1).0).expected_state);
+
+        // and the exchange sends the verifier behind the URL's challenge, to a
+        // token endpoint that checks it like the provider would
+        let challenge = query_param(&auth_url, "code_challenge").to_string();
+        let app = Router::new().route("/token", post(move |Form(form): Form<HashMap<String, String>>| async move {
+            if form.get("code_verifier").map(|verifier| code_challenge(verifier)) != Some(challenge) {
+                return (StatusCode::BAD_REQUEST, r#"{"error": "invalid_grant"}"#);
+            }
+            (StatusCode::OK, r#"{"access_token": "a", "refresh_token": "r", "expires_in": 3600, "token_type": "bearer"}"#)
+        }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let tokens = oauth_client.exchange_code_for_token("the_code").await.unwrap();
+        assert_eq!(tokens.access_token, "a");
+    }
+
+    #[test]
+    fn test_fitbit_authorization_url_has_pkce_challenge() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(
+            FITBIT,
+            "23ABCD".to_string(),
+            "test_client_secret".to_string(),
+            "http://127.0.0.1:3000/auth/fitbit/callback".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+
+        let auth_url = oauth_client.get_authorization_url();
+        assert!(auth_url.starts_with("https://www.fitbit.com/oauth2/authorize?"));
+        assert!(auth_url.contains("scope=activity%20heartrate%20profile"));
+        assert!(auth_url.contains(&format!("code_challenge={}&code_challenge_method=S256", code_challenge(&oauth_client.code_verifier))));
+        assert_eq!(oauth_client.tokens_file(), temp_dir.path().join("fitbit_tokens.json"));
+
+        // Withings doesn't take PKCE
+        let withings = OAuthClient::new(WITHINGS, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        assert!(!withings.get_authorization_url().contains("code_challenge"));
+    }
+
+    #[test]
+    fn test_withings_authorization_url_uses_commas() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(
+            WITHINGS,
+            "client".to_string(),
+            "secret".to_string(),
+            "http://127.0.0.1:3000/auth/withings/callback".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+        let auth_url = oauth_client.get_authorization_url();
+        assert!(auth_url.starts_with("https://account.withings.com/oauth2_user/authorize2?"));
+        assert!(auth_url.contains("scope=user.metrics%2Cuser.activity"));
+    }
+
+    #[test]
+    fn test_gmail_authorization_url_asks_for_offline_access() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(
+            GMAIL,
+            "1234-abcd.apps.googleusercontent.com".to_string(),
+            "secret".to_string(),
+            "http://127.0.0.1:3000/auth/gmail/callback".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+        let auth_url = oauth_client.get_authorization_url();
+        assert!(auth_url.starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+        assert!(auth_url.contains("scope=https%3A%2F%2Fmail.google.com%2F"));
+        assert!(auth_url.contains("code_challenge_method=S256"));
+        assert!(auth_url.ends_with("&access_type=offline&prompt=consent"));
+        assert_eq!(oauth_client.tokens_file(), temp_dir.path().join("gmail_tokens.json"));
+    }
+
+    #[test]
+    fn test_parse_token_response() {
+        let standard = r#"{"access_token": "a", "refresh_token": "r", "expires_in": 3600, "token_type": "Bearer"}"#;
+        assert_eq!(parse_token_response(TokenFormat::Standard, standard).unwrap().access_token, "a");
+
+        // Withings nests the tokens in `body`
+        let withings = r#"{"status": 0, "body": {"userid": "363", "access_token": "a", "refresh_token": "r",
+            "expires_in": 10800, "scope": "user.metrics,user.activity", "csrf_token": "c", "token_type": "Bearer"}}"#;
+        let tokens = parse_token_response(TokenFormat::Withings, withings).unwrap();
+        assert_eq!((tokens.refresh_token.as_str(), tokens.expires_in), ("r", Some(10800)));
+        assert!(parse_token_response(TokenFormat::Standard, withings).is_err());
+
+        // and reports errors with HTTP 200
+        let error = r#"{"status": 503, "body": {}, "error": "Invalid params: invalid code"}"#;
+        let message = parse_token_response(TokenFormat::Withings, error).unwrap_err().to_string();
+        assert!(message.contains("invalid code"), "{}", message);
+
+        // Google's refresh responses keep the old refresh token by leaving it out
+        let google = r#"{"access_token": "ya29.a", "expires_in": 3599, "scope": "https://mail.google.com/", "token_type": "Bearer"}"#;
+        assert_eq!(parse_token_response(TokenFormat::Standard, google).unwrap().refresh_token, "");
+    }
+
+    #[test]
+    fn test_code_challenge() {
+        // base64url of the SHA-256, without padding
+        assert_eq!(code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r7wW1gFWFOEjXk"), "bwWFMyPfdG9qreDhH2lmftFx_dFeLDalzcT1gb_j68g");
+        let verifier = generate_code_verifier();
+        assert_eq!(verifier.len(), 64);
+        assert_ne!(verifier, generate_code_verifier());
     }
 
     #[test]
     fn test_token_serialization() {
-        let tokens = WhoopTokens {
+        let tokens = OAuthTokens {
             access_token: "test_access_token".to_string(),
             refresh_token: "test_refresh_token".to_string(),
             expires_at: Utc::now(),
@@ -643,10 +1412,238 @@ mod tests {
         };
 
         let serialized = serde_json::to_string(&tokens).unwrap();
-        let deserialized: WhoopTokens = serde_json::from_str(&serialized).unwrap();
+        let deserialized: OAuthTokens = serde_json::from_str(&serialized).unwrap();
 
         assert_eq!(tokens.access_token, deserialized.access_token);
         assert_eq!(tokens.refresh_token, deserialized.refresh_token);
         assert_eq!(tokens.token_type, deserialized.token_type);
     }
-}
\ No newline at end of file
+
+    fn token_response(expires_in: Option<u64>) -> TokenResponse {
+        TokenResponse {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_in,
+            token_type: "Bearer".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_token_response_expires_in_passes_through_sane_values() {
+        let now = Utc::now();
+        let tokens = OAuthTokens::from_response(token_response(Some(3600)), now);
+        assert_eq!(tokens.expires_at, now + chrono::Duration::seconds(3600));
+    }
+
+    #[test]
+    fn test_token_response_missing_or_zero_expires_in_refreshes_immediately() {
+        let now = Utc::now();
+        assert_eq!(OAuthTokens::from_response(token_response(None), now).expires_at, now);
+        assert_eq!(OAuthTokens::from_response(token_response(Some(0)), now).expires_at, now);
+
+        let parsed: TokenResponse = serde_json::from_str(
+            r#"{"access_token":"a","refresh_token":"r","token_type":"Bearer"}"#
+        ).unwrap();
+        assert!(parsed.expires_in.is_none());
+    }
+
+    #[test]
+    fn test_token_response_expires_in_is_clamped() {
+        let now = Utc::now();
+
+        let tiny = OAuthTokens::from_response(token_response(Some(1)), now);
+        assert_eq!(tiny.expires_at, now + chrono::Duration::seconds(MIN_TOKEN_LIFETIME_SECS as i64));
+
+        let huge = OAuthTokens::from_response(token_response(Some(u64::MAX)), now);
+        assert_eq!(huge.expires_at, now + chrono::Duration::seconds(MAX_TOKEN_LIFETIME_SECS as i64));
+    }
+
+    #[test]
+    fn test_load_tokens_treats_far_future_expiry_as_expired() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(
+            WHOOP,
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+            "http://localhost:3000/callback".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+
+        let tokens = OAuthTokens {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_at: Utc::now() + chrono::Duration::days(5000),
+            token_type: "Bearer".to_string(),
+        };
+        oauth_client.save_tokens(&tokens).unwrap();
+
+        let loaded = oauth_client.load_tokens().unwrap();
+        assert!(loaded.expires_at <= Utc::now());
+    }
+
+    #[test]
+    fn test_load_tokens_keeps_plausible_expiry() {
+        let temp_dir = tempdir().unwrap();
+        let oauth_client = OAuthClient::new(
+            WHOOP,
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+            "http://localhost:3000/callback".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+        let tokens = OAuthTokens {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_at,
+            token_type: "Bearer".to_string(),
+        };
+        oauth_client.save_tokens(&tokens).unwrap();
+
+        assert_eq!(oauth_client.load_tokens().unwrap().expires_at, expires_at);
+    }
+
+    /// A token endpoint per provider at `/<token file>`, answering in each
+    /// provider's format and recording every request's form
+    async fn mock_token_endpoint() -> (String, Arc<std::sync::Mutex<Vec<(String, HashMap<String, String>)>>>) {
+        use axum::extract::{Form, Path};
+
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let app = Router::new().route("/:provider", post(move |Path(provider): Path<String>, Form(form): Form<HashMap<String, String>>| async move {
+            let count = recorded.lock().unwrap().len();
+            recorded.lock().unwrap().push((provider.clone(), form));
+            let tokens = serde_json::json!({
+                "access_token": format!("access_{}", count),
+                "refresh_token": format!("refresh_{}", count),
+                "expires_in": 3600,
+                "token_type": "Bearer",
+            });
+            if provider == WITHINGS.token_file {
+                serde_json::json!({"status": 0, "body": tokens}).to_string()
+            } else {
+                tokens.to_string()
+            }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (base_url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_providers_share_token_handling() {
+        let (base_url, requests) = mock_token_endpoint().await;
+        let temp_dir = tempdir().unwrap();
+
+        for provider in [WHOOP, WITHINGS] {
+            let token_url = format!("{}/{}", base_url, provider.token_file);
+            let provider = OAuthProvider { token_url: Box::leak(token_url.into_boxed_str()), ..provider };
+            let oauth_client = OAuthClient::new(provider, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+            requests.lock().unwrap().clear();
+
+            let tokens = oauth_client.exchange_code_for_token("the_code").await.unwrap();
+            oauth_client.save_tokens(&tokens).unwrap();
+            // Each provider keeps its existing file, e.g. whoop_tokens.json
+            assert!(temp_dir.path().join(provider.token_file).exists());
+
+            // A token that's good for longer than the buffer is used as is
+            assert_eq!(oauth_client.get_valid_access_token().await.unwrap(), "access_0");
+            assert_eq!(requests.lock().unwrap().len(), 1);
+
+            // and one about to expire is refreshed and saved
+            let expiring = OAuthTokens { expires_at: Utc::now() + chrono::Duration::minutes(2), ..oauth_client.load_tokens().unwrap() };
+            oauth_client.save_tokens(&expiring).unwrap();
+            assert_eq!(oauth_client.get_valid_access_token().await.unwrap(), "access_1");
+            assert_eq!(oauth_client.load_tokens().unwrap().refresh_token, "refresh_1");
+
+            let requests = requests.lock().unwrap();
+            let [(_, exchange), (_, refresh)] = &requests[..] else { panic!("{:?}", requests) };
+            assert_eq!((exchange["grant_type"].as_str(), exchange["code"].as_str(), exchange["client_secret"].as_str()), ("authorization_code", "the_code", "secret"));
+            assert_eq!((refresh["grant_type"].as_str(), refresh["refresh_token"].as_str()), ("refresh_token", "refresh_0"));
+            assert_eq!(exchange.contains_key("code_verifier"), provider.pkce);
+            for (name, value) in provider.token_params {
+                assert_eq!((exchange[*name].as_str(), refresh[*name].as_str()), (*value, *value));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_status_and_refresh() {
+        let (base_url, requests) = mock_token_endpoint().await;
+        let temp_dir = tempdir().unwrap();
+        let token_url = format!("{}/{}", base_url, WHOOP.token_file);
+        let provider = OAuthProvider { token_url: Box::leak(token_url.into_boxed_str()), ..WHOOP };
+        let oauth_client = OAuthClient::new(provider, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        assert!(run_token_status(&oauth_client).await.is_err());
+        assert!(run_token_refresh(&oauth_client).await.is_err());
+
+        let tokens = OAuthTokens {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+            token_type: "Bearer".to_string(),
+        };
+        oauth_client.save_tokens(&tokens).unwrap();
+
+        // Both refresh even though the token is good for an hour, and save the rotated refresh token
+        run_token_status(&oauth_client).await.unwrap();
+        assert_eq!(oauth_client.load_tokens().unwrap().refresh_token, "refresh_0");
+        run_token_refresh(&oauth_client).await.unwrap();
+        assert_eq!(oauth_client.load_tokens().unwrap().refresh_token, "refresh_1");
+        assert_eq!(requests.lock().unwrap()[0].1["refresh_token"], "refresh");
+
+        // A token endpoint that's gone fails the status check
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let token_url = format!("http://{}/token", closed.local_addr().unwrap());
+        drop(closed);
+        let provider = OAuthProvider { token_url: Box::leak(token_url.into_boxed_str()), ..WHOOP };
+        let unreachable = OAuthClient::new(provider, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf());
+        let error = run_token_status(&unreachable).await.unwrap_err();
+        assert!(error.to_string().contains("whoop-auth"), "{}", error);
+        assert_eq!(oauth_client.load_tokens().unwrap().refresh_token, "refresh_1");
+    }
+
+    #[test]
+    fn test_facebook_token_exchange_response() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let response = serde_json::json!({"access_token": "EAAlong", "token_type": "bearer", "expires_in": 5183944});
+        let token = FacebookPageToken::from_exchange(&response, now).unwrap();
+        assert_eq!(token.access_token, "EAAlong");
+        assert_eq!(token.expires_at, Some(now + chrono::Duration::seconds(5_183_944)));
+
+        // Page tokens from a long-lived user token never expire
+        let response = serde_json::json!({"access_token": "EAApage", "token_type": "bearer"});
+        assert_eq!(FacebookPageToken::from_exchange(&response, now).unwrap().expires_at, None);
+
+        let error = serde_json::json!({"error": {"message": "Error validating client secret.", "type": "OAuthException", "code": 1}});
+        let message = FacebookPageToken::from_exchange(&error, now).unwrap_err().to_string();
+        assert_eq!(message, "Facebook API error: Error validating client secret.");
+    }
+
+    #[test]
+    fn test_facebook_token_file_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let data_directory = temp_dir.path().join("data");
+        assert!(FacebookPageToken::load(&data_directory).unwrap_err().to_string().contains("facebook-auth"));
+
+        let token = FacebookPageToken {
+            access_token: "EAAlong".to_string(),
+            expires_at: Some("2026-12-14T09:30:00Z".parse().unwrap()),
+        };
+        token.save(&data_directory).unwrap();
+        assert!(data_directory.join(FACEBOOK_TOKEN_FILE).exists());
+        assert_eq!(FacebookPageToken::load(&data_directory).unwrap(), token);
+    }
+
+    #[test]
+    fn test_facebook_token_expires_soon() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let token = |expires_at: Option<DateTime<Utc>>| FacebookPageToken { access_token: "EAAlong".to_string(), expires_at };
+        assert!(!token(None).expires_soon(now));
+        assert!(!token(Some(now + chrono::Duration::days(8))).expires_soon(now));
+        assert!(token(Some(now + chrono::Duration::days(7))).expires_soon(now));
+        assert!(token(Some(now - chrono::Duration::hours(1))).expires_soon(now));
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use hmac::Mac;
+use sha2::{Digest, Sha256};
+
+use crate::signing::hmac_sha256;
+
+pub const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded; charset=utf-8";
+
+/// Long-term IAM access keys
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+/// AWS regions look like `us-east-1` or `eu-west-2`
+pub fn validate_region(region: &str) -> anyhow::Result<()> {
+    let valid = !region.is_empty()
+        && region.split('-').count() >= 3
+        && region.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        anyhow::bail!("'{}' is not an AWS region such as us-east-1", region);
+    }
+    Ok(())
+}
+
+/// Encodes Query API parameters in the order given
+pub fn form_body(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", urlencoding::encode(key), urlencoding::encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// The Signature Version 4 `X-Amz-Date` and `Authorization` headers for an
+/// AWS Query API request, a form POST of `body` to the root path of `host`
+pub fn sign_form_post(
+    credentials: &Credentials,
+    region: &str,
+    service: &str,
+    host: &str,
+    body: &str,
+    now: DateTime<Utc>,
+) -> [(&'static str, String); 2] {
+    sign_post(credentials, region, service, host, FORM_CONTENT_TYPE, body, now)
+}
+
+fn sign_post(
+    credentials: &Credentials,
+    region: &str,
+    service: &str,
+    host: &str,
+    content_type: &str,
+    body: &str,
+    now: DateTime<Utc>,
+) -> [(&'static str, String); 2] {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let signed_headers = "content-type;host;x-amz-date";
+
+    let canonical_request = format!(
+        "POST\n/\n\ncontent-type:{}\nhost:{}\nx-amz-date:{}\n\n{}\n{}",
+        content_type,
+        host,
+        amz_date,
+        signed_headers,
+        hex::encode(Sha256::digest(body.as_bytes())),
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes())),
+    );
+
+    let key = signing_key(&credentials.secret_access_key, &date, region, service);
+    let signature = hex::encode(hmac(&key, string_to_sign.as_bytes()));
+
+    [
+        ("X-Amz-Date", amz_date),
+        (
+            "Authorization",
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                credentials.access_key_id, scope, signed_headers, signature
+            ),
+        ),
+    ]
+}
+
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac(format!("AWS4{}", secret_access_key).as_bytes(), date.as_bytes());
+    let key = hmac(&key, region.as_bytes());
+    let key = hmac(&key, service.as_bytes());
+    hmac(&key, b"aws4_request")
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac_sha256(key, data).finalize().into_bytes().to_vec()
+}
+
+/// The text of the first `<tag>` element in an XML response. The Query API
+/// responses are simple enough not to need a full parser.
+pub fn xml_text<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&close)?;
+    Some(xml[start..end].trim())
+}
+
+/// `Code: Message` from an AWS `<ErrorResponse>`, or the whole body if it isn't one
+pub fn error_message(body: &str) -> String {
+    match (xml_text(body, "Code"), xml_text(body, "Message")) {
+        (Some(code), Some(message)) => format!("{}: {}", code, unescape_xml(message)),
+        (Some(code), None) => code.to_string(),
+        _ => body.trim().to_string(),
+    }
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+    #[test]
+    fn test_signing_key_matches_aws_example() {
+        let key = signing_key(SECRET, "20120215", "us-east-1", "iam");
+        assert_eq!(hex::encode(key), "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d");
+    }
+
+    #[test]
+    fn test_sign_form_post() {
+        let credentials = Credentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: SECRET.to_string(),
+        };
+        let now = "2015-08-30T12:36:00Z".parse().unwrap();
+        let body = form_body(&[("Action", "GetSendQuota"), ("Version", "2010-12-01")]);
+        assert_eq!(body, "Action=GetSendQuota&Version=2010-12-01");
+
+        let [(_, amz_date), (_, authorization)] =
+            sign_form_post(&credentials, "us-east-1", "ses", "email.us-east-1.amazonaws.com", &body, now);
+        assert_eq!(amz_date, "20150830T123600Z");
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/ses/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature="
+        ));
+
+        // Any change to the body changes the signature
+        let [_, (_, other)] = sign_form_post(&credentials, "us-east-1", "ses", "email.us-east-1.amazonaws.com", "Action=SendEmail", now);
+        assert_ne!(authorization, other);
+    }
+
+    /// `post-x-www-form-urlencoded` from the AWS Signature Version 4 test suite
+    #[test]
+    fn test_sign_post_matches_aws_test_suite() {
+        let credentials = Credentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: SECRET.to_string(),
+        };
+        let now = "2015-08-30T12:36:00Z".parse().unwrap();
+        let [_, (_, authorization)] = sign_post(
+            &credentials,
+            "us-east-1",
+            "service",
+            "example.amazonaws.com",
+            "application/x-www-form-urlencoded",
+            "Param1=value1",
+            now,
+        );
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+        );
+    }
+
+    #[test]
+    fn test_error_message() {
+        let body = r#"<ErrorResponse xmlns="http://ses.amazonaws.com/doc/2010-12-01/">
+  <Error>
+    <Type>Sender</Type>
+    <Code>MessageRejected</Code>
+    <Message>Email address is not verified. The following identities failed the check in region US-EAST-1: &lt;me@example.com&gt;</Message>
+  </Error>
+  <RequestId>0f5b4a7e</RequestId>
+</ErrorResponse>"#;
+        assert_eq!(
+            error_message(body),
+            "MessageRejected: Email address is not verified. The following identities failed the check in region US-EAST-1: <me@example.com>"
+        );
+        assert_eq!(error_message("Service Unavailable"), "Service Unavailable");
+    }
+
+    #[test]
+    fn test_validate_region() {
+        assert!(validate_region("eu-west-2").is_ok());
+        assert!(validate_region("us-gov-west-1").is_ok());
+        assert!(validate_region("US-EAST-1").is_err());
+        assert!(validate_region("example.com").is_err());
+    }
+}
@@ -2,13 +2,11 @@ use super::{Output, OutputResult};
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use crate::duration_parser::ConfigDuration;
+use super::OutputContext;
 
 /// Represents the result of checking for incoming responses
 #[derive(Debug, Clone)]
 pub enum CheckinResponse {
-    /// No new responses found
-    None,
     /// Found a valid checkin response
     Found {
         /// Timestamp when the response was received
@@ -20,6 +18,40 @@ pub enum CheckinResponse {
     },
 }
 
+/// How full the mailbox that receives check-in replies is. Once it is full,
+/// replies bounce and check-ins go unseen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailboxUsage {
+    /// Usage of the fullest quota resource, as a percentage of its limit
+    pub percent: u64,
+    /// Which resource that is, e.g. "storage 950/1000 KiB"
+    pub resource: String,
+}
+
+impl MailboxUsage {
+    /// The fullest resource across IMAP quota roots, or `None` if no
+    /// resource has a limit
+    pub fn from_quotas(quotas: &[async_imap::types::Quota]) -> Option<Self> {
+        use async_imap::types::QuotaResourceName;
+
+        quotas.iter()
+            .flat_map(|quota| &quota.resources)
+            .filter(|resource| resource.limit > 0)
+            .max_by_key(|resource| resource.get_usage_percentage())
+            .map(|resource| {
+                let resource_description = match &resource.name {
+                    QuotaResourceName::Storage => format!("storage {}/{} KiB", resource.usage, resource.limit),
+                    QuotaResourceName::Message => format!("messages {}/{}", resource.usage, resource.limit),
+                    QuotaResourceName::Atom(name) => format!("{} {}/{}", name.to_lowercase(), resource.usage, resource.limit),
+                };
+                MailboxUsage {
+                    percent: resource.get_usage_percentage(),
+                    resource: resource_description,
+                }
+            })
+    }
+}
+
 /// Trait for outputs that can both send messages and receive responses
 /// This extends the basic Output functionality with bidirectional communication
 #[async_trait]
@@ -40,6 +72,18 @@ pub trait BidirectionalOutput: Send + Sync {
     /// Mark responses as processed up to the given timestamp
     /// This prevents re-processing the same responses
     async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()>;
+
+    /// How full the mailbox that receives responses is, where the channel
+    /// has one and the server reports it
+    async fn mailbox_usage(&self) -> Result<Option<MailboxUsage>> {
+        Ok(None)
+    }
+
+    /// Notified when responses arrive between checks, for outputs that are
+    /// pushed replies, so the daemon can check straight away
+    fn reply_signal(&self) -> Option<std::sync::Arc<tokio::sync::Notify>> {
+        None
+    }
 }
 
 /// Wrapper that makes any Output into a BidirectionalOutput by composition
@@ -87,8 +131,10 @@ impl BidirectionalOutputFactory {
         output_type: &str,
         config: &std::collections::HashMap<String, String>,
         is_bidirectional: bool,
+        additional_imap_accounts: &[super::email_bidirectional::ImapAccount],
+        acknowledgement_senders: &[String],
         data_directory: Option<&std::path::Path>,
-        max_time_since_last_checkin: ConfigDuration,
+        context: OutputContext,
     ) -> Result<Box<dyn BidirectionalOutput>> {
         tracing::debug!("Creating bidirectional output: type={}, is_bidirectional={}", output_type, is_bidirectional);
         match output_type {
@@ -96,18 +142,166 @@ impl BidirectionalOutputFactory {
                 if is_bidirectional {
                     // Create the specialized bidirectional email output
                     tracing::info!("Creating true bidirectional email output with IMAP support");
-                    let output = super::email_bidirectional::BidirectionalEmailOutput::new(config)?;
+                    let output = super::email_bidirectional::BidirectionalEmailOutput::new(config)?
+                        .with_additional_accounts(additional_imap_accounts)
+                        .with_acknowledgement_senders(acknowledgement_senders)
+                        .with_data_directory(data_directory);
                     Ok(Box::new(output))
                 } else {
                     // Wrap the regular email output
                     tracing::info!("Creating regular email output (wrapped for bidirectional compatibility)");
-                    let output = super::email::EmailOutput::new(config)?;
+                    let output = super::email::EmailOutput::new(config, super::MessageKind::Checkin)?.with_data_directory(data_directory);
                     Ok(Box::new(BidirectionalWrapper::new(output)))
                 }
             }
+            "email_mailgun" => {
+                // Replies arrive by email, so pair it with a bidirectional email output to read them
+                let output = super::email_mailgun::EmailMailgunOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "email_ses" => {
+                // Replies arrive by email, so pair it with a bidirectional email output to read them
+                let output = super::email_ses::EmailSesOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "apprise" => {
+                let output = super::apprise::AppriseOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "console" => {
+                let output = super::console::ConsoleOutput::new(config, super::MessageKind::Checkin)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "discord" => {
+                let output = super::discord::DiscordOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "exec" => {
+                let output = super::exec::ExecOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
             "facebook_messenger" => {
-                // Facebook Messenger could potentially be bidirectional too
-                let output = super::facebook_messenger::FacebookMessengerOutput::new(config)?;
+                if is_bidirectional {
+                    // Messages from user_id, delivered to the webhook, count as check-ins
+                    let output = super::facebook_messenger::FacebookMessengerOutput::new_bidirectional(config, data_directory)?;
+                    Ok(Box::new(output))
+                } else {
+                    let output = super::facebook_messenger::FacebookMessengerOutput::new(config, data_directory)?;
+                    Ok(Box::new(BidirectionalWrapper::new(output)))
+                }
+            }
+            "file" => {
+                // Bidirectional outputs are only used for check-in requests
+                let output = super::file::FileOutput::new(config, super::MessageKind::Checkin, data_directory)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "file_activity" => {
+                // Changes to the watched paths count as responses
+                let output = super::file_activity::FileActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "fitbit" => {
+                // Like WHOOP, synced heart rate and step data count as responses
+                tracing::info!("Creating Fitbit bidirectional output with device activity monitoring");
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Fitbit output"))?
+                    .to_path_buf();
+                let output = super::fitbit::FitbitOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "garmin" => {
+                // Like WHOOP, device syncs count as responses
+                tracing::info!("Creating Garmin bidirectional output with device activity monitoring");
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Garmin output"))?
+                    .to_path_buf();
+                let output = super::garmin::GarminOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "git_activity" => {
+                // Recent commits count as responses
+                let output = super::git_activity::GitActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "irc" => {
+                let output = super::irc::IrcOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "lastfm" => {
+                // Recent scrobbles count as responses
+                let output = super::lastfm::LastfmOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "local_activity" => {
+                // Logins and changes to watched files count as responses
+                let output = super::local_activity::LocalActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "matrix" => {
+                // Room messages from admin_user_id count as check-ins
+                let output = super::matrix::MatrixOutput::new(config, data_directory)?;
+                if is_bidirectional {
+                    Ok(Box::new(output))
+                } else {
+                    Ok(Box::new(BidirectionalWrapper::new(output)))
+                }
+            }
+            "mqtt_presence" => {
+                // Messages on the topic count as responses
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for mqtt_presence output"))?
+                    .to_path_buf();
+                let output = super::mqtt_presence::MqttPresenceOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "ntfy" => {
+                // Messages on the topic, or response_topic, count as check-ins
+                let output = super::ntfy::NtfyOutput::new(config, data_directory)?;
+                if is_bidirectional {
+                    Ok(Box::new(output))
+                } else {
+                    Ok(Box::new(BidirectionalWrapper::new(output)))
+                }
+            }
+            "pushover" => {
+                let output = super::pushover::PushoverOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "rocketchat" => {
+                let output = super::rocketchat::RocketChatOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "slack" => {
+                let output = super::slack::SlackOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "sns" => {
+                let output = super::sns::SnsOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "steam" => {
+                // Being online or playing a game counts as a response
+                let output = super::steam::SteamOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "syslog" => {
+                let output = super::syslog::SyslogOutput::new(config, super::MessageKind::Checkin)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "voice_twilio" => {
+                let output = super::voice_twilio::VoiceTwilioOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "webhook" => {
+                let output = super::webhook::WebhookOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "sms_twilio" => {
+                let output = super::sms_twilio::SmsTwilioOutput::new(config)?;
+                Ok(Box::new(BidirectionalWrapper::new(output)))
+            }
+            "whatsapp" => {
+                let output = super::whatsapp::WhatsAppOutput::new(config)?;
                 Ok(Box::new(BidirectionalWrapper::new(output)))
             }
             "whoop" => {
@@ -116,9 +310,26 @@ impl BidirectionalOutputFactory {
                 let data_dir = data_directory
                     .ok_or_else(|| anyhow::anyhow!("Data directory required for WHOOP output"))?
                     .to_path_buf();
-                let output = super::whoop::WhoopOutput::new(config, data_dir, max_time_since_last_checkin)?;
+                let output = super::whoop::WhoopOutput::new(config, data_dir, context)?;
+                Ok(Box::new(output))
+            }
+            "withings" => {
+                // Like WHOOP, scale measurements and sleep records count as responses
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Withings output"))?
+                    .to_path_buf();
+                let output = super::withings::WithingsOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
                 Ok(Box::new(output))
             }
+            "xmpp" => {
+                // Chat messages from to_jid count as check-ins
+                let output = super::xmpp::XmppOutput::new(config, data_directory)?;
+                if is_bidirectional {
+                    Ok(Box::new(output))
+                } else {
+                    Ok(Box::new(BidirectionalWrapper::new(output)))
+                }
+            }
             _ => anyhow::bail!("Unknown output type: {}", output_type),
         }
     }
@@ -160,4 +371,33 @@ pub async fn mark_all_processed_until(
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_imap::types::{Quota, QuotaResource, QuotaResourceName};
+
+    #[test]
+    fn test_mailbox_usage_reports_fullest_resource() {
+        let quotas = vec![Quota {
+            root_name: String::new(),
+            resources: vec![
+                QuotaResource { name: QuotaResourceName::Storage, usage: 950, limit: 1000 },
+                QuotaResource { name: QuotaResourceName::Message, usage: 10, limit: 5000 },
+            ],
+        }];
+
+        let usage = MailboxUsage::from_quotas(&quotas).unwrap();
+        assert_eq!(usage.percent, 95);
+        assert_eq!(usage.resource, "storage 950/1000 KiB");
+    }
+
+    #[test]
+    fn test_mailbox_usage_ignores_unlimited_resources() {
+        let quotas = vec![Quota {
+            root_name: "user".to_string(),
+            resources: vec![QuotaResource { name: QuotaResourceName::Storage, usage: 950, limit: 0 }],
+        }];
+        assert!(MailboxUsage::from_quotas(&quotas).is_none());
+    }
+}
@@ -0,0 +1,266 @@
+use super::bidirectional::{BidirectionalOutput, CheckinResponse, MailboxUsage};
+use super::{Output, OutputResult};
+use crate::duration_parser::ConfigDuration;
+use crate::schedule;
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// A fault injected into every output of one type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Sends fail and health checks report unhealthy until this time
+    FailUntil(DateTime<Utc>),
+    /// Sends and health checks are delayed by this long
+    Latency(ConfigDuration),
+    /// Sends are skipped, as if the output had nothing to do
+    Skip,
+}
+
+/// Faults to inject into outputs, parsed from a spec such as
+/// `email:fail_until=+72h,file:latency=20s,facebook_messenger:skip`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChaosSpec {
+    rules: Vec<(String, Fault)>,
+}
+
+impl ChaosSpec {
+    /// Parses a spec. Relative `fail_until` times (`+72h`) count from `now`.
+    pub fn parse(spec: &str, now: DateTime<Utc>) -> Result<Self> {
+        let mut rules = Vec::new();
+        for rule in spec.split(',').map(str::trim).filter(|rule| !rule.is_empty()) {
+            let (output_type, fault) = rule.split_once(':')
+                .with_context(|| format!("Chaos rule '{}' must look like <output_type>:<fault>", rule))?;
+            let fault = match fault.split_once('=') {
+                Some(("fail_until", until)) => Fault::FailUntil(parse_until(until, now)?),
+                Some(("latency", latency)) => Fault::Latency(latency.parse()
+                    .with_context(|| format!("Invalid latency in chaos rule '{}'", rule))?),
+                None if fault == "fail" => Fault::FailUntil(DateTime::<Utc>::MAX_UTC),
+                None if fault == "skip" => Fault::Skip,
+                _ => bail!("Unknown fault in chaos rule '{}'. Valid faults: fail, fail_until=<time>, latency=<duration>, skip", rule),
+            };
+            rules.push((output_type.trim().to_string(), fault));
+        }
+
+        if rules.is_empty() {
+            bail!("Chaos spec is empty");
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn faults_for(&self, output_type: &str) -> Vec<Fault> {
+        self.rules.iter()
+            .filter(|(rule_type, _)| rule_type == output_type)
+            .map(|(_, fault)| fault.clone())
+            .collect()
+    }
+}
+
+impl std::fmt::Display for ChaosSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rules: Vec<String> = self.rules.iter()
+            .map(|(output_type, fault)| match fault {
+                Fault::FailUntil(until) if *until == DateTime::<Utc>::MAX_UTC => format!("{} fails", output_type),
+                Fault::FailUntil(until) => format!("{} fails until {}", output_type, until.format("%Y-%m-%d %H:%M:%S UTC")),
+                Fault::Latency(latency) => format!("{} is delayed by {}", output_type, latency),
+                Fault::Skip => format!("{} skips sends", output_type),
+            })
+            .collect();
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+/// `+72h` relative to `now`, or an RFC 3339 timestamp
+fn parse_until(until: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    match until.strip_prefix('+') {
+        Some(duration) => {
+            let duration: ConfigDuration = duration.parse()
+                .with_context(|| format!("Invalid fail_until duration '{}'", until))?;
+            Ok(schedule::add_duration(now, duration))
+        }
+        None => Ok(DateTime::parse_from_rfc3339(until)
+            .with_context(|| format!("fail_until must be +<duration> or an RFC 3339 time, got '{}'", until))?
+            .with_timezone(&Utc)),
+    }
+}
+
+/// Wraps an output and applies injected faults before delegating to it
+pub struct ChaosOutput<O: ?Sized> {
+    inner: Box<O>,
+    faults: Vec<Fault>,
+}
+
+impl<O: ?Sized> ChaosOutput<O> {
+    pub fn new(inner: Box<O>, faults: Vec<Fault>) -> Self {
+        Self { inner, faults }
+    }
+
+    async fn delay(&self) {
+        for fault in &self.faults {
+            if let Fault::Latency(latency) = fault {
+                tokio::time::sleep(latency.as_duration()).await;
+            }
+        }
+    }
+
+    fn failing(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.faults.iter().find_map(|fault| match fault {
+            Fault::FailUntil(until) if !schedule::is_reached(*until, now) => Some(*until),
+            _ => None,
+        })
+    }
+
+    /// The result chaos decides on for a send, or `None` to let it through
+    async fn intercept_send(&self) -> Option<OutputResult> {
+        self.delay().await;
+        if self.failing(Utc::now()).is_some() {
+            return Some(OutputResult::Failed("Chaos: injected failure".to_string()));
+        }
+        if self.faults.contains(&Fault::Skip) {
+            return Some(OutputResult::Skipped("Chaos: injected skip".to_string()));
+        }
+        None
+    }
+
+    async fn intercept_health_check(&self) -> Option<bool> {
+        self.delay().await;
+        self.failing(Utc::now()).map(|_| false)
+    }
+}
+
+#[async_trait]
+impl Output for ChaosOutput<dyn Output> {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        match self.intercept_send().await {
+            Some(result) => Ok(result),
+            None => self.inner.send_message(message).await,
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.intercept_health_check().await {
+            Some(healthy) => Ok(healthy),
+            None => self.inner.health_check().await,
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.inner.get_name()
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for ChaosOutput<dyn BidirectionalOutput> {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        match self.intercept_send().await {
+            Some(result) => Ok(result),
+            None => self.inner.send_message(message).await,
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.intercept_health_check().await {
+            Some(healthy) => Ok(healthy),
+            None => self.inner.health_check().await,
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.inner.get_name()
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        if self.failing(Utc::now()).is_some() {
+            bail!("Chaos: injected failure");
+        }
+        self.inner.check_for_responses(since).await
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        self.inner.mark_processed_until(timestamp).await
+    }
+
+    async fn mailbox_usage(&self) -> Result<Option<MailboxUsage>> {
+        self.inner.mailbox_usage().await
+    }
+
+    fn reply_signal(&self) -> Option<std::sync::Arc<tokio::sync::Notify>> {
+        self.inner.reply_signal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    struct AlwaysWorks;
+
+    #[async_trait]
+    impl Output for AlwaysWorks {
+        async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+            Ok(OutputResult::Success)
+        }
+
+        async fn health_check(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn get_name(&self) -> &str {
+            "works"
+        }
+    }
+
+    fn chaos(faults: Vec<Fault>) -> ChaosOutput<dyn Output> {
+        ChaosOutput::new(Box::new(AlwaysWorks) as Box<dyn Output>, faults)
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let now = Utc::now();
+        let spec = ChaosSpec::parse("email:fail_until=+72h, file:latency=20s,file:skip", now).unwrap();
+
+        assert_eq!(spec.faults_for("email"), vec![Fault::FailUntil(now + Duration::hours(72))]);
+        assert_eq!(spec.faults_for("file"), vec![Fault::Latency(ConfigDuration::from_seconds(20)), Fault::Skip]);
+        assert!(spec.faults_for("whoop").is_empty());
+
+        let absolute = ChaosSpec::parse("email:fail_until=2030-01-01T00:00:00Z", now).unwrap();
+        assert_eq!(absolute.faults_for("email"), vec![Fault::FailUntil("2030-01-01T00:00:00Z".parse().unwrap())]);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_specs() {
+        let now = Utc::now();
+        assert!(ChaosSpec::parse("", now).is_err());
+        assert!(ChaosSpec::parse("email", now).is_err());
+        assert!(ChaosSpec::parse("email:explode", now).is_err());
+        assert!(ChaosSpec::parse("email:latency=soon", now).is_err());
+        assert!(ChaosSpec::parse("email:fail_until=tomorrow", now).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failing_output_fails_sends_and_health_checks() {
+        let output = chaos(vec![Fault::FailUntil(Utc::now() + Duration::hours(1))]);
+        assert!(matches!(output.send_message("hi").await.unwrap(), OutputResult::Failed(_)));
+        assert!(!output.health_check().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_expired_failure_lets_sends_through() {
+        let output = chaos(vec![Fault::FailUntil(Utc::now() - Duration::hours(1))]);
+        assert!(output.send_message("hi").await.unwrap().is_success());
+        assert!(output.health_check().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_skip_and_latency() {
+        let skipped = chaos(vec![Fault::Skip]);
+        assert!(matches!(skipped.send_message("hi").await.unwrap(), OutputResult::Skipped(_)));
+        assert!(skipped.health_check().await.unwrap());
+
+        let slow = chaos(vec![Fault::Latency(ConfigDuration::from_seconds(1))]);
+        let started = std::time::Instant::now();
+        assert!(slow.send_message("hi").await.unwrap().is_success());
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,74 @@
+use super::{MessageKind, Output, OutputResult};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Prints every message to stdout instead of sending it, to see exactly
+/// what would go out before configuring real credentials
+#[derive(Debug, Clone)]
+pub struct ConsoleOutput {
+    /// Shown in the banner, to tell several console outputs apart
+    name: String,
+    kind: MessageKind,
+}
+
+impl ConsoleOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["name"];
+
+    pub fn new(config: &HashMap<String, String>, kind: MessageKind) -> Result<Self> {
+        Ok(ConsoleOutput {
+            name: config.get("name").cloned().unwrap_or_else(|| "console".to_string()),
+            kind,
+        })
+    }
+
+    fn render(&self, message: &str, now: DateTime<Utc>) -> String {
+        format!(
+            "===== {} ({}) at {} =====\n{}\n===== end of {} =====",
+            self.name,
+            self.kind.as_str(),
+            now.to_rfc3339(),
+            message.trim_end(),
+            self.name,
+        )
+    }
+}
+
+#[async_trait]
+impl Output for ConsoleOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        println!("{}", self.render(message, Utc::now()));
+        Ok(OutputResult::Success)
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn get_name(&self) -> &str {
+        "console"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_console_render() {
+        let now = "2026-10-15T09:30:00Z".parse().unwrap();
+
+        let output = ConsoleOutput::new(&HashMap::new(), MessageKind::LastSignal).unwrap();
+        assert_eq!(
+            output.render("Goodbye\n", now),
+            "===== console (last_signal) at 2026-10-15T09:30:00+00:00 =====\nGoodbye\n===== end of console ====="
+        );
+
+        let mut config = HashMap::new();
+        config.insert("name".to_string(), "pretend email to Alice".to_string());
+        let output = ConsoleOutput::new(&config, MessageKind::Checkin).unwrap();
+        assert!(output.render("Check in", now).starts_with("===== pretend email to Alice (checkin) at "));
+    }
+}
@@ -0,0 +1,213 @@
+use super::{Output, OutputResult};
+use crate::formatting::{self, MessageFormat};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode, Url};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Discord rejects message content longer than this
+const MAX_MESSAGE_CHARS: usize = 2000;
+
+/// Longest a single rate limit is waited out before the post counts as failed
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// How many rate limits one post waits out before giving up
+const MAX_RATE_LIMIT_RETRIES: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct DiscordOutput {
+    webhook_url: Url,
+    username: Option<String>,
+    avatar_url: Option<String>,
+    client: Client,
+}
+
+impl DiscordOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["webhook_url", "username", "avatar_url"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let webhook_url = config
+            .get("webhook_url")
+            .context("Missing 'webhook_url' field in discord config")?;
+
+        Ok(DiscordOutput {
+            webhook_url: parse_webhook_url(webhook_url)?,
+            username: config.get("username").cloned(),
+            avatar_url: config.get("avatar_url").cloned(),
+            client: Client::new(),
+        })
+    }
+
+    async fn post(&self, content: &str) -> Result<OutputResult> {
+        let mut payload = json!({ "content": content });
+        if let Some(username) = &self.username {
+            payload["username"] = json!(username);
+        }
+        if let Some(avatar_url) = &self.avatar_url {
+            payload["avatar_url"] = json!(avatar_url);
+        }
+
+        let mut rate_limits = 0;
+        loop {
+            let response = match self
+                .client
+                .post(self.webhook_url.clone())
+                .json(&payload)
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(e) => {
+                    // The URL's last segment is the webhook token, so leave it out
+                    return Ok(OutputResult::Failed(format!("HTTP request failed: {}", e.without_url())));
+                }
+            };
+
+            let status_code = response.status();
+            if status_code.is_success() {
+                return Ok(OutputResult::Success);
+            }
+
+            let text = match response.text().await {
+                Ok(text) => text,
+                Err(e) => return Ok(OutputResult::Failed(format!("Discord HTTP {} (failed to read response: {})", status_code, e))),
+            };
+
+            if status_code == StatusCode::TOO_MANY_REQUESTS && rate_limits < MAX_RATE_LIMIT_RETRIES {
+                match retry_after(&text) {
+                    Some(wait) if wait <= MAX_RATE_LIMIT_WAIT => {
+                        rate_limits += 1;
+                        tracing::info!("Discord rate limited, retrying in {:.1}s", wait.as_secs_f64());
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            return Ok(OutputResult::Failed(format!("Discord HTTP {}: {}", status_code, text)));
+        }
+    }
+}
+
+/// Webhook URLs look like `https://discord.com/api/webhooks/<id>/<token>`
+pub fn parse_webhook_url(webhook_url: &str) -> Result<Url> {
+    let url = Url::parse(webhook_url).context("Invalid 'webhook_url' in discord config")?;
+    if url.scheme() != "https" {
+        anyhow::bail!("Discord 'webhook_url' must use https");
+    }
+    if url.host_str().is_none() {
+        anyhow::bail!("Discord 'webhook_url' has no host");
+    }
+    Ok(url)
+}
+
+/// Identifies the webhook by its ID, leaving out the secret token. URLs
+/// without one, such as a proxy, are fingerprinted instead.
+pub fn webhook_label(webhook_url: &str) -> String {
+    webhook_url
+        .split("/webhooks/")
+        .nth(1)
+        .and_then(|path| path.split('/').next())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| super::url_fingerprint(webhook_url))
+}
+
+/// The wait a 429 response asks for. Discord gives `retry_after` in seconds,
+/// possibly fractional.
+fn retry_after(body: &str) -> Option<Duration> {
+    let body: serde_json::Value = serde_json::from_str(body).ok()?;
+    let seconds = body.get("retry_after")?.as_f64()?;
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[async_trait]
+impl Output for DiscordOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let parts = formatting::split_message(message, MAX_MESSAGE_CHARS);
+        let total = parts.len();
+
+        for (i, part) in parts.iter().enumerate() {
+            match self.post(part).await? {
+                OutputResult::Success => {}
+                OutputResult::Failed(error) if total > 1 => {
+                    return Ok(OutputResult::Failed(format!("Part {}/{}: {}", i + 1, total, error)));
+                }
+                other => return Ok(other),
+            }
+        }
+
+        Ok(OutputResult::Success)
+    }
+
+    /// Discord renders Markdown itself, so it's posted as written
+    async fn send_formatted(&self, message: &str, _format: MessageFormat) -> Result<OutputResult> {
+        self.send_message(message).await
+    }
+
+    /// A GET on the webhook URL returns the webhook without posting anything
+    async fn health_check(&self) -> Result<bool> {
+        let response = match self.client.get(self.webhook_url.clone()).send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::debug!("Discord health check HTTP error: {}", e.without_url());
+                return Ok(false);
+            }
+        };
+
+        if response.status().is_success() {
+            Ok(true)
+        } else {
+            tracing::debug!("Discord health check HTTP error: {}", response.status());
+            Ok(false)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "discord"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEBHOOK_URL: &str = "https://discord.com/api/webhooks/123456789012345678/XXXXXXXXXXXXXXXXXXXXXXXX";
+
+    #[test]
+    fn test_discord_output_creation() {
+        let mut config = HashMap::new();
+        config.insert("webhook_url".to_string(), WEBHOOK_URL.to_string());
+        config.insert("username".to_string(), "LastSignal".to_string());
+
+        let output = DiscordOutput::new(&config).unwrap();
+        assert_eq!(output.webhook_url.host_str(), Some("discord.com"));
+        assert_eq!(output.username.as_deref(), Some("LastSignal"));
+        assert!(output.avatar_url.is_none());
+    }
+
+    #[test]
+    fn test_discord_output_rejects_missing_or_invalid_url() {
+        assert!(DiscordOutput::new(&HashMap::new()).is_err());
+        assert!(parse_webhook_url("not a url").is_err());
+        assert!(parse_webhook_url("http://discord.com/api/webhooks/1/X").is_err());
+    }
+
+    #[test]
+    fn test_webhook_label_omits_token() {
+        assert_eq!(webhook_label(WEBHOOK_URL), "123456789012345678");
+        assert!(webhook_label("https://example.com/hook").starts_with("example.com#"));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        assert_eq!(retry_after(r#"{"message": "You are being rate limited.", "retry_after": 0.5, "global": false}"#), Some(Duration::from_millis(500)));
+        assert_eq!(retry_after(r#"{"retry_after": 2}"#), Some(Duration::from_secs(2)));
+        assert_eq!(retry_after(r#"{"retry_after": -1}"#), None);
+        assert_eq!(retry_after("Too Many Requests"), None);
+    }
+}
@@ -1,29 +1,452 @@
-use super::{Output, OutputResult};
+use super::{MessageKind, Output, OutputResult};
+use crate::formatting::{self, MessageFormat};
+use crate::message_adapter::render_template_variables;
+use crate::oauth::{OAuthClient, GMAIL};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use lettre::{
-    message::header::ContentType,
-    transport::smtp::authentication::Credentials,
+    message::{header::ContentType, Mailbox, MultiPart},
+    transport::smtp::{self, authentication::{Credentials, Mechanism}, response::Response, PoolConfig},
+    transport::smtp::client::{Certificate, Tls, TlsParameters},
     AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
 };
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Only used for the authorization step, which `gmail-auth` does itself
+const GMAIL_REDIRECT_URI: &str = "http://127.0.0.1:3000/auth/gmail/callback";
+
+/// How the email outputs log in to SMTP and IMAP, from `auth_method`
+#[derive(Debug, Clone)]
+pub enum MailAuth {
+    Password(String),
+    /// Google's XOAUTH2 with tokens from `gmail-auth`. The OAuth client is
+    /// set up once the data directory holding the tokens is known.
+    Xoauth2 {
+        client_id: String,
+        client_secret: String,
+        oauth_client: Option<Arc<RwLock<OAuthClient>>>,
+        _refresh_task: Option<Arc<RefreshTask>>,
+    },
+}
+
+/// The background token refresh, stopped when the last output using it is dropped
+#[derive(Debug)]
+pub struct RefreshTask(tokio::task::JoinHandle<()>);
+
+impl Drop for RefreshTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl MailAuth {
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        match config.get("auth_method").map(String::as_str) {
+            None | Some("password") => Ok(MailAuth::Password(
+                config
+                    .get("password")
+                    .context("Missing 'password' field in email config")?
+                    .clone(),
+            )),
+            Some("xoauth2") => Ok(MailAuth::Xoauth2 {
+                // Google's refresh requests need the client credentials
+                client_id: config
+                    .get("client_id")
+                    .context("Missing 'client_id' field in email config, needed for auth_method = \"xoauth2\"")?
+                    .clone(),
+                client_secret: config
+                    .get("client_secret")
+                    .context("Missing 'client_secret' field in email config, needed for auth_method = \"xoauth2\"")?
+                    .clone(),
+                oauth_client: None,
+                _refresh_task: None,
+            }),
+            Some(other) => anyhow::bail!("Invalid 'auth_method' in email config: '{}' is not password or xoauth2", other),
+        }
+    }
+
+    /// Reads XOAUTH2 tokens from `data_directory` and keeps them fresh in the
+    /// background, like WHOOP's. Passwords are left as they are.
+    pub fn with_data_directory(self, data_directory: Option<&Path>) -> Self {
+        match (self, data_directory) {
+            (MailAuth::Xoauth2 { client_id, client_secret, .. }, Some(data_directory)) => {
+                let oauth_client = Arc::new(RwLock::new(OAuthClient::new(
+                    GMAIL,
+                    client_id.clone(),
+                    client_secret.clone(),
+                    GMAIL_REDIRECT_URI.to_string(),
+                    data_directory.to_path_buf(),
+                )));
+                let refresh_task = RefreshTask(crate::oauth::spawn_background_refresh(Arc::clone(&oauth_client), true));
+                MailAuth::Xoauth2 {
+                    client_id,
+                    client_secret,
+                    oauth_client: Some(oauth_client),
+                    _refresh_task: Some(Arc::new(refresh_task)),
+                }
+            }
+            (auth, _) => auth,
+        }
+    }
+
+    pub fn is_xoauth2(&self) -> bool {
+        matches!(self, MailAuth::Xoauth2 { .. })
+    }
+
+    /// The password, or a current access token. An expired token is
+    /// refreshed here, so health checks don't fail just because the
+    /// background refresh hasn't run yet.
+    pub async fn secret(&self) -> Result<String> {
+        match self {
+            MailAuth::Password(password) => Ok(password.clone()),
+            MailAuth::Xoauth2 { oauth_client: Some(oauth_client), .. } => {
+                oauth_client.read().await.get_valid_access_token().await
+            }
+            MailAuth::Xoauth2 { oauth_client: None, .. } => {
+                anyhow::bail!("auth_method = \"xoauth2\" needs a data directory for its tokens")
+            }
+        }
+    }
+
+    /// Credentials for `username` and the SASL mechanisms to try them with.
+    /// Unencrypted relays usually don't offer AUTH, so an empty password
+    /// skips it there.
+    pub async fn smtp_login(&self, username: &str, security: SmtpSecurity) -> Result<SmtpLogin> {
+        let secret = self.secret().await?;
+        let mechanisms = match self {
+            MailAuth::Password(_) if security == SmtpSecurity::None && secret.is_empty() => return Ok(None),
+            MailAuth::Password(_) => vec![Mechanism::Plain, Mechanism::Login],
+            MailAuth::Xoauth2 { .. } => vec![Mechanism::Xoauth2],
+        };
+        Ok(Some((Credentials::new(username.to_string(), secret), mechanisms)))
+    }
+}
+
+/// Trust settings for mail servers with a private CA or a self-signed
+/// certificate, from `tls_ca_file` and `tls_accept_invalid_certs`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MailTls {
+    /// Each PEM certificate in `tls_ca_file`, trusted alongside the system's
+    ca_certificates: Vec<String>,
+    accept_invalid_certs: bool,
+}
+
+/// The `CERTIFICATE` blocks in a PEM file, which may hold a chain
+fn pem_certificates(pem: &str) -> Vec<String> {
+    const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+    const END: &str = "-----END CERTIFICATE-----";
+    pem.split(BEGIN)
+        .skip(1)
+        .filter_map(|rest| rest.split_once(END))
+        .map(|(body, _)| format!("{}{}{}\n", BEGIN, body, END))
+        .collect()
+}
+
+impl MailTls {
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        let ca_certificates = match config.get("tls_ca_file") {
+            Some(path) => {
+                let path = PathBuf::from(path);
+                let pem = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read tls_ca_file {}", path.display()))?;
+                let certificates = pem_certificates(&pem);
+                if certificates.is_empty() {
+                    anyhow::bail!("tls_ca_file {} contains no PEM certificates", path.display());
+                }
+                for certificate in &certificates {
+                    async_native_tls::Certificate::from_pem(certificate.as_bytes())
+                        .with_context(|| format!("Invalid certificate in tls_ca_file {}", path.display()))?;
+                }
+                certificates
+            }
+            None => Vec::new(),
+        };
+
+        let accept_invalid_certs = match config.get("tls_accept_invalid_certs").map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => anyhow::bail!("Invalid 'tls_accept_invalid_certs' in email config: '{}' is not true or false", other),
+        };
+        if accept_invalid_certs {
+            tracing::warn!(
+                "⚠️  TLS certificate validation is DISABLED for {}. Anyone on the network path can read and alter your mail. Prefer tls_ca_file.",
+                config.get("smtp_host").map_or("this email output", String::as_str)
+            );
+        }
+
+        Ok(MailTls { ca_certificates, accept_invalid_certs })
+    }
+
+    /// TLS settings for lettre's SMTP transport to `host`
+    fn smtp_parameters(&self, host: &str) -> Result<TlsParameters> {
+        let mut builder = TlsParameters::builder(host.to_string())
+            .dangerous_accept_invalid_certs(self.accept_invalid_certs);
+        for certificate in &self.ca_certificates {
+            builder = builder.add_root_certificate(
+                Certificate::from_pem(certificate.as_bytes()).context("Invalid tls_ca_file certificate")?,
+            );
+        }
+        builder.build().context("Failed to set up SMTP TLS")
+    }
+
+    /// A connector for IMAP with these settings
+    pub fn imap_connector(&self) -> Result<async_native_tls::TlsConnector> {
+        let mut connector = async_native_tls::TlsConnector::new().danger_accept_invalid_certs(self.accept_invalid_certs);
+        for certificate in &self.ca_certificates {
+            connector = connector.add_root_certificate(
+                async_native_tls::Certificate::from_pem(certificate.as_bytes()).context("Invalid tls_ca_file certificate")?,
+            );
+        }
+        Ok(connector)
+    }
+}
+
+/// How the SMTP connection is secured, from `smtp_security`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// Upgrade a plain connection with STARTTLS, usually on port 587
+    StartTls,
+    /// TLS from the start, usually on port 465
+    Tls,
+    /// No encryption, for a relay on the same machine
+    None,
+}
+
+impl SmtpSecurity {
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        match config.get("smtp_security").map(String::as_str) {
+            None | Some("starttls") => Ok(SmtpSecurity::StartTls),
+            Some("tls") => Ok(SmtpSecurity::Tls),
+            Some("none") => Ok(SmtpSecurity::None),
+            Some(other) => anyhow::bail!("Invalid 'smtp_security' in email config: '{}' is not starttls, tls or none", other),
+        }
+    }
+
+    /// A transport to `host:port` secured this way, logging in with `login`
+    /// from `MailAuth::smtp_login`. It keeps one connection open between
+    /// uses, as providers such as Gmail limit simultaneous connections.
+    pub fn transport(self, host: &str, port: u16, login: SmtpLogin, tls: &MailTls) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
+        let builder = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+            .port(port)
+            .pool_config(PoolConfig::new().max_size(1));
+        let builder = match self {
+            SmtpSecurity::StartTls => builder.tls(Tls::Required(tls.smtp_parameters(host)?)),
+            SmtpSecurity::Tls => builder.tls(Tls::Wrapper(tls.smtp_parameters(host)?)),
+            SmtpSecurity::None => builder,
+        };
+        let builder = match login {
+            Some((credentials, mechanisms)) => builder.credentials(credentials).authentication(mechanisms),
+            None => builder,
+        };
+        Ok(builder.build())
+    }
+}
+
+impl std::fmt::Display for SmtpSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SmtpSecurity::StartTls => "starttls",
+            SmtpSecurity::Tls => "tls",
+            SmtpSecurity::None => "none",
+        })
+    }
+}
+
+/// Retries unless `max_retries` is set
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// The wait before the first retry, doubling each time up to `MAX_RETRY_DELAY`
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(5);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(120);
+
+/// How the email outputs retry a send that failed for a reason that may
+/// pass, such as greylisting or a refused connection, from `max_retries`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmtpRetry {
+    max_retries: u32,
+    initial_delay: Duration,
+}
+
+impl SmtpRetry {
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        let max_retries = match config.get("max_retries") {
+            None => DEFAULT_MAX_RETRIES,
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("Invalid 'max_retries' in email config: '{}' is not a whole number", value))?,
+        };
+        Ok(SmtpRetry { max_retries, initial_delay: INITIAL_RETRY_DELAY })
+    }
+
+    /// The wait before retry `attempt`, counting from 0. It doubles each
+    /// time, with up to half of it random so outputs sharing a server don't
+    /// retry in step.
+    fn delay(&self, attempt: u32) -> Duration {
+        let full = self
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_RETRY_DELAY.max(self.initial_delay));
+        full / 2 + full.mul_f64(rand::random::<f64>() / 2.0)
+    }
+
+    /// Sends `email`, retrying transient failures. The last error is
+    /// returned once retries run out, and permanent ones straight away.
+    pub async fn send(&self, transport: &AsyncSmtpTransport<Tokio1Executor>, email: &Message) -> Result<Response, smtp::Error> {
+        let envelope = email.envelope();
+        let formatted = email.formatted();
+        let mut attempt = 0;
+        loop {
+            match transport.send_raw(envelope, &formatted).await {
+                Err(e) if attempt < self.max_retries && is_transient_error(&e) => {
+                    let delay = self.delay(attempt);
+                    attempt += 1;
+                    tracing::warn!("Sending email failed: {}. Retry {} of {} in {:?}", e, attempt, self.max_retries, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// A login from `MailAuth::smtp_login`
+type SmtpLogin = Option<(Credentials, Vec<Mechanism>)>;
+
+type SmtpTransport = AsyncSmtpTransport<Tokio1Executor>;
+
+/// The SMTP transport kept between sends and health checks, so its pooled
+/// connection is reused rather than a new one opened every time. The pool
+/// checks a kept connection with NOOP before using it and drops it if that
+/// fails.
+#[derive(Debug, Clone, Default)]
+pub struct SmtpConnection(Arc<tokio::sync::Mutex<Option<(SmtpLogin, SmtpTransport)>>>);
+
+impl SmtpConnection {
+    /// The kept transport if it logs in with `login`, otherwise a new one
+    /// from `build`, kept in its place. A refreshed XOAUTH2 token is a new
+    /// login, so it gets a new transport.
+    pub async fn transport(
+        &self,
+        login: SmtpLogin,
+        build: impl FnOnce(SmtpLogin) -> Result<SmtpTransport>,
+    ) -> Result<SmtpTransport> {
+        let mut kept = self.0.lock().await;
+        if let Some((kept_login, transport)) = kept.as_ref()
+            && *kept_login == login
+        {
+            return Ok(transport.clone());
+        }
+        let transport = build(login.clone())?;
+        *kept = Some((login, transport.clone()));
+        Ok(transport)
+    }
+
+    /// Drops the kept transport after a failure, so the next use connects afresh
+    pub async fn discard(&self) {
+        self.0.lock().await.take();
+    }
+}
+
+/// Whether a failed send may succeed later: a 4xx reply such as
+/// greylisting, or the connection failing or being dropped, which lettre
+/// reports as an incomplete response. 5xx replies, certificate problems
+/// and full mailboxes won't change by retrying.
+fn is_transient_error(error: &smtp::Error) -> bool {
+    !(error.is_permanent()
+        || error.is_client()
+        || error.is_tls()
+        || error.is_transport_shutdown()
+        || is_quota_error(error))
+}
+
+/// The subject unless `subject` is set
+pub const DEFAULT_SUBJECT: &str = "LastSignal Notification";
+
+/// A `subject` with `{timestamp}` and `{type}`, `checkin` or `last_signal`, filled in
+pub fn render_subject(template: &str, kind: MessageKind) -> String {
+    render_template_variables(template).replace("{type}", kind.as_str())
+}
+
+/// The display name in `key`. Names outside ASCII are RFC 2047-encoded
+/// when the header is written, but line breaks and other control characters
+/// would break the header, so they are rejected.
+pub fn display_name(config: &HashMap<String, String>, key: &str) -> Result<Option<String>> {
+    match config.get(key).map(|name| name.trim()) {
+        None | Some("") => Ok(None),
+        Some(name) if name.chars().any(char::is_control) => {
+            anyhow::bail!("Invalid '{}' in email config: names can't contain line breaks or control characters", key)
+        }
+        Some(name) => Ok(Some(name.to_string())),
+    }
+}
+
+/// `address` as a mailbox, with `name` replacing any display name it has
+pub fn named_mailbox(address: &str, name: Option<&str>, key: &str) -> Result<Mailbox> {
+    let address = address.trim();
+    let mailbox: Mailbox = address
+        .parse()
+        .with_context(|| format!("Invalid address '{}' in email '{}'", address, key))?;
+    Ok(match name {
+        Some(name) => Mailbox::new(Some(name.to_string()), mailbox.email),
+        None => mailbox,
+    })
+}
+
+/// The addresses in a comma-separated list, naming any that doesn't parse
+pub fn parse_mailboxes(list: &str, key: &str) -> Result<Vec<Mailbox>> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| named_mailbox(address, None, key))
+        .collect()
+}
 
 #[derive(Debug, Clone)]
 pub struct EmailOutput {
-    to: String,
-    from: String,
+    /// Everyone gets the one message, so a last signal to several people
+    /// needs a single set of SMTP credentials
+    to: Vec<Mailbox>,
+    cc: Vec<Mailbox>,
+    bcc: Vec<Mailbox>,
+    from: Mailbox,
     smtp_host: String,
     smtp_port: u16,
+    smtp_security: SmtpSecurity,
+    tls: MailTls,
     username: String,
-    password: String,
+    auth: MailAuth,
+    /// Also send plain messages as HTML, rendering them as markdown
+    html: bool,
+    subject: String,
+    kind: MessageKind,
+    retry: SmtpRetry,
+    smtp: SmtpConnection,
 }
 
 impl EmailOutput {
-    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
-        let to = config
-            .get("to")
-            .context("Missing 'to' field in email config")?
-            .clone();
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &[
+        "to", "to_name", "cc", "bcc", "from", "from_name", "smtp_host", "smtp_port", "smtp_security", "tls_ca_file", "tls_accept_invalid_certs", "username", "password",
+        "auth_method", "client_id", "client_secret", "html", "subject", "max_retries",
+    ];
+
+    pub fn new(config: &HashMap<String, String>, kind: MessageKind) -> Result<Self> {
+        let mut to = parse_mailboxes(config.get("to").context("Missing 'to' field in email config")?, "to")?;
+        if to.is_empty() {
+            anyhow::bail!("'to' in email config lists no addresses");
+        }
+        if let Some(to_name) = display_name(config, "to_name")? {
+            match to.as_mut_slice() {
+                [mailbox] => mailbox.name = Some(to_name),
+                _ => anyhow::bail!("'to_name' in email config needs a single 'to' address"),
+            }
+        }
+        let cc = parse_mailboxes(config.get("cc").map_or("", String::as_str), "cc")?;
+        let bcc = parse_mailboxes(config.get("bcc").map_or("", String::as_str), "bcc")?;
 
         let smtp_host = config
             .get("smtp_host")
@@ -41,50 +464,87 @@ impl EmailOutput {
             .context("Missing 'username' field in email config")?
             .clone();
 
-        let password = config
-            .get("password")
-            .context("Missing 'password' field in email config")?
-            .clone();
+        let from = named_mailbox(
+            config.get("from").unwrap_or(&username),
+            display_name(config, "from_name")?.as_deref(),
+            "from",
+        )?;
 
-        let from = config
-            .get("from")
-            .unwrap_or(&username)
-            .clone();
+        let smtp_security = SmtpSecurity::from_config(config)?;
+
+        let html = match config.get("html").map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => anyhow::bail!("Invalid 'html' in email config: '{}' is not true or false", other),
+        };
 
         Ok(EmailOutput {
             to,
+            cc,
+            bcc,
             from,
             smtp_host,
             smtp_port,
+            smtp_security,
+            tls: MailTls::from_config(config)?,
             username,
-            password,
+            auth: MailAuth::from_config(config)?,
+            html,
+            subject: config.get("subject").map_or(DEFAULT_SUBJECT, String::as_str).to_string(),
+            kind,
+            retry: SmtpRetry::from_config(config)?,
+            smtp: SmtpConnection::default(),
         })
     }
 
+    /// Keeps XOAUTH2 tokens in `data_directory`
+    pub fn with_data_directory(mut self, data_directory: Option<&Path>) -> Self {
+        self.auth = self.auth.with_data_directory(data_directory);
+        self
+    }
+
     async fn create_transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
-        let creds = Credentials::new(self.username.clone(), self.password.clone());
+        let login = self.auth.smtp_login(&self.username, self.smtp_security).await?;
+        self.smtp
+            .transport(login, |login| self.smtp_security.transport(&self.smtp_host, self.smtp_port, login, &self.tls))
+            .await
+    }
 
-        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.smtp_host)
-            .context("Failed to create SMTP transport")?
-            .port(self.smtp_port)
-            .credentials(creds)
-            .build();
+    /// Builds the email for a message. Markdown messages are sent as
+    /// multipart/alternative with a plain text part and a rendered HTML part.
+    /// With `html`, plain messages are too, keeping the text as it is.
+    fn build_email(&self, message: &str, format: MessageFormat) -> Result<Message> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(render_subject(&self.subject, self.kind));
+        for mailbox in &self.to {
+            builder = builder.to(mailbox.clone());
+        }
+        for mailbox in &self.cc {
+            builder = builder.cc(mailbox.clone());
+        }
+        for mailbox in &self.bcc {
+            builder = builder.bcc(mailbox.clone());
+        }
 
-        Ok(transport)
-    }
-}
+        let email = match format {
+            MessageFormat::Plain if self.html => builder.multipart(MultiPart::alternative_plain_html(
+                message.to_string(),
+                formatting::to_html(message, MessageFormat::Markdown),
+            )),
+            MessageFormat::Plain => builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.to_string()),
+            MessageFormat::Markdown => builder.multipart(MultiPart::alternative_plain_html(
+                formatting::to_plain_text(message, format),
+                formatting::to_html(message, format),
+            )),
+        };
 
-#[async_trait]
-impl Output for EmailOutput {
-    async fn send_message(&self, message: &str) -> Result<OutputResult> {
-        let email = Message::builder()
-            .from(self.from.parse().context("Invalid from email address")?)
-            .to(self.to.parse().context("Invalid to email address")?)
-            .subject("LastSignal Notification")
-            .header(ContentType::TEXT_PLAIN)
-            .body(message.to_string())
-            .context("Failed to build email message")?;
+        email.context("Failed to build email message")
+    }
 
+    async fn deliver(&self, email: Message) -> Result<OutputResult> {
         let transport = match self.create_transport().await {
             Ok(t) => t,
             Err(e) => {
@@ -92,19 +552,55 @@ impl Output for EmailOutput {
             }
         };
 
-        match transport.send(email).await {
+        let result = self.retry.send(&transport, &email).await;
+        if result.is_err() {
+            self.smtp.discard().await;
+        }
+        match result {
             Ok(_) => Ok(OutputResult::Success),
+            Err(e) if is_quota_error(&e) => Ok(OutputResult::quota_exceeded(e)),
             Err(e) => Ok(OutputResult::Failed(format!("Failed to send email: {}", e))),
         }
     }
+}
+
+/// Whether the SMTP server refused a message because a mailbox is full,
+/// by reply code 552 or enhanced status code x.2.2
+pub fn is_quota_error(error: &lettre::transport::smtp::Error) -> bool {
+    is_quota_response(error.status().map(|code| code.to_string()).as_deref(), &error.to_string())
+}
 
+fn is_quota_response(code: Option<&str>, text: &str) -> bool {
+    let text = text.to_ascii_lowercase();
+    code == Some("552")
+        || ["4.2.2", "5.2.2", "over quota", "quota exceeded", "mailbox full", "mailbox is full"]
+            .iter()
+            .any(|marker| text.contains(marker))
+}
+
+#[async_trait]
+impl Output for EmailOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        self.send_formatted(message, MessageFormat::Plain).await
+    }
+
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        let email = self.build_email(message, format)?;
+        self.deliver(email).await
+    }
+
+    /// Sends NOOP on the kept connection, or connects if there isn't one
     async fn health_check(&self) -> Result<bool> {
         match self.create_transport().await {
             Ok(transport) => {
                 match transport.test_connection().await {
                     Ok(_) => Ok(true),
                     Err(e) => {
-                        tracing::debug!("Email health check failed: {}", e);
+                        tracing::debug!(
+                            "Email health check failed for {}:{} (smtp_security = {}): {}",
+                            self.smtp_host, self.smtp_port, self.smtp_security, e
+                        );
+                        self.smtp.discard().await;
                         Ok(false)
                     }
                 }
@@ -134,12 +630,12 @@ mod tests {
         config.insert("username".to_string(), "user@example.com".to_string());
         config.insert("password".to_string(), "password".to_string());
 
-        let output = EmailOutput::new(&config).unwrap();
-        assert_eq!(output.to, "test@example.com");
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        assert_eq!(output.to, vec!["test@example.com".parse::<Mailbox>().unwrap()]);
         assert_eq!(output.smtp_host, "smtp.example.com");
         assert_eq!(output.smtp_port, 587);
         assert_eq!(output.username, "user@example.com");
-        assert_eq!(output.from, "user@example.com"); // defaults to username
+        assert_eq!(output.from.to_string(), "user@example.com"); // defaults to username
     }
 
     #[test]
@@ -152,14 +648,407 @@ mod tests {
         config.insert("username".to_string(), "user@example.com".to_string());
         config.insert("password".to_string(), "password".to_string());
 
-        let output = EmailOutput::new(&config).unwrap();
-        assert_eq!(output.from, "from@example.com");
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        assert_eq!(output.from.to_string(), "from@example.com");
+    }
+
+    const TEST_CA: &str = "-----BEGIN CERTIFICATE-----
+MIIBkjCCATegAwIBAgIUWHySFTI0tccwbvAtIty10svjiv0wCgYIKoZIzj0EAwIw
+HTEbMBkGA1UEAwwSTGFzdFNpZ25hbCBUZXN0IENBMCAXDTI2MTAxNTA2NTYwMVoY
+DzIxMjYwOTIxMDY1NjAxWjAdMRswGQYDVQQDDBJMYXN0U2lnbmFsIFRlc3QgQ0Ew
+WTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAARJfVElGZMYq4eLGaI5o29Qt6N0G0Kv
+We6LlW5oRacGxxe9V+gpNrLXQV4rZm9XBimQ4a7T5G7B6QCPTJgFG1BTo1MwUTAd
+BgNVHQ4EFgQU4GVzpEVc/rblJLQHVlXWunUnaZQwHwYDVR0jBBgwFoAU4GVzpEVc
+/rblJLQHVlXWunUnaZQwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNJADBG
+AiEA5vSdv22wjeQmWJTS1/2Erb7fU2vQpx1KXkfn8w5QJQECIQDdNRCforOfD3aT
+koxlpqkb+Nd1ydVpp9/s3/5N03ldeg==
+-----END CERTIFICATE-----
+";
+
+    #[tokio::test]
+    async fn test_mail_tls() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let chain = temp_dir.path().join("chain.pem");
+        std::fs::write(&chain, format!("# Home CA\n{}{}", TEST_CA, TEST_CA)).unwrap();
+
+        let mut config = HashMap::new();
+        assert_eq!(MailTls::from_config(&config).unwrap(), MailTls::default());
+
+        config.insert("tls_ca_file".to_string(), chain.display().to_string());
+        let tls = MailTls::from_config(&config).unwrap();
+        assert_eq!(tls.ca_certificates, vec![TEST_CA.to_string(), TEST_CA.to_string()]);
+        assert!(!tls.accept_invalid_certs);
+        assert!(tls.imap_connector().is_ok());
+        assert!(SmtpSecurity::Tls.transport("mail.home.arpa", 465, None, &tls).is_ok());
+
+        let not_pem = temp_dir.path().join("ca.der");
+        std::fs::write(&not_pem, b"\x30\x82\x01\x92").unwrap();
+        config.insert("tls_ca_file".to_string(), not_pem.display().to_string());
+        assert!(MailTls::from_config(&config).is_err());
+
+        let corrupt = temp_dir.path().join("corrupt.pem");
+        std::fs::write(&corrupt, "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydA==\n-----END CERTIFICATE-----\n").unwrap();
+        config.insert("tls_ca_file".to_string(), corrupt.display().to_string());
+        assert!(MailTls::from_config(&config).is_err());
+
+        config.insert("tls_ca_file".to_string(), temp_dir.path().join("missing.pem").display().to_string());
+        assert!(MailTls::from_config(&config).is_err());
+
+        config.remove("tls_ca_file");
+        config.insert("tls_accept_invalid_certs".to_string(), "true".to_string());
+        assert!(MailTls::from_config(&config).unwrap().accept_invalid_certs);
+        config.insert("tls_accept_invalid_certs".to_string(), "yes".to_string());
+        assert!(MailTls::from_config(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_smtp_security() {
+        let mut config = HashMap::new();
+        assert_eq!(SmtpSecurity::from_config(&config).unwrap(), SmtpSecurity::StartTls);
+        for (value, security) in [("starttls", SmtpSecurity::StartTls), ("tls", SmtpSecurity::Tls), ("none", SmtpSecurity::None)] {
+            config.insert("smtp_security".to_string(), value.to_string());
+            assert_eq!(SmtpSecurity::from_config(&config).unwrap(), security);
+            assert_eq!(security.to_string(), value);
+            assert!(security.transport("smtp.example.com", 465, None, &MailTls::default()).is_ok());
+        }
+        config.insert("smtp_security".to_string(), "ssl".to_string());
+        assert!(SmtpSecurity::from_config(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mail_auth() {
+        let mut config = test_config();
+        let (_, mechanisms) = MailAuth::from_config(&config).unwrap().smtp_login("user@example.com", SmtpSecurity::StartTls).await.unwrap().unwrap();
+        assert_eq!(mechanisms, vec![Mechanism::Plain, Mechanism::Login]);
+
+        config.insert("password".to_string(), String::new());
+        let relay = MailAuth::from_config(&config).unwrap();
+        assert!(relay.smtp_login("user@example.com", SmtpSecurity::None).await.unwrap().is_none());
+        assert!(relay.smtp_login("user@example.com", SmtpSecurity::StartTls).await.unwrap().is_some());
+
+        // XOAUTH2 needs the client credentials rather than a password
+        config.remove("password");
+        config.insert("auth_method".to_string(), "xoauth2".to_string());
+        assert!(MailAuth::from_config(&config).is_err());
+        config.insert("client_id".to_string(), "1234-abcd.apps.googleusercontent.com".to_string());
+        config.insert("client_secret".to_string(), "GOCSPX-secret".to_string());
+        assert!(EmailOutput::new(&config, MessageKind::LastSignal).unwrap().auth.is_xoauth2());
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let tokens = crate::oauth::OAuthTokens {
+            access_token: "ya29.token".to_string(),
+            refresh_token: "1//refresh".to_string(),
+            expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
+            token_type: "Bearer".to_string(),
+        };
+        let auth = MailAuth::from_config(&config).unwrap();
+        assert!(auth.secret().await.is_err());
+        let auth = auth.with_data_directory(Some(temp_dir.path()));
+        let error = auth.secret().await.unwrap_err().to_string();
+        assert!(error.contains("gmail-auth"), "{}", error);
+
+        std::fs::write(temp_dir.path().join("gmail_tokens.json"), serde_json::to_string(&tokens).unwrap()).unwrap();
+        let (_, mechanisms) = auth.smtp_login("user@gmail.com", SmtpSecurity::StartTls).await.unwrap().unwrap();
+        assert_eq!(mechanisms, vec![Mechanism::Xoauth2]);
+        assert_eq!(auth.secret().await.unwrap(), "ya29.token");
+
+        config.insert("auth_method".to_string(), "oauth".to_string());
+        assert!(MailAuth::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_several_recipients() {
+        let mut config = test_config();
+        config.insert("to".to_string(), "alice@example.com, Bob <bob@example.org>".to_string());
+        config.insert("cc".to_string(), "carol@example.com".to_string());
+        config.insert("bcc".to_string(), "archive@example.com".to_string());
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        assert_eq!(output.to.len(), 2);
+
+        let email = output.build_email("Call me", MessageFormat::Plain).unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+        assert!(formatted.contains("To: alice@example.com, Bob <bob@example.org>"), "{}", formatted);
+        assert!(formatted.contains("Cc: carol@example.com"));
+        // Bcc is only in the envelope
+        assert!(!formatted.contains("archive@example.com"));
+        let envelope: Vec<String> = email.envelope().to().iter().map(ToString::to_string).collect();
+        assert_eq!(envelope, ["alice@example.com", "bob@example.org", "carol@example.com", "archive@example.com"]);
+    }
+
+    #[test]
+    fn test_malformed_address_is_named() {
+        let mut config = test_config();
+        config.insert("to".to_string(), "alice@example.com, bob.example.org".to_string());
+        let error = EmailOutput::new(&config, MessageKind::LastSignal).unwrap_err().to_string();
+        assert_eq!(error, "Invalid address 'bob.example.org' in email 'to'");
+
+        config.insert("to".to_string(), "alice@example.com".to_string());
+        config.insert("bcc".to_string(), "archive@@example.com".to_string());
+        let error = EmailOutput::new(&config, MessageKind::LastSignal).unwrap_err().to_string();
+        assert!(error.contains("'archive@@example.com' in email 'bcc'"), "{}", error);
+
+        config.remove("bcc");
+        config.insert("to".to_string(), " , ".to_string());
+        assert!(EmailOutput::new(&config, MessageKind::LastSignal).is_err());
+    }
+
+    #[test]
+    fn test_display_names() {
+        let mut config = test_config();
+        config.insert("from".to_string(), "joe.lastsignal@gmail.com".to_string());
+        config.insert("from_name".to_string(), "LastSignal for Joe".to_string());
+        config.insert("to_name".to_string(), "Jörg Müller".to_string());
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        let formatted = String::from_utf8(output.build_email("Call me", MessageFormat::Plain).unwrap().formatted()).unwrap();
+        assert!(formatted.contains("From: \"LastSignal for Joe\" <joe.lastsignal@gmail.com>\r\n"), "{}", formatted);
+        // Non-ASCII names are RFC 2047 encoded
+        assert!(formatted.contains("To: =?utf-8?b?SsO2cmcgTcO8bGxlcg==?= <test@example.com>\r\n"), "{}", formatted);
+
+        config.insert("from_name".to_string(), "Joe\r\nBcc: everyone@example.com".to_string());
+        assert!(EmailOutput::new(&config, MessageKind::LastSignal).is_err());
+
+        config.remove("from_name");
+        config.insert("to".to_string(), "a@example.com, b@example.com".to_string());
+        let error = EmailOutput::new(&config, MessageKind::LastSignal).unwrap_err().to_string();
+        assert!(error.contains("single 'to' address"), "{}", error);
+    }
+
+    #[test]
+    fn test_subject_placeholders() {
+        let output = EmailOutput::new(&test_config(), MessageKind::LastSignal).unwrap();
+        let formatted = String::from_utf8(output.build_email("Call me", MessageFormat::Plain).unwrap().formatted()).unwrap();
+        assert!(formatted.contains("Subject: LastSignal Notification\r\n"));
+
+        let mut config = test_config();
+        config.insert("subject".to_string(), "URGENT: please check on Joe ({type}, {timestamp})".to_string());
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        let formatted = String::from_utf8(output.build_email("Call me", MessageFormat::Plain).unwrap().formatted()).unwrap();
+        assert!(formatted.contains("Subject: URGENT: please check on Joe (last_signal, 20"), "{}", formatted);
+        assert!(!formatted.contains("{timestamp}"));
+
+        assert_eq!(render_subject("Check in ({type})", MessageKind::Checkin), "Check in (checkin)");
+    }
+
+    #[test]
+    fn test_build_email_plain() {
+        let output = EmailOutput::new(&test_config(), MessageKind::LastSignal).unwrap();
+        let email = output.build_email("Call **me**", MessageFormat::Plain).unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+
+        assert!(formatted.contains("Content-Type: text/plain"));
+        assert!(!formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("Call **me**"));
+    }
+
+    #[test]
+    fn test_build_email_markdown_is_multipart() {
+        let output = EmailOutput::new(&test_config(), MessageKind::LastSignal).unwrap();
+        let email = output.build_email("Call **me**", MessageFormat::Markdown).unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("Content-Type: text/plain"));
+        assert!(formatted.contains("Content-Type: text/html"));
+        assert!(formatted.contains("<strong>me</strong>"));
+    }
+
+    #[test]
+    fn test_html_option_adds_rendered_part() {
+        let mut config = test_config();
+        config.insert("html".to_string(), "true".to_string());
+        let output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        let email = output.build_email("# Last Signal\n\nCall **555-0100**", MessageFormat::Plain).unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+
+        assert!(formatted.contains("Content-Type: multipart/alternative"));
+        let plain = formatted.find("Content-Type: text/plain").unwrap();
+        let html = formatted.find("Content-Type: text/html").unwrap();
+        assert!(plain < html, "the plain text part should come first");
+        // The plain part keeps the text as written
+        assert!(formatted[plain..html].contains("Call **555-0100**"));
+        assert!(formatted[html..].contains("<h1>Last Signal</h1>"));
+        assert!(formatted[html..].contains("<strong>555-0100</strong>"));
+
+        config.insert("html".to_string(), "yes".to_string());
+        assert!(EmailOutput::new(&config, MessageKind::LastSignal).is_err());
+    }
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), "test@example.com".to_string());
+        config.insert("smtp_host".to_string(), "smtp.example.com".to_string());
+        config.insert("smtp_port".to_string(), "587".to_string());
+        config.insert("username".to_string(), "user@example.com".to_string());
+        config.insert("password".to_string(), "password".to_string());
+        config
     }
 
     #[test]
     fn test_email_output_missing_config() {
         let config = HashMap::new();
-        let result = EmailOutput::new(&config);
+        let result = EmailOutput::new(&config, MessageKind::LastSignal);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_quota_responses_are_recognised() {
+        assert!(is_quota_response(Some("552"), "Requested mail action aborted: exceeded storage allocation"));
+        assert!(is_quota_response(Some("452"), "4.2.2 The email account that you tried to reach is over quota"));
+        assert!(is_quota_response(Some("550"), "5.2.2 Mailbox full"));
+        assert!(!is_quota_response(Some("550"), "5.1.1 User unknown"));
+        assert!(!is_quota_response(None, "Connection refused"));
+    }
+
+    /// A plain SMTP server on localhost that answers MAIL FROM on each
+    /// connection with the next of `mail_replies`, repeating the last one.
+    /// An empty reply hangs up before the greeting. Returns its port and a
+    /// count of connections.
+    async fn mock_smtp(mail_replies: &'static [&'static str]) -> (u16, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let mail_reply = mail_replies[index.min(mail_replies.len() - 1)];
+                if mail_reply.is_empty() {
+                    continue;
+                }
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut lines = BufReader::new(reader).lines();
+                    writer.write_all(b"220 mock ESMTP\r\n").await.unwrap();
+                    let mut in_data = false;
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        let reply = match line.to_ascii_uppercase() {
+                            _ if in_data && line == "." => {
+                                in_data = false;
+                                "250 2.0.0 Queued"
+                            }
+                            _ if in_data => continue,
+                            command if command.starts_with("MAIL FROM") => mail_reply,
+                            command if command.starts_with("DATA") => {
+                                in_data = true;
+                                "354 Go ahead"
+                            }
+                            command if command.starts_with("QUIT") => {
+                                writer.write_all(b"221 Bye\r\n").await.ok();
+                                break;
+                            }
+                            _ => "250 OK",
+                        };
+                        if writer.write_all(format!("{}\r\n", reply).as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        (port, connections)
+    }
+
+    /// An output sending through `mock_smtp` on `port`, retrying quickly
+    fn mock_output(port: u16, max_retries: u32) -> EmailOutput {
+        let mut config = test_config();
+        config.insert("smtp_host".to_string(), "127.0.0.1".to_string());
+        config.insert("smtp_port".to_string(), port.to_string());
+        config.insert("smtp_security".to_string(), "none".to_string());
+        config.insert("password".to_string(), String::new());
+        let mut output = EmailOutput::new(&config, MessageKind::LastSignal).unwrap();
+        output.retry = SmtpRetry { max_retries, initial_delay: Duration::from_millis(10) };
+        output
+    }
+
+    #[test]
+    fn test_smtp_retry_config() {
+        let mut config = test_config();
+        assert_eq!(SmtpRetry::from_config(&config).unwrap().max_retries, DEFAULT_MAX_RETRIES);
+        config.insert("max_retries".to_string(), "0".to_string());
+        assert_eq!(SmtpRetry::from_config(&config).unwrap().max_retries, 0);
+        config.insert("max_retries".to_string(), "-1".to_string());
+        assert!(EmailOutput::new(&config, MessageKind::LastSignal).is_err());
+
+        // Each wait is between half and all of a doubling delay, up to the cap
+        let retry = SmtpRetry { max_retries: 10, initial_delay: INITIAL_RETRY_DELAY };
+        for (attempt, full) in [(0, 5), (1, 10), (2, 20), (5, 120), (31, 120)] {
+            let delay = retry.delay(attempt);
+            let full = Duration::from_secs(full);
+            assert!(delay >= full / 2 && delay <= full, "attempt {}: {:?}", attempt, delay);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_greylisting_is_retried() {
+        let (port, connections) = mock_smtp(&["451 4.7.1 Greylisted, try again later", "250 2.1.0 OK"]).await;
+        let result = mock_output(port, 3).send_message("Are you OK?").await.unwrap();
+        assert!(matches!(result, OutputResult::Success), "{:?}", result);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retries_run_out() {
+        let (port, connections) = mock_smtp(&["421 4.3.2 Service not available"]).await;
+        let result = mock_output(port, 2).send_message("Are you OK?").await.unwrap();
+        assert!(matches!(&result, OutputResult::Failed(reason) if reason.contains("421")), "{:?}", result);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let (port, connections) = mock_smtp(&["550 5.7.1 Relaying denied"]).await;
+        let result = mock_output(port, 3).send_message("Are you OK?").await.unwrap();
+        assert!(matches!(&result, OutputResult::Failed(reason) if reason.contains("550")), "{:?}", result);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // A full mailbox is reported as such rather than waited out
+        let (port, connections) = mock_smtp(&["452 4.2.2 Mailbox full"]).await;
+        let result = mock_output(port, 3).send_message("Are you OK?").await.unwrap();
+        assert!(result.is_persistent_failure(), "{:?}", result);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_connection_is_retried() {
+        let (port, connections) = mock_smtp(&["", "250 2.1.0 OK"]).await;
+        let result = mock_output(port, 1).send_message("Are you OK?").await.unwrap();
+        assert!(matches!(result, OutputResult::Success), "{:?}", result);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // Nothing listens on a port that was just freed
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let result = mock_output(port, 1).send_message("Are you OK?").await.unwrap();
+        assert!(matches!(result, OutputResult::Failed(_)), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_connection_is_reused() {
+        let (port, connections) = mock_smtp(&["250 2.1.0 OK"]).await;
+        let output = mock_output(port, 0);
+        for _ in 0..2 {
+            let result = output.send_message("Are you OK?").await.unwrap();
+            assert!(matches!(result, OutputResult::Success), "{:?}", result);
+            // The connection goes back to the pool in the background
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_connection_is_discarded() {
+        let (port, connections) = mock_smtp(&["", "250 2.1.0 OK"]).await;
+        let output = mock_output(port, 0);
+        let result = output.send_message("Are you OK?").await.unwrap();
+        assert!(matches!(result, OutputResult::Failed(_)), "{:?}", result);
+        assert!(output.smtp.0.lock().await.is_none());
+
+        let result = output.send_message("Are you OK?").await.unwrap();
+        assert!(matches!(result, OutputResult::Success), "{:?}", result);
+        assert!(output.smtp.0.lock().await.is_some());
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+}
@@ -1,39 +1,550 @@
-use super::bidirectional::{BidirectionalOutput, CheckinResponse};
+use super::bidirectional::{BidirectionalOutput, CheckinResponse, MailboxUsage};
+use super::email::{display_name, is_quota_error, named_mailbox, render_subject, MailAuth, MailTls, SmtpConnection, SmtpRetry, SmtpSecurity};
+use super::MessageKind;
 use super::{Output, OutputResult};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use lettre::{
-    message::header::ContentType,
-    transport::smtp::authentication::Credentials,
-    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{header::ContentType, Mailbox},
+    AsyncSmtpTransport, Message, Tokio1Executor,
 };
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
 
 // For IMAP email checking
+use async_imap::imap_proto::types::Address;
 use async_imap::{Client, Session};
-use async_native_tls::{TlsConnector, TlsStream};
+use async_native_tls::TlsStream;
 use async_std::net::TcpStream;
 
+/// A mailbox polled for check-in replies
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImapAccount {
+    pub host: String,
+    #[serde(default = "default_imap_port")]
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    #[serde(default = "default_imap_folder")]
+    pub folder: String,
+    #[serde(default)]
+    pub security: ImapSecurity,
+}
+
+/// How the IMAP connection is secured, from `imap_security`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImapSecurity {
+    /// TLS from the start, usually on port 993
+    #[default]
+    Tls,
+    /// Upgrade a plain connection with STARTTLS, usually on port 143
+    StartTls,
+}
+
+impl ImapSecurity {
+    fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        match config.get("imap_security").map(String::as_str) {
+            None | Some("tls") => Ok(ImapSecurity::Tls),
+            Some("starttls") => Ok(ImapSecurity::StartTls),
+            Some(other) => anyhow::bail!("Invalid 'imap_security' in email config: '{}' is not tls or starttls", other),
+        }
+    }
+}
+
+impl ImapAccount {
+    /// Which kept session is this account's
+    fn session_key(&self) -> String {
+        format!("{}@{}:{}", self.username, self.host, self.port)
+    }
+}
+
+fn default_imap_port() -> u16 {
+    993
+}
+
+fn default_imap_folder() -> String {
+    "INBOX".to_string()
+}
+
 #[derive(Debug, Clone)]
 pub struct BidirectionalEmailOutput {
     // SMTP fields (for sending)
-    to: String,
-    from: String,
+    to: Mailbox,
+    from: Mailbox,
     smtp_host: String,
     smtp_port: u16,
+    smtp_security: SmtpSecurity,
+    tls: MailTls,
     username: String,
-    password: String,
+    auth: MailAuth,
+    retry: SmtpRetry,
+    smtp: SmtpConnection,
     
-    // IMAP fields (for receiving)
-    imap_host: String,
-    imap_port: u16,
+    // IMAP accounts (for receiving), the primary one first
+    imap_accounts: Vec<ImapAccount>,
     
-    // Subject prefix to look for in replies
-    subject_prefix: String,
+    // Subject of requests, with placeholders
+    subject: String,
+
+    // What replies have in their subject, from `subject`
+    reply_subject: String,
+
+    // Lowercased addresses whose replies count, `to` by default
+    allowed_senders: Vec<String>,
+
+    // Where the Message-IDs of sent requests are kept, to match replies by thread
+    sent_ids_file: Option<PathBuf>,
+
+    // Watches the primary mailbox with IMAP IDLE, when `imap_idle` is on
+    idle: Option<Arc<IdleWatcher>>,
+
+    // What to do with replies once they have been processed as check-ins
+    processed_action: ProcessedAction,
+
+    // Replies found but not yet processed, for `processed_action`
+    pending_replies: Arc<Mutex<Vec<PendingReply>>>,
+
+    // IMAP sessions kept between checks, by account
+    imap_sessions: Arc<KeptConnections<ImapSession>>,
+}
+
+/// What happens to a reply once it has counted as a check-in, so it isn't
+/// fetched again on every check
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProcessedAction {
+    None,
+    MarkRead,
+    Move(String),
+}
+
+/// One IMAP command applying a `ProcessedAction` to the replies' UIDs
+#[derive(Debug, PartialEq, Eq)]
+enum ImapStep {
+    MarkSeen,
+    Move(String),
+    Copy(String),
+    MarkDeleted,
+    /// Expunges just the replies, with UIDPLUS
+    UidExpunge,
+    /// Expunges every message marked deleted in the mailbox
+    Expunge,
+}
+
+impl ProcessedAction {
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "none" => Ok(ProcessedAction::None),
+            "mark_read" => Ok(ProcessedAction::MarkRead),
+            other => match other.strip_prefix("move:").map(str::trim) {
+                Some(folder) if !folder.is_empty() => Ok(ProcessedAction::Move(folder.to_string())),
+                _ => anyhow::bail!(
+                    "Invalid 'processed_action' in email config: '{}' is not none, mark_read or move:<folder>",
+                    other
+                ),
+            },
+        }
+    }
+
+    /// The commands for this action on a server with or without the MOVE
+    /// and UIDPLUS extensions
+    fn steps(&self, has_move: bool, has_uidplus: bool) -> Vec<ImapStep> {
+        match self {
+            ProcessedAction::None => vec![],
+            ProcessedAction::MarkRead => vec![ImapStep::MarkSeen],
+            ProcessedAction::Move(folder) if has_move => vec![ImapStep::Move(folder.clone())],
+            ProcessedAction::Move(folder) => vec![
+                ImapStep::Copy(folder.clone()),
+                ImapStep::MarkDeleted,
+                if has_uidplus { ImapStep::UidExpunge } else { ImapStep::Expunge },
+            ],
+        }
+    }
+}
+
+/// A reply found in `account`, awaiting `processed_action`
+#[derive(Debug, Clone, PartialEq)]
+struct PendingReply {
+    account: ImapAccount,
+    uid: u32,
+    timestamp: DateTime<Utc>,
+}
+
+/// Adds a reply unless it is already pending
+fn track_pending(pending: &mut Vec<PendingReply>, reply: PendingReply) {
+    if !pending.iter().any(|existing| existing.account == reply.account && existing.uid == reply.uid) {
+        pending.push(reply);
+    }
+}
+
+/// Removes the replies at or before `until`, grouped by account
+fn take_processed(pending: &mut Vec<PendingReply>, until: DateTime<Utc>) -> Vec<(ImapAccount, Vec<u32>)> {
+    let mut grouped: Vec<(ImapAccount, Vec<u32>)> = Vec::new();
+    pending.retain(|reply| {
+        if reply.timestamp > until {
+            return true;
+        }
+        match grouped.iter_mut().find(|(account, _)| *account == reply.account) {
+            Some((_, uids)) => uids.push(reply.uid),
+            None => grouped.push((reply.account.clone(), vec![reply.uid])),
+        }
+        false
+    });
+    grouped
+}
+
+/// Answers the server's XOAUTH2 challenge with the user and access token.
+/// A failed login is reported as a second challenge holding a JSON error,
+/// which is answered with an empty response.
+struct Xoauth2Authenticator {
+    response: String,
+}
+
+impl Xoauth2Authenticator {
+    fn new(username: &str, access_token: &str) -> Self {
+        Xoauth2Authenticator { response: format!("user={}\x01auth=Bearer {}\x01\x01", username, access_token) }
+    }
+}
+
+impl async_imap::Authenticator for Xoauth2Authenticator {
+    type Response = String;
+
+    fn process(&mut self, _challenge: &[u8]) -> String {
+        std::mem::take(&mut self.response)
+    }
+}
+
+/// RFC 2177 lets servers drop an IDLE after 30 minutes of inactivity, so it
+/// is re-issued well before then
+const IDLE_REFRESH: std::time::Duration = std::time::Duration::from_secs(25 * 60);
+
+/// Backoff between IDLE reconnection attempts
+const IDLE_RECONNECT_MIN: std::time::Duration = std::time::Duration::from_secs(5);
+const IDLE_RECONNECT_MAX: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// How far back to search when IDLE reports new mail. The search is by
+/// date, so this only needs to cover clock skew in the reply's Date header.
+const IDLE_LOOKBACK_HOURS: i64 = 24;
+
+/// How far back to look for replies when there is no previous check-in to
+/// search from
+const REPLY_LOOKBACK_DAYS: i64 = 30;
+
+/// What the IDLE task shares with `check_for_responses`
+#[derive(Debug, Default)]
+struct IdleState {
+    /// Replies noticed by IDLE and not yet returned
+    queue: Mutex<Vec<CheckinResponse>>,
+    /// When the current IDLE session started watching, `None` while disconnected
+    watching_since: Mutex<Option<DateTime<Utc>>>,
+    /// When the primary mailbox was last polled
+    last_poll: Mutex<Option<DateTime<Utc>>>,
+    /// Wakes the daemon when replies are queued
+    replies_queued: Arc<Notify>,
+}
+
+impl IdleState {
+    fn queue_replies(&self, replies: Vec<CheckinResponse>) {
+        if replies.is_empty() {
+            return;
+        }
+        self.queue.lock().unwrap_or_else(|e| e.into_inner()).extend(replies);
+        self.replies_queued.notify_one();
+    }
+
+    /// Queued replies after `since`, emptying the queue
+    fn take_queued(&self, since: Option<DateTime<Utc>>) -> Vec<CheckinResponse> {
+        let queued = std::mem::take(&mut *self.queue.lock().unwrap_or_else(|e| e.into_inner()));
+        queued
+            .into_iter()
+            .filter(|response| match (response, since) {
+                (CheckinResponse::Found { timestamp, .. }, Some(since)) => *timestamp > since,
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Whether IDLE covers the primary mailbox, so it needn't be polled. It
+    /// is polled once after IDLE starts, to catch replies from before then.
+    fn primary_watched(&self) -> bool {
+        let watching_since = *self.watching_since.lock().unwrap_or_else(|e| e.into_inner());
+        let last_poll = *self.last_poll.lock().unwrap_or_else(|e| e.into_inner());
+        matches!((watching_since, last_poll), (Some(watching), Some(polled)) if polled >= watching)
+    }
+
+    fn set_watching_since(&self, since: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        std::mem::replace(&mut *self.watching_since.lock().unwrap_or_else(|e| e.into_inner()), since)
+    }
+}
+
+/// The IDLE task, started on the first check for responses so commands like
+/// `status` don't open a session. Dropping it stops the task.
+#[derive(Debug, Default)]
+struct IdleWatcher {
+    state: Arc<IdleState>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Drop for IdleWatcher {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.get_mut().unwrap_or_else(|e| e.into_inner()).take() {
+            task.abort();
+        }
+    }
+}
+
+type ImapSession = Session<TlsStream<TcpStream>>;
+
+/// Connections kept between operations, one per account, so a short check
+/// interval doesn't open a new TLS session every time. A connection is
+/// taken out while in use and only kept again once the operation worked, so
+/// one that failed is dropped and the next operation connects afresh.
+#[derive(Debug)]
+struct KeptConnections<C>(tokio::sync::Mutex<HashMap<String, C>>);
+
+impl<C> Default for KeptConnections<C> {
+    fn default() -> Self {
+        KeptConnections(tokio::sync::Mutex::new(HashMap::new()))
+    }
+}
+
+impl<C> KeptConnections<C> {
+    /// The connection kept for `key`, if `check` hands it back as still working
+    async fn take_working<F, Fut>(&self, key: &str, check: F) -> Option<C>
+    where
+        F: FnOnce(C) -> Fut,
+        Fut: std::future::Future<Output = Option<C>>,
+    {
+        let kept = self.0.lock().await.remove(key)?;
+        check(kept).await
+    }
+
+    /// Keeps `connection` for the next operation on `key`
+    async fn keep(&self, key: &str, connection: C) {
+        self.0.lock().await.insert(key.to_string(), connection);
+    }
+}
+
+/// How many sent Message-IDs are searched for, newest first, so the IMAP
+/// search stays short while check-ins go unanswered
+const MAX_TRACKED_MESSAGE_IDS: usize = 50;
+
+/// A check-in request we sent, whose replies reference its Message-ID
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+struct SentMessage {
+    message_id: String,
+    sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct SentMessages {
+    sent: Vec<SentMessage>,
+}
+
+/// One file per recipient, e.g. `email_threads_me_example.com.json`
+fn sent_ids_file_name(to: &str) -> String {
+    let name: String = crate::acknowledgement::sender_address(to)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("email_threads_{}.json", name)
+}
+
+/// A new `<random@domain>` Message-ID, using the domain of `from`
+fn generate_message_id(from: &str) -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let address = crate::acknowledgement::sender_address(from);
+    let domain = address.rsplit_once('@').map_or("lastsignal.invalid", |(_, domain)| domain);
+    format!("<{}.lastsignal@{}>", hex::encode(bytes), domain)
+}
+
+/// A header field's value from a raw header block, with folded lines joined
+fn header_field(header: &str, name: &str) -> Option<String> {
+    let mut value: Option<String> = None;
+    for line in header.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some(value) = value.as_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if value.is_some() {
+            break;
+        }
+        if let Some((field, rest)) = line.split_once(':')
+            && field.trim().eq_ignore_ascii_case(name)
+        {
+            value = Some(rest.trim().to_string());
+        }
+    }
+    value
+}
+
+/// Message-IDs a message replies to or is threaded under, from its
+/// `In-Reply-To` and `References` headers
+fn referenced_ids(header: &str) -> Vec<String> {
+    ["In-Reply-To", "References"]
+        .iter()
+        .filter_map(|name| header_field(header, name))
+        .flat_map(|value| {
+            value
+                .split('<')
+                .skip(1)
+                .filter_map(|rest| rest.split_once('>'))
+                .map(|(id, _)| format!("<{}>", id.trim()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// What every reply to a request has in its subject, derived from the
+/// `subject` template so the two can't drift apart: "RE: " and the subject
+/// up to `{timestamp}`, which differs in each request
+fn reply_subject(subject: &str) -> Result<String> {
+    let subject = subject.replace("{type}", MessageKind::Checkin.as_str());
+    let fixed = subject.split("{timestamp}").next().unwrap_or_default().trim_end();
+    if fixed.is_empty() {
+        anyhow::bail!("'subject' in email config must start with text before {{timestamp}}, so replies can be found");
+    }
+    Ok(format!("RE: {}", fixed))
+}
+
+/// Whether a message answers one of our requests: it references a request's
+/// Message-ID, or failing that, its subject contains `reply_subject`.
+/// Threading also catches replies whose client writes "AW:" or "SV:".
+fn is_checkin_reply(subject: &str, referenced: &[String], sent_ids: &[String], reply_subject: &str) -> bool {
+    if referenced.iter().any(|id| sent_ids.contains(id)) {
+        return true;
+    }
+    subject.to_lowercase().contains(&reply_subject.to_lowercase())
+}
+
+/// IMAP search for candidate replies: everything since `since`, or the last
+/// `REPLY_LOOKBACK_DAYS` without one. Matching happens here rather than with
+/// a server-side SUBJECT search, which can't see into RFC 2047 encoded
+/// subjects on many servers.
+fn search_criteria(since: Option<DateTime<Utc>>, now: DateTime<Utc>) -> String {
+    let since = since.unwrap_or_else(|| now - chrono::Duration::days(REPLY_LOOKBACK_DAYS));
+    format!("SINCE {}", since.format("%d-%b-%Y"))
+}
+
+/// The bytes of an encoded-word's text in the Q encoding: quoted-printable,
+/// with `_` for a space
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut chars = text.bytes();
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                let hex = [chars.next()?, chars.next()?];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    Some(bytes)
+}
+
+/// The text of the RFC 2047 encoded-word at the start of `value`, such as
+/// `=?UTF-8?B?...?=`, and its length. `None` if it isn't one, or it uses a
+/// charset or encoding we don't know.
+fn decode_encoded_word(value: &str) -> Option<(String, usize)> {
+    use base64::Engine;
+
+    let mut parts = value.strip_prefix("=?")?.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let rest = parts.next()?;
+    let end = rest.find("?=")?;
+    let text = &rest[..end];
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let bytes = match encoding {
+        "B" | "b" => base64::engine::general_purpose::STANDARD.decode(text).ok()?,
+        "Q" | "q" => decode_q(text)?,
+        _ => return None,
+    };
+    // RFC 2231 allows a language after the charset, e.g. `UTF-8*en`
+    let label = charset.split('*').next().unwrap_or_default();
+    let decoded = encoding_rs::Encoding::for_label(label.trim().as_bytes())?
+        .decode_without_bom_handling(&bytes)
+        .0
+        .into_owned();
+    // `=?`, the three parts with a `?` between each, and `?=`
+    Some((decoded, charset.len() + encoding.len() + text.len() + 6))
+}
+
+/// A header value with its RFC 2047 encoded-words decoded. Whitespace
+/// between two encoded-words is dropped, as the RFC says, and anything that
+/// doesn't decode is left as it was.
+fn decode_header(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((text, len)) => {
+                if !(after_word && before.trim().is_empty()) {
+                    decoded.push_str(before);
+                }
+                decoded.push_str(&text);
+                rest = &candidate[len..];
+                after_word = true;
+            }
+            None => {
+                decoded.push_str(before);
+                decoded.push_str("=?");
+                rest = &candidate[2..];
+                after_word = false;
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// `mailbox@host` of an envelope address, lowercased. Group markers and
+/// display names without an address have none.
+fn envelope_address(address: &Address) -> Option<String> {
+    let mailbox = address.mailbox.as_ref()?;
+    let host = address.host.as_ref()?;
+    Some(format!("{}@{}", String::from_utf8_lossy(mailbox), String::from_utf8_lossy(host)).to_lowercase())
+}
+
+/// Whether a reply from `address` counts. `user+tag@host` also matches an
+/// allowed `user@host`, since replies may come from a subaddress.
+fn sender_allowed(address: &Address, allowed_senders: &[String]) -> bool {
+    let Some(sender) = envelope_address(address) else {
+        return false;
+    };
+    let untagged = sender.split_once('@').and_then(|(mailbox, host)| {
+        mailbox.split_once('+').map(|(user, _)| format!("{}@{}", user, host))
+    });
+    allowed_senders
+        .iter()
+        .any(|allowed| *allowed == sender || untagged.as_ref() == Some(allowed))
 }
 
 impl BidirectionalEmailOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &[
+        "to", "to_name", "from", "from_name", "smtp_host", "smtp_port", "smtp_security", "tls_ca_file", "tls_accept_invalid_certs", "username", "password",
+        "auth_method", "client_id", "client_secret", "imap_host", "imap_port", "imap_security", "subject", "subject_prefix", "allowed_senders", "imap_idle",
+        "imap_mailbox", "processed_action", "max_retries",
+    ];
+
     pub fn new(config: &HashMap<String, String>) -> Result<Self> {
         let to = config
             .get("to")
@@ -56,15 +567,13 @@ impl BidirectionalEmailOutput {
             .context("Missing 'username' field in email config")?
             .clone();
 
-        let password = config
-            .get("password")
-            .context("Missing 'password' field in email config")?
-            .clone();
+        let auth = MailAuth::from_config(config)?;
 
-        let from = config
-            .get("from")
-            .unwrap_or(&username)
-            .clone();
+        let from = named_mailbox(
+            config.get("from").unwrap_or(&username),
+            display_name(config, "from_name")?.as_deref(),
+            "from",
+        )?;
 
         // IMAP configuration - use defaults if not specified
         let imap_host = config
@@ -78,55 +587,217 @@ impl BidirectionalEmailOutput {
             .parse()
             .context("Invalid 'imap_port' value in email config")?;
 
-        let subject_prefix = config
-            .get("subject_prefix")
-            .map_or("LastSignal", |v| v)
-            .to_string();
+        // `subject_prefix` is the older way to set the subject
+        let subject = match (config.get("subject"), config.get("subject_prefix")) {
+            (Some(subject), _) => subject.clone(),
+            (None, Some(prefix)) => format!("{} Notification", prefix),
+            (None, None) => super::email::DEFAULT_SUBJECT.to_string(),
+        };
+        let reply_subject = reply_subject(&subject)?;
+
+        let allowed_senders: Vec<String> = config
+            .get("allowed_senders")
+            .unwrap_or(&to)
+            .split(',')
+            .map(crate::acknowledgement::sender_address)
+            .filter(|sender| !sender.is_empty())
+            .collect();
+        if allowed_senders.is_empty() {
+            anyhow::bail!("'allowed_senders' in email config lists no addresses");
+        }
+
+        let imap_idle = match config.get("imap_idle").map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => anyhow::bail!("Invalid 'imap_idle' in email config: '{}' is not true or false", other),
+        };
+
+        let processed_action = match config.get("processed_action") {
+            Some(action) => ProcessedAction::parse(action)?,
+            None => ProcessedAction::None,
+        };
+
+        let primary_account = ImapAccount {
+            host: imap_host,
+            port: imap_port,
+            username: username.clone(),
+            // XOAUTH2 logs in with a token instead
+            password: match &auth {
+                MailAuth::Password(password) => password.clone(),
+                MailAuth::Xoauth2 { .. } => String::new(),
+            },
+            folder: config.get("imap_mailbox").cloned().unwrap_or_else(default_imap_folder),
+            security: ImapSecurity::from_config(config)?,
+        };
 
         Ok(BidirectionalEmailOutput {
-            to,
+            to: named_mailbox(&to, display_name(config, "to_name")?.as_deref(), "to")?,
             from,
             smtp_host,
             smtp_port,
+            smtp_security: SmtpSecurity::from_config(config)?,
+            tls: MailTls::from_config(config)?,
             username,
-            password,
-            imap_host,
-            imap_port,
-            subject_prefix,
+            auth,
+            retry: SmtpRetry::from_config(config)?,
+            smtp: SmtpConnection::default(),
+            imap_accounts: vec![primary_account],
+            subject,
+            reply_subject,
+            allowed_senders,
+            sent_ids_file: None,
+            idle: imap_idle.then(|| Arc::new(IdleWatcher::default())),
+            processed_action,
+            pending_replies: Arc::new(Mutex::new(Vec::new())),
+            imap_sessions: Arc::new(KeptConnections::default()),
         })
     }
 
+    /// Keeps the Message-IDs of sent requests in `data_directory`, so replies
+    /// can be matched by thread. Without one, only the subject is matched.
+    /// XOAUTH2 tokens are kept there too.
+    pub fn with_data_directory(mut self, data_directory: Option<&Path>) -> Self {
+        self.sent_ids_file = data_directory.map(|dir| dir.join(sent_ids_file_name(self.to.email.as_ref())));
+        self.auth = self.auth.with_data_directory(data_directory);
+        self
+    }
+
+    fn load_sent_messages(&self) -> Result<SentMessages> {
+        let Some(path) = &self.sent_ids_file else {
+            return Ok(SentMessages::default());
+        };
+        if !path.exists() {
+            return Ok(SentMessages::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read sent email ids: {:?}", path))?;
+        serde_json::from_str(&content).context("Failed to parse sent email ids")
+    }
+
+    fn save_sent_messages(&self, sent: &SentMessages) -> Result<()> {
+        let Some(path) = &self.sent_ids_file else {
+            return Ok(());
+        };
+        let content = serde_json::to_string_pretty(sent).context("Failed to serialize sent email ids")?;
+        std::fs::write(path, content).with_context(|| format!("Failed to write sent email ids: {:?}", path))
+    }
+
+    fn record_sent(&self, message_id: String, sent_at: DateTime<Utc>) -> Result<()> {
+        if self.sent_ids_file.is_none() {
+            return Ok(());
+        }
+        let mut sent = self.load_sent_messages()?;
+        sent.sent.push(SentMessage { message_id, sent_at });
+        let excess = sent.sent.len().saturating_sub(MAX_TRACKED_MESSAGE_IDS);
+        sent.sent.drain(..excess);
+        self.save_sent_messages(&sent)
+    }
+
+    fn sent_ids(&self) -> Result<Vec<String>> {
+        Ok(self.load_sent_messages()?.sent.into_iter().map(|sent| sent.message_id).collect())
+    }
+
+    /// Also lets through replies from these last signal recipients, which
+    /// acknowledge the last signal rather than check in
+    pub fn with_acknowledgement_senders(mut self, senders: &[String]) -> Self {
+        for sender in senders {
+            if !self.allowed_senders.contains(sender) {
+                self.allowed_senders.push(sender.clone());
+            }
+        }
+        self
+    }
+
+    /// Also polls these mailboxes for replies. Reminders are still sent
+    /// once, through the SMTP settings.
+    pub fn with_additional_accounts(mut self, accounts: &[ImapAccount]) -> Self {
+        self.imap_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// A check-in request with its own Message-ID, for matching replies
+    fn build_request(&self, message: &str, message_id: &str) -> Result<Message> {
+        Message::builder()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .subject(render_subject(&self.subject, MessageKind::Checkin))
+            .message_id(Some(message_id.to_string()))
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.to_string())
+            .context("Failed to build email message")
+    }
+
     async fn create_smtp_transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
-        let creds = Credentials::new(self.username.clone(), self.password.clone());
+        let login = self.auth.smtp_login(&self.username, self.smtp_security).await?;
+        self.smtp
+            .transport(login, |login| self.smtp_security.transport(&self.smtp_host, self.smtp_port, login, &self.tls))
+            .await
+    }
 
-        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.smtp_host)
-            .context("Failed to create SMTP transport")?
-            .port(self.smtp_port)
-            .credentials(creds)
-            .build();
+    /// A session on `account`: the one kept from an earlier operation if it
+    /// still answers NOOP, or a new one
+    async fn imap_session(&self, account: &ImapAccount) -> Result<ImapSession> {
+        use tokio::time::{timeout, Duration};
 
-        Ok(transport)
+        let kept = self.imap_sessions.take_working(&account.session_key(), |mut session| async move {
+            match timeout(Duration::from_secs(10), session.noop()).await {
+                Ok(Ok(())) => Some(session),
+                _ => None,
+            }
+        }).await;
+        match kept {
+            Some(session) => {
+                tracing::debug!("Reusing IMAP session for {} on {}", account.username, account.host);
+                Ok(session)
+            }
+            None => self.create_imap_session(account).await,
+        }
     }
 
-    async fn create_imap_session(&self) -> Result<Session<TlsStream<TcpStream>>> {
+    /// Keeps `session` for the next operation on `account`
+    async fn keep_imap_session(&self, account: &ImapAccount, session: ImapSession) {
+        self.imap_sessions.keep(&account.session_key(), session).await;
+    }
+
+    async fn create_imap_session(&self, account: &ImapAccount) -> Result<ImapSession> {
         use tokio::time::{timeout, Duration};
         
-        let addr = format!("{}:{}", self.imap_host, self.imap_port);
+        let addr = format!("{}:{}", account.host, account.port);
         tracing::debug!("Connecting to IMAP server: {}", addr);
         
-        let tcp_stream = timeout(Duration::from_secs(30), TcpStream::connect(&addr)).await
+        let mut tcp_stream = timeout(Duration::from_secs(30), TcpStream::connect(&addr)).await
             .context("IMAP connection timed out")?
             .context("Failed to connect to IMAP server")?;
-        
-        tracing::debug!("Establishing TLS connection to {}", self.imap_host);
-        let tls = TlsConnector::new();
-        let tls_stream = timeout(Duration::from_secs(30), tls.connect(&self.imap_host, tcp_stream)).await
+
+        if account.security == ImapSecurity::StartTls {
+            tracing::debug!("Upgrading IMAP connection to {} with STARTTLS", account.host);
+            let mut client = Client::new(tcp_stream);
+            timeout(Duration::from_secs(30), client.read_response()).await
+                .context("IMAP greeting timed out")?
+                .context("IMAP server closed the connection before greeting")?
+                .context("Failed to read IMAP greeting")?;
+            timeout(Duration::from_secs(30), client.run_command_and_check_ok("STARTTLS", None)).await
+                .context("IMAP STARTTLS timed out")?
+                .context("IMAP server refused STARTTLS")?;
+            tcp_stream = client.into_inner();
+        }
+
+        tracing::debug!("Establishing TLS connection to {}", account.host);
+        let tls = self.tls.imap_connector()?;
+        let tls_stream = timeout(Duration::from_secs(30), tls.connect(&account.host, tcp_stream)).await
             .context("TLS connection timed out")?
             .context("Failed to establish TLS connection")?;
 
-        tracing::info!("Logging in to IMAP as {}", self.username);
+        tracing::info!("Logging in to IMAP as {}", account.username);
         let client = Client::new(tls_stream);
-        let session = timeout(Duration::from_secs(30), client.login(&self.username, &self.password)).await
+        // XOAUTH2 covers the primary account; additional accounts have passwords
+        let login = if self.auth.is_xoauth2() && self.imap_accounts.first() == Some(account) {
+            let authenticator = Xoauth2Authenticator::new(&account.username, &self.auth.secret().await?);
+            timeout(Duration::from_secs(30), client.authenticate("XOAUTH2", authenticator)).await
+        } else {
+            timeout(Duration::from_secs(30), client.login(&account.username, &account.password)).await
+        };
+        let session = login
             .context("IMAP login timed out")?
             .map_err(|e| anyhow::anyhow!("Failed to login to IMAP: {}", e.0))?;
 
@@ -134,37 +805,195 @@ impl BidirectionalEmailOutput {
         Ok(session)
     }
 
-    async fn check_inbox_for_replies(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+    /// Usage of the fullest account's quota, for accounts whose server
+    /// supports the QUOTA extension
+    async fn check_mailbox_usage(&self) -> Result<Option<MailboxUsage>> {
+        let mut fullest: Option<MailboxUsage> = None;
+        for account in &self.imap_accounts {
+            if let Some(usage) = self.check_account_usage(account).await?
+                && fullest.as_ref().is_none_or(|fullest| usage.percent > fullest.percent)
+            {
+                fullest = Some(usage);
+            }
+        }
+        Ok(fullest)
+    }
+
+    async fn check_account_usage(&self, account: &ImapAccount) -> Result<Option<MailboxUsage>> {
+        use tokio::time::{timeout, Duration};
+
+        let mut session = self.imap_session(account).await?;
+        let capabilities = timeout(Duration::from_secs(30), session.capabilities()).await
+            .context("IMAP capability request timed out")?
+            .context("Failed to request IMAP capabilities")?;
+        if !capabilities.has_str("QUOTA") {
+            tracing::debug!("IMAP server {} does not support QUOTA, mailbox usage unknown", account.host);
+            self.keep_imap_session(account, session).await;
+            return Ok(None);
+        }
+
+        let (_, quotas) = timeout(Duration::from_secs(30), session.get_quota_root(&account.folder)).await
+            .context("IMAP quota request timed out")?
+            .with_context(|| format!("Failed to request {} quota", account.folder))?;
+        self.keep_imap_session(account, session).await;
+
+        Ok(MailboxUsage::from_quotas(&quotas).map(|usage| MailboxUsage {
+            resource: format!("{} {}", account.username, usage.resource),
+            ..usage
+        }))
+    }
+
+    /// Starts the IDLE task if it isn't running
+    fn ensure_idle_started(&self, watcher: &IdleWatcher) {
+        let mut task = watcher.task.lock().unwrap_or_else(|e| e.into_inner());
+        if task.is_none() {
+            // The task's copy doesn't hold the watcher, so dropping the output stops it
+            let output = BidirectionalEmailOutput { idle: None, ..self.clone() };
+            *task = Some(tokio::spawn(watch_with_idle(output, Arc::clone(&watcher.state))));
+        }
+    }
+
+    /// Keeps an IDLE session open on the primary mailbox, queueing replies
+    /// as they arrive. Returns `Ok` if the server doesn't support IDLE.
+    async fn run_idle_session(&self, state: &IdleState) -> Result<()> {
+        use async_imap::extensions::idle::IdleResponse;
+        use tokio::time::{timeout, Duration};
+
+        let account = &self.imap_accounts[0];
+        let mut session = self.create_imap_session(account).await?;
+        let capabilities = timeout(Duration::from_secs(30), session.capabilities()).await
+            .context("IMAP capability request timed out")?
+            .context("Failed to request IMAP capabilities")?;
+        if !capabilities.has_str("IDLE") {
+            timeout(Duration::from_secs(10), session.logout()).await.ok();
+            return Ok(());
+        }
+
+        timeout(Duration::from_secs(30), session.select(&account.folder)).await
+            .with_context(|| format!("{} select timed out", account.folder))?
+            .with_context(|| format!("Failed to select {}", account.folder))?;
+        state.set_watching_since(Some(Utc::now()));
+        tracing::info!("Watching {} on {} for replies with IMAP IDLE", account.folder, account.host);
+
+        loop {
+            let mut handle = session.idle();
+            timeout(Duration::from_secs(30), handle.init()).await
+                .context("IMAP IDLE start timed out")?
+                .context("Failed to start IMAP IDLE")?;
+            let (wait, _interrupt) = handle.wait_with_timeout(IDLE_REFRESH);
+            // Keepalives reset the library's timeout, so this one bounds the IDLE
+            let new_data = match timeout(IDLE_REFRESH, wait).await {
+                Ok(Ok(IdleResponse::NewData(_))) => true,
+                Ok(Ok(_)) | Err(_) => false,
+                Ok(Err(e)) => return Err(e).context("IMAP IDLE failed"),
+            };
+            session = timeout(Duration::from_secs(30), handle.done()).await
+                .context("IMAP IDLE end timed out")?
+                .context("Failed to end IMAP IDLE")?;
+
+            if new_data {
+                tracing::debug!("IMAP IDLE reported changes in {} on {}", account.folder, account.host);
+                let since = Utc::now() - chrono::Duration::hours(IDLE_LOOKBACK_HOURS);
+                state.queue_replies(self.check_inbox_for_replies(account, Some(since)).await?);
+            }
+        }
+    }
+
+    /// Applies `processed_action` to replies in `account`
+    async fn apply_processed_action(&self, account: &ImapAccount, uids: &[u32]) -> Result<()> {
+        use futures_util::stream::StreamExt;
+        use tokio::time::{timeout, Duration};
+
+        let mut session = self.imap_session(account).await?;
+        let capabilities = timeout(Duration::from_secs(30), session.capabilities()).await
+            .context("IMAP capability request timed out")?
+            .context("Failed to request IMAP capabilities")?;
+        let steps = self.processed_action.steps(capabilities.has_str("MOVE"), capabilities.has_str("UIDPLUS"));
+        timeout(Duration::from_secs(30), session.select(&account.folder)).await
+            .with_context(|| format!("{} select timed out", account.folder))?
+            .with_context(|| format!("Failed to select {}", account.folder))?;
+
+        let uid_set = uids.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+        for step in steps {
+            tracing::debug!("Applying {:?} to replies {} in {} on {}", step, uid_set, account.folder, account.host);
+            let result = timeout(Duration::from_secs(30), async {
+                match &step {
+                    ImapStep::MarkSeen => {
+                        session.uid_store(&uid_set, "+FLAGS.SILENT (\\Seen)").await?.collect::<Vec<_>>().await;
+                    }
+                    ImapStep::MarkDeleted => {
+                        session.uid_store(&uid_set, "+FLAGS.SILENT (\\Deleted)").await?.collect::<Vec<_>>().await;
+                    }
+                    ImapStep::Move(folder) => session.uid_mv(&uid_set, folder).await?,
+                    ImapStep::Copy(folder) => session.uid_copy(&uid_set, folder).await?,
+                    ImapStep::UidExpunge => {
+                        session.uid_expunge(&uid_set).await?.collect::<Vec<_>>().await;
+                    }
+                    ImapStep::Expunge => {
+                        session.expunge().await?.collect::<Vec<_>>().await;
+                    }
+                }
+                Ok::<_, async_imap::error::Error>(())
+            })
+            .await;
+            result
+                .with_context(|| format!("IMAP {:?} timed out", step))?
+                .with_context(|| format!("IMAP {:?} failed", step))?;
+        }
+
+        self.keep_imap_session(account, session).await;
+        Ok(())
+    }
+
+    /// Replies from every account. An account that can't be read is logged
+    /// and skipped, and it is only an error if none can be.
+    async fn check_accounts_for_replies(&self, accounts: &[ImapAccount], since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let mut responses = Vec::new();
+        let mut first_error = None;
+        let mut any_succeeded = false;
+
+        for account in accounts {
+            match self.check_inbox_for_replies(account, since).await {
+                Ok(account_responses) => {
+                    any_succeeded = true;
+                    responses.extend(account_responses);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to check {} on {} for replies: {:#}", account.folder, account.host, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if !any_succeeded => Err(e),
+            _ => Ok(responses),
+        }
+    }
+
+    async fn check_inbox_for_replies(&self, account: &ImapAccount, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
         use tokio::time::{timeout, Duration};
         
-        tracing::debug!("Checking inbox for replies since: {:?}", since);
-        let mut session = self.create_imap_session().await?;
+        tracing::debug!("Checking {} on {} for replies since: {:?}", account.folder, account.host, since);
+        let mut session = self.imap_session(account).await?;
         
-        // Select INBOX
-        tracing::debug!("Selecting INBOX");
-        timeout(Duration::from_secs(30), session.select("INBOX")).await
-            .context("INBOX select timed out")?
-            .context("Failed to select INBOX")?;
-
-        // Build search criteria - only look for replies (RE: prefix)
-        let search_criteria = if let Some(since_date) = since {
-            // Search for emails since the given date that are replies to our subject
-            format!("SINCE {} SUBJECT \"RE: {} Notification\"", 
-                since_date.format("%d-%b-%Y"), 
-                self.subject_prefix)
-        } else {
-            // Just search for reply emails to our subject
-            format!("SUBJECT \"RE: {} Notification\"", self.subject_prefix)
-        };
+        tracing::debug!("Selecting {}", account.folder);
+        timeout(Duration::from_secs(30), session.select(&account.folder)).await
+            .with_context(|| format!("{} select timed out", account.folder))?
+            .with_context(|| format!("Failed to select {}", account.folder))?;
+
+        // Fetch recent mail and pick out the replies here, by thread or by decoded subject
+        let sent_ids = self.sent_ids()?;
+        let search_criteria = search_criteria(since, Utc::now());
 
         tracing::info!("Searching with criteria: {}", search_criteria);
-        let message_ids = timeout(Duration::from_secs(30), session.search(&search_criteria)).await
+        let message_ids = timeout(Duration::from_secs(30), session.uid_search(&search_criteria)).await
             .context("Email search timed out")?
             .context("Failed to search emails")?;
 
         if message_ids.is_empty() {
             tracing::info!("No messages found matching search criteria");
-            timeout(Duration::from_secs(10), session.logout()).await.ok();
+            self.keep_imap_session(account, session).await;
             return Ok(vec![]);
         }
         
@@ -177,7 +1006,7 @@ impl BidirectionalEmailOutput {
             .join(",");
         use futures_util::stream::StreamExt;
         
-        let mut message_stream = timeout(Duration::from_secs(30), session.fetch(&message_ids_str, "ENVELOPE")).await
+        let mut message_stream = timeout(Duration::from_secs(30), session.uid_fetch(&message_ids_str, "(ENVELOPE BODY.PEEK[HEADER])")).await
             .context("Message fetch timed out")?
             .context("Failed to fetch messages")?;
 
@@ -191,47 +1020,66 @@ impl BidirectionalEmailOutput {
                     continue;
                 }
             };
-            if let Some(envelope) = message.envelope() {
-                if let (Some(date), Some(subject), Some(from)) = (
-                    envelope.date.as_ref(),
-                    envelope.subject.as_ref(),
-                    envelope.from.as_ref().and_then(|f| f.first())
+            if let Some(envelope) = message.envelope()
+                && let (Some(date), Some(subject), Some(from)) = (
+                envelope.date.as_ref(),
+                envelope.subject.as_ref(),
+                envelope.from.as_ref().and_then(|f| f.first())
+            ) {
+                // Parse the date
+                if let Ok(parsed_date) = chrono::DateTime::parse_from_rfc2822(
+                    &String::from_utf8_lossy(date)
                 ) {
-                    // Parse the date
-                    if let Ok(parsed_date) = chrono::DateTime::parse_from_rfc2822(
-                        &String::from_utf8_lossy(date)
-                    ) {
-                        let timestamp = parsed_date.with_timezone(&Utc);
-                        
-                        // Check if this is after our 'since' timestamp
-                        if let Some(since_time) = since {
-                            if timestamp <= since_time {
-                                continue;
-                            }
-                        }
-                        
-                        let subject_str = String::from_utf8_lossy(subject);
-                        let from_str = if let (Some(name), Some(email)) = (from.name.as_ref(), from.mailbox.as_ref()) {
-                            format!("{} <{}@{}>", 
-                                String::from_utf8_lossy(name),
-                                String::from_utf8_lossy(email),
-                                from.host.as_ref().map(|h| String::from_utf8_lossy(h)).unwrap_or_default()
-                            )
-                        } else if let Some(email) = from.mailbox.as_ref() {
-                            format!("{}@{}", 
-                                String::from_utf8_lossy(email),
-                                from.host.as_ref().map(|h| String::from_utf8_lossy(h)).unwrap_or_default()
-                            )
-                        } else {
-                            "Unknown".to_string()
-                        };
-                        
-                        responses.push(CheckinResponse::Found {
-                            timestamp,
-                            subject: subject_str.to_string(),
-                            from: from_str,
-                        });
+                    let timestamp = parsed_date.with_timezone(&Utc);
+                    
+                    // Check if this is after our 'since' timestamp
+                    if let Some(since_time) = since
+                        && timestamp <= since_time {
+                        continue;
+                    }
+                    
+                    if !sender_allowed(from, &self.allowed_senders) {
+                        tracing::info!(
+                            "Ignoring reply from {}, who is not in allowed_senders",
+                            envelope_address(from).unwrap_or_else(|| "an unknown sender".to_string())
+                        );
+                        continue;
                     }
+
+                    let subject_str = decode_header(&String::from_utf8_lossy(subject));
+                    let header = message.header().map(String::from_utf8_lossy).unwrap_or_default();
+                    if !is_checkin_reply(&subject_str, &referenced_ids(&header), &sent_ids, &self.reply_subject) {
+                        tracing::debug!("Ignoring '{}', which isn't a reply to a check-in request", subject_str);
+                        continue;
+                    }
+
+                    let from_str = if let (Some(name), Some(email)) = (from.name.as_ref(), from.mailbox.as_ref()) {
+                        format!("{} <{}@{}>", 
+                            decode_header(&String::from_utf8_lossy(name)),
+                            String::from_utf8_lossy(email),
+                            from.host.as_ref().map(|h| String::from_utf8_lossy(h)).unwrap_or_default()
+                        )
+                    } else if let Some(email) = from.mailbox.as_ref() {
+                        format!("{}@{}", 
+                            String::from_utf8_lossy(email),
+                            from.host.as_ref().map(|h| String::from_utf8_lossy(h)).unwrap_or_default()
+                        )
+                    } else {
+                        "Unknown".to_string()
+                    };
+                    
+                    if self.processed_action != ProcessedAction::None
+                        && let Some(uid) = message.uid
+                    {
+                        let reply = PendingReply { account: account.clone(), uid, timestamp };
+                        track_pending(&mut self.pending_replies.lock().unwrap_or_else(|e| e.into_inner()), reply);
+                    }
+
+                    responses.push(CheckinResponse::Found {
+                        timestamp,
+                        subject: subject_str,
+                        from: from_str,
+                    });
                 }
             }
         }
@@ -239,22 +1087,42 @@ impl BidirectionalEmailOutput {
         // Explicitly drop the message stream to release the session borrow
         drop(message_stream);
         
-        tracing::debug!("Processed {} email responses, logging out", responses.len());
-        timeout(Duration::from_secs(10), session.logout()).await.ok();
+        tracing::debug!("Processed {} email responses", responses.len());
+        self.keep_imap_session(account, session).await;
         Ok(responses)
     }
 }
 
+/// Runs IDLE sessions until the output is dropped, reconnecting with backoff.
+/// Gives up, leaving polling to find replies, if the server lacks IDLE.
+async fn watch_with_idle(output: BidirectionalEmailOutput, state: Arc<IdleState>) {
+    let host = output.imap_accounts[0].host.clone();
+    let mut backoff = IDLE_RECONNECT_MIN;
+    loop {
+        let result = output.run_idle_session(&state).await;
+        let was_watching = state.set_watching_since(None).is_some();
+        match result {
+            Ok(()) => {
+                tracing::info!("IMAP server {} does not support IDLE, polling for replies instead", host);
+                return;
+            }
+            Err(e) => {
+                if was_watching {
+                    backoff = IDLE_RECONNECT_MIN;
+                }
+                tracing::warn!("IMAP IDLE on {} stopped: {:#}. Reconnecting in {:?}", host, e, backoff);
+            }
+        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(IDLE_RECONNECT_MAX);
+    }
+}
+
 #[async_trait]
 impl Output for BidirectionalEmailOutput {
     async fn send_message(&self, message: &str) -> Result<OutputResult> {
-        let email = Message::builder()
-            .from(self.from.parse().context("Invalid from email address")?)
-            .to(self.to.parse().context("Invalid to email address")?)
-            .subject(&format!("{} Notification", self.subject_prefix))
-            .header(ContentType::TEXT_PLAIN)
-            .body(message.to_string())
-            .context("Failed to build email message")?;
+        let message_id = generate_message_id(self.from.email.as_ref());
+        let email = self.build_request(message, &message_id)?;
 
         let transport = match self.create_smtp_transport().await {
             Ok(t) => t,
@@ -263,12 +1131,24 @@ impl Output for BidirectionalEmailOutput {
             }
         };
 
-        match transport.send(email).await {
-            Ok(_) => Ok(OutputResult::Success),
+        let result = self.retry.send(&transport, &email).await;
+        if result.is_err() {
+            self.smtp.discard().await;
+        }
+        match result {
+            Ok(_) => {
+                if let Err(e) = self.record_sent(message_id, Utc::now()) {
+                    // Replies will still be matched by subject
+                    tracing::warn!("Failed to record sent email id: {:#}", e);
+                }
+                Ok(OutputResult::Success)
+            }
+            Err(e) if is_quota_error(&e) => Ok(OutputResult::quota_exceeded(e)),
             Err(e) => Ok(OutputResult::Failed(format!("Failed to send email: {}", e))),
         }
     }
 
+    /// Sends NOOP on kept connections, or connects where there isn't one
     async fn health_check(&self) -> Result<bool> {
         // Check both SMTP (sending) and IMAP (receiving) connectivity
         let smtp_ok = match self.create_smtp_transport().await {
@@ -276,7 +1156,11 @@ impl Output for BidirectionalEmailOutput {
                 match transport.test_connection().await {
                     Ok(_) => true,
                     Err(e) => {
-                        tracing::debug!("SMTP health check failed: {}", e);
+                        tracing::debug!(
+                    "SMTP health check failed for {}:{} (smtp_security = {}): {}",
+                    self.smtp_host, self.smtp_port, self.smtp_security, e
+                );
+                        self.smtp.discard().await;
                         false
                     }
                 }
@@ -287,18 +1171,32 @@ impl Output for BidirectionalEmailOutput {
             }
         };
 
-        let imap_ok = match self.create_imap_session().await {
-            Ok(mut session) => {
-                let result = session.logout().await.is_ok();
-                result
-            }
-            Err(e) => {
-                tracing::debug!("IMAP health check failed: {}", e);
-                false
+        // Each receive path is reported on its own. Reminders can still go
+        // out, and replies be seen, while at least one account works.
+        let mut any_imap_ok = false;
+        for account in &self.imap_accounts {
+            let imap_ok = match self.imap_session(account).await {
+                Ok(session) => {
+                    self.keep_imap_session(account, session).await;
+                    true
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "IMAP health check failed for {} on {}:{} ({:?}): {:#}",
+                        account.username, account.host, account.port, account.security, e
+                    );
+                    false
+                }
+            };
+            if imap_ok {
+                tracing::debug!("IMAP account {} on {} is healthy", account.username, account.host);
+            } else {
+                tracing::warn!("IMAP account {} on {} is unhealthy, replies sent there won't be seen", account.username, account.host);
             }
-        };
+            any_imap_ok |= imap_ok;
+        }
 
-        Ok(smtp_ok && imap_ok)
+        Ok(smtp_ok && any_imap_ok)
     }
 
     fn get_name(&self) -> &str {
@@ -321,13 +1219,49 @@ impl BidirectionalOutput for BidirectionalEmailOutput {
     }
     
     async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
-        self.check_inbox_for_replies(since).await
+        let Some(idle) = &self.idle else {
+            return self.check_accounts_for_replies(&self.imap_accounts, since).await;
+        };
+        self.ensure_idle_started(idle);
+
+        let mut responses = idle.state.take_queued(since);
+        if idle.state.primary_watched() {
+            // IDLE queues the primary mailbox's replies, so only the others are polled
+            responses.extend(self.check_accounts_for_replies(&self.imap_accounts[1..], since).await?);
+        } else {
+            let polled_at = Utc::now();
+            responses.extend(self.check_accounts_for_replies(&self.imap_accounts, since).await?);
+            *idle.state.last_poll.lock().unwrap_or_else(|e| e.into_inner()) = Some(polled_at);
+        }
+        Ok(responses)
     }
     
-    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
-        // For email, we don't need to mark as processed since we use timestamp-based filtering
-        // The IMAP search with SINCE handles this automatically
-        Ok(())
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        let processed = take_processed(&mut self.pending_replies.lock().unwrap_or_else(|e| e.into_inner()), timestamp);
+        for (account, uids) in processed {
+            // The check-in is already recorded, and a reply left behind is found again harmlessly
+            if let Err(e) = self.apply_processed_action(&account, &uids).await {
+                tracing::warn!("Failed to apply processed_action to replies in {} on {}: {:#}", account.folder, account.host, e);
+            }
+        }
+
+        // The IMAP search with SINCE keeps old replies out, so only requests
+        // answered by now need forgetting
+        let mut sent = self.load_sent_messages()?;
+        let before = sent.sent.len();
+        sent.sent.retain(|sent| sent.sent_at >= timestamp);
+        if sent.sent.len() == before {
+            return Ok(());
+        }
+        self.save_sent_messages(&sent)
+    }
+
+    fn reply_signal(&self) -> Option<Arc<Notify>> {
+        self.idle.as_ref().map(|idle| Arc::clone(&idle.state.replies_queued))
+    }
+
+    async fn mailbox_usage(&self) -> Result<Option<MailboxUsage>> {
+        self.check_mailbox_usage().await
     }
 }
 
@@ -345,14 +1279,75 @@ mod tests {
         config.insert("password".to_string(), "password".to_string());
 
         let output = BidirectionalEmailOutput::new(&config).unwrap();
-        assert_eq!(output.to, "test@example.com");
+        assert_eq!(output.to.to_string(), "test@example.com");
         assert_eq!(output.smtp_host, "smtp.example.com");
         assert_eq!(output.smtp_port, 587);
         assert_eq!(output.username, "user@example.com");
-        assert_eq!(output.from, "user@example.com");
-        assert_eq!(output.imap_host, "imap.example.com"); // auto-converted
-        assert_eq!(output.imap_port, 993); // default IMAP SSL port
-        assert_eq!(output.subject_prefix, "LastSignal"); // default
+        assert_eq!(output.from.to_string(), "user@example.com");
+        assert_eq!(output.imap_accounts[0].host, "imap.example.com"); // auto-converted
+        assert_eq!(output.imap_accounts[0].port, 993); // default IMAP SSL port
+        assert_eq!(output.imap_accounts[0].folder, "INBOX");
+        assert_eq!(output.subject, "LastSignal Notification"); // default
+        assert_eq!(output.reply_subject, "RE: LastSignal Notification");
+        assert_eq!(output.allowed_senders, vec!["test@example.com"]); // defaults to `to`
+    }
+
+    fn address<'a>(name: Option<&'a str>, mailbox: Option<&'a str>, host: Option<&'a str>) -> Address<'a> {
+        Address {
+            name: name.map(|name| name.as_bytes().into()),
+            adl: None,
+            mailbox: mailbox.map(|mailbox| mailbox.as_bytes().into()),
+            host: host.map(|host| host.as_bytes().into()),
+        }
+    }
+
+    #[test]
+    fn test_sender_allowed() {
+        let allowed = vec!["me@example.com".to_string()];
+        assert!(sender_allowed(&address(Some("Me"), Some("me"), Some("example.com")), &allowed));
+        assert!(sender_allowed(&address(None, Some("ME"), Some("Example.COM")), &allowed));
+        assert!(!sender_allowed(&address(Some("Mail Delivery System"), Some("mailer-daemon"), Some("example.com")), &allowed));
+        assert!(!sender_allowed(&address(None, Some("me"), Some("example.org")), &allowed));
+    }
+
+    #[test]
+    fn test_sender_allowed_display_name_only() {
+        // "me@example.com" as a display name, with no address behind it
+        let allowed = vec!["me@example.com".to_string()];
+        assert!(!sender_allowed(&address(Some("me@example.com"), None, None), &allowed));
+        assert!(!sender_allowed(&address(Some("me@example.com"), Some("me"), None), &allowed));
+    }
+
+    #[test]
+    fn test_sender_allowed_subaddress() {
+        let allowed = vec!["me@example.com".to_string()];
+        assert!(sender_allowed(&address(None, Some("me+lastsignal"), Some("example.com")), &allowed));
+        assert!(!sender_allowed(&address(None, Some("other+me"), Some("example.com")), &allowed));
+
+        // An allowed subaddress has to match exactly
+        let tagged = vec!["me+phone@example.com".to_string()];
+        assert!(sender_allowed(&address(None, Some("me+phone"), Some("example.com")), &tagged));
+        assert!(!sender_allowed(&address(None, Some("me"), Some("example.com")), &tagged));
+    }
+
+    #[test]
+    fn test_multiple_allowed_senders() {
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), "test@example.com".to_string());
+        config.insert("smtp_host".to_string(), "smtp.example.com".to_string());
+        config.insert("smtp_port".to_string(), "587".to_string());
+        config.insert("username".to_string(), "user@example.com".to_string());
+        config.insert("password".to_string(), "password".to_string());
+        config.insert("allowed_senders".to_string(), "Me <Me@Example.com>, partner@example.org".to_string());
+
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert_eq!(output.allowed_senders, vec!["me@example.com", "partner@example.org"]);
+        assert!(sender_allowed(&address(None, Some("partner"), Some("example.org")), &output.allowed_senders));
+        assert!(sender_allowed(&address(None, Some("me"), Some("example.com")), &output.allowed_senders));
+        assert!(!sender_allowed(&address(None, Some("test"), Some("example.com")), &output.allowed_senders));
+
+        config.insert("allowed_senders".to_string(), " , ".to_string());
+        assert!(BidirectionalEmailOutput::new(&config).is_err());
     }
 
     #[test]
@@ -368,8 +1363,404 @@ mod tests {
         config.insert("subject_prefix".to_string(), "MyApp".to_string());
 
         let output = BidirectionalEmailOutput::new(&config).unwrap();
-        assert_eq!(output.imap_host, "mail.example.com");
-        assert_eq!(output.imap_port, 143);
-        assert_eq!(output.subject_prefix, "MyApp");
+        assert_eq!(output.imap_accounts[0].host, "mail.example.com");
+        assert_eq!(output.imap_accounts[0].port, 143);
+        assert_eq!(output.reply_subject, "RE: MyApp Notification");
+    }
+
+    #[test]
+    fn test_additional_imap_accounts_follow_primary() {
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), "test@example.com".to_string());
+        config.insert("smtp_host".to_string(), "smtp.example.com".to_string());
+        config.insert("smtp_port".to_string(), "587".to_string());
+        config.insert("username".to_string(), "user@example.com".to_string());
+        config.insert("password".to_string(), "password".to_string());
+
+        let work: ImapAccount = toml::from_str(r#"
+host = "imap.work.example.com"
+username = "me@work.example.com"
+password = "work-password"
+"#).unwrap();
+        let output = BidirectionalEmailOutput::new(&config).unwrap()
+            .with_additional_accounts(std::slice::from_ref(&work));
+
+        assert_eq!(output.imap_accounts.len(), 2);
+        assert_eq!(output.imap_accounts[0].username, "user@example.com");
+        assert_eq!(output.imap_accounts[1], ImapAccount {
+            host: "imap.work.example.com".to_string(),
+            port: 993,
+            username: "me@work.example.com".to_string(),
+            password: "work-password".to_string(),
+            folder: "INBOX".to_string(),
+            security: ImapSecurity::Tls,
+        });
+    }
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), "me@example.com".to_string());
+        config.insert("smtp_host".to_string(), "smtp.example.com".to_string());
+        config.insert("smtp_port".to_string(), "587".to_string());
+        config.insert("username".to_string(), "lastsignal@example.org".to_string());
+        config.insert("password".to_string(), "password".to_string());
+        config
+    }
+
+    #[test]
+    fn test_xoauth2_authenticator() {
+        use async_imap::Authenticator;
+        let mut authenticator = Xoauth2Authenticator::new("me@gmail.com", "ya29.token");
+        assert_eq!(authenticator.process(b""), "user=me@gmail.com\x01auth=Bearer ya29.token\x01\x01");
+        // The error challenge gets an empty answer, ending the exchange
+        assert_eq!(authenticator.process(br#"{"status":"400","schemes":"Bearer","scope":"https://mail.google.com/"}"#), "");
+
+        // Only the primary account logs in with XOAUTH2
+        let mut config = test_config();
+        config.remove("password");
+        config.insert("auth_method".to_string(), "xoauth2".to_string());
+        config.insert("client_id".to_string(), "1234-abcd.apps.googleusercontent.com".to_string());
+        config.insert("client_secret".to_string(), "GOCSPX-secret".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert!(output.auth.is_xoauth2());
+        assert_eq!(output.imap_accounts[0].password, "");
+    }
+
+    #[test]
+    fn test_generate_message_id() {
+        let id = generate_message_id("LastSignal <lastsignal@Example.org>");
+        assert!(id.starts_with('<') && id.ends_with(".lastsignal@example.org>"), "{}", id);
+        assert_ne!(id, generate_message_id("lastsignal@example.org"));
+    }
+
+    #[test]
+    fn test_referenced_ids() {
+        let header = "From: Me <me@example.com>\r\n\
+            Subject: AW: LastSignal Notification\r\n\
+            In-Reply-To: <abc.lastsignal@example.org>\r\n\
+            References: <older@example.org>\r\n\
+            \t<abc.lastsignal@example.org>\r\n\
+            Message-ID: <reply@example.com>\r\n";
+        assert_eq!(referenced_ids(header), vec![
+            "<abc.lastsignal@example.org>",
+            "<older@example.org>",
+            "<abc.lastsignal@example.org>",
+        ]);
+        assert!(referenced_ids("Subject: hello\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_localized_reply_prefixes_match_by_thread() {
+        let sent = vec!["<abc.lastsignal@example.org>".to_string()];
+        let replying = vec!["<abc.lastsignal@example.org>".to_string()];
+        for subject in ["AW: LastSignal Notification", "SV: LastSignal Notification", "回复: LastSignal Notification"] {
+            assert!(is_checkin_reply(subject, &replying, &sent, "RE: LastSignal Notification"), "{}", subject);
+            assert!(!is_checkin_reply(subject, &[], &sent, "RE: LastSignal Notification"), "{}", subject);
+        }
+
+        // The subject heuristic is still the fallback, in any case
+        assert!(is_checkin_reply("Re: LastSignal Notification", &[], &sent, "RE: LastSignal Notification"));
+        assert!(!is_checkin_reply("AW: Lunch?", &["<other@example.com>".to_string()], &sent, "RE: LastSignal Notification"));
+    }
+
+    #[test]
+    fn test_forward_keeping_references_matches() {
+        let sent = vec!["<abc.lastsignal@example.org>".to_string()];
+        let header = "Subject: Fwd: LastSignal Notification\r\n\
+            References: <abc.lastsignal@example.org>\r\n";
+        let subject = header_field(header, "subject").unwrap();
+        assert!(is_checkin_reply(&subject, &referenced_ids(header), &sent, "RE: LastSignal Notification"));
+
+        let dropped = "Subject: Fwd: LastSignal Notification\r\n";
+        assert!(!is_checkin_reply(&header_field(dropped, "Subject").unwrap(), &referenced_ids(dropped), &sent, "RE: LastSignal Notification"));
+    }
+
+    #[tokio::test]
+    async fn test_kept_connections() {
+        /// Stands in for a session, answering NOOP while `alive`
+        #[derive(Debug, PartialEq)]
+        struct Connection {
+            id: u32,
+            alive: bool,
+        }
+        async fn noop(connection: Connection) -> Option<Connection> {
+            connection.alive.then_some(connection)
+        }
+
+        let kept = KeptConnections::default();
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await, None);
+
+        kept.keep("me@imap.example.com:993", Connection { id: 1, alive: true }).await;
+        let connection = kept.take_working("me@imap.example.com:993", noop).await.unwrap();
+        assert_eq!(connection.id, 1);
+        // While in use, another operation gets nothing and connects for itself
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await, None);
+
+        // An operation that fails drops its connection rather than keeping it
+        let _ = connection;
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await, None);
+
+        // A kept connection that stopped answering is dropped too
+        kept.keep("me@imap.example.com:993", Connection { id: 2, alive: false }).await;
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await, None);
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await, None);
+
+        // Each account has its own
+        kept.keep("me@imap.example.com:993", Connection { id: 3, alive: true }).await;
+        assert_eq!(kept.take_working("other@imap.example.com:993", noop).await, None);
+        assert_eq!(kept.take_working("me@imap.example.com:993", noop).await.unwrap().id, 3);
+    }
+
+    #[test]
+    fn test_search_criteria() {
+        let now = "2026-10-15T09:30:00Z".parse().unwrap();
+        let since = "2026-10-01T23:59:00Z".parse().unwrap();
+        assert_eq!(search_criteria(Some(since), now), "SINCE 01-Oct-2026");
+        assert_eq!(search_criteria(None, now), "SINCE 15-Sep-2026");
+    }
+
+    #[test]
+    fn test_decode_header() {
+        let cases = [
+            // Base64 and Q, in UTF-8
+            ("=?UTF-8?B?UkU6IExhc3RTaWduYWwgTm90aWZpY2F0aW9u?=", "RE: LastSignal Notification"),
+            ("=?utf-8?q?RE:_LastSignal_Notification_=E2=9C=93?=", "RE: LastSignal Notification ✓"),
+            ("=?UTF-8?B?5Zue5aSNOg==?= LastSignal Notification", "回复: LastSignal Notification"),
+            // Single-byte and legacy charsets
+            ("=?ISO-8859-1?Q?RE:_Gr=FC=DFe?=", "RE: Grüße"),
+            ("=?windows-1252?Q?RE:_=93LastSignal=94?=", "RE: “LastSignal”"),
+            ("=?KOI8-R?B?8NLJ18XU?=", "Привет"),
+            ("=?Shift_JIS?B?grGC8YLJgr+CzQ==?=", "こんにちは"),
+            ("=?ISO-2022-JP?B?GyRCJDMkcyRLJEEkTxsoQg==?=", "こんにちは"),
+            // A language after the charset
+            ("=?UTF-8*en?Q?RE:_Hi?=", "RE: Hi"),
+        ];
+        for (encoded, expected) in cases {
+            assert_eq!(decode_header(encoded), expected, "{}", encoded);
+        }
+
+        // Whitespace between encoded-words goes, around plain text it stays
+        assert_eq!(
+            decode_header("=?UTF-8?Q?RE:_Last?= \r\n =?UTF-8?Q?Signal?= Notification"),
+            "RE: LastSignal Notification"
+        );
+        assert_eq!(decode_header("RE: =?ISO-8859-1?Q?Gr=FC=DFe?= von Joe"), "RE: Grüße von Joe");
+
+        // Plain subjects, and anything that doesn't decode, are left alone
+        for subject in ["RE: LastSignal Notification", "1 =? 2", "=?unknown-charset?Q?Hi?=", "=?UTF-8?X?Hi?=", "=?UTF-8?Q?=ZZ?="] {
+            assert_eq!(decode_header(subject), subject);
+        }
+    }
+
+    #[test]
+    fn test_encoded_subject_is_a_reply() {
+        let header = "Subject: =?UTF-8?B?UkU6IExhc3RTaWduYWwgTm90aWZpY2F0aW9uIDIwMjYtMTAtMTUgMDk6MzA6MDAgVVRD?=\r\n";
+        let subject = decode_header(&header_field(header, "Subject").unwrap());
+        assert!(is_checkin_reply(&subject, &[], &[], "RE: LastSignal Notification"));
+
+        // Apple Mail and Outlook encode a localised subject word by word
+        let subject = decode_header("=?iso-8859-1?Q?RE:_LastSignal_Notification_=28R=FCckmeldung=29?=");
+        assert!(is_checkin_reply(&subject, &[], &[], "RE: LastSignal Notification"));
+        assert!(!is_checkin_reply(&decode_header("=?UTF-8?B?UkU6IEx1bmNoPw==?="), &[], &[], "RE: LastSignal Notification"));
+    }
+
+    #[test]
+    fn test_request_display_names() {
+        let mut config = test_config();
+        config.insert("to_name".to_string(), "Joe".to_string());
+        config.insert("from_name".to_string(), "LastSignal for Joe".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap().with_data_directory(None);
+        let email = output.build_request("Are you OK?", "<abc.lastsignal@example.org>").unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+        assert!(formatted.contains("From: \"LastSignal for Joe\" <lastsignal@example.org>\r\n"), "{}", formatted);
+        assert!(formatted.contains("To: Joe <me@example.com>\r\n"), "{}", formatted);
+        assert!(formatted.contains("Message-ID: <abc.lastsignal@example.org>\r\n"));
+
+        // The name doesn't change whose replies count
+        assert_eq!(output.allowed_senders, vec!["me@example.com"]);
+
+        config.insert("to_name".to_string(), "Joe\nBcc: x@example.com".to_string());
+        assert!(BidirectionalEmailOutput::new(&config).is_err());
+    }
+
+    #[test]
+    fn test_reply_subject_follows_subject() {
+        let mut config = test_config();
+        config.insert("subject".to_string(), "Are you OK, Joe? ({type}) {timestamp}".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert_eq!(output.reply_subject, "RE: Are you OK, Joe? (checkin)");
+        assert!(is_checkin_reply("Re: Are you ok, Joe? (checkin) 2026-10-15 09:30:00 UTC", &[], &[], &output.reply_subject));
+        assert!(!is_checkin_reply("Re: LastSignal Notification", &[], &[], &output.reply_subject));
+
+        // `subject` wins over the older `subject_prefix`
+        config.insert("subject_prefix".to_string(), "MyApp".to_string());
+        assert_eq!(BidirectionalEmailOutput::new(&config).unwrap().reply_subject, "RE: Are you OK, Joe? (checkin)");
+
+        assert_eq!(reply_subject("Say \"hi\"").unwrap(), "RE: Say \"hi\"");
+
+        // Replies to a subject that starts with the time have nothing fixed to search for
+        config.insert("subject".to_string(), "{timestamp} check-in".to_string());
+        assert!(BidirectionalEmailOutput::new(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sent_ids_are_cleared_by_watermark() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = BidirectionalEmailOutput::new(&test_config()).unwrap().with_data_directory(Some(temp_dir.path()));
+        assert_eq!(output.sent_ids_file, Some(temp_dir.path().join("email_threads_me_example.com.json")));
+
+        let earlier = Utc::now() - chrono::Duration::hours(2);
+        let later = Utc::now() - chrono::Duration::hours(1);
+        output.record_sent("<first@example.org>".to_string(), earlier).unwrap();
+        output.record_sent("<second@example.org>".to_string(), later).unwrap();
+        assert_eq!(output.sent_ids().unwrap(), vec!["<first@example.org>", "<second@example.org>"]);
+
+        output.mark_processed_until(earlier + chrono::Duration::minutes(30)).await.unwrap();
+        assert_eq!(output.sent_ids().unwrap(), vec!["<second@example.org>"]);
+
+        // Without a data directory nothing is tracked
+        let untracked = BidirectionalEmailOutput::new(&test_config()).unwrap();
+        untracked.record_sent("<first@example.org>".to_string(), earlier).unwrap();
+        assert!(untracked.sent_ids().unwrap().is_empty());
+    }
+
+    fn found(timestamp: DateTime<Utc>) -> CheckinResponse {
+        CheckinResponse::Found {
+            timestamp,
+            subject: "AW: LastSignal Notification".to_string(),
+            from: "me@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_imap_idle_config() {
+        let output = BidirectionalEmailOutput::new(&test_config()).unwrap();
+        assert!(output.idle.is_none());
+        assert!(output.reply_signal().is_none());
+
+        let mut config = test_config();
+        config.insert("imap_idle".to_string(), "true".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert!(output.reply_signal().is_some());
+
+        config.insert("imap_idle".to_string(), "yes".to_string());
+        assert!(BidirectionalEmailOutput::new(&config).is_err());
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_idle_queue_wakes_and_drains() {
+        let state = IdleState::default();
+        let earlier = Utc::now() - chrono::Duration::hours(2);
+        let later = Utc::now() - chrono::Duration::hours(1);
+        state.queue_replies(vec![found(earlier), found(later)]);
+
+        // The notification is kept until someone waits for it
+        tokio::time::timeout(std::time::Duration::from_secs(1), state.replies_queued.notified()).await.unwrap();
+
+        let replies = state.take_queued(Some(earlier));
+        assert!(matches!(replies.as_slice(), [CheckinResponse::Found { timestamp, .. }] if *timestamp == later));
+        assert!(state.take_queued(None).is_empty());
+    }
+
+    #[test]
+    fn test_primary_polled_once_after_idle_starts() {
+        let state = IdleState::default();
+        assert!(!state.primary_watched());
+
+        let watching = Utc::now();
+        state.set_watching_since(Some(watching));
+        *state.last_poll.lock().unwrap() = Some(watching - chrono::Duration::minutes(5));
+        assert!(!state.primary_watched());
+
+        *state.last_poll.lock().unwrap() = Some(watching + chrono::Duration::seconds(1));
+        assert!(state.primary_watched());
+
+        // A dropped session goes back to polling
+        state.set_watching_since(None);
+        assert!(!state.primary_watched());
+    }
+
+    #[test]
+    fn test_imap_mailbox_and_processed_action_config() {
+        let output = BidirectionalEmailOutput::new(&test_config()).unwrap();
+        assert_eq!(output.imap_accounts[0].folder, "INBOX");
+        assert_eq!(output.processed_action, ProcessedAction::None);
+
+        let mut config = test_config();
+        config.insert("imap_mailbox".to_string(), "[Gmail]/All Mail".to_string());
+        config.insert("processed_action".to_string(), "move: LastSignal/Processed".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert_eq!(output.imap_accounts[0].folder, "[Gmail]/All Mail");
+        assert_eq!(output.processed_action, ProcessedAction::Move("LastSignal/Processed".to_string()));
+
+        assert_eq!(ProcessedAction::parse("none").unwrap(), ProcessedAction::None);
+        assert_eq!(ProcessedAction::parse("mark_read").unwrap(), ProcessedAction::MarkRead);
+        for invalid in ["move:", "move: ", "archive", "Mark_Read"] {
+            assert!(ProcessedAction::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_processed_action_steps() {
+        assert!(ProcessedAction::None.steps(true, true).is_empty());
+        assert_eq!(ProcessedAction::MarkRead.steps(false, false), vec![ImapStep::MarkSeen]);
+
+        let archive = ProcessedAction::Move("Archive".to_string());
+        assert_eq!(archive.steps(true, false), vec![ImapStep::Move("Archive".to_string())]);
+        assert_eq!(archive.steps(false, true), vec![
+            ImapStep::Copy("Archive".to_string()),
+            ImapStep::MarkDeleted,
+            ImapStep::UidExpunge,
+        ]);
+        assert_eq!(archive.steps(false, false), vec![
+            ImapStep::Copy("Archive".to_string()),
+            ImapStep::MarkDeleted,
+            ImapStep::Expunge,
+        ]);
+    }
+
+    #[test]
+    fn test_pending_replies_taken_up_to_watermark() {
+        let inbox = BidirectionalEmailOutput::new(&test_config()).unwrap().imap_accounts[0].clone();
+        let work = ImapAccount { host: "imap.work.example.com".to_string(), ..inbox.clone() };
+        let earlier = Utc::now() - chrono::Duration::hours(2);
+        let later = Utc::now() - chrono::Duration::hours(1);
+
+        let mut pending = Vec::new();
+        for (account, uid, timestamp) in [(&inbox, 7, earlier), (&inbox, 7, earlier), (&work, 3, earlier), (&inbox, 9, later)] {
+            track_pending(&mut pending, PendingReply { account: account.clone(), uid, timestamp });
+        }
+        assert_eq!(pending.len(), 3);
+
+        let processed = take_processed(&mut pending, earlier);
+        assert_eq!(processed, vec![(inbox.clone(), vec![7]), (work, vec![3])]);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].uid, 9);
+    }
+
+    #[test]
+    fn test_smtp_and_imap_security() {
+        let output = BidirectionalEmailOutput::new(&test_config()).unwrap();
+        assert_eq!(output.smtp_security, SmtpSecurity::StartTls);
+        assert_eq!(output.imap_accounts[0].security, ImapSecurity::Tls);
+
+        let mut config = test_config();
+        config.insert("smtp_security".to_string(), "tls".to_string());
+        config.insert("imap_security".to_string(), "starttls".to_string());
+        let output = BidirectionalEmailOutput::new(&config).unwrap();
+        assert_eq!(output.smtp_security, SmtpSecurity::Tls);
+        assert_eq!(output.imap_accounts[0].security, ImapSecurity::StartTls);
+
+        config.insert("imap_security".to_string(), "none".to_string());
+        assert!(BidirectionalEmailOutput::new(&config).is_err());
+
+        let account: ImapAccount = toml::from_str(r#"
+host = "imap.example.com"
+port = 143
+username = "me"
+password = "secret"
+security = "starttls"
+"#).unwrap();
+        assert_eq!(account.security, ImapSecurity::StartTls);
+    }
+}
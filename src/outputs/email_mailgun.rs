@@ -0,0 +1,229 @@
+use super::{Output, OutputResult};
+use crate::formatting::{self, MessageFormat};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Same subject as `EmailOutput`, so replies are recognised by the IMAP checker
+const SUBJECT: &str = "LastSignal Notification";
+
+/// Mailgun's US region. EU domains use `https://api.eu.mailgun.net`.
+const DEFAULT_BASE_URL: &str = "https://api.mailgun.net";
+
+#[derive(Debug, Clone)]
+pub struct EmailMailgunOutput {
+    api_key: String,
+    domain: String,
+    from: String,
+    to: String,
+    base_url: Url,
+    client: Client,
+}
+
+impl EmailMailgunOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["api_key", "domain", "from", "to", "base_url"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let api_key = config
+            .get("api_key")
+            .context("Missing 'api_key' field in email_mailgun config")?
+            .clone();
+
+        let domain = config
+            .get("domain")
+            .context("Missing 'domain' field in email_mailgun config")?
+            .clone();
+        if domain.is_empty() || domain.contains(['/', ' ', '@']) {
+            anyhow::bail!("Invalid 'domain' in email_mailgun config: '{}' is not a sending domain such as mg.example.com", domain);
+        }
+
+        let from = config
+            .get("from")
+            .context("Missing 'from' field in email_mailgun config")?
+            .clone();
+        from.parse::<lettre::message::Mailbox>().context("Invalid 'from' email address in email_mailgun config")?;
+
+        let to = config
+            .get("to")
+            .context("Missing 'to' field in email_mailgun config")?
+            .clone();
+        to.parse::<lettre::message::Mailbox>().context("Invalid 'to' email address in email_mailgun config")?;
+
+        let base_url = config.get("base_url").map(String::as_str).unwrap_or(DEFAULT_BASE_URL);
+        let base_url = Url::parse(base_url).context("Invalid 'base_url' in email_mailgun config")?;
+        if base_url.scheme() != "https" {
+            anyhow::bail!("email_mailgun 'base_url' must use https");
+        }
+
+        Ok(EmailMailgunOutput {
+            api_key,
+            domain,
+            from,
+            to,
+            base_url,
+            client: Client::new(),
+        })
+    }
+
+    fn api_url(&self, path: &str) -> Result<Url> {
+        self.base_url.join(path).context("Invalid Mailgun API URL")
+    }
+
+    /// Markdown messages get an HTML part alongside the plain text one, like
+    /// `EmailOutput`'s multipart emails
+    fn message_form(&self, message: &str, format: MessageFormat) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("from", self.from.clone()),
+            ("to", self.to.clone()),
+            ("subject", SUBJECT.to_string()),
+            ("text", formatting::to_plain_text(message, format)),
+        ];
+        if format == MessageFormat::Markdown {
+            form.push(("html", formatting::to_html(message, format)));
+        }
+        form
+    }
+
+    fn failure_message(&self, status: reqwest::StatusCode, body: &str) -> String {
+        let message = serde_json::from_str::<Value>(body)
+            .ok()
+            .and_then(|body| body.get("message").and_then(Value::as_str).map(str::to_string))
+            .unwrap_or_else(|| body.trim().to_string());
+
+        if is_unauthorized_recipient(&message) {
+            format!(
+                "Mailgun refused to send to {} because {} is a sandbox domain, which only sends to authorized recipients. \
+                 Add {} under Authorized Recipients in the Mailgun dashboard and confirm the invitation, or send from a verified domain",
+                self.to, self.domain, self.to
+            )
+        } else {
+            format!("Mailgun HTTP {}: {}", status, message)
+        }
+    }
+}
+
+/// Mailgun's answer when a sandbox domain sends to an address not on its
+/// authorized recipients list
+fn is_unauthorized_recipient(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("authorized recipients") || message.contains("free accounts are for test purposes only")
+}
+
+#[async_trait]
+impl Output for EmailMailgunOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        self.send_formatted(message, MessageFormat::Plain).await
+    }
+
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        let url = self.api_url(&format!("v3/{}/messages", self.domain))?;
+        let response = match self
+            .client
+            .post(url)
+            .basic_auth("api", Some(&self.api_key))
+            .form(&self.message_form(message, format))
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return Ok(OutputResult::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        let text = response.text().await.context("Failed to read Mailgun response")?;
+        if status_code.is_success() {
+            Ok(OutputResult::Success)
+        } else {
+            Ok(OutputResult::Failed(self.failure_message(status_code, &text)))
+        }
+    }
+
+    /// The key is valid and can see the sending domain
+    async fn health_check(&self) -> Result<bool> {
+        let url = self.api_url(&format!("v3/domains/{}", self.domain))?;
+        match self.client.get(url).basic_auth("api", Some(&self.api_key)).send().await {
+            Ok(resp) if resp.status().is_success() => Ok(true),
+            Ok(resp) => {
+                tracing::debug!("Mailgun health check HTTP error: {}", resp.status());
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("Mailgun health check HTTP error: {}", redact::redact(&e.to_string()));
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "email_mailgun"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("api_key".to_string(), "key-0123456789".to_string());
+        config.insert("domain".to_string(), "mg.example.com".to_string());
+        config.insert("from".to_string(), "LastSignal <lastsignal@mg.example.com>".to_string());
+        config.insert("to".to_string(), "me@example.org".to_string());
+        config
+    }
+
+    #[test]
+    fn test_email_mailgun_output_creation() {
+        let output = EmailMailgunOutput::new(&test_config()).unwrap();
+        assert_eq!(output.api_url("v3/mg.example.com/messages").unwrap().as_str(), "https://api.mailgun.net/v3/mg.example.com/messages");
+
+        let mut config = test_config();
+        config.insert("base_url".to_string(), "https://api.eu.mailgun.net".to_string());
+        let output = EmailMailgunOutput::new(&config).unwrap();
+        assert_eq!(output.api_url("v3/domains/mg.example.com").unwrap().as_str(), "https://api.eu.mailgun.net/v3/domains/mg.example.com");
+
+        for key in ["api_key", "domain", "from", "to"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(EmailMailgunOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+
+        for (key, value) in [("domain", "https://mg.example.com"), ("to", "not an address"), ("base_url", "http://api.mailgun.net")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(EmailMailgunOutput::new(&config).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_message_form_matches_smtp_email() {
+        let output = EmailMailgunOutput::new(&test_config()).unwrap();
+
+        let form = output.message_form("Call **me**", MessageFormat::Plain);
+        assert!(form.contains(&("subject", "LastSignal Notification".to_string())));
+        assert!(form.contains(&("text", "Call **me**".to_string())));
+        assert!(!form.iter().any(|(key, _)| *key == "html"));
+
+        let form = output.message_form("Call **me**", MessageFormat::Markdown);
+        assert!(form.iter().any(|(key, value)| *key == "html" && value.contains("<strong>me</strong>")));
+    }
+
+    #[test]
+    fn test_sandbox_recipient_error_is_actionable() {
+        let output = EmailMailgunOutput::new(&test_config()).unwrap();
+
+        let body = r#"{"message": "Domain sandbox123.mailgun.org is not allowed to send: Free accounts are for test purposes only. Please upgrade or add the address to authorized recipients in Account Settings."}"#;
+        let error = output.failure_message(reqwest::StatusCode::FORBIDDEN, body);
+        assert!(error.contains("Add me@example.org under Authorized Recipients"), "{}", error);
+
+        let error = output.failure_message(reqwest::StatusCode::UNAUTHORIZED, "Forbidden");
+        assert_eq!(error, "Mailgun HTTP 401 Unauthorized: Forbidden");
+
+        let error = output.failure_message(reqwest::StatusCode::BAD_REQUEST, r#"{"message": "'from' parameter is not a valid address. please check documentation"}"#);
+        assert_eq!(error, "Mailgun HTTP 400 Bad Request: 'from' parameter is not a valid address. please check documentation");
+    }
+}
@@ -0,0 +1,211 @@
+use super::aws::{self, Credentials};
+use super::{Output, OutputResult};
+use crate::formatting::{self, MessageFormat};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// Same subject as `EmailOutput`, so replies are recognised by the IMAP checker
+const SUBJECT: &str = "LastSignal Notification";
+
+const API_VERSION: &str = "2010-12-01";
+
+/// SES signs requests under this service name, whatever the endpoint host
+const SIGNING_SERVICE: &str = "ses";
+
+#[derive(Debug, Clone)]
+pub struct EmailSesOutput {
+    credentials: Credentials,
+    region: String,
+    from: String,
+    to: String,
+    client: Client,
+}
+
+impl EmailSesOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["access_key_id", "secret_access_key", "region", "from", "to"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let access_key_id = config
+            .get("access_key_id")
+            .context("Missing 'access_key_id' field in email_ses config")?
+            .clone();
+
+        let secret_access_key = config
+            .get("secret_access_key")
+            .context("Missing 'secret_access_key' field in email_ses config")?
+            .clone();
+
+        let region = config
+            .get("region")
+            .context("Missing 'region' field in email_ses config")?
+            .clone();
+        aws::validate_region(&region).context("Invalid 'region' in email_ses config")?;
+
+        let from = config
+            .get("from")
+            .context("Missing 'from' field in email_ses config")?
+            .clone();
+        from.parse::<lettre::message::Mailbox>().context("Invalid 'from' email address in email_ses config")?;
+
+        let to = config
+            .get("to")
+            .context("Missing 'to' field in email_ses config")?
+            .clone();
+        to.parse::<lettre::message::Mailbox>().context("Invalid 'to' email address in email_ses config")?;
+
+        Ok(EmailSesOutput {
+            credentials: Credentials { access_key_id, secret_access_key },
+            region,
+            from,
+            to,
+            client: Client::new(),
+        })
+    }
+
+    fn host(&self) -> String {
+        format!("email.{}.amazonaws.com", self.region)
+    }
+
+    /// `SendEmail` parameters. Markdown messages get an HTML body alongside
+    /// the plain text one, like `EmailOutput`'s multipart emails.
+    fn send_email_params(&self, message: &str, format: MessageFormat) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("Action", "SendEmail".to_string()),
+            ("Version", API_VERSION.to_string()),
+            ("Source", self.from.clone()),
+            ("Destination.ToAddresses.member.1", self.to.clone()),
+            ("Message.Subject.Data", SUBJECT.to_string()),
+            ("Message.Subject.Charset", "UTF-8".to_string()),
+            ("Message.Body.Text.Data", formatting::to_plain_text(message, format)),
+            ("Message.Body.Text.Charset", "UTF-8".to_string()),
+        ];
+        if format == MessageFormat::Markdown {
+            params.push(("Message.Body.Html.Data", formatting::to_html(message, format)));
+            params.push(("Message.Body.Html.Charset", "UTF-8".to_string()));
+        }
+        params
+    }
+
+    /// Calls a Query API action. `Ok(Err)` carries SES's own error, unchanged.
+    async fn call(&self, params: &[(&str, String)]) -> Result<std::result::Result<String, String>> {
+        let params: Vec<(&str, &str)> = params.iter().map(|(key, value)| (*key, value.as_str())).collect();
+        let body = aws::form_body(&params);
+        let host = self.host();
+
+        let mut request = self
+            .client
+            .post(format!("https://{}/", host))
+            .header("Content-Type", aws::FORM_CONTENT_TYPE)
+            .body(body.clone());
+        for (name, value) in aws::sign_form_post(&self.credentials, &self.region, SIGNING_SERVICE, &host, &body, Utc::now()) {
+            request = request.header(name, value);
+        }
+
+        let response = match request.send().await {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Err(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        let text = response.text().await.context("Failed to read SES response")?;
+        if status_code.is_success() {
+            Ok(Ok(text))
+        } else {
+            Ok(Err(format!("SES HTTP {}: {}", status_code, aws::error_message(&text))))
+        }
+    }
+}
+
+#[async_trait]
+impl Output for EmailSesOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        self.send_formatted(message, MessageFormat::Plain).await
+    }
+
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        match self.call(&self.send_email_params(message, format)).await? {
+            Ok(_) => Ok(OutputResult::Success),
+            Err(error) => Ok(OutputResult::Failed(error)),
+        }
+    }
+
+    /// The keys are valid and sending is enabled for the account in this region
+    async fn health_check(&self) -> Result<bool> {
+        let params = [("Action", "GetAccountSendingEnabled".to_string()), ("Version", API_VERSION.to_string())];
+        match self.call(&params).await {
+            Ok(Ok(body)) if aws::xml_text(&body, "Enabled") == Some("true") => Ok(true),
+            Ok(Ok(_)) => {
+                tracing::debug!("SES health check: sending is disabled for this account in {}", self.region);
+                Ok(false)
+            }
+            Ok(Err(error)) => {
+                tracing::debug!("SES health check failed: {}", error);
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("SES health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "email_ses"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("access_key_id".to_string(), "AKIDEXAMPLE".to_string());
+        config.insert("secret_access_key".to_string(), "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string());
+        config.insert("region".to_string(), "eu-west-2".to_string());
+        config.insert("from".to_string(), "lastsignal@example.com".to_string());
+        config.insert("to".to_string(), "me@example.org".to_string());
+        config
+    }
+
+    fn param<'a>(params: &'a [(&'static str, String)], name: &str) -> Option<&'a str> {
+        params.iter().find(|(key, _)| *key == name).map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_email_ses_output_creation() {
+        let output = EmailSesOutput::new(&test_config()).unwrap();
+        assert_eq!(output.host(), "email.eu-west-2.amazonaws.com");
+
+        for key in ["access_key_id", "secret_access_key", "region", "from", "to"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(EmailSesOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+
+        for (key, value) in [("region", "Europe"), ("to", "not an address")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(EmailSesOutput::new(&config).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_send_email_params_match_smtp_email() {
+        let output = EmailSesOutput::new(&test_config()).unwrap();
+
+        let params = output.send_email_params("Call **me**", MessageFormat::Plain);
+        assert_eq!(param(&params, "Message.Subject.Data"), Some("LastSignal Notification"));
+        assert_eq!(param(&params, "Destination.ToAddresses.member.1"), Some("me@example.org"));
+        assert_eq!(param(&params, "Message.Body.Text.Data"), Some("Call **me**"));
+        assert_eq!(param(&params, "Message.Body.Html.Data"), None);
+
+        let params = output.send_email_params("Call **me**", MessageFormat::Markdown);
+        assert!(param(&params, "Message.Body.Html.Data").unwrap().contains("<strong>me</strong>"));
+    }
+}
@@ -0,0 +1,366 @@
+use super::{Output, OutputResult};
+use crate::duration_parser::ConfigDuration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// Placeholder in `args` replaced by the message. Without it the message is
+/// written to the command's stdin instead.
+const MESSAGE_PLACEHOLDER: &str = "{message}";
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Longest stderr excerpt kept in a failure, in characters
+const MAX_STDERR_CHARS: usize = 1000;
+
+/// Runs a local command for each message, for delivery mechanisms LastSignal
+/// has no output for, such as signal-cli or a home automation script
+#[derive(Debug, Clone)]
+pub struct ExecOutput {
+    command: String,
+    args: Vec<String>,
+    health_command: Option<Vec<String>>,
+    timeout: Duration,
+}
+
+impl ExecOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["command", "args", "health_command", "timeout"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let command = config
+            .get("command")
+            .context("Missing 'command' field in exec config")?
+            .clone();
+        if command.trim().is_empty() {
+            anyhow::bail!("exec 'command' is empty");
+        }
+
+        let args = match config.get("args") {
+            Some(args) => split_args(args).context("Invalid 'args' in exec config")?,
+            None => Vec::new(),
+        };
+
+        let health_command = config.get("health_command")
+            .map(|health_command| split_args(health_command).context("Invalid 'health_command' in exec config"))
+            .transpose()?;
+        if health_command.as_ref().is_some_and(Vec::is_empty) {
+            anyhow::bail!("exec 'health_command' is empty");
+        }
+
+        let timeout = match config.get("timeout") {
+            Some(timeout) => timeout.parse::<ConfigDuration>()
+                .context("Invalid 'timeout' in exec config")?
+                .as_duration(),
+            None => Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+        };
+
+        Ok(ExecOutput {
+            command,
+            args,
+            health_command,
+            timeout,
+        })
+    }
+
+    fn passes_message_in_args(&self) -> bool {
+        self.args.iter().any(|arg| arg.contains(MESSAGE_PLACEHOLDER))
+    }
+
+    fn render_args(&self, message: &str) -> Vec<String> {
+        self.args.iter().map(|arg| arg.replace(MESSAGE_PLACEHOLDER, message)).collect()
+    }
+}
+
+/// Runs `program` to completion, writing `stdin` to it if given.
+/// The child is killed if it outlives `timeout`.
+pub async fn run_command(program: &str, args: &[String], stdin: Option<&str>, timeout: Duration) -> OutputResult {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => return OutputResult::Failed(format!("Failed to start '{}': {}", program, e)),
+    };
+
+    let child_stdin = child.stdin.take();
+    let write_stdin = async {
+        if let (Some(mut child_stdin), Some(input)) = (child_stdin, stdin) {
+            // A command that exits without reading its input has still run
+            if let Err(e) = child_stdin.write_all(input.as_bytes()).await
+                && e.kind() != std::io::ErrorKind::BrokenPipe
+            {
+                tracing::debug!("Failed to write message to '{}' stdin: {}", program, e);
+            }
+            // Dropping stdin closes it, so the command sees end of input
+        }
+    };
+
+    // Dropping the child on timeout kills it
+    let output = match tokio::time::timeout(timeout, async {
+        let ((), output) = tokio::join!(write_stdin, child.wait_with_output());
+        output
+    })
+    .await
+    {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => return OutputResult::Failed(format!("Failed to wait for '{}': {}", program, e)),
+        Err(_) => return OutputResult::Failed(format!("'{}' timed out after {:?}", program, timeout)),
+    };
+
+    if output.status.success() {
+        return OutputResult::Success;
+    }
+
+    let stderr = stderr_excerpt(&output.stderr);
+    let status = match output.status.code() {
+        Some(code) => format!("exit code {}", code),
+        None => "a signal".to_string(),
+    };
+    if stderr.is_empty() {
+        OutputResult::Failed(format!("'{}' failed with {}", program, status))
+    } else {
+        OutputResult::Failed(format!("'{}' failed with {}: {}", program, status, stderr))
+    }
+}
+
+/// Splits a command line into arguments like a POSIX shell would, honouring
+/// single quotes, double quotes and backslash escapes, without expanding anything
+pub fn split_args(line: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("Unterminated single quote in '{}'", line),
+                    }
+                }
+            }
+            '"' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => anyhow::bail!("Unterminated double quote in '{}'", line),
+                        },
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("Unterminated double quote in '{}'", line),
+                    }
+                }
+            }
+            '\\' => {
+                in_arg = true;
+                match chars.next() {
+                    Some(c) => current.push(c),
+                    None => anyhow::bail!("Trailing backslash in '{}'", line),
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                in_arg = true;
+                current.push(c);
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+/// The end of stderr, where the error usually is
+fn stderr_excerpt(stderr: &[u8]) -> String {
+    let stderr = String::from_utf8_lossy(stderr);
+    let stderr = stderr.trim();
+    let chars = stderr.chars().count();
+    if chars <= MAX_STDERR_CHARS {
+        return stderr.to_string();
+    }
+    format!("...{}", stderr.chars().skip(chars - MAX_STDERR_CHARS).collect::<String>())
+}
+
+/// Where `command` would be run from: itself if it is a path, otherwise the
+/// first match on `PATH`
+fn find_executable(command: &str) -> Option<PathBuf> {
+    if command.contains(std::path::MAIN_SEPARATOR) {
+        let path = PathBuf::from(command);
+        return is_executable(&path).then_some(path);
+    }
+
+    std::env::var_os("PATH").and_then(|paths| {
+        std::env::split_paths(&paths)
+            .map(|dir| dir.join(command))
+            .find(|path| is_executable(path))
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[async_trait]
+impl Output for ExecOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let stdin = if self.passes_message_in_args() { None } else { Some(message) };
+        Ok(run_command(&self.command, &self.render_args(message), stdin, self.timeout).await)
+    }
+
+    /// Runs `health_command` if set, which must exit with 0. Otherwise checks
+    /// that `command` exists and is executable, without running it.
+    async fn health_check(&self) -> Result<bool> {
+        let Some(health_command) = &self.health_command else {
+            let found = find_executable(&self.command).is_some();
+            if !found {
+                tracing::debug!("Exec health check: '{}' is not an executable file or on PATH", self.command);
+            }
+            return Ok(found);
+        };
+
+        match run_command(&health_command[0], &health_command[1..], None, self.timeout).await {
+            OutputResult::Success => Ok(true),
+            result => {
+                tracing::debug!("Exec health check failed: {}", result.error_message().unwrap_or_default());
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "exec"
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn exec_output(command: &str, args: &str) -> ExecOutput {
+        let mut config = HashMap::new();
+        config.insert("command".to_string(), command.to_string());
+        config.insert("args".to_string(), args.to_string());
+        ExecOutput::new(&config).unwrap()
+    }
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(split_args("  -a  b ").unwrap(), vec!["-a", "b"]);
+        assert_eq!(
+            split_args(r#"send -m '{message}' --title "Last \"Signal\"" a\ b ''"#).unwrap(),
+            vec!["send", "-m", "{message}", "--title", "Last \"Signal\"", "a b", ""]
+        );
+        assert_eq!(split_args(r#""$HOME \n""#).unwrap(), vec!["$HOME \\n"]);
+        assert!(split_args("'unterminated").is_err());
+        assert!(split_args("\"unterminated").is_err());
+        assert!(split_args("trailing\\").is_err());
+    }
+
+    #[test]
+    fn test_exec_output_config() {
+        assert!(ExecOutput::new(&HashMap::new()).is_err());
+
+        let output = exec_output("signal-cli", "send -m {message} +447700900123");
+        assert!(output.passes_message_in_args());
+        assert_eq!(output.timeout, Duration::from_secs(30));
+        // The message stays a single argument whatever it contains
+        assert_eq!(
+            output.render_args("it's \"urgent\" now"),
+            vec!["send", "-m", "it's \"urgent\" now", "+447700900123"]
+        );
+        assert!(!exec_output("notify", "--stdin").passes_message_in_args());
+    }
+
+    #[tokio::test]
+    async fn test_message_is_piped_to_stdin() {
+        let output = exec_output("sh", r#"-c 'test "$(cat)" = "Please check in
+today"'"#);
+        assert!(output.send_message("Please check in\ntoday").await.unwrap().is_success());
+        assert!(!output.send_message("something else").await.unwrap().is_success());
+
+        // Commands that ignore their input still succeed
+        let output = exec_output("true", "");
+        assert!(output.send_message(&"x".repeat(1 << 20)).await.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_message_is_substituted_into_args() {
+        let output = exec_output("sh", r#"-c 'test "$0" = "it'\''s \$HOME" && test -z "$(cat)"' {message}"#);
+        assert!(output.send_message("it's $HOME").await.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_failure_includes_exit_code_and_stderr() {
+        let output = exec_output("sh", "-c 'echo no route to phone >&2; exit 3'");
+        match output.send_message("hi").await.unwrap() {
+            OutputResult::Failed(error) => {
+                assert!(error.contains("exit code 3"), "{}", error);
+                assert!(error.ends_with("no route to phone"), "{}", error);
+            }
+            other => panic!("expected a failure, got {:?}", other),
+        }
+
+        let output = exec_output("/nonexistent/lastsignal-notify", "");
+        assert!(matches!(output.send_message("hi").await.unwrap(), OutputResult::Failed(error) if error.contains("Failed to start")));
+    }
+
+    #[tokio::test]
+    async fn test_slow_command_is_killed_after_timeout() {
+        let mut output = exec_output("sleep", "10");
+        output.timeout = Duration::from_millis(200);
+
+        let started = std::time::Instant::now();
+        match output.send_message("hi").await.unwrap() {
+            OutputResult::Failed(error) => assert!(error.contains("timed out"), "{}", error),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        assert!(exec_output("sh", "").health_check().await.unwrap());
+        assert!(!exec_output("lastsignal-no-such-command", "").health_check().await.unwrap());
+
+        let mut output = exec_output("lastsignal-no-such-command", "");
+        output.health_command = Some(split_args("sh -c 'exit 0'").unwrap());
+        assert!(output.health_check().await.unwrap());
+        output.health_command = Some(split_args("sh -c 'exit 1'").unwrap());
+        assert!(!output.health_check().await.unwrap());
+    }
+}
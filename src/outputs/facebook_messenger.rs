@@ -1,28 +1,359 @@
 use super::{Output, OutputResult};
+use crate::formatting;
+use crate::oauth::{FacebookPageToken, FACEBOOK_TOKEN_FILE};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::redact;
+use crate::signing::hmac_sha256;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use hmac::Mac;
 use reqwest::Client;
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Same port `facebook-auth` listens on, so one tunnel serves both
+const DEFAULT_WEBHOOK_PORT: u16 = 3001;
 
 #[derive(Debug, Clone)]
 pub struct FacebookMessengerOutput {
     user_id: String,
-    access_token: String,
+    access_token: PageToken,
     client: Client,
+    /// Only check the page token, not that `user_id` can be reached with it
+    shallow_health_check: bool,
+    /// Receives replies, when the output is bidirectional
+    webhook: Option<Arc<Webhook>>,
+}
+
+/// Where the page token comes from
+#[derive(Debug, Clone, PartialEq)]
+enum PageToken {
+    Configured(String),
+    /// `access_token = "stored"`: the long-lived token `facebook-auth` keeps
+    /// in this data directory, read on each use so a new one is picked up
+    Stored(PathBuf),
+}
+
+/// Why a page token needs renewing, if it expires soon
+fn expiry_warning(token: &FacebookPageToken, now: DateTime<Utc>) -> Option<String> {
+    let expires_at = token.expires_at.filter(|_| token.expires_soon(now))?;
+    let when = if expires_at <= now { "expired" } else { "expires" };
+    Some(format!(
+        "Facebook page token {} at {}. Run 'lastsignal facebook-auth' with --app-id and --app-secret again to renew it",
+        when,
+        expires_at.format("%Y-%m-%d %H:%M UTC")
+    ))
+}
+
+/// Listens for Messenger webhook events. Meta only delivers to public HTTPS
+/// URLs, so this sits behind a tunnel or reverse proxy.
+#[derive(Debug)]
+struct Webhook {
+    address: SocketAddr,
+    state: WebhookState,
+    /// Started on the first check for responses, so commands like `status`
+    /// don't take the port from a running daemon
+    server: tokio::sync::Mutex<Option<(SocketAddr, JoinHandle<()>)>>,
+}
+
+#[derive(Debug, Clone)]
+struct WebhookState {
+    verify_token: String,
+    /// Checks `X-Hub-Signature-256`, when set
+    app_secret: Option<String>,
+    inbox: Arc<Inbox>,
+}
+
+/// Messages from `user_id` not yet processed as check-ins
+#[derive(Debug)]
+struct Inbox {
+    user_id: String,
+    /// Where the messages are kept across restarts
+    store_file: Option<PathBuf>,
+    messages: Mutex<Vec<StoredMessage>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+struct StoredMessage {
+    timestamp: DateTime<Utc>,
+    text: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct InboxFile {
+    messages: Vec<StoredMessage>,
+}
+
+impl Inbox {
+    fn load(user_id: &str, store_file: Option<PathBuf>) -> Result<Self> {
+        let messages = match &store_file {
+            Some(path) if path.exists() => {
+                let content = std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read facebook_messenger inbox: {:?}", path))?;
+                serde_json::from_str::<InboxFile>(&content)
+                    .context("Failed to parse facebook_messenger inbox")?
+                    .messages
+            }
+            _ => Vec::new(),
+        };
+        Ok(Inbox {
+            user_id: user_id.to_string(),
+            store_file,
+            messages: Mutex::new(messages),
+        })
+    }
+
+    fn save(&self, messages: &[StoredMessage]) -> Result<()> {
+        let Some(path) = &self.store_file else {
+            return Ok(());
+        };
+        let content = serde_json::to_string_pretty(&InboxFile { messages: messages.to_vec() })
+            .context("Failed to serialize facebook_messenger inbox")?;
+        std::fs::write(path, content).with_context(|| format!("Failed to write facebook_messenger inbox: {:?}", path))
+    }
+
+    fn record(&self, received: Vec<StoredMessage>) -> Result<()> {
+        let mut messages = self.messages.lock().unwrap_or_else(|e| e.into_inner());
+        messages.extend(received);
+        messages.sort_by_key(|message| message.timestamp);
+        self.save(&messages)
+    }
+
+    fn after(&self, after: Option<DateTime<Utc>>) -> Vec<StoredMessage> {
+        let messages = self.messages.lock().unwrap_or_else(|e| e.into_inner());
+        messages
+            .iter()
+            .filter(|message| after.is_none_or(|after| message.timestamp > after))
+            .cloned()
+            .collect()
+    }
+
+    /// Drops messages at or before `until`
+    fn prune(&self, until: DateTime<Utc>) -> Result<()> {
+        let mut messages = self.messages.lock().unwrap_or_else(|e| e.into_inner());
+        let before = messages.len();
+        messages.retain(|message| message.timestamp > until);
+        if messages.len() == before {
+            return Ok(());
+        }
+        self.save(&messages)
+    }
+}
+
+/// One inbox per sender, e.g. `facebook_messenger_1234567890.json`
+fn store_file_name(user_id: &str) -> String {
+    let name: String = user_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    format!("facebook_messenger_{}.json", name)
+}
+
+/// The `hub.challenge` to echo back when Meta verifies the webhook with our token
+fn verification_challenge<'a>(params: &'a HashMap<String, String>, verify_token: &str) -> Option<&'a str> {
+    let mode = params.get("hub.mode")?;
+    let token = params.get("hub.verify_token")?;
+    let challenge = params.get("hub.challenge")?;
+    (mode == "subscribe" && token == verify_token).then_some(challenge.as_str())
+}
+
+/// Checks `X-Hub-Signature-256: sha256=<hex HMAC-SHA256 of the body keyed with the app secret>`
+fn valid_signature(app_secret: &str, body: &[u8], header: Option<&str>) -> bool {
+    let Some(signature) = header
+        .and_then(|header| header.strip_prefix("sha256="))
+        .and_then(|signature| hex::decode(signature).ok())
+    else {
+        return false;
+    };
+    hmac_sha256(app_secret.as_bytes(), body).verify_slice(&signature).is_ok()
+}
+
+/// Messages from `user_id` in a webhook event. Echoes of our own messages
+/// and other senders are ignored.
+fn messages_from(payload: &Value, user_id: &str) -> Vec<StoredMessage> {
+    let Some(entries) = payload.get("entry").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| entry.get("messaging").and_then(Value::as_array))
+        .flatten()
+        .filter(|event| event.get("sender").and_then(|sender| sender.get("id")).and_then(Value::as_str) == Some(user_id))
+        .filter_map(|event| {
+            let message = event.get("message")?;
+            if message.get("is_echo").and_then(Value::as_bool) == Some(true) {
+                return None;
+            }
+            let timestamp = event
+                .get("timestamp")
+                .and_then(Value::as_i64)
+                .and_then(DateTime::from_timestamp_millis)
+                .unwrap_or_else(Utc::now);
+            let text = message.get("text").and_then(Value::as_str).unwrap_or("(attachment)").to_string();
+            Some(StoredMessage { timestamp, text })
+        })
+        .collect()
+}
+
+/// `GET /webhook`, Meta's subscription check
+async fn handle_verify(State(state): State<WebhookState>, Query(params): Query<HashMap<String, String>>) -> Response {
+    match verification_challenge(&params, &state.verify_token) {
+        Some(challenge) => challenge.to_string().into_response(),
+        None => {
+            tracing::warn!("Rejected Facebook webhook verification with a missing or wrong verify_token");
+            StatusCode::FORBIDDEN.into_response()
+        }
+    }
+}
+
+/// `POST /webhook`, message events
+async fn handle_event(State(state): State<WebhookState>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    if let Some(app_secret) = &state.app_secret {
+        let signature = headers.get("x-hub-signature-256").and_then(|value| value.to_str().ok());
+        if !valid_signature(app_secret, &body, signature) {
+            tracing::warn!("Rejected Facebook webhook event with a missing or wrong signature");
+            return StatusCode::FORBIDDEN;
+        }
+    }
+
+    let text = String::from_utf8_lossy(&body);
+    redact::log_payload("Facebook webhook event", "received", &text);
+    let Ok(payload) = serde_json::from_str::<Value>(&text) else {
+        return StatusCode::BAD_REQUEST;
+    };
+
+    let messages = messages_from(&payload, &state.inbox.user_id);
+    if messages.is_empty() {
+        return StatusCode::OK;
+    }
+    tracing::info!("Facebook Messenger: received {} message(s) from {}", messages.len(), state.inbox.user_id);
+    match state.inbox.record(messages) {
+        Ok(()) => StatusCode::OK,
+        Err(e) => {
+            // Meta retries events that aren't acknowledged
+            tracing::error!("Failed to store Facebook Messenger messages: {:#}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+impl Webhook {
+    fn new(config: &HashMap<String, String>, user_id: &str, data_directory: Option<&Path>) -> Result<Self> {
+        let verify_token = config
+            .get("verify_token")
+            .context("Missing 'verify_token' field in facebook_messenger config, needed to receive replies")?
+            .clone();
+        let port = match config.get("webhook_port") {
+            Some(port) => port
+                .parse::<u16>()
+                .with_context(|| format!("Invalid 'webhook_port' in facebook_messenger config: '{}'", port))?,
+            None => DEFAULT_WEBHOOK_PORT,
+        };
+        let bind: IpAddr = config
+            .get("webhook_bind")
+            .map(String::as_str)
+            .unwrap_or("127.0.0.1")
+            .parse()
+            .context("Invalid 'webhook_bind' in facebook_messenger config")?;
+
+        let store_file = data_directory.map(|dir| dir.join(store_file_name(user_id)));
+        Ok(Webhook {
+            address: SocketAddr::new(bind, port),
+            state: WebhookState {
+                verify_token,
+                app_secret: config.get("app_secret").cloned(),
+                inbox: Arc::new(Inbox::load(user_id, store_file)?),
+            },
+            server: tokio::sync::Mutex::new(None),
+        })
+    }
+
+    /// Starts the server if it isn't running, returning where it listens
+    async fn ensure_started(&self) -> Result<SocketAddr> {
+        let mut server = self.server.lock().await;
+        if let Some((local_addr, _)) = &*server {
+            return Ok(*local_addr);
+        }
+
+        let listener = tokio::net::TcpListener::bind(self.address)
+            .await
+            .with_context(|| format!("Failed to bind Facebook Messenger webhook to {}", self.address))?;
+        let local_addr = listener.local_addr().context("Failed to read Facebook Messenger webhook address")?;
+        let app = Router::new()
+            .route("/webhook", get(handle_verify).post(handle_event))
+            .with_state(self.state.clone());
+
+        tracing::info!("Facebook Messenger webhook listening on {}", local_addr);
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("Facebook Messenger webhook stopped: {}", e);
+            }
+        });
+        *server = Some((local_addr, task));
+        Ok(local_addr)
+    }
+}
+
+impl Drop for Webhook {
+    fn drop(&mut self) {
+        if let Some((_, task)) = self.server.get_mut().take() {
+            task.abort();
+        }
+    }
 }
 
 impl FacebookMessengerOutput {
-    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] =
+        &["user_id", "access_token", "verify_token", "webhook_port", "webhook_bind", "app_secret", "shallow_health_check"];
+
+    /// `access_token = "stored"` reads the token from `data_directory`
+    pub fn new(config: &HashMap<String, String>, data_directory: Option<&Path>) -> Result<Self> {
         let user_id = config
             .get("user_id")
             .context("Missing 'user_id' field in facebook_messenger config")?
             .clone();
 
-        let access_token = config
+        let access_token = match config
             .get("access_token")
             .context("Missing 'access_token' field in facebook_messenger config")?
-            .clone();
+            .as_str()
+        {
+            "stored" => {
+                let data_directory = data_directory
+                    .context("access_token = \"stored\" in facebook_messenger config needs a data directory")?;
+                match FacebookPageToken::load(data_directory) {
+                    Ok(token) => {
+                        if let Some(warning) = expiry_warning(&token, Utc::now()) {
+                            tracing::warn!("⚠️  {}", warning);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Facebook Messenger: {:#}", e),
+                }
+                PageToken::Stored(data_directory.to_path_buf())
+            }
+            token => PageToken::Configured(token.to_string()),
+        };
+
+        let shallow_health_check = match config.get("shallow_health_check").map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => anyhow::bail!("Invalid 'shallow_health_check' in facebook_messenger config: '{}' is not true or false", other),
+        };
 
         let client = Client::new();
 
@@ -30,27 +361,90 @@ impl FacebookMessengerOutput {
             user_id,
             access_token,
             client,
+            shallow_health_check,
+            webhook: None,
         })
     }
 
-    fn get_send_url(&self) -> String {
+    /// An output that also receives replies from `user_id` through a webhook
+    pub fn new_bidirectional(config: &HashMap<String, String>, data_directory: Option<&Path>) -> Result<Self> {
+        let mut output = Self::new(config, data_directory)?;
+        output.webhook = Some(Arc::new(Webhook::new(config, &output.user_id, data_directory)?));
+        Ok(output)
+    }
+
+    /// The configured token, or the one `facebook-auth` stored
+    fn page_token(&self) -> Result<FacebookPageToken> {
+        match &self.access_token {
+            PageToken::Configured(access_token) => Ok(FacebookPageToken {
+                access_token: access_token.clone(),
+                expires_at: None,
+            }),
+            PageToken::Stored(data_directory) => FacebookPageToken::load(data_directory)
+                .with_context(|| format!("Failed to read {}", FACEBOOK_TOKEN_FILE)),
+        }
+    }
+
+    fn get_send_url(&self, access_token: &str) -> String {
         format!(
             "https://graph.facebook.com/v18.0/me/messages?access_token={}",
-            self.access_token
+            access_token
         )
     }
 
-    fn get_profile_url(&self) -> String {
+    fn get_profile_url(&self, access_token: &str) -> String {
         format!(
             "https://graph.facebook.com/v18.0/me?access_token={}",
-            self.access_token
+            access_token
         )
     }
+
+    /// The recipient's profile, which the page can only read for someone
+    /// who has messaged it
+    fn get_recipient_url(&self, access_token: &str) -> String {
+        format!(
+            "https://graph.facebook.com/v18.0/{}?fields=name&access_token={}",
+            self.user_id, access_token
+        )
+    }
+
+    async fn graph_get(&self, url: &str) -> Result<Value> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            // reqwest errors include the URL, which carries the access token
+            .map_err(|e| anyhow::anyhow!("HTTP request failed: {}", redact::redact(&e.to_string())))?;
+        let status = response.status();
+        let text = response.text().await.context("Failed to read Facebook API response")?;
+        graph_body(status, &text)
+    }
 }
 
-#[async_trait]
-impl Output for FacebookMessengerOutput {
-    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+/// A Graph API response body, or its error. Errors usually come with a 4xx
+/// status, and their message says what is wrong.
+fn graph_body(status: reqwest::StatusCode, text: &str) -> Result<Value> {
+    let json: Value = serde_json::from_str(text).with_context(|| format!("HTTP {}: unexpected response", status))?;
+    if let Some(error) = json.get("error") {
+        let message = error.get("message").and_then(Value::as_str).unwrap_or("Unknown Facebook API error");
+        anyhow::bail!("Facebook API error: {}", message);
+    }
+    if !status.is_success() {
+        anyhow::bail!("HTTP {}", status);
+    }
+    Ok(json)
+}
+
+/// Messenger rejects text messages longer than this
+const MAX_MESSAGE_CHARS: usize = 2000;
+
+impl FacebookMessengerOutput {
+    async fn send_text(&self, message: &str) -> Result<OutputResult> {
+        let token = match self.page_token() {
+            Ok(token) => token,
+            Err(e) => return Ok(OutputResult::Failed(format!("{:#}", e))),
+        };
         let payload = json!({
             "recipient": {
                 "id": self.user_id
@@ -62,7 +456,7 @@ impl Output for FacebookMessengerOutput {
 
         let response = match self
             .client
-            .post(&self.get_send_url())
+            .post(self.get_send_url(&token.access_token))
             .header("Content-Type", "application/json")
             .json(&payload)
             .send()
@@ -70,7 +464,8 @@ impl Output for FacebookMessengerOutput {
         {
             Ok(resp) => resp,
             Err(e) => {
-                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", e)));
+                // reqwest errors include the URL, which carries the access token
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string()))));
             }
         };
 
@@ -96,42 +491,67 @@ impl Output for FacebookMessengerOutput {
             }
         }
     }
+}
+
+#[async_trait]
+impl Output for FacebookMessengerOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let parts = formatting::split_message(message, MAX_MESSAGE_CHARS);
+        let total = parts.len();
 
+        for (i, part) in parts.iter().enumerate() {
+            match self.send_text(part).await? {
+                OutputResult::Success => {}
+                OutputResult::Failed(error) if total > 1 => {
+                    return Ok(OutputResult::Failed(format!("Part {}/{}: {}", i + 1, total, error)));
+                }
+                other => return Ok(other),
+            }
+        }
+
+        Ok(OutputResult::Success)
+    }
+
+    /// The page token works and isn't about to expire, and unless
+    /// `shallow_health_check` is set, the page can reach `user_id` with it
     async fn health_check(&self) -> Result<bool> {
-        let response = match self
-            .client
-            .get(&self.get_profile_url())
-            .send()
-            .await
-        {
-            Ok(resp) => resp,
+        let token = match self.page_token() {
+            Ok(token) => token,
             Err(e) => {
-                tracing::debug!("Facebook Messenger health check HTTP error: {}", e);
+                tracing::warn!("Facebook Messenger health check failed: {:#}", e);
                 return Ok(false);
             }
         };
+        if let Some(warning) = expiry_warning(&token, Utc::now()) {
+            tracing::warn!("Facebook Messenger health check failed: {}", warning);
+            return Ok(false);
+        }
 
-        if response.status().is_success() {
-            match response.json::<serde_json::Value>().await {
-                Ok(json) => {
-                    if json.get("error").is_some() {
-                        tracing::debug!("Facebook Messenger health check API error: {:?}", json["error"]);
-                        Ok(false)
-                    } else if json.get("id").is_some() {
-                        Ok(true)
-                    } else {
-                        tracing::debug!("Facebook Messenger health check: unexpected response format");
-                        Ok(false)
-                    }
-                }
-                Err(e) => {
-                    tracing::debug!("Facebook Messenger health check parse error: {}", e);
-                    Ok(false)
-                }
+        match self.graph_get(&self.get_profile_url(&token.access_token)).await {
+            Ok(page) if page.get("id").is_some() => {}
+            Ok(_) => {
+                tracing::debug!("Facebook Messenger health check: unexpected response format");
+                return Ok(false);
+            }
+            Err(e) => {
+                tracing::debug!("Facebook Messenger health check failed: {:#}", e);
+                return Ok(false);
+            }
+        }
+        if self.shallow_health_check {
+            return Ok(true);
+        }
+
+        match self.graph_get(&self.get_recipient_url(&token.access_token)).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                tracing::warn!(
+                    "Facebook Messenger health check: the page token works, but user_id {} can't be reached with it ({:#}). \
+                     user_id must be the page-scoped ID of someone who has messaged this page, as `lastsignal facebook-auth` captures",
+                    self.user_id, e
+                );
+                Ok(false)
             }
-        } else {
-            tracing::debug!("Facebook Messenger health check HTTP error: {}", response.status());
-            Ok(false)
         }
     }
 
@@ -140,6 +560,47 @@ impl Output for FacebookMessengerOutput {
     }
 }
 
+#[async_trait]
+impl BidirectionalOutput for FacebookMessengerOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let Some(webhook) = &self.webhook else {
+            return Ok(vec![]);
+        };
+        webhook.ensure_started().await?;
+
+        Ok(webhook
+            .state
+            .inbox
+            .after(since)
+            .into_iter()
+            .map(|message| CheckinResponse::Found {
+                timestamp: message.timestamp,
+                subject: message.text,
+                from: format!("Facebook user {}", self.user_id),
+            })
+            .collect())
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        match &self.webhook {
+            Some(webhook) => webhook.state.inbox.prune(timestamp),
+            None => Ok(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,27 +611,255 @@ mod tests {
         config.insert("user_id".to_string(), "123456789".to_string());
         config.insert("access_token".to_string(), "test_token".to_string());
 
-        let output = FacebookMessengerOutput::new(&config).unwrap();
+        let output = FacebookMessengerOutput::new(&config, None).unwrap();
         assert_eq!(output.user_id, "123456789");
-        assert_eq!(output.access_token, "test_token");
+        assert_eq!(output.access_token, PageToken::Configured("test_token".to_string()));
     }
 
     #[test]
     fn test_facebook_messenger_output_missing_config() {
         let config = HashMap::new();
-        let result = FacebookMessengerOutput::new(&config);
+        let result = FacebookMessengerOutput::new(&config, None);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_prefix_and_suffix_count_towards_message_limit() {
+        let mut config = HashMap::new();
+        config.insert("message_prefix".to_string(), "LASTSIGNAL: ".to_string());
+        config.insert("message_suffix".to_string(), "\n-- sent by LastSignal".to_string());
+        let output_config = crate::config::OutputConfig {
+            output_type: "facebook_messenger".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+
+        let message = "word ".repeat(399) + "end"; // 1998 characters on its own
+        let wrapped = crate::outputs::wrap_message(&message, &output_config);
+        let parts = formatting::split_message(&wrapped, MAX_MESSAGE_CHARS);
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| part.chars().count() <= MAX_MESSAGE_CHARS));
+        assert!(parts[0].starts_with("LASTSIGNAL: word"));
+        assert!(parts[1].ends_with("-- sent by LastSignal"));
+    }
+
     #[test]
     fn test_facebook_messenger_urls() {
         let mut config = HashMap::new();
         config.insert("user_id".to_string(), "123456789".to_string());
         config.insert("access_token".to_string(), "test_token".to_string());
 
-        let output = FacebookMessengerOutput::new(&config).unwrap();
+        let output = FacebookMessengerOutput::new(&config, None).unwrap();
         
-        assert!(output.get_send_url().contains("test_token"));
-        assert!(output.get_profile_url().contains("test_token"));
+        let token = output.page_token().unwrap();
+        assert_eq!(token.expires_at, None);
+        assert!(output.get_send_url(&token.access_token).contains("test_token"));
+        assert!(output.get_profile_url(&token.access_token).contains("test_token"));
+        assert_eq!(
+            output.get_recipient_url(&token.access_token),
+            "https://graph.facebook.com/v18.0/123456789?fields=name&access_token=test_token"
+        );
+    }
+
+    #[test]
+    fn test_shallow_health_check_config() {
+        let mut config = HashMap::new();
+        config.insert("user_id".to_string(), "123456789".to_string());
+        config.insert("access_token".to_string(), "test_token".to_string());
+        assert!(!FacebookMessengerOutput::new(&config, None).unwrap().shallow_health_check);
+
+        config.insert("shallow_health_check".to_string(), "true".to_string());
+        assert!(FacebookMessengerOutput::new(&config, None).unwrap().shallow_health_check);
+
+        config.insert("shallow_health_check".to_string(), "yes".to_string());
+        assert!(FacebookMessengerOutput::new(&config, None).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_stored_page_token() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut config = HashMap::new();
+        config.insert("user_id".to_string(), "123456789".to_string());
+        config.insert("access_token".to_string(), "stored".to_string());
+        assert!(FacebookMessengerOutput::new(&config, None).is_err());
+
+        // A missing token is only a warning until it is used
+        let output = FacebookMessengerOutput::new(&config, Some(temp_dir.path())).unwrap();
+        assert!(output.page_token().is_err());
+
+        // Tokens renewed by `facebook-auth` are picked up without a restart
+        let token = FacebookPageToken {
+            access_token: "long_lived_token".to_string(),
+            expires_at: Some("2026-12-14T09:30:00Z".parse().unwrap()),
+        };
+        token.save(temp_dir.path()).unwrap();
+        assert_eq!(output.page_token().unwrap(), token);
+        assert!(output.get_send_url(&output.page_token().unwrap().access_token).contains("long_lived_token"));
+    }
+
+    #[test]
+    fn test_expiry_warning() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let token = |expires_at: Option<&str>| FacebookPageToken {
+            access_token: "long_lived_token".to_string(),
+            expires_at: expires_at.map(|at| at.parse().unwrap()),
+        };
+        assert_eq!(expiry_warning(&token(None), now), None);
+        assert_eq!(expiry_warning(&token(Some("2026-12-14T09:30:00Z")), now), None);
+
+        let soon = expiry_warning(&token(Some("2026-10-20T09:30:00Z")), now).unwrap();
+        assert!(soon.starts_with("Facebook page token expires at 2026-10-20 09:30 UTC"), "{}", soon);
+        let expired = expiry_warning(&token(Some("2026-10-14T09:30:00Z")), now).unwrap();
+        assert!(expired.starts_with("Facebook page token expired at"), "{}", expired);
+    }
+
+    #[test]
+    fn test_graph_body() {
+        let profile = graph_body(reqwest::StatusCode::OK, r#"{"name": "Joe Bloggs", "id": "123456789"}"#).unwrap();
+        assert_eq!(profile["name"], "Joe Bloggs");
+
+        // A user_id that hasn't messaged the page, or belongs to another page
+        let unreachable = r#"{"error": {"message": "(#100) No profile available for this user.", "type": "OAuthException", "code": 100, "fbtrace_id": "A1b2C3"}}"#;
+        let error = graph_body(reqwest::StatusCode::BAD_REQUEST, unreachable).unwrap_err().to_string();
+        assert_eq!(error, "Facebook API error: (#100) No profile available for this user.");
+
+        assert!(graph_body(reqwest::StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>").is_err());
+        assert!(graph_body(reqwest::StatusCode::SERVICE_UNAVAILABLE, "{}").is_err());
+    }
+
+    fn webhook_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("user_id".to_string(), "123456789".to_string());
+        config.insert("access_token".to_string(), "test_token".to_string());
+        config.insert("verify_token".to_string(), "s3cret-verify".to_string());
+        config.insert("webhook_port".to_string(), "0".to_string());
+        config
+    }
+
+    fn message_event(sender: &str, timestamp_ms: i64, message: Value) -> Value {
+        json!({"object": "page", "entry": [{"id": "PAGE_ID", "time": timestamp_ms, "messaging": [
+            {"sender": {"id": sender}, "recipient": {"id": "PAGE_ID"}, "timestamp": timestamp_ms, "message": message}
+        ]}]})
+    }
+
+    #[test]
+    fn test_bidirectional_requires_verify_token() {
+        let mut config = webhook_config();
+        config.remove("verify_token");
+        assert!(FacebookMessengerOutput::new_bidirectional(&config, None).is_err());
+
+        let mut config = webhook_config();
+        config.insert("webhook_port".to_string(), "http".to_string());
+        assert!(FacebookMessengerOutput::new_bidirectional(&config, None).is_err());
+    }
+
+    #[test]
+    fn test_verification_challenge() {
+        let params = |token: &str| -> HashMap<String, String> {
+            [("hub.mode", "subscribe"), ("hub.verify_token", token), ("hub.challenge", "1158201444")]
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+        assert_eq!(verification_challenge(&params("s3cret-verify"), "s3cret-verify"), Some("1158201444"));
+        assert_eq!(verification_challenge(&params("guess"), "s3cret-verify"), None);
+        assert_eq!(verification_challenge(&HashMap::new(), "s3cret-verify"), None);
+    }
+
+    #[test]
+    fn test_valid_signature() {
+        let body = br#"{"object":"page","entry":[]}"#;
+        let header = format!("sha256={}", hex::encode(hmac_sha256(b"app_secret", body).finalize().into_bytes()));
+
+        assert!(valid_signature("app_secret", body, Some(&header)));
+        assert!(!valid_signature("other_secret", body, Some(&header)));
+        assert!(!valid_signature("app_secret", b"{}", Some(&header)));
+        assert!(!valid_signature("app_secret", body, None));
+    }
+
+    #[test]
+    fn test_messages_from_only_the_user() {
+        let reply = message_event("123456789", 1_760_520_600_000, json!({"mid": "m_1", "text": "I'm fine"}));
+        assert_eq!(messages_from(&reply, "123456789"), vec![StoredMessage {
+            timestamp: DateTime::from_timestamp(1_760_520_600, 0).unwrap(),
+            text: "I'm fine".to_string(),
+        }]);
+
+        let stranger = message_event("987654321", 1_760_520_600_000, json!({"mid": "m_2", "text": "hi"}));
+        assert!(messages_from(&stranger, "123456789").is_empty());
+
+        let echo = message_event("123456789", 1_760_520_600_000, json!({"mid": "m_3", "text": "Check in", "is_echo": true}));
+        assert!(messages_from(&echo, "123456789").is_empty());
+
+        let sticker = message_event("123456789", 1_760_520_600_000, json!({"mid": "m_4", "attachments": [{"type": "image"}]}));
+        assert_eq!(messages_from(&sticker, "123456789")[0].text, "(attachment)");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_records_replies() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = FacebookMessengerOutput::new_bidirectional(&webhook_config(), Some(temp_dir.path())).unwrap();
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+        let address = output.webhook.as_ref().unwrap().ensure_started().await.unwrap();
+        let url = format!("http://{}/webhook", address);
+        let client = Client::new();
+
+        let verify = |token: &'static str| {
+            client
+                .get(&url)
+                .query(&[("hub.mode", "subscribe"), ("hub.verify_token", token), ("hub.challenge", "42")])
+                .send()
+        };
+        let response = verify("s3cret-verify").await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "42");
+        assert_eq!(verify("guess").await.unwrap().status(), reqwest::StatusCode::FORBIDDEN);
+
+        let earlier = Utc::now() - chrono::Duration::hours(2);
+        let later = Utc::now() - chrono::Duration::hours(1);
+        for (timestamp, text) in [(earlier, "first"), (later, "second")] {
+            let event = message_event("123456789", timestamp.timestamp_millis(), json!({"mid": text, "text": text}));
+            let response = client.post(&url).json(&event).send().await.unwrap();
+            assert_eq!(response.status(), reqwest::StatusCode::OK);
+        }
+
+        let responses = output.check_for_responses(Some(earlier)).await.unwrap();
+        let [CheckinResponse::Found { subject, from, .. }] = responses.as_slice() else {
+            panic!("expected one reply after the first");
+        };
+        assert_eq!(subject, "second");
+        assert_eq!(from, "Facebook user 123456789");
+
+        // Processed messages are pruned, and the rest survive a restart
+        output.mark_processed_until(earlier).await.unwrap();
+        drop(output);
+        let reloaded = FacebookMessengerOutput::new_bidirectional(&webhook_config(), Some(temp_dir.path())).unwrap();
+        let remaining = reloaded.webhook.as_ref().unwrap().state.inbox.after(None);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].text, "second");
+        assert!(temp_dir.path().join("facebook_messenger_123456789.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_checks_signature() {
+        let mut config = webhook_config();
+        config.insert("app_secret".to_string(), "app_secret".to_string());
+        let output = FacebookMessengerOutput::new_bidirectional(&config, None).unwrap();
+        let address = output.webhook.as_ref().unwrap().ensure_started().await.unwrap();
+
+        let event = message_event("123456789", Utc::now().timestamp_millis(), json!({"mid": "m_1", "text": "ok"}));
+        let response = Client::new()
+            .post(format!("http://{}/webhook", address))
+            .header("X-Hub-Signature-256", "sha256=00")
+            .json(&event)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+    }
+}
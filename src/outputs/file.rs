@@ -0,0 +1,321 @@
+use super::{MessageKind, Output, OutputResult};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::io::AsyncWriteExt;
+
+/// One lock per file, shared by every output writing to it in this process, so
+/// entries from a checkin and a last signal output never interleave or race a rotation
+static FILE_LOCKS: LazyLock<Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>> = LazyLock::new(Default::default);
+
+fn file_lock(path: &Path) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = FILE_LOCKS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    locks.entry(path.to_path_buf()).or_default().clone()
+}
+
+/// Appends every message to a local text file. Useful for trying LastSignal
+/// out before configuring real credentials, and as a permanent record of
+/// everything that was sent.
+#[derive(Debug, Clone)]
+pub struct FileOutput {
+    path: PathBuf,
+    kind: MessageKind,
+    /// Once an entry would take the file past this many bytes, it is moved
+    /// to `<path>.1` and a new file started
+    max_size: Option<u64>,
+}
+
+impl FileOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["path", "max_size"];
+
+    pub fn new(
+        config: &HashMap<String, String>,
+        kind: MessageKind,
+        data_directory: Option<&Path>,
+    ) -> Result<Self> {
+        let path = config
+            .get("path")
+            .context("Missing 'path' field in file config")?;
+
+        let max_size = config.get("max_size")
+            .map(|max_size| parse_size(max_size).context("Invalid 'max_size' in file config"))
+            .transpose()?;
+
+        Ok(FileOutput {
+            path: resolve_path(path, data_directory)?,
+            kind,
+            max_size,
+        })
+    }
+
+    fn rotated_path(&self) -> PathBuf {
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        PathBuf::from(rotated)
+    }
+
+    /// Moves the file aside if `entry_len` more bytes would take it over `max_size`.
+    /// A file is never rotated while empty, so one oversized entry still gets written.
+    async fn rotate_if_needed(&self, entry_len: u64) -> Result<()> {
+        let Some(max_size) = self.max_size else {
+            return Ok(());
+        };
+        let current_size = match tokio::fs::metadata(&self.path).await {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read output file size: {:?}", self.path)),
+        };
+        if current_size == 0 || current_size + entry_len <= max_size {
+            return Ok(());
+        }
+
+        let rotated = self.rotated_path();
+        tokio::fs::rename(&self.path, &rotated)
+            .await
+            .with_context(|| format!("Failed to rotate output file to {:?}", rotated))
+    }
+
+    fn format_entry(&self, message: &str) -> String {
+        format!(
+            "=== {} [{}] ===\n{}\n\n",
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+            self.kind.as_str(),
+            message.trim_end()
+        )
+    }
+
+    async fn append(&self, entry: &str) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+
+        let lock = file_lock(&self.path);
+        let _guard = lock.lock().await;
+        self.rotate_if_needed(entry.len() as u64).await?;
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("Failed to open output file: {:?}", self.path))?;
+
+        file.write_all(entry.as_bytes())
+            .await
+            .with_context(|| format!("Failed to write to output file: {:?}", self.path))?;
+        // tokio completes file writes in the background; make sure this one landed
+        file.flush()
+            .await
+            .with_context(|| format!("Failed to flush output file: {:?}", self.path))?;
+
+        Ok(())
+    }
+}
+
+/// A size in bytes, optionally with a `KB`, `MB` or `GB` suffix (powers of 1024)
+pub fn parse_size(size: &str) -> Result<u64> {
+    let size = size.trim();
+    let split = size.find(|c: char| !c.is_ascii_digit()).unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let number: u64 = number.parse().with_context(|| format!("Invalid size '{}'", size))?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        _ => anyhow::bail!("Invalid size unit in '{}'. Valid units: B, KB, MB, GB", size),
+    };
+    if number == 0 {
+        anyhow::bail!("Size must be greater than 0");
+    }
+    number.checked_mul(multiplier).with_context(|| format!("Size '{}' is too large", size))
+}
+
+/// Expands `~/` and resolves relative paths against the data directory
+fn resolve_path(path: &str, data_directory: Option<&Path>) -> Result<PathBuf> {
+    if let Some(rest) = path.strip_prefix("~/") {
+        let home_dir = dirs::home_dir().context("Could not determine home directory")?;
+        return Ok(home_dir.join(rest));
+    }
+
+    let path = PathBuf::from(path);
+    match data_directory {
+        Some(data_dir) if path.is_relative() => Ok(data_dir.join(path)),
+        _ => Ok(path),
+    }
+}
+
+#[async_trait]
+impl Output for FileOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        match self.append(&self.format_entry(message)).await {
+            Ok(()) => Ok(OutputResult::Success),
+            Err(e) => Ok(OutputResult::Failed(format!("{:#}", e))),
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        let Some(parent) = self.path.parent() else {
+            return Ok(false);
+        };
+
+        if let Err(e) = tokio::fs::create_dir_all(parent).await {
+            tracing::debug!("File output health check: cannot create {:?}: {}", parent, e);
+            return Ok(false);
+        }
+
+        // Rotation and first writes create files next to the log, so the directory must be writable
+        let probe = parent.join(format!(".lastsignal-probe-{}", std::process::id()));
+        if let Err(e) = tokio::fs::write(&probe, b"").await {
+            tracing::debug!("File output health check: cannot write in {:?}: {}", parent, e);
+            return Ok(false);
+        }
+        if let Err(e) = tokio::fs::remove_file(&probe).await {
+            tracing::debug!("File output health check: cannot remove {:?}: {}", probe, e);
+            return Ok(false);
+        }
+
+        // An existing log must also be writable itself
+        if self.path.exists()
+            && let Err(e) = tokio::fs::OpenOptions::new().append(true).open(&self.path).await
+        {
+            tracing::debug!("File output health check: cannot write {:?}: {}", self.path, e);
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn get_name(&self) -> &str {
+        "file"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn file_config(path: &str) -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("path".to_string(), path.to_string());
+        config
+    }
+
+    #[test]
+    fn test_file_output_missing_config() {
+        let result = FileOutput::new(&HashMap::new(), MessageKind::Checkin, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_file_output_relative_path_uses_data_directory() {
+        let temp_dir = tempdir().unwrap();
+        let output = FileOutput::new(&file_config("sent.log"), MessageKind::Checkin, Some(temp_dir.path())).unwrap();
+        assert_eq!(output.path, temp_dir.path().join("sent.log"));
+
+        let output = FileOutput::new(&file_config("/var/log/sent.log"), MessageKind::Checkin, Some(temp_dir.path())).unwrap();
+        assert_eq!(output.path, PathBuf::from("/var/log/sent.log"));
+    }
+
+    #[tokio::test]
+    async fn test_file_output_appends_with_kind() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("nested").join("sent.log");
+        let path_str = path.to_str().unwrap();
+
+        let checkin = FileOutput::new(&file_config(path_str), MessageKind::Checkin, None).unwrap();
+        let last_signal = FileOutput::new(&file_config(path_str), MessageKind::LastSignal, None).unwrap();
+
+        assert!(checkin.health_check().await.unwrap());
+        assert!(matches!(checkin.send_message("Please check in").await.unwrap(), OutputResult::Success));
+        assert!(matches!(last_signal.send_message("Goodbye").await.unwrap(), OutputResult::Success));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("[checkin] ===\nPlease check in\n"));
+        assert!(contents.contains("[last_signal] ===\nGoodbye\n"));
+        assert!(contents.find("Please check in").unwrap() < contents.find("Goodbye").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_appends_stay_whole() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("sent.log");
+        let output = Arc::new(FileOutput::new(&file_config(path.to_str().unwrap()), MessageKind::Checkin, None).unwrap());
+
+        let tasks: Vec<_> = (0..20)
+            .map(|i| {
+                let output = output.clone();
+                tokio::spawn(async move { output.send_message(&format!("message {} {}", i, "x".repeat(10_000))).await })
+            })
+            .collect();
+        for task in tasks {
+            assert!(task.await.unwrap().unwrap().is_success());
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let entries: Vec<&str> = contents.split("=== ").filter(|entry| !entry.is_empty()).collect();
+        assert_eq!(entries.len(), 20);
+        for entry in entries {
+            // Each entry holds exactly one whole message
+            let (_, body) = entry.split_once("===\n").unwrap();
+            let body = body.trim_end();
+            assert!(body.starts_with("message "));
+            assert!(body.ends_with(&format!(" {}", "x".repeat(10_000))));
+            assert_eq!(body.matches('x').count(), 10_000);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_output_rotates_at_max_size() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("sent.log");
+        let mut config = file_config(path.to_str().unwrap());
+        config.insert("max_size".to_string(), "200".to_string());
+        let output = FileOutput::new(&config, MessageKind::Checkin, None).unwrap();
+
+        output.send_message("first").await.unwrap();
+        output.send_message("second").await.unwrap();
+        assert!(!output.rotated_path().exists());
+
+        output.send_message(&"third ".repeat(20)).await.unwrap();
+        let rotated = std::fs::read_to_string(output.rotated_path()).unwrap();
+        assert!(rotated.contains("first") && rotated.contains("second"));
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert!(current.contains("third") && !current.contains("first"));
+
+        // Only one old file is kept
+        output.send_message("fourth").await.unwrap();
+        let rotated = std::fs::read_to_string(output.rotated_path()).unwrap();
+        assert!(rotated.contains("third") && !rotated.contains("first"));
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("10KB").unwrap(), 10 * 1024);
+        assert_eq!(parse_size("5 mb").unwrap(), 5 * 1024 * 1024);
+        assert_eq!(parse_size("1G").unwrap(), 1024 * 1024 * 1024);
+        assert!(parse_size("0MB").is_err());
+        assert!(parse_size("10 TB").is_err());
+        assert!(parse_size("big").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_output_unwritable_path() {
+        let temp_dir = tempdir().unwrap();
+        // A regular file cannot be used as a parent directory
+        let blocker = temp_dir.path().join("blocker");
+        std::fs::write(&blocker, "").unwrap();
+        let path = blocker.join("sent.log");
+
+        let output = FileOutput::new(&file_config(path.to_str().unwrap()), MessageKind::Checkin, None).unwrap();
+        assert!(!output.health_check().await.unwrap());
+        assert!(matches!(output.send_message("hello").await.unwrap(), OutputResult::Failed(_)));
+    }
+}
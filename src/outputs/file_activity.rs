@@ -0,0 +1,297 @@
+use super::local_activity::{expand_home, modified_time};
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Treats recent changes to files, such as a notes vault or browser profile,
+/// as signs of life
+#[derive(Debug, Clone)]
+pub struct FileActivityOutput {
+    /// Glob patterns, with `~` already expanded
+    patterns: Vec<String>,
+    max_time_since_last_checkin: ConfigDuration,
+}
+
+fn has_wildcard(component: &str) -> bool {
+    component.contains(['*', '?'])
+}
+
+/// Shell-style matching of one path component, where `*` is any run of
+/// characters and `?` is any one character
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Where the last `*` was, and how much of the name it had taken
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Hidden entries only match a pattern that starts with a dot, as in a shell
+fn entries_matching(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            (!name.starts_with('.') || pattern.starts_with('.')) && wildcard_match(pattern, &name)
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
+
+/// `dir` and every directory below it, not following symlinks
+fn with_subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![dir.to_path_buf()];
+    let mut i = 0;
+    while i < dirs.len() {
+        if let Ok(entries) = std::fs::read_dir(&dirs[i]) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                    dirs.push(entry.path());
+                }
+            }
+        }
+        i += 1;
+    }
+    dirs
+}
+
+/// The existing paths a pattern matches. `*` and `?` match within one path
+/// component, and a `**` component matches any number of directories.
+fn expand_glob(pattern: &str) -> Vec<PathBuf> {
+    let mut matches = vec![PathBuf::new()];
+    let components: Vec<Component> = Path::new(pattern).components().collect();
+
+    for (i, component) in components.iter().enumerate() {
+        let part = component.as_os_str().to_string_lossy();
+        let is_last = i + 1 == components.len();
+        matches = if part == "**" {
+            let found: Vec<PathBuf> = matches.iter().flat_map(|dir| with_subdirectories(dir)).collect();
+            if is_last {
+                // A trailing `**` means everything below, files included
+                found.iter().flat_map(|dir| entries_matching(dir, "*")).chain(found.iter().cloned()).collect()
+            } else {
+                found
+            }
+        } else if has_wildcard(&part) {
+            matches.iter().flat_map(|dir| entries_matching(dir, &part)).collect()
+        } else {
+            matches.into_iter().map(|dir| dir.join(component.as_os_str())).collect()
+        };
+    }
+
+    matches.retain(|path| !path.as_os_str().is_empty() && path.exists());
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+impl FileActivityOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["paths"];
+
+    pub fn new(config: &HashMap<String, String>, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let paths = config
+            .get("paths")
+            .context("Missing 'paths' field in file_activity config")?;
+        let patterns: Vec<String> = paths
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+            .map(|path| expand_home(path).to_string_lossy().into_owned())
+            .collect();
+        if patterns.is_empty() {
+            anyhow::bail!("file_activity 'paths' lists no paths");
+        }
+
+        Ok(FileActivityOutput {
+            patterns,
+            max_time_since_last_checkin,
+        })
+    }
+
+    /// The most recently modified matching path. Patterns matching nothing are
+    /// logged and skipped, so one missing path doesn't fail the check.
+    fn most_recent_change(&self) -> Option<(PathBuf, DateTime<Utc>)> {
+        let mut most_recent: Option<(PathBuf, DateTime<Utc>)> = None;
+        for pattern in &self.patterns {
+            let paths = expand_glob(pattern);
+            if paths.is_empty() {
+                tracing::debug!("file_activity: '{}' matches nothing", pattern);
+            }
+            for path in paths {
+                match modified_time(&path) {
+                    Ok(timestamp) => {
+                        if most_recent.as_ref().is_none_or(|(_, newest)| timestamp > *newest) {
+                            most_recent = Some((path, timestamp));
+                        }
+                    }
+                    Err(e) => tracing::debug!("file_activity: {:#}", e),
+                }
+            }
+        }
+        most_recent
+    }
+}
+
+#[async_trait]
+impl Output for FileActivityOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // File activity is used purely for checking if the user is alive
+        Ok(OutputResult::Skipped("file_activity is a check-only adapter".to_string()))
+    }
+
+    /// At least one pattern matches something
+    async fn health_check(&self) -> Result<bool> {
+        let healthy = self.patterns.iter().any(|pattern| !expand_glob(pattern).is_empty());
+        if !healthy {
+            tracing::debug!("file_activity health check: none of {} match any files", self.patterns.join(", "));
+        }
+        Ok(healthy)
+    }
+
+    fn get_name(&self) -> &str {
+        "file_activity"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for FileActivityOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let Some((path, modified)) = self.most_recent_change() else {
+            tracing::warn!("file_activity: none of {} match any files", self.patterns.join(", "));
+            return Ok(vec![]);
+        };
+
+        Ok(super::activity_checkin(
+            "file_activity",
+            modified,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            format!("File changed: {}", path.display()),
+            "File Activity".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn age(path: &Path, hours: u64) {
+        let then = SystemTime::now() - Duration::from_secs(hours * 60 * 60);
+        std::fs::File::options().write(true).open(path).unwrap().set_modified(then).unwrap();
+    }
+
+    fn output(paths: &str) -> FileActivityOutput {
+        let mut config = HashMap::new();
+        config.insert("paths".to_string(), paths.to_string());
+        FileActivityOutput::new(&config, ConfigDuration::from_hours(24)).unwrap()
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.md", "daily.md"));
+        assert!(wildcard_match("2026-??-*.md", "2026-10-15.md"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("*.md", "daily.txt"));
+        assert!(!wildcard_match("?", "ab"));
+        assert!(wildcard_match("a*b*c", "aXbYbZc"));
+    }
+
+    #[test]
+    fn test_expand_glob() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("vault/journal/2026")).unwrap();
+        std::fs::write(root.join("vault/todo.md"), "").unwrap();
+        std::fs::write(root.join("vault/journal/2026/10-15.md"), "").unwrap();
+        std::fs::write(root.join("vault/journal/notes.txt"), "").unwrap();
+        std::fs::write(root.join("vault/.hidden.md"), "").unwrap();
+
+        let vault = root.join("vault");
+        assert_eq!(expand_glob(&format!("{}/*.md", vault.display())), vec![vault.join("todo.md")]);
+        assert_eq!(
+            expand_glob(&format!("{}/**/*.md", vault.display())),
+            vec![vault.join("journal/2026/10-15.md"), vault.join("todo.md")]
+        );
+        assert!(expand_glob(&format!("{}/**", vault.display())).contains(&vault.join("journal/notes.txt")));
+        assert_eq!(expand_glob(&format!("{}/todo.md", vault.display())), vec![vault.join("todo.md")]);
+        assert!(expand_glob(&format!("{}/missing/*.md", vault.display())).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recent_change_is_a_checkin() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let old = temp_dir.path().join("old.md");
+        let recent = temp_dir.path().join("recent.md");
+        std::fs::write(&old, "").unwrap();
+        std::fs::write(&recent, "").unwrap();
+        age(&old, 48);
+        age(&recent, 2);
+
+        let missing = temp_dir.path().join("missing/*.md");
+        let output = output(&format!("{}, {}/*.md", missing.display(), temp_dir.path().display()));
+        let responses = output.check_for_responses(None).await.unwrap();
+        let [CheckinResponse::Found { subject, .. }] = responses.as_slice() else {
+            panic!("expected one check-in");
+        };
+        assert_eq!(subject, &format!("File changed: {}", recent.display()));
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+
+        age(&recent, 30);
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nothing_matching_is_not_an_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = output(&format!("{}/nothing-here", temp_dir.path().display()));
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+        assert!(!<dyn Output>::health_check(&output).await.unwrap());
+
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(_)));
+    }
+}
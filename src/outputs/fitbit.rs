@@ -0,0 +1,340 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::oauth::{OAuthClient, FITBIT};
+use crate::duration_parser::{ConfigDuration, DurationStyle};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const API_BASE: &str = "https://api.fitbit.com";
+
+/// Only used for the authorization step, which `fitbit-auth` does itself
+const DEFAULT_REDIRECT_URI: &str = "http://127.0.0.1:3000/auth/fitbit/callback";
+
+/// Fitbit API client for checking synced heart rate and step data
+#[derive(Debug)]
+pub struct FitbitOutput {
+    client: Client,
+    oauth_client: Arc<RwLock<OAuthClient>>,
+    max_time_since_last_checkin: ConfigDuration,
+    name: String,
+    _refresh_task_handle: tokio::task::JoinHandle<()>,
+}
+
+#[derive(Deserialize, Debug)]
+struct FitbitProfileResponse {
+    user: FitbitUser,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FitbitUser {
+    /// The user's timezone offset, which intraday times are local to
+    offset_from_utc_millis: i64,
+}
+
+#[derive(Deserialize, Debug)]
+struct IntradayPoint {
+    /// `HH:MM:SS` in the user's local time
+    time: String,
+    value: f64,
+}
+
+/// Which intraday series to read, and where its points are in the response
+#[derive(Debug, Clone, Copy)]
+enum Series {
+    Heart,
+    Steps,
+}
+
+impl Series {
+    fn resource(self) -> &'static str {
+        match self {
+            Series::Heart => "heart",
+            Series::Steps => "steps",
+        }
+    }
+
+    fn dataset_key(self) -> &'static str {
+        match self {
+            Series::Heart => "activities-heart-intraday",
+            Series::Steps => "activities-steps-intraday",
+        }
+    }
+}
+
+/// The time of the last point showing the user wore or moved the device.
+/// Steps are recorded as zero for every minute of the day, so only nonzero ones count.
+fn latest_activity_time(response: &Value, series: Series) -> Result<Option<NaiveTime>> {
+    let dataset = response
+        .get(series.dataset_key())
+        .and_then(|intraday| intraday.get("dataset"))
+        .with_context(|| format!("Fitbit response has no {} dataset. Intraday data needs a Personal app type", series.resource()))?;
+    let points: Vec<IntradayPoint> = serde_json::from_value(dataset.clone())
+        .with_context(|| format!("Failed to parse Fitbit {} dataset", series.resource()))?;
+
+    points
+        .iter()
+        .rev()
+        .find(|point| point.value > 0.0)
+        .map(|point| NaiveTime::parse_from_str(&point.time, "%H:%M:%S").context("Failed to parse Fitbit intraday time"))
+        .transpose()
+}
+
+/// A Fitbit local date and time as UTC, given the profile's offset
+fn local_to_utc(date: NaiveDate, time: NaiveTime, offset_from_utc_millis: i64) -> DateTime<Utc> {
+    date.and_time(time).and_utc() - chrono::Duration::milliseconds(offset_from_utc_millis)
+}
+
+/// The user's local date at `now`, which names the day Fitbit files data under
+fn local_date(now: DateTime<Utc>, offset_from_utc_millis: i64) -> NaiveDate {
+    (now + chrono::Duration::milliseconds(offset_from_utc_millis)).date_naive()
+}
+
+impl FitbitOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["client_id", "client_secret"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        // Fitbit refresh tokens are single use and need the client credentials,
+        // so unlike WHOOP there is no falling back to dummy values
+        let client_id = config
+            .get("client_id")
+            .context("Missing 'client_id' field in fitbit config")?
+            .clone();
+        let client_secret = config
+            .get("client_secret")
+            .context("Missing 'client_secret' field in fitbit config")?
+            .clone();
+
+        let oauth_client = Arc::new(RwLock::new(OAuthClient::new(
+            FITBIT,
+            client_id,
+            client_secret,
+            DEFAULT_REDIRECT_URI.to_string(),
+            data_directory,
+        )));
+
+        let refresh_task_handle = crate::oauth::spawn_background_refresh(Arc::clone(&oauth_client), true);
+
+        Ok(Self {
+            client: Client::new(),
+            oauth_client,
+            max_time_since_last_checkin,
+            name: "Fitbit".to_string(),
+            _refresh_task_handle: refresh_task_handle,
+        })
+    }
+
+    async fn get_json(&self, path: &str, description: &str) -> Result<Value> {
+        let oauth_client = self.oauth_client.read().await;
+        let access_token = oauth_client.get_valid_access_token().await?;
+        let response = self
+            .client
+            .get(format!("{}{}", API_BASE, path))
+            .bearer_auth(&access_token)
+            .send()
+            .await
+            .with_context(|| format!("Failed to fetch {} from Fitbit API", description))?;
+
+        let status = response.status();
+        let response_text = response.text().await
+            .context("Failed to read response text from Fitbit API")?;
+
+        redact::log_payload(&format!("Fitbit {} API response", description), status, &response_text);
+
+        if !status.is_success() {
+            anyhow::bail!("Fitbit API returned error: {}", status);
+        }
+
+        serde_json::from_str(&response_text)
+            .with_context(|| format!("Failed to parse {} response from Fitbit API", description))
+    }
+
+    async fn get_utc_offset_millis(&self) -> Result<i64> {
+        let profile = self.get_json("/1/user/-/profile.json", "profile").await?;
+        let profile: FitbitProfileResponse = serde_json::from_value(profile)
+            .context("Failed to parse profile response from Fitbit API")?;
+        Ok(profile.user.offset_from_utc_millis)
+    }
+
+    /// The latest heart rate or step reading, looking at yesterday too in case
+    /// the device hasn't synced since midnight
+    async fn get_most_recent_activity_timestamp(&self) -> Result<DateTime<Utc>> {
+        let offset = self.get_utc_offset_millis().await?;
+        let today = local_date(Utc::now(), offset);
+
+        for date in [today, today - chrono::Duration::days(1)] {
+            let mut most_recent: Option<DateTime<Utc>> = None;
+
+            for series in [Series::Heart, Series::Steps] {
+                let path = format!("/1/user/-/activities/{}/date/{}/1d/1min.json", series.resource(), date.format("%Y-%m-%d"));
+                let result = match self.get_json(&path, series.resource()).await {
+                    Ok(response) => latest_activity_time(&response, series),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(Some(time)) => {
+                        let timestamp = local_to_utc(date, time, offset);
+                        most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp)));
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::debug!("Fitbit: could not read {} data for {}: {}", series.resource(), date, e),
+                }
+            }
+
+            if let Some(timestamp) = most_recent {
+                return Ok(timestamp);
+            }
+        }
+
+        anyhow::bail!("No recent heart rate or step data found from Fitbit API")
+    }
+}
+
+#[async_trait]
+impl Output for FitbitOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Fitbit is used purely for checking if the user is alive via device activity
+        Ok(OutputResult::Skipped("Fitbit is a check-only adapter".to_string()))
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.get_most_recent_activity_timestamp().await {
+            Ok(timestamp) => {
+                let now = Utc::now();
+                let hours_since_activity = (now - timestamp).num_hours();
+
+                tracing::info!(
+                    "Fitbit health check: most recent activity was {}",
+                    DurationStyle::Verbose.relative(timestamp - now)
+                );
+
+                Ok(hours_since_activity <= self.max_time_since_last_checkin.as_hours() as i64)
+            }
+            Err(e) => {
+                tracing::warn!("Fitbit health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for FitbitOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let most_recent_activity = self.get_most_recent_activity_timestamp().await?;
+
+        Ok(super::activity_checkin(
+            "Fitbit",
+            most_recent_activity,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            "Fitbit Device Activity Detected".to_string(),
+            "Fitbit Device".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("client_id".to_string(), "23ABCD".to_string());
+        config.insert("client_secret".to_string(), "0123456789abcdef".to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn test_fitbit_output_creation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = FitbitOutput::new(&test_config(), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(<dyn Output>::get_name(&output), "Fitbit");
+
+        for key in ["client_id", "client_secret"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(FitbitOutput::new(&config, temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fitbit_send_message_returns_skipped() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = FitbitOutput::new(&test_config(), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(reason) if reason.contains("check-only adapter")));
+    }
+
+    #[test]
+    fn test_latest_activity_time() {
+        let heart = json!({
+            "activities-heart": [],
+            "activities-heart-intraday": {
+                "dataset": [{"time": "07:01:00", "value": 64}, {"time": "07:02:00", "value": 66}],
+                "datasetInterval": 1,
+                "datasetType": "minute"
+            }
+        });
+        assert_eq!(latest_activity_time(&heart, Series::Heart).unwrap(), NaiveTime::from_hms_opt(7, 2, 0));
+
+        // Steps has an entry for every minute, most of them zero
+        let steps = json!({
+            "activities-steps-intraday": {
+                "dataset": [{"time": "08:15:00", "value": 12}, {"time": "08:16:00", "value": 0}, {"time": "08:17:00", "value": 0}]
+            }
+        });
+        assert_eq!(latest_activity_time(&steps, Series::Steps).unwrap(), NaiveTime::from_hms_opt(8, 15, 0));
+
+        let idle = json!({"activities-steps-intraday": {"dataset": [{"time": "00:00:00", "value": 0}]}});
+        assert_eq!(latest_activity_time(&idle, Series::Steps).unwrap(), None);
+
+        // Without intraday access the dataset is missing altogether
+        assert!(latest_activity_time(&json!({"activities-heart": []}), Series::Heart).is_err());
+    }
+
+    #[test]
+    fn test_local_time_conversion() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let time = NaiveTime::from_hms_opt(7, 30, 0).unwrap();
+
+        // UTC-7
+        assert_eq!(local_to_utc(date, time, -25_200_000).to_rfc3339(), "2026-10-15T14:30:00+00:00");
+        // UTC+10, the previous UTC day
+        assert_eq!(local_to_utc(date, time, 36_000_000).to_rfc3339(), "2026-10-14T21:30:00+00:00");
+
+        let now: DateTime<Utc> = "2026-10-15T23:30:00Z".parse().unwrap();
+        assert_eq!(local_date(now, 36_000_000), NaiveDate::from_ymd_opt(2026, 10, 16).unwrap());
+        assert_eq!(local_date(now, -25_200_000), date);
+    }
+}
@@ -0,0 +1,253 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::garmin_auth::{GarminAuth, OAuthConsumer};
+use crate::duration_parser::{ConfigDuration, DurationStyle};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const API_BASE: &str = "https://connectapi.garmin.com";
+
+/// Garmin Connect client for checking when a device last synced
+#[derive(Debug)]
+pub struct GarminOutput {
+    client: Client,
+    auth: Arc<GarminAuth>,
+    max_time_since_last_checkin: ConfigDuration,
+    name: String,
+    _refresh_task_handle: tokio::task::JoinHandle<()>,
+}
+
+/// `lastSyncTimestampGMT` from a daily summary, e.g. `2026-10-15T08:12:34.0`,
+/// if the device synced that day
+fn sync_time(summary: &Value) -> Result<Option<DateTime<Utc>>> {
+    let Some(timestamp) = summary.get("lastSyncTimestampGMT").and_then(Value::as_str) else {
+        return Ok(None);
+    };
+    let timestamp = NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f")
+        .context("Failed to parse Garmin lastSyncTimestampGMT")?
+        .and_utc();
+    Ok(Some(timestamp))
+}
+
+/// When the night's sleep ended, from `dailySleepDTO.sleepEndTimestampGMT` in milliseconds
+fn sleep_end_time(sleep: &Value) -> Option<DateTime<Utc>> {
+    sleep
+        .get("dailySleepDTO")
+        .and_then(|sleep| sleep.get("sleepEndTimestampGMT"))
+        .and_then(Value::as_i64)
+        .and_then(DateTime::from_timestamp_millis)
+}
+
+impl GarminOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["consumer_key", "consumer_secret"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let auth = Arc::new(GarminAuth::new(OAuthConsumer::from_config(config)?, data_directory)?);
+
+        // Exchange for a new bearer token every 30 minutes when it's close to expiring
+        let refresh_auth = Arc::clone(&auth);
+        let refresh_task_handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60 * 30)); // 30 minutes
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                interval.tick().await;
+
+                match refresh_auth.refresh_if_expiring(chrono::Duration::minutes(35)).await {
+                    Ok(true) => tracing::info!("Garmin: Successfully refreshed access token in background"),
+                    Ok(false) => tracing::debug!("Garmin: Token still valid, no refresh needed"),
+                    Err(e) => tracing::warn!("Garmin: Could not refresh token in background: {}", e),
+                }
+            }
+        });
+
+        Ok(Self {
+            client: Client::new(),
+            auth,
+            max_time_since_last_checkin,
+            name: "Garmin".to_string(),
+            _refresh_task_handle: refresh_task_handle,
+        })
+    }
+
+    async fn get_json(&self, path: &str, query: &[(&str, &str)], description: &str) -> Result<Value> {
+        let access_token = self.auth.get_valid_access_token().await?;
+        let response = self
+            .client
+            .get(format!("{}{}", API_BASE, path))
+            .bearer_auth(&access_token)
+            .query(query)
+            .send()
+            .await
+            .with_context(|| format!("Failed to fetch {} from Garmin Connect", description))?;
+
+        let status = response.status();
+        let response_text = response.text().await
+            .context("Failed to read response text from Garmin Connect")?;
+
+        redact::log_payload(&format!("Garmin {} API response", description), status, &response_text);
+
+        if !status.is_success() {
+            anyhow::bail!("Garmin Connect returned error: {}", status);
+        }
+
+        serde_json::from_str(&response_text)
+            .with_context(|| format!("Failed to parse {} response from Garmin Connect", description))
+    }
+
+    /// Daily summaries and sleep are filed under the display name, not "me"
+    async fn get_display_name(&self) -> Result<String> {
+        let profile = self.get_json("/userprofile-service/socialProfile", &[], "profile").await?;
+        profile
+            .get("displayName")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .context("Garmin profile has no displayName")
+    }
+
+    /// The later of the last device sync and the end of last night's sleep,
+    /// looking at yesterday's summary too in case nothing has synced today
+    async fn get_most_recent_activity_timestamp(&self) -> Result<DateTime<Utc>> {
+        let display_name = self.get_display_name().await?;
+        let today = Utc::now().date_naive();
+        let mut most_recent: Option<DateTime<Utc>> = None;
+
+        for date in [today, today - chrono::Duration::days(1)] {
+            let date = date_string(date);
+            let summary_path = format!("/usersummary-service/usersummary/daily/{}", display_name);
+            match self.get_json(&summary_path, &[("calendarDate", &date)], "daily summary").await.and_then(|summary| sync_time(&summary)) {
+                Ok(Some(timestamp)) => most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp))),
+                Ok(None) => {}
+                Err(e) => tracing::debug!("Garmin: could not read daily summary for {}: {}", date, e),
+            }
+
+            if most_recent.is_some() {
+                break;
+            }
+        }
+
+        let sleep_path = format!("/wellness-service/wellness/dailySleepData/{}", display_name);
+        match self.get_json(&sleep_path, &[("date", &date_string(today))], "sleep").await {
+            Ok(sleep) => {
+                if let Some(timestamp) = sleep_end_time(&sleep) {
+                    most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp)));
+                }
+            }
+            Err(e) => tracing::debug!("Garmin: could not read sleep data: {}", e),
+        }
+
+        most_recent.context("No recent sync or sleep data found from Garmin Connect")
+    }
+}
+
+fn date_string(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+#[async_trait]
+impl Output for GarminOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Garmin is used purely for checking if the user is alive via device syncs
+        Ok(OutputResult::Skipped("Garmin is a check-only adapter".to_string()))
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.get_most_recent_activity_timestamp().await {
+            Ok(timestamp) => {
+                let now = Utc::now();
+                let hours_since_activity = (now - timestamp).num_hours();
+
+                tracing::info!(
+                    "Garmin health check: most recent activity was {}",
+                    DurationStyle::Verbose.relative(timestamp - now)
+                );
+
+                Ok(hours_since_activity <= self.max_time_since_last_checkin.as_hours() as i64)
+            }
+            Err(e) => {
+                tracing::warn!("Garmin health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for GarminOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let most_recent_activity = self.get_most_recent_activity_timestamp().await?;
+
+        Ok(super::activity_checkin(
+            "Garmin",
+            most_recent_activity,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            "Garmin Device Activity Detected".to_string(),
+            "Garmin Device".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_garmin_send_message_returns_skipped() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert!(GarminOutput::new(&HashMap::new(), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).is_err());
+
+        let config = [("consumer_key", "key"), ("consumer_secret", "secret")]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let output = GarminOutput::new(&config, temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(<dyn Output>::get_name(&output), "Garmin");
+
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(reason) if reason.contains("check-only adapter")));
+
+        // No tokens yet, so no activity can be fetched
+        assert!(!<dyn Output>::health_check(&output).await.unwrap());
+    }
+
+    #[test]
+    fn test_sync_and_sleep_times() {
+        let summary = json!({"totalSteps": 4210, "lastSyncTimestampGMT": "2026-10-15T08:12:34.0"});
+        assert_eq!(sync_time(&summary).unwrap().unwrap().to_rfc3339(), "2026-10-15T08:12:34+00:00");
+        assert_eq!(sync_time(&json!({"totalSteps": null, "lastSyncTimestampGMT": null})).unwrap(), None);
+        assert!(sync_time(&json!({"lastSyncTimestampGMT": "yesterday"})).is_err());
+
+        let sleep = json!({"dailySleepDTO": {"sleepStartTimestampGMT": 1760475600000_i64, "sleepEndTimestampGMT": 1760504400000_i64}});
+        assert_eq!(sleep_end_time(&sleep).unwrap().to_rfc3339(), "2025-10-15T05:00:00+00:00");
+        assert_eq!(sleep_end_time(&json!({"dailySleepDTO": {"sleepEndTimestampGMT": null}})), None);
+    }
+}
@@ -0,0 +1,247 @@
+use super::local_activity::expand_home;
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::process::Command;
+
+const GIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Treats recent commits in local repositories as signs of life
+#[derive(Debug, Clone)]
+pub struct GitActivityOutput {
+    repos: Vec<PathBuf>,
+    /// Also count reflog entries, so checkouts, rebases and pulls count too
+    reflog: bool,
+    max_time_since_last_checkin: ConfigDuration,
+}
+
+/// Runs git in `repo`, returning its trimmed stdout
+async fn git_output(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = tokio::time::timeout(
+        GIT_TIMEOUT,
+        Command::new("git").arg("-C").arg(repo).args(args).kill_on_drop(true).output(),
+    )
+    .await
+    .with_context(|| format!("git timed out after {:?}", GIT_TIMEOUT))?
+    .context("Failed to run git")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The `%cI` committer date of a commit
+fn parse_commit_date(output: &str) -> Result<DateTime<Utc>> {
+    Ok(DateTime::parse_from_rfc3339(output)
+        .with_context(|| format!("Unexpected git commit date '{}'", output))?
+        .with_timezone(&Utc))
+}
+
+/// The time in a reflog selector printed with `--date=unix`, e.g. `HEAD@{1760520600}`
+fn parse_reflog_date(output: &str) -> Result<DateTime<Utc>> {
+    let seconds = output
+        .rsplit_once("@{")
+        .and_then(|(_, rest)| rest.strip_suffix('}'))
+        .and_then(|seconds| seconds.parse::<i64>().ok())
+        .with_context(|| format!("Unexpected git reflog entry '{}'", output))?;
+    DateTime::from_timestamp(seconds, 0).context("git reflog time out of range")
+}
+
+impl GitActivityOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["repos", "reflog"];
+
+    pub fn new(config: &HashMap<String, String>, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let repos: Vec<PathBuf> = config
+            .get("repos")
+            .context("Missing 'repos' field in git_activity config")?
+            .split(',')
+            .map(str::trim)
+            .filter(|repo| !repo.is_empty())
+            .map(expand_home)
+            .collect();
+        if repos.is_empty() {
+            anyhow::bail!("git_activity 'repos' lists no repositories");
+        }
+
+        let reflog = match config.get("reflog").map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => anyhow::bail!("Invalid 'reflog' in git_activity config: '{}' is not true or false", other),
+        };
+
+        Ok(GitActivityOutput {
+            repos,
+            reflog,
+            max_time_since_last_checkin,
+        })
+    }
+
+    /// The newest of `HEAD`'s committer date and, with `reflog`, the latest reflog entry
+    async fn repo_activity(&self, repo: &Path) -> Result<DateTime<Utc>> {
+        let mut newest = parse_commit_date(&git_output(repo, &["log", "-1", "--format=%cI"]).await?)?;
+        if self.reflog {
+            let entry = git_output(repo, &["reflog", "-1", "--date=unix", "--format=%gd"]).await?;
+            if !entry.is_empty() {
+                newest = newest.max(parse_reflog_date(&entry)?);
+            }
+        }
+        Ok(newest)
+    }
+
+    /// The most recently active repository. Missing or broken repositories
+    /// are skipped with a warning.
+    async fn most_recent_activity(&self) -> Option<(PathBuf, DateTime<Utc>)> {
+        let mut most_recent: Option<(PathBuf, DateTime<Utc>)> = None;
+        for repo in &self.repos {
+            match self.repo_activity(repo).await {
+                Ok(timestamp) => {
+                    if most_recent.as_ref().is_none_or(|(_, newest)| timestamp > *newest) {
+                        most_recent = Some((repo.clone(), timestamp));
+                    }
+                }
+                Err(e) => tracing::warn!("git_activity: skipping {}: {:#}", repo.display(), e),
+            }
+        }
+        most_recent
+    }
+}
+
+#[async_trait]
+impl Output for GitActivityOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Git activity is used purely for checking if the user is alive
+        Ok(OutputResult::Skipped("git_activity is a check-only adapter".to_string()))
+    }
+
+    /// At least one repository can be read
+    async fn health_check(&self) -> Result<bool> {
+        Ok(self.most_recent_activity().await.is_some())
+    }
+
+    fn get_name(&self) -> &str {
+        "git_activity"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for GitActivityOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let Some((repo, timestamp)) = self.most_recent_activity().await else {
+            return Ok(vec![]);
+        };
+
+        Ok(super::activity_checkin(
+            "git_activity",
+            timestamp,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            format!("Git activity in {}", repo.display()),
+            "Git Activity".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(repos: &str, reflog: bool) -> GitActivityOutput {
+        let mut config = HashMap::new();
+        config.insert("repos".to_string(), repos.to_string());
+        config.insert("reflog".to_string(), reflog.to_string());
+        GitActivityOutput::new(&config, ConfigDuration::from_hours(24)).unwrap()
+    }
+
+    fn git(path: &Path, args: &[&str], date: Option<&str>) {
+        let mut command = std::process::Command::new("git");
+        command
+            .arg("-C")
+            .arg(path)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com", "-c", "commit.gpgsign=false"])
+            .args(args);
+        if let Some(date) = date {
+            command.env("GIT_AUTHOR_DATE", date).env("GIT_COMMITTER_DATE", date);
+        }
+        let output = command.output().unwrap();
+        assert!(output.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    }
+
+    /// A repository with one commit dated `date`
+    fn commit_repo(path: &Path, date: &str) {
+        std::fs::create_dir_all(path).unwrap();
+        git(path, &["init", "-q"], None);
+        std::fs::write(path.join("notes.md"), date).unwrap();
+        git(path, &["add", "notes.md"], None);
+        git(path, &["commit", "-q", "-m", "Daily notes"], Some(date));
+    }
+
+    #[test]
+    fn test_parse_dates() {
+        assert_eq!(parse_commit_date("2026-10-15T10:30:00+01:00").unwrap().to_rfc3339(), "2026-10-15T09:30:00+00:00");
+        assert_eq!(parse_reflog_date("HEAD@{1760520600}").unwrap().timestamp(), 1_760_520_600);
+        assert!(parse_reflog_date("HEAD@{0}x").is_err());
+        assert!(GitActivityOutput::new(&HashMap::new(), ConfigDuration::from_hours(24)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recent_commit_is_a_checkin() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let old = temp_dir.path().join("old");
+        let recent = temp_dir.path().join("recent");
+        let broken = temp_dir.path().join("broken");
+        commit_repo(&old, "2020-01-01T12:00:00Z");
+        commit_repo(&recent, &Utc::now().to_rfc3339());
+        std::fs::create_dir_all(broken.join(".git")).unwrap();
+
+        let output = output(&format!("{}, {}, {}", old.display(), broken.display(), recent.display()), false);
+        let responses = output.check_for_responses(None).await.unwrap();
+        let [CheckinResponse::Found { subject, .. }] = responses.as_slice() else {
+            panic!("expected one check-in");
+        };
+        assert_eq!(subject, &format!("Git activity in {}", recent.display()));
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_old_commits_and_reflog() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path().join("repo");
+        commit_repo(&repo, "2020-01-01T12:00:00Z");
+
+        assert!(output(&repo.display().to_string(), false).check_for_responses(None).await.unwrap().is_empty());
+
+        // The commit is old, but checking out a branch just now is in the reflog
+        git(&repo, &["checkout", "-q", "-b", "topic"], None);
+        let responses = output(&repo.display().to_string(), true).check_for_responses(None).await.unwrap();
+        assert_eq!(responses.len(), 1);
+
+        let missing = output(&temp_dir.path().join("missing").display().to_string(), true);
+        assert!(missing.check_for_responses(None).await.unwrap().is_empty());
+        assert!(!<dyn Output>::health_check(&missing).await.unwrap());
+    }
+}
@@ -0,0 +1,631 @@
+use super::{Output, OutputResult};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+/// Longest IRC line, including the trailing CRLF
+const MAX_LINE_BYTES: usize = 512;
+
+/// Room left for the `:nick!user@host ` prefix the server adds when relaying
+/// a message, with the longest user and host names servers commonly allow
+const RELAY_PREFIX_RESERVE: usize = 1 + 1 + 10 + 1 + 63 + 1;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Registration can take a while on servers that look up the client's hostname and ident
+const REGISTRATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long each later step (identify, join, delivery) may take
+const STEP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for NickServ to confirm the login before carrying on anyway
+const IDENTIFY_WAIT: Duration = Duration::from_secs(5);
+
+/// Lines sent before pausing between lines, to stay under servers' flood limits
+const BURST_LINES: usize = 4;
+
+const LINE_DELAY: Duration = Duration::from_millis(1000);
+
+/// Marker echoed back by the server once every line sent before it has been processed
+const DELIVERY_PING: &str = "lastsignal-delivered";
+
+#[derive(Debug, Clone)]
+pub struct IrcOutput {
+    server: String,
+    port: u16,
+    tls: bool,
+    nick: String,
+    nickserv_password: Option<String>,
+    /// A channel (`#family`) to join and message, or a nick to message directly
+    target: String,
+}
+
+/// A parsed server line
+#[derive(Debug, PartialEq, Eq)]
+struct IrcMessage {
+    prefix: Option<String>,
+    command: String,
+    params: Vec<String>,
+}
+
+impl IrcMessage {
+    fn parse(line: &str) -> Option<Self> {
+        let mut rest = line.trim_end_matches(['\r', '\n']);
+        let prefix = match rest.strip_prefix(':') {
+            Some(stripped) => {
+                let (prefix, remainder) = stripped.split_once(' ')?;
+                rest = remainder;
+                Some(prefix.to_string())
+            }
+            None => None,
+        };
+
+        let (middle, trailing) = match rest.split_once(" :") {
+            Some((middle, trailing)) => (middle, Some(trailing)),
+            None => (rest, None),
+        };
+        let mut words = middle.split(' ').filter(|word| !word.is_empty());
+        let command = words.next()?.to_ascii_uppercase();
+        let mut params: Vec<String> = words.map(str::to_string).collect();
+        params.extend(trailing.map(str::to_string));
+
+        Some(IrcMessage { prefix, command, params })
+    }
+
+    /// The nick part of the prefix, e.g. `NickServ` from `NickServ!services@host`
+    fn source_nick(&self) -> Option<&str> {
+        self.prefix.as_deref().map(|prefix| prefix.split('!').next().unwrap_or(prefix))
+    }
+
+    /// The human-readable last parameter, usually the reason for an error
+    fn text(&self) -> &str {
+        self.params.last().map(String::as_str).unwrap_or_default()
+    }
+}
+
+trait IrcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> IrcStream for S {}
+
+/// One connection to the server. Answers PINGs while waiting for replies.
+struct IrcSession<S> {
+    stream: BufReader<S>,
+    nick: String,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> IrcSession<S> {
+    fn new(stream: S, nick: &str) -> Self {
+        IrcSession { stream: BufReader::new(stream), nick: nick.to_string() }
+    }
+
+    async fn send(&mut self, line: &str) -> Result<()> {
+        // A stray CR or LF would end the line early and start another command
+        let line: String = line.chars().filter(|c| !matches!(c, '\r' | '\n')).collect();
+        self.stream.write_all(format!("{}\r\n", line).as_bytes()).await.context("Failed to write to IRC server")?;
+        self.stream.flush().await.context("Failed to write to IRC server")
+    }
+
+    async fn next_message(&mut self) -> Result<IrcMessage> {
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await.context("Failed to read from IRC server")? == 0 {
+                anyhow::bail!("Server closed the connection");
+            }
+            let Some(message) = IrcMessage::parse(&line) else {
+                continue;
+            };
+            match message.command.as_str() {
+                "PING" => self.send(&format!("PONG :{}", message.text())).await?,
+                "ERROR" => anyhow::bail!("Server closed the connection: {}", message.text()),
+                _ => return Ok(message),
+            }
+        }
+    }
+
+    /// Sends NICK and USER and waits for the welcome. A nick already in use
+    /// is retried with underscores appended.
+    async fn register(&mut self) -> Result<()> {
+        self.send(&format!("NICK {}", self.nick)).await?;
+        self.send(&format!("USER {} 0 * :LastSignal", self.nick)).await?;
+
+        let mut retries = 0;
+        loop {
+            let message = self.next_message().await?;
+            match message.command.as_str() {
+                "001" => return Ok(()),
+                // ERR_NICKNAMEINUSE, ERR_NICKCOLLISION
+                "433" | "436" if retries < 3 => {
+                    retries += 1;
+                    self.nick.push('_');
+                    self.send(&format!("NICK {}", self.nick)).await?;
+                }
+                // ERR_ERRONEUSNICKNAME, ERR_NICKNAMEINUSE, ERR_NICKCOLLISION, ERR_UNAVAILRESOURCE,
+                // ERR_NEEDMOREPARAMS, ERR_PASSWDMISMATCH, ERR_YOUREBANNEDCREEP
+                "432" | "433" | "436" | "437" | "461" | "464" | "465" => {
+                    anyhow::bail!("{} {}", message.command, message.text())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Identifies to NickServ. Some networks never confirm, so silence is not an error.
+    async fn identify(&mut self, password: &str) -> Result<()> {
+        self.send(&format!("PRIVMSG NickServ :IDENTIFY {}", password)).await?;
+
+        let confirmation = tokio::time::timeout(IDENTIFY_WAIT, async {
+            loop {
+                let message = self.next_message().await?;
+                match message.command.as_str() {
+                    // RPL_LOGGEDIN
+                    "900" => return Ok(()),
+                    "NOTICE" if message.source_nick().is_some_and(|nick| nick.eq_ignore_ascii_case("NickServ")) => {
+                        let text = message.text().to_ascii_lowercase();
+                        if ["invalid", "incorrect", "not registered", "failed"].iter().any(|marker| text.contains(marker)) {
+                            anyhow::bail!("NickServ: {}", message.text());
+                        }
+                        if ["identified", "logged in", "accepted"].iter().any(|marker| text.contains(marker)) {
+                            return Ok(());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .await;
+
+        match confirmation {
+            Ok(result) => result,
+            Err(_) => {
+                tracing::debug!("NickServ did not confirm identification within {}s, carrying on", IDENTIFY_WAIT.as_secs());
+                Ok(())
+            }
+        }
+    }
+
+    async fn join(&mut self, channel: &str) -> Result<()> {
+        self.send(&format!("JOIN {}", channel)).await?;
+        loop {
+            let message = self.next_message().await?;
+            match message.command.as_str() {
+                // RPL_ENDOFNAMES, sent once the join has completed
+                "366" => return Ok(()),
+                // ERR_NOSUCHCHANNEL, ERR_TOOMANYCHANNELS, ERR_CHANNELISFULL, ERR_INVITEONLYCHAN,
+                // ERR_BANNEDFROMCHAN, ERR_BADCHANNELKEY, ERR_BADCHANMASK, ERR_NEEDREGGEDNICK
+                "403" | "405" | "471" | "473" | "474" | "475" | "476" | "477" => {
+                    anyhow::bail!("{} {}", message.command, message.text())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Sends every line, then waits for the server to answer a PING sent after
+    /// them, so any error about the messages has arrived by the time it returns
+    async fn deliver(&mut self, target: &str, lines: &[String]) -> Result<()> {
+        for (i, line) in lines.iter().enumerate() {
+            if i >= BURST_LINES {
+                tokio::time::sleep(LINE_DELAY).await;
+            }
+            self.send(&format!("PRIVMSG {} :{}", target, line)).await?;
+        }
+
+        self.send(&format!("PING :{}", DELIVERY_PING)).await?;
+        loop {
+            let message = self.next_message().await?;
+            match message.command.as_str() {
+                "PONG" if message.text() == DELIVERY_PING => return Ok(()),
+                // ERR_NOSUCHNICK, ERR_CANNOTSENDTOCHAN, ERR_TOOMANYTARGETS, ERR_NORECIPIENT,
+                // ERR_NOTEXTTOSEND, ERR_NOTOPLEVEL, ERR_WILDTOPLEVEL
+                "401" | "404" | "407" | "411" | "412" | "413" | "414" => {
+                    anyhow::bail!("{} {}", message.command, message.text())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    async fn quit(mut self) {
+        if let Err(e) = self.send("QUIT :LastSignal").await {
+            tracing::debug!("Failed to send IRC QUIT: {}", e);
+        }
+        let _ = self.stream.shutdown().await;
+    }
+}
+
+impl IrcOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["server", "port", "tls", "nick", "nickserv_password", "target"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let server = config
+            .get("server")
+            .context("Missing 'server' field in irc config")?
+            .trim()
+            .to_string();
+        if server.is_empty() {
+            anyhow::bail!("irc 'server' is empty");
+        }
+
+        let tls = match config.get("tls").map(|tls| tls.trim().to_ascii_lowercase()) {
+            None => true,
+            Some(tls) if tls == "true" => true,
+            Some(tls) if tls == "false" => false,
+            Some(tls) => anyhow::bail!("irc 'tls' must be true or false, got '{}'", tls),
+        };
+
+        let port = match config.get("port") {
+            Some(port) => port.parse().context("Invalid 'port' in irc config")?,
+            None if tls => 6697,
+            None => 6667,
+        };
+
+        let nick = config.get("nick").cloned().unwrap_or_else(|| "lastsignal".to_string());
+        validate_nick(&nick)?;
+
+        let target = config
+            .get("target")
+            .context("Missing 'target' field in irc config")?
+            .clone();
+        if target.is_empty() || target.contains([' ', ',', '\r', '\n']) {
+            anyhow::bail!("irc 'target' must be a single channel or nick, got '{}'", target);
+        }
+
+        Ok(IrcOutput {
+            server,
+            port,
+            tls,
+            nick,
+            nickserv_password: config.get("nickserv_password").cloned(),
+            target,
+        })
+    }
+
+    fn is_channel(&self) -> bool {
+        self.target.starts_with(['#', '&', '+', '!'])
+    }
+
+    async fn connect(&self) -> Result<IrcSession<Box<dyn IrcStream>>> {
+        let tcp = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((self.server.as_str(), self.port)))
+            .await
+            .with_context(|| format!("Timed out connecting to {}:{}", self.server, self.port))?
+            .with_context(|| format!("Failed to connect to {}:{}", self.server, self.port))?;
+
+        let stream: Box<dyn IrcStream> = if self.tls {
+            let connector = tokio_native_tls::TlsConnector::from(
+                tokio_native_tls::native_tls::TlsConnector::new().context("Failed to set up TLS")?,
+            );
+            let tls = tokio::time::timeout(CONNECT_TIMEOUT, connector.connect(&self.server, tcp))
+                .await
+                .context("Timed out during TLS handshake")?
+                .context("TLS handshake failed")?;
+            Box::new(tls)
+        } else {
+            Box::new(tcp)
+        };
+
+        Ok(IrcSession::new(stream, &self.nick))
+    }
+
+    /// Connects and registers, reporting which of the two failed
+    async fn open_session(&self) -> std::result::Result<IrcSession<Box<dyn IrcStream>>, String> {
+        let mut session = self.connect().await.map_err(|e| format!("IRC connection failed: {:#}", e))?;
+        match tokio::time::timeout(REGISTRATION_TIMEOUT, session.register()).await {
+            Ok(Ok(())) => Ok(session),
+            Ok(Err(e)) => Err(format!("IRC registration failed: {:#}", e)),
+            Err(_) => Err(format!("IRC registration timed out after {}s", REGISTRATION_TIMEOUT.as_secs())),
+        }
+    }
+
+    async fn send_lines<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        session: &mut IrcSession<S>,
+        lines: &[String],
+    ) -> std::result::Result<(), String> {
+        if let Some(password) = &self.nickserv_password {
+            step(session.identify(password)).await.map_err(|e| format!("IRC NickServ identification failed: {}", e))?;
+        }
+        if self.is_channel() {
+            step(session.join(&self.target)).await.map_err(|e| format!("IRC join of {} failed: {}", self.target, e))?;
+        }
+        step(session.deliver(&self.target, lines)).await.map_err(|e| format!("IRC PRIVMSG to {} failed: {}", self.target, e))
+    }
+}
+
+async fn step(future: impl std::future::Future<Output = Result<()>>) -> std::result::Result<(), String> {
+    match tokio::time::timeout(STEP_TIMEOUT, future).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(format!("{:#}", e)),
+        Err(_) => Err(format!("timed out after {}s", STEP_TIMEOUT.as_secs())),
+    }
+}
+
+/// Nicks are letters, digits and `[]\`_^{|}-`, not starting with a digit or `-`
+fn validate_nick(nick: &str) -> Result<()> {
+    let valid = !nick.is_empty()
+        && !nick.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+        && nick.chars().all(|c| c.is_ascii_alphanumeric() || "[]\\`_^{|}-".contains(c));
+    if !valid {
+        anyhow::bail!("Invalid irc 'nick' '{}'", nick);
+    }
+    Ok(())
+}
+
+/// Most text bytes one PRIVMSG to `target` can carry once the server adds its prefix
+fn max_text_bytes(nick: &str, target: &str) -> usize {
+    let command = format!("PRIVMSG {} :", target).len();
+    MAX_LINE_BYTES - 2 - command - nick.len() - RELAY_PREFIX_RESERVE
+}
+
+/// Splits the message into IRC lines of at most `max_bytes`, breaking long
+/// lines at spaces where possible and never inside a character. Blank lines
+/// are dropped, as IRC cannot send them.
+fn split_lines(message: &str, max_bytes: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in message.lines() {
+        let mut rest = line.trim_end();
+        while !rest.is_empty() {
+            if rest.len() <= max_bytes {
+                lines.push(rest.to_string());
+                break;
+            }
+            let mut end = max_bytes;
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            let split = if rest[end..].starts_with(' ') {
+                end
+            } else {
+                rest[..end].rfind(' ').filter(|&space| space > 0).unwrap_or(end)
+            };
+            lines.push(rest[..split].to_string());
+            rest = rest[split..].trim_start();
+        }
+    }
+    lines
+}
+
+#[async_trait]
+impl Output for IrcOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let lines = split_lines(message, max_text_bytes(&self.nick, &self.target));
+        if lines.is_empty() {
+            return Ok(OutputResult::Failed("Message is empty".to_string()));
+        }
+
+        let mut session = match self.open_session().await {
+            Ok(session) => session,
+            Err(error) => return Ok(OutputResult::Failed(error)),
+        };
+        let result = self.send_lines(&mut session, &lines).await;
+        session.quit().await;
+
+        match result {
+            Ok(()) => Ok(OutputResult::Success),
+            Err(error) => Ok(OutputResult::Failed(error)),
+        }
+    }
+
+    /// Connects, completes registration and quits
+    async fn health_check(&self) -> Result<bool> {
+        match self.open_session().await {
+            Ok(session) => {
+                session.quit().await;
+                Ok(true)
+            }
+            Err(error) => {
+                tracing::debug!("IRC health check: {}", error);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "irc"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::DuplexStream;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("server".to_string(), "irc.libera.chat".to_string());
+        config.insert("target".to_string(), "#family".to_string());
+        config
+    }
+
+    /// A fake server that answers each line the client sends with `respond`,
+    /// and returns everything it received
+    fn fake_server(
+        stream: DuplexStream,
+        respond: impl Fn(&IrcMessage) -> Vec<String> + Send + 'static,
+    ) -> tokio::task::JoinHandle<Vec<String>> {
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            let mut received = Vec::new();
+            loop {
+                let mut line = String::new();
+                if stream.read_line(&mut line).await.unwrap_or(0) == 0 {
+                    return received;
+                }
+                let line = line.trim_end().to_string();
+                for reply in respond(&IrcMessage::parse(&line).unwrap()) {
+                    stream.write_all(format!("{}\r\n", reply).as_bytes()).await.unwrap();
+                }
+                received.push(line);
+            }
+        })
+    }
+
+    fn friendly_server(message: &IrcMessage) -> Vec<String> {
+        match message.command.as_str() {
+            "USER" => vec![
+                "PING :irc.example.net".to_string(),
+                ":irc.example.net 001 lastsignal :Welcome".to_string(),
+            ],
+            "JOIN" => vec![
+                format!(":lastsignal!u@h JOIN {}", message.params[0]),
+                format!(":irc.example.net 366 lastsignal {} :End of /NAMES list.", message.params[0]),
+            ],
+            "PING" => vec![format!(":irc.example.net PONG irc.example.net :{}", message.text())],
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_irc_output_config() {
+        let output = IrcOutput::new(&test_config()).unwrap();
+        assert_eq!((output.port, output.tls), (6697, true));
+        assert_eq!(output.nick, "lastsignal");
+        assert!(output.is_channel());
+
+        let mut config = test_config();
+        config.insert("tls".to_string(), "false".to_string());
+        config.insert("target".to_string(), "alice".to_string());
+        let output = IrcOutput::new(&config).unwrap();
+        assert_eq!((output.port, output.tls), (6667, false));
+        assert!(!output.is_channel());
+
+        for (key, value) in [("tls", "yes"), ("port", "irc"), ("nick", "9lives"), ("target", "#a,#b")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(IrcOutput::new(&config).is_err(), "{} = {} should be an error", key, value);
+        }
+        for key in ["server", "target"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(IrcOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+    }
+
+    #[test]
+    fn test_parse_message() {
+        assert_eq!(
+            IrcMessage::parse(":NickServ!NickServ@services. NOTICE lastsignal :You are now identified\r\n"),
+            Some(IrcMessage {
+                prefix: Some("NickServ!NickServ@services.".to_string()),
+                command: "NOTICE".to_string(),
+                params: vec!["lastsignal".to_string(), "You are now identified".to_string()],
+            })
+        );
+        let ping = IrcMessage::parse("PING :irc.example.net").unwrap();
+        assert_eq!((ping.command.as_str(), ping.text()), ("PING", "irc.example.net"));
+        assert_eq!(IrcMessage::parse(""), None);
+    }
+
+    #[test]
+    fn test_split_lines_respects_byte_limit() {
+        assert_eq!(split_lines("one\n\ntwo  \n", 100), vec!["one", "two"]);
+        assert_eq!(split_lines("aaa bbb ccc", 7), vec!["aaa bbb", "ccc"]);
+        assert_eq!(split_lines("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+
+        // Multi-byte characters are never cut in half
+        let lines = split_lines(&"é".repeat(10), 5);
+        assert!(lines.iter().all(|line| line.len() <= 5));
+        assert_eq!(lines.concat(), "é".repeat(10));
+
+        let max = max_text_bytes("lastsignal", "#family");
+        let line = format!(":lastsignal!{}@{} PRIVMSG #family :{}\r\n", "u".repeat(10), "h".repeat(63), "x".repeat(max));
+        assert!(line.len() <= MAX_LINE_BYTES);
+    }
+
+    #[tokio::test]
+    async fn test_delivers_to_channel_after_identifying() {
+        let mut config = test_config();
+        config.insert("nickserv_password".to_string(), "hunter2".to_string());
+        let output = IrcOutput::new(&config).unwrap();
+
+        let (client, server) = tokio::io::duplex(4096);
+        let server = fake_server(server, |message| match message.command.as_str() {
+            "PRIVMSG" if message.params[0] == "NickServ" => {
+                vec![":NickServ!NickServ@services. NOTICE lastsignal :You are now identified for lastsignal.".to_string()]
+            }
+            _ => friendly_server(message),
+        });
+
+        let mut session = IrcSession::new(client, &output.nick);
+        session.register().await.unwrap();
+        output.send_lines(&mut session, &["Please check in".to_string(), "Reply here".to_string()]).await.unwrap();
+        session.quit().await;
+
+        let received = server.await.unwrap();
+        assert_eq!(
+            received,
+            vec![
+                "NICK lastsignal",
+                "USER lastsignal 0 * :LastSignal",
+                "PONG :irc.example.net",
+                "PRIVMSG NickServ :IDENTIFY hunter2",
+                "JOIN #family",
+                "PRIVMSG #family :Please check in",
+                "PRIVMSG #family :Reply here",
+                "PING :lastsignal-delivered",
+                "QUIT :LastSignal",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nick_in_use_is_retried() {
+        let (client, server) = tokio::io::duplex(4096);
+        let server = fake_server(server, |message| match message.command.as_str() {
+            "NICK" if message.params[0] == "lastsignal" => {
+                vec![":irc.example.net 433 * lastsignal :Nickname is already in use".to_string()]
+            }
+            "NICK" => vec![":irc.example.net 001 lastsignal_ :Welcome".to_string()],
+            _ => Vec::new(),
+        });
+
+        let mut session = IrcSession::new(client, "lastsignal");
+        session.register().await.unwrap();
+        assert_eq!(session.nick, "lastsignal_");
+        session.quit().await;
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failures_name_their_step() {
+        let output = IrcOutput::new(&test_config()).unwrap();
+
+        let (client, server) = tokio::io::duplex(4096);
+        let server = fake_server(server, |message| match message.command.as_str() {
+            "JOIN" => vec![":irc.example.net 474 lastsignal #family :Cannot join channel (+b)".to_string()],
+            _ => friendly_server(message),
+        });
+        let mut session = IrcSession::new(client, &output.nick);
+        session.register().await.unwrap();
+        let error = output.send_lines(&mut session, &["hi".to_string()]).await.unwrap_err();
+        assert_eq!(error, "IRC join of #family failed: 474 Cannot join channel (+b)");
+        session.quit().await;
+        server.await.unwrap();
+
+        let (client, server) = tokio::io::duplex(4096);
+        let server = fake_server(server, |message| match message.command.as_str() {
+            "PRIVMSG" => vec![":irc.example.net 404 lastsignal #family :Cannot send to channel".to_string()],
+            _ => friendly_server(message),
+        });
+        let mut session = IrcSession::new(client, &output.nick);
+        session.register().await.unwrap();
+        let error = output.send_lines(&mut session, &["hi".to_string()]).await.unwrap_err();
+        assert_eq!(error, "IRC PRIVMSG to #family failed: 404 Cannot send to channel");
+        session.quit().await;
+        server.await.unwrap();
+
+        let (client, server) = tokio::io::duplex(4096);
+        let server = fake_server(server, |message| match message.command.as_str() {
+            "USER" => vec!["ERROR :Closing Link: (K-lined)".to_string()],
+            _ => Vec::new(),
+        });
+        let mut session = IrcSession::new(client, &output.nick);
+        let error = session.register().await.unwrap_err();
+        assert!(error.to_string().contains("K-lined"), "{}", error);
+        drop(session);
+        server.await.unwrap();
+    }
+}
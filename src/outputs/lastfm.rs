@@ -0,0 +1,229 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const API_URL: &str = "https://ws.audioscrobbler.com/2.0/";
+
+/// Treats recent scrobbles on Last.fm as a sign of life
+#[derive(Debug, Clone)]
+pub struct LastfmOutput {
+    client: Client,
+    api_key: String,
+    username: String,
+    max_time_since_last_checkin: ConfigDuration,
+}
+
+/// The newest scrobble in a `user.getRecentTracks` response. A track that is
+/// playing right now has no date, so it counts as `now`.
+fn newest_scrobble(response: &Value, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+    let tracks = response
+        .get("recenttracks")
+        .and_then(|recent| recent.get("track"))
+        .context("Last.fm response has no recenttracks")?;
+
+    // A single track comes back as an object rather than a one-element array
+    let tracks = match tracks {
+        Value::Array(tracks) => tracks.iter().collect::<Vec<_>>(),
+        track => vec![track],
+    };
+
+    let mut newest: Option<DateTime<Utc>> = None;
+    for track in tracks {
+        let now_playing = track
+            .get("@attr")
+            .and_then(|attr| attr.get("nowplaying"))
+            .and_then(Value::as_str)
+            == Some("true");
+        let timestamp = if now_playing {
+            now
+        } else {
+            let Some(uts) = track.get("date").and_then(|date| date.get("uts")).and_then(Value::as_str) else {
+                continue;
+            };
+            let seconds: i64 = uts.parse().context("Invalid Last.fm scrobble timestamp")?;
+            DateTime::from_timestamp(seconds, 0).context("Invalid Last.fm scrobble timestamp")?
+        };
+        newest = Some(newest.map_or(timestamp, |existing| existing.max(timestamp)));
+    }
+    Ok(newest)
+}
+
+/// Last.fm reports bad keys and unknown users as `{"error": 6, "message": ...}`,
+/// sometimes with a 200 status
+fn api_error(response: &Value) -> Option<String> {
+    let code = response.get("error")?.as_i64()?;
+    let message = response.get("message").and_then(Value::as_str).unwrap_or("unknown error");
+    Some(format!("Last.fm error {}: {}", code, message))
+}
+
+impl LastfmOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["api_key", "username"];
+
+    pub fn new(config: &HashMap<String, String>, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let api_key = config
+            .get("api_key")
+            .context("Missing 'api_key' field in lastfm config")?
+            .clone();
+        let username = config
+            .get("username")
+            .context("Missing 'username' field in lastfm config")?
+            .clone();
+
+        Ok(LastfmOutput {
+            client: Client::new(),
+            api_key,
+            username,
+            max_time_since_last_checkin,
+        })
+    }
+
+    async fn call(&self, method: &str, extra: &[(&str, &str)]) -> Result<Value> {
+        let mut query = vec![
+            ("method", method),
+            ("user", self.username.as_str()),
+            ("api_key", self.api_key.as_str()),
+            ("format", "json"),
+        ];
+        query.extend_from_slice(extra);
+
+        let response = self
+            .client
+            .get(API_URL)
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Last.fm request failed: {}", e.without_url()))?;
+
+        let status = response.status();
+        let text = response.text().await.context("Failed to read Last.fm response")?;
+        redact::log_payload(&format!("Last.fm {} response", method), status, &text);
+
+        let body: Value = serde_json::from_str(&text)
+            .with_context(|| format!("Failed to parse Last.fm {} response (HTTP {})", method, status))?;
+        if let Some(error) = api_error(&body) {
+            anyhow::bail!(error);
+        }
+        if !status.is_success() {
+            anyhow::bail!("Last.fm returned HTTP {}", status);
+        }
+        Ok(body)
+    }
+}
+
+#[async_trait]
+impl Output for LastfmOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Last.fm is used purely for checking if the user is alive via listening activity
+        Ok(OutputResult::Skipped("Last.fm is a check-only adapter".to_string()))
+    }
+
+    /// The API key works and the username exists
+    async fn health_check(&self) -> Result<bool> {
+        match self.call("user.getInfo", &[]).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                tracing::debug!("Last.fm health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "lastfm"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for LastfmOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let response = self.call("user.getRecentTracks", &[("limit", "1")]).await?;
+        let now = Utc::now();
+        let Some(newest) = newest_scrobble(&response, now)? else {
+            tracing::debug!("Last.fm: {} has no scrobbles", self.username);
+            return Ok(vec![]);
+        };
+
+        Ok(super::activity_checkin(
+            "Last.fm",
+            newest,
+            self.max_time_since_last_checkin,
+            now,
+            "Last.fm Listening Activity Detected".to_string(),
+            format!("Last.fm user {}", self.username),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_lastfm_output_config() {
+        let mut config = HashMap::new();
+        config.insert("api_key".to_string(), "0123456789abcdef".to_string());
+        assert!(LastfmOutput::new(&config, ConfigDuration::from_hours(24)).is_err());
+
+        config.insert("username".to_string(), "rj".to_string());
+        let output = LastfmOutput::new(&config, ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(<dyn Output>::get_name(&output), "lastfm");
+    }
+
+    #[test]
+    fn test_newest_scrobble() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+
+        let scrobbled = json!({"recenttracks": {"track": [
+            {"name": "Older", "date": {"uts": "1760436000", "#text": "14 Oct 2025, 10:00"}},
+            {"name": "Newest", "date": {"uts": "1760520600", "#text": "15 Oct 2025, 09:30"}}
+        ]}});
+        assert_eq!(newest_scrobble(&scrobbled, now).unwrap().unwrap().to_rfc3339(), "2025-10-15T09:30:00+00:00");
+
+        // The currently playing track has no date and counts as now
+        let playing = json!({"recenttracks": {"track": [
+            {"name": "Playing", "@attr": {"nowplaying": "true"}},
+            {"name": "Before", "date": {"uts": "1760436000"}}
+        ]}});
+        assert_eq!(newest_scrobble(&playing, now).unwrap(), Some(now));
+
+        let single = json!({"recenttracks": {"track": {"name": "Only", "date": {"uts": "1760436000"}}}});
+        assert!(newest_scrobble(&single, now).unwrap().is_some());
+
+        let empty = json!({"recenttracks": {"track": [], "@attr": {"total": "0"}}});
+        assert_eq!(newest_scrobble(&empty, now).unwrap(), None);
+    }
+
+    #[test]
+    fn test_api_error() {
+        assert_eq!(
+            api_error(&json!({"error": 6, "message": "User not found"})).as_deref(),
+            Some("Last.fm error 6: User not found")
+        );
+        assert_eq!(api_error(&json!({"user": {"name": "rj"}})), None);
+    }
+}
@@ -0,0 +1,271 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_UTMP: &str = "/var/run/utmp";
+const DEFAULT_WTMP: &str = "/var/log/wtmp";
+
+/// Size of glibc's `struct utmp` on Linux, the same on 32 and 64-bit
+const UTMP_RECORD_SIZE: usize = 384;
+/// `ut_type` of a user login
+const USER_PROCESS: i16 = 7;
+/// Offset of `ut_tv.tv_sec` within a record
+const UTMP_TV_SEC_OFFSET: usize = 340;
+
+/// Treats logins to this machine, and changes to files such as a shell
+/// history, as signs of life
+#[derive(Debug, Clone)]
+pub struct LocalActivityOutput {
+    /// Login records to read, `utmp` for current sessions and `wtmp` for past ones
+    login_files: Vec<PathBuf>,
+    /// Files or directories whose modification time counts as activity
+    paths: Vec<PathBuf>,
+    max_time_since_last_checkin: ConfigDuration,
+}
+
+/// The latest login time in utmp/wtmp records
+fn latest_login(records: &[u8]) -> Option<DateTime<Utc>> {
+    records
+        .chunks_exact(UTMP_RECORD_SIZE)
+        .filter(|record| i16::from_ne_bytes([record[0], record[1]]) == USER_PROCESS)
+        .filter_map(|record| {
+            let seconds = &record[UTMP_TV_SEC_OFFSET..UTMP_TV_SEC_OFFSET + 4];
+            let seconds = i32::from_ne_bytes(seconds.try_into().ok()?);
+            DateTime::from_timestamp(seconds as i64, 0)
+        })
+        .max()
+}
+
+pub(super) fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+pub(super) fn modified_time(path: &Path) -> Result<DateTime<Utc>> {
+    let modified = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .with_context(|| format!("Failed to read modification time of {}", path.display()))?;
+    Ok(modified.into())
+}
+
+impl LocalActivityOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["logins", "utmp", "wtmp", "paths"];
+
+    pub fn new(config: &HashMap<String, String>, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let logins = match config.get("logins").map(String::as_str) {
+            None | Some("true") => true,
+            Some("false") => false,
+            Some(other) => anyhow::bail!("Invalid 'logins' in local_activity config: '{}' is not true or false", other),
+        };
+
+        let login_files = if logins {
+            vec![
+                PathBuf::from(config.get("utmp").map(String::as_str).unwrap_or(DEFAULT_UTMP)),
+                PathBuf::from(config.get("wtmp").map(String::as_str).unwrap_or(DEFAULT_WTMP)),
+            ]
+        } else {
+            Vec::new()
+        };
+
+        let paths: Vec<PathBuf> = config
+            .get("paths")
+            .map(|paths| paths.split(',').map(str::trim).filter(|path| !path.is_empty()).map(expand_home).collect())
+            .unwrap_or_default();
+
+        if login_files.is_empty() && paths.is_empty() {
+            anyhow::bail!("local_activity has nothing to watch: set 'paths' or leave 'logins' on");
+        }
+
+        Ok(LocalActivityOutput {
+            login_files,
+            paths,
+            max_time_since_last_checkin,
+        })
+    }
+
+    /// The newest login or modification time. Sources that can't be read are
+    /// skipped, so a missing history file doesn't hide a recent login.
+    #[cfg(unix)]
+    fn latest_activity(&self) -> Result<DateTime<Utc>> {
+        let mut most_recent: Option<DateTime<Utc>> = None;
+        let mut record = |timestamp: DateTime<Utc>| {
+            most_recent = Some(most_recent.map_or(timestamp, |existing: DateTime<Utc>| existing.max(timestamp)));
+        };
+
+        for login_file in &self.login_files {
+            if !cfg!(target_os = "linux") {
+                tracing::debug!("local_activity: login records are only read on Linux, skipping {}", login_file.display());
+                continue;
+            }
+            match std::fs::read(login_file) {
+                Ok(records) => {
+                    if let Some(timestamp) = latest_login(&records) {
+                        record(timestamp);
+                    }
+                }
+                Err(e) => tracing::debug!("local_activity: could not read {}: {}", login_file.display(), e),
+            }
+        }
+
+        for path in &self.paths {
+            match modified_time(path) {
+                Ok(timestamp) => record(timestamp),
+                Err(e) => tracing::debug!("local_activity: {:#}", e),
+            }
+        }
+
+        most_recent.context("No login records or watched paths could be read")
+    }
+
+    #[cfg(not(unix))]
+    fn latest_activity(&self) -> Result<DateTime<Utc>> {
+        anyhow::bail!("local_activity is only supported on Unix")
+    }
+}
+
+#[async_trait]
+impl Output for LocalActivityOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Local activity is used purely for checking if the user is alive
+        Ok(OutputResult::Skipped("local_activity is a check-only adapter".to_string()))
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.latest_activity() {
+            Ok(timestamp) => {
+                let cutoff_time = Utc::now() - chrono::Duration::hours(self.max_time_since_last_checkin.as_hours() as i64);
+                Ok(timestamp > cutoff_time)
+            }
+            Err(e) => {
+                tracing::debug!("local_activity health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "local_activity"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for LocalActivityOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let most_recent_activity = self.latest_activity()?;
+
+        Ok(super::activity_checkin(
+            "local_activity",
+            most_recent_activity,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            "Local Activity Detected".to_string(),
+            "This computer".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn utmp_record(ut_type: i16, seconds: i32) -> Vec<u8> {
+        let mut record = vec![0u8; UTMP_RECORD_SIZE];
+        record[0..2].copy_from_slice(&ut_type.to_ne_bytes());
+        record[UTMP_TV_SEC_OFFSET..UTMP_TV_SEC_OFFSET + 4].copy_from_slice(&seconds.to_ne_bytes());
+        record
+    }
+
+    fn paths_only(paths: &str) -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("logins".to_string(), "false".to_string());
+        config.insert("paths".to_string(), paths.to_string());
+        config
+    }
+
+    #[test]
+    fn test_latest_login() {
+        // A boot record (type 2) is newer than the logins but isn't one
+        let mut records = utmp_record(USER_PROCESS, 1_760_000_000);
+        records.extend(utmp_record(USER_PROCESS, 1_760_500_000));
+        records.extend(utmp_record(2, 1_760_900_000));
+        assert_eq!(latest_login(&records), DateTime::from_timestamp(1_760_500_000, 0));
+
+        assert_eq!(latest_login(&utmp_record(2, 1_760_900_000)), None);
+        assert_eq!(latest_login(&[]), None);
+    }
+
+    #[test]
+    fn test_local_activity_config() {
+        let output = LocalActivityOutput::new(&HashMap::new(), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(output.login_files, vec![PathBuf::from(DEFAULT_UTMP), PathBuf::from(DEFAULT_WTMP)]);
+
+        let mut config = HashMap::new();
+        config.insert("logins".to_string(), "false".to_string());
+        assert!(LocalActivityOutput::new(&config, ConfigDuration::from_hours(24)).is_err());
+        config.insert("logins".to_string(), "sometimes".to_string());
+        assert!(LocalActivityOutput::new(&config, ConfigDuration::from_hours(24)).is_err());
+
+        let output = LocalActivityOutput::new(&paths_only("/tmp/a, ~/.bash_history"), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(output.paths[0], PathBuf::from("/tmp/a"));
+        assert!(output.paths[1].ends_with(".bash_history"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_recently_modified_path_is_a_checkin() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let history = temp_dir.path().join("history");
+        let stale = temp_dir.path().join("stale");
+        std::fs::write(&history, "ls\n").unwrap();
+        std::fs::write(&stale, "").unwrap();
+
+        let two_days_ago = SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60);
+        std::fs::File::options().write(true).open(&stale).unwrap().set_modified(two_days_ago).unwrap();
+
+        let missing = temp_dir.path().join("missing");
+        let paths = format!("{},{},{}", stale.display(), history.display(), missing.display());
+        let output = LocalActivityOutput::new(&paths_only(&paths), ConfigDuration::from_hours(24)).unwrap();
+        let responses = output.check_for_responses(None).await.unwrap();
+        assert!(matches!(responses.as_slice(), [CheckinResponse::Found { .. }]));
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+
+        let output = LocalActivityOutput::new(&paths_only(&stale.display().to_string()), ConfigDuration::from_hours(24)).unwrap();
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+        assert!(!<dyn Output>::health_check(&output).await.unwrap());
+
+        let output = LocalActivityOutput::new(&paths_only(&missing.display().to_string()), ConfigDuration::from_hours(24)).unwrap();
+        assert!(output.check_for_responses(None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_local_activity_send_message_returns_skipped() {
+        let output = LocalActivityOutput::new(&HashMap::new(), ConfigDuration::from_hours(24)).unwrap();
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(reason) if reason.contains("check-only adapter")));
+    }
+}
@@ -0,0 +1,372 @@
+use super::bidirectional::{BidirectionalOutput, CheckinResponse};
+use super::{Output, OutputResult};
+use crate::formatting::{self, MessageFormat};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// How many recent room messages are searched for check-in replies
+const MESSAGES_LIMIT: u32 = 50;
+
+#[derive(Debug)]
+pub struct MatrixOutput {
+    homeserver_url: String,
+    access_token: String,
+    room_id: String,
+    /// Only messages from this user count as check-in replies
+    admin_user_id: Option<String>,
+    /// Where the timestamp of the last processed reply is kept
+    cursor_file: Option<PathBuf>,
+    client: Client,
+    /// Makes transaction IDs unique within one process
+    transaction_counter: AtomicU64,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct MatrixCursor {
+    last_processed: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    #[serde(default)]
+    chunk: Vec<RoomEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RoomEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    sender: String,
+    /// Milliseconds since the Unix epoch
+    origin_server_ts: i64,
+    #[serde(default)]
+    content: serde_json::Value,
+}
+
+impl MatrixOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["homeserver_url", "access_token", "room_id", "admin_user_id"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: Option<&Path>) -> Result<Self> {
+        let homeserver_url = config
+            .get("homeserver_url")
+            .context("Missing 'homeserver_url' field in matrix config")?
+            .trim_end_matches('/')
+            .to_string();
+
+        let access_token = config
+            .get("access_token")
+            .context("Missing 'access_token' field in matrix config")?
+            .clone();
+
+        let room_id = config
+            .get("room_id")
+            .context("Missing 'room_id' field in matrix config")?
+            .clone();
+
+        let cursor_file = data_directory.map(|dir| dir.join(cursor_file_name(&room_id)));
+
+        Ok(MatrixOutput {
+            homeserver_url,
+            access_token,
+            room_id,
+            admin_user_id: config.get("admin_user_id").cloned(),
+            cursor_file,
+            client: Client::new(),
+            transaction_counter: AtomicU64::new(0),
+        })
+    }
+
+    fn room_url(&self, path: &str) -> String {
+        format!(
+            "{}/_matrix/client/v3/rooms/{}/{}",
+            self.homeserver_url,
+            urlencoding::encode(&self.room_id),
+            path
+        )
+    }
+
+    fn next_transaction_id(&self) -> String {
+        let counter = self.transaction_counter.fetch_add(1, Ordering::Relaxed);
+        format!("lastsignal-{}-{}", Utc::now().timestamp_millis(), counter)
+    }
+
+    fn load_cursor(&self) -> Result<MatrixCursor> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(MatrixCursor::default());
+        };
+        if !cursor_file.exists() {
+            return Ok(MatrixCursor::default());
+        }
+
+        let content = std::fs::read_to_string(cursor_file)
+            .with_context(|| format!("Failed to read Matrix cursor file: {:?}", cursor_file))?;
+        serde_json::from_str(&content).context("Failed to parse Matrix cursor file")
+    }
+
+    fn save_cursor(&self, cursor: &MatrixCursor) -> Result<()> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(());
+        };
+
+        let content = serde_json::to_string_pretty(cursor).context("Failed to serialize Matrix cursor")?;
+        std::fs::write(cursor_file, content)
+            .with_context(|| format!("Failed to write Matrix cursor file: {:?}", cursor_file))
+    }
+
+    async fn fetch_recent_messages(&self, admin_user_id: &str) -> Result<MessagesResponse> {
+        let filter = json!({ "types": ["m.room.message"], "senders": [admin_user_id] }).to_string();
+        let response = self
+            .client
+            .get(self.room_url("messages"))
+            .bearer_auth(&self.access_token)
+            .query(&[("dir", "b"), ("limit", &MESSAGES_LIMIT.to_string()), ("filter", &filter)])
+            .send()
+            .await
+            .context("Matrix messages request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Matrix messages HTTP {}: {}", status, text);
+        }
+
+        response.json().await.context("Failed to parse Matrix messages response")
+    }
+}
+
+/// One cursor file per room, e.g. `matrix_abc_example.org.json` for `!abc:example.org`
+fn cursor_file_name(room_id: &str) -> String {
+    let room: String = room_id
+        .trim_start_matches('!')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("matrix_{}.json", room)
+}
+
+/// An `m.text` event. Markdown is sent as HTML in `formatted_body`, with the
+/// plain text in `body` for clients that don't render it.
+fn message_content(message: &str, format: MessageFormat) -> serde_json::Value {
+    match format {
+        MessageFormat::Plain => json!({ "msgtype": "m.text", "body": message }),
+        MessageFormat::Markdown => json!({
+            "msgtype": "m.text",
+            "body": formatting::to_plain_text(message, format),
+            "format": "org.matrix.custom.html",
+            "formatted_body": formatting::to_html(message, format),
+        }),
+    }
+}
+
+/// Text messages from `admin_user_id` sent after `after`
+fn replies_from(messages: &MessagesResponse, admin_user_id: &str, after: Option<DateTime<Utc>>) -> Vec<CheckinResponse> {
+    messages.chunk.iter()
+        .filter(|event| event.event_type == "m.room.message" && event.sender == admin_user_id)
+        .filter_map(|event| {
+            let timestamp = DateTime::from_timestamp_millis(event.origin_server_ts)?;
+            if after.is_some_and(|after| timestamp <= after) {
+                return None;
+            }
+            Some(CheckinResponse::Found {
+                timestamp,
+                subject: event.content.get("body").and_then(|body| body.as_str()).unwrap_or_default().to_string(),
+                from: event.sender.clone(),
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Output for MatrixOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        self.send_formatted(message, MessageFormat::Plain).await
+    }
+
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        let payload = message_content(message, format);
+        let url = self.room_url(&format!("send/m.room.message/{}", self.next_transaction_id()));
+
+        let response = match self
+            .client
+            .put(url)
+            .bearer_auth(&self.access_token)
+            .json(&payload)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", e)));
+            }
+        };
+
+        let status_code = response.status();
+        if status_code.is_success() {
+            return Ok(OutputResult::Success);
+        }
+
+        match response.text().await {
+            Ok(text) => Ok(OutputResult::Failed(format!("Matrix HTTP {}: {}", status_code, text))),
+            Err(e) => Ok(OutputResult::Failed(format!("Matrix HTTP {} (failed to read response: {})", status_code, e))),
+        }
+    }
+
+    /// The homeserver answers `/versions` and still accepts the access token
+    async fn health_check(&self) -> Result<bool> {
+        let versions = self.client
+            .get(format!("{}/_matrix/client/versions", self.homeserver_url))
+            .send()
+            .await;
+        match versions {
+            Ok(resp) if resp.status().is_success() => {}
+            Ok(resp) => {
+                tracing::debug!("Matrix health check: /versions returned {}", resp.status());
+                return Ok(false);
+            }
+            Err(e) => {
+                tracing::debug!("Matrix health check HTTP error: {}", e);
+                return Ok(false);
+            }
+        }
+
+        let whoami = self.client
+            .get(format!("{}/_matrix/client/v3/account/whoami", self.homeserver_url))
+            .bearer_auth(&self.access_token)
+            .send()
+            .await;
+        match whoami {
+            Ok(resp) if resp.status().is_success() => Ok(true),
+            Ok(resp) => {
+                tracing::debug!("Matrix health check: whoami returned {}", resp.status());
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("Matrix health check HTTP error: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "matrix"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for MatrixOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        Output::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Output::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        Output::get_name(self)
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let Some(admin_user_id) = &self.admin_user_id else {
+            return Ok(vec![]);
+        };
+
+        let after = since.max(self.load_cursor()?.last_processed);
+        let messages = self.fetch_recent_messages(admin_user_id).await?;
+        Ok(replies_from(&messages, admin_user_id, after))
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        let mut cursor = self.load_cursor()?;
+        if cursor.last_processed.is_some_and(|processed| processed >= timestamp) {
+            return Ok(());
+        }
+        cursor.last_processed = Some(timestamp);
+        self.save_cursor(&cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("homeserver_url".to_string(), "https://matrix.example.org/".to_string());
+        config.insert("access_token".to_string(), "syt_secret".to_string());
+        config.insert("room_id".to_string(), "!abc123:example.org".to_string());
+        config.insert("admin_user_id".to_string(), "@me:example.org".to_string());
+        config
+    }
+
+    #[test]
+    fn test_matrix_output_creation() {
+        let output = MatrixOutput::new(&test_config(), None).unwrap();
+        assert_eq!(
+            output.room_url("messages"),
+            "https://matrix.example.org/_matrix/client/v3/rooms/%21abc123%3Aexample.org/messages"
+        );
+        assert_ne!(output.next_transaction_id(), output.next_transaction_id());
+
+        for key in ["homeserver_url", "access_token", "room_id"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(MatrixOutput::new(&config, None).is_err(), "missing {} should be an error", key);
+        }
+    }
+
+    #[test]
+    fn test_replies_from_admin_after_cursor() {
+        let messages: MessagesResponse = serde_json::from_value(json!({
+            "chunk": [
+                { "type": "m.room.message", "sender": "@me:example.org", "origin_server_ts": 1_750_000_300_000_i64, "content": { "msgtype": "m.text", "body": "ok" } },
+                { "type": "m.room.message", "sender": "@bot:example.org", "origin_server_ts": 1_750_000_200_000_i64, "content": { "body": "reminder" } },
+                { "type": "m.room.message", "sender": "@me:example.org", "origin_server_ts": 1_750_000_000_000_i64, "content": { "body": "old" } },
+            ]
+        })).unwrap();
+
+        let after = DateTime::from_timestamp(1_750_000_100, 0);
+        let replies = replies_from(&messages, "@me:example.org", after);
+        assert_eq!(replies.len(), 1);
+        match &replies[0] {
+            CheckinResponse::Found { timestamp, subject, from } => {
+                assert_eq!(timestamp.timestamp(), 1_750_000_300);
+                assert_eq!(subject, "ok");
+                assert_eq!(from, "@me:example.org");
+            }
+        }
+    }
+
+    #[test]
+    fn test_markdown_is_sent_as_html() {
+        assert_eq!(message_content("Call **me**", MessageFormat::Plain), json!({ "msgtype": "m.text", "body": "Call **me**" }));
+
+        let content = message_content("Call **me** at [home](https://example.com)", MessageFormat::Markdown);
+        assert_eq!(content["body"], "Call me at home (https://example.com)");
+        assert_eq!(content["format"], "org.matrix.custom.html");
+        assert_eq!(content["formatted_body"], "<p>Call <strong>me</strong> at <a href=\"https://example.com\">home</a></p>\n");
+    }
+
+    #[tokio::test]
+    async fn test_mark_processed_until_persists_latest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = MatrixOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(output.cursor_file, Some(temp_dir.path().join("matrix_abc123_example.org.json")));
+
+        let later = Utc::now();
+        let earlier = later - chrono::Duration::hours(1);
+        output.mark_processed_until(later).await.unwrap();
+        output.mark_processed_until(earlier).await.unwrap();
+
+        let reloaded = MatrixOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(reloaded.load_cursor().unwrap().last_processed, Some(later));
+    }
+}
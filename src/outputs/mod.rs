@@ -1,14 +1,54 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveTime, Utc};
 use std::collections::HashMap;
+use crate::acknowledgement::AckLinks;
 use crate::config::OutputConfig;
-use crate::state::StateManager;
+use crate::state::{AppState, StateManager};
 use crate::duration_parser::ConfigDuration;
+use crate::encryption;
+use crate::formatting::{self, MessageFormat};
+use crate::message_adapter::render_template_variables;
+use bidirectional::CheckinResponse;
+use sha2::{Digest, Sha256};
 
+pub mod apprise;
+pub mod aws;
+pub mod chaos;
+pub mod console;
+pub mod discord;
 pub mod email;
 pub mod email_bidirectional;
+pub mod email_mailgun;
+pub mod email_ses;
+pub mod exec;
 pub mod facebook_messenger;
+pub mod file;
+pub mod file_activity;
+pub mod fitbit;
+pub mod garmin;
+pub mod git_activity;
+pub mod irc;
+pub mod lastfm;
+pub mod local_activity;
+pub mod matrix;
+pub mod mqtt_presence;
+pub mod ntfy;
+pub mod pushover;
+pub mod rate_limit;
+pub mod rocketchat;
+pub mod slack;
+pub mod sms_twilio;
+pub mod sns;
+pub mod steam;
+pub mod syslog;
+pub mod voice_twilio;
+pub mod webhook;
+pub mod whatsapp;
 pub mod whoop;
+pub mod whoop_webhook;
+pub mod withings;
+pub mod xmpp;
 pub mod bidirectional;
 
 #[derive(Debug, Clone)]
@@ -18,11 +58,41 @@ pub enum OutputResult {
     Skipped(String),
 }
 
+/// Which part of the lifecycle a message belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    Checkin,
+    LastSignal,
+}
+
+impl MessageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKind::Checkin => "checkin",
+            MessageKind::LastSignal => "last_signal",
+        }
+    }
+}
+
+/// Start of a `Failed` message for a send the server refused because a
+/// mailbox is full. Retrying won't help until someone frees up space.
+const QUOTA_FAILURE: &str = "Mailbox full or over quota";
+
 impl OutputResult {
     pub fn is_success(&self) -> bool {
         matches!(self, OutputResult::Success)
     }
 
+    pub fn quota_exceeded(detail: impl std::fmt::Display) -> Self {
+        OutputResult::Failed(format!("{}: {}", QUOTA_FAILURE, detail))
+    }
+
+    /// A failure that will keep happening until the configuration or the
+    /// receiving account is fixed, as opposed to a transient network error
+    pub fn is_persistent_failure(&self) -> bool {
+        matches!(self, OutputResult::Failed(msg) if msg.starts_with(QUOTA_FAILURE))
+    }
+
     pub fn error_message(&self) -> Option<&str> {
         match self {
             OutputResult::Success => None,
@@ -35,99 +105,397 @@ impl OutputResult {
 #[async_trait]
 pub trait Output: Send + Sync {
     async fn send_message(&self, message: &str) -> Result<OutputResult>;
+
+    /// Sends a message written in `format`, rendering it for this channel first.
+    /// Channels without rich text support get Markdown reduced to plain text.
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        self.send_message(&formatting::to_plain_text(message, format)).await
+    }
+
     async fn health_check(&self) -> Result<bool>;
     fn get_name(&self) -> &str;
 }
 
+/// What outputs are created for: the daemon's timings, which activity outputs
+/// judge check-ins against, and whether they live long enough to need upkeep
+#[derive(Debug, Clone, Copy)]
+pub struct OutputContext {
+    pub max_time_since_last_checkin: ConfigDuration,
+    pub check_interval: ConfigDuration,
+    /// True for the daemon, false for one-shot commands like `status`, which
+    /// have no use for background tasks such as token refresh
+    pub long_running: bool,
+}
+
+/// The check-in an activity output reports for activity at `timestamp`.
+/// Like WHOOP, activity outputs judge aliveness against their `window`
+/// (`max_time_since_last_checkin`) rather than the `since` they're polled
+/// with, so activity keeps counting until it falls outside the window.
+pub fn activity_checkin(
+    source: &str,
+    timestamp: DateTime<Utc>,
+    window: ConfigDuration,
+    now: DateTime<Utc>,
+    subject: String,
+    from: String,
+) -> Vec<CheckinResponse> {
+    let cutoff_time = now - chrono::Duration::seconds(window.as_secs() as i64);
+    if timestamp > cutoff_time {
+        tracing::info!("{}: {} at {}, treating as check-in", source, subject, timestamp);
+        vec![CheckinResponse::Found { timestamp, subject, from }]
+    } else {
+        tracing::debug!("{}: No activity within {}. Most recent was at {}", source, window, timestamp);
+        vec![]
+    }
+}
+
 pub struct OutputFactory;
 
 impl OutputFactory {
     pub fn create_output(
         output_type: &str,
         config: &HashMap<String, String>,
+        kind: MessageKind,
         data_directory: Option<&std::path::Path>,
-        max_time_since_last_checkin: ConfigDuration,
+        context: OutputContext,
     ) -> Result<Box<dyn Output>> {
         match output_type {
+            "apprise" => {
+                let output = apprise::AppriseOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "console" => {
+                let output = console::ConsoleOutput::new(config, kind)?;
+                Ok(Box::new(output))
+            }
+            "discord" => {
+                let output = discord::DiscordOutput::new(config)?;
+                Ok(Box::new(output))
+            }
             "email" => {
-                let output = email::EmailOutput::new(config)?;
+                let output = email::EmailOutput::new(config, kind)?.with_data_directory(data_directory);
+                Ok(Box::new(output))
+            }
+            "email_mailgun" => {
+                let output = email_mailgun::EmailMailgunOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "email_ses" => {
+                let output = email_ses::EmailSesOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "exec" => {
+                let output = exec::ExecOutput::new(config)?;
                 Ok(Box::new(output))
             }
             "facebook_messenger" => {
-                let output = facebook_messenger::FacebookMessengerOutput::new(config)?;
+                let output = facebook_messenger::FacebookMessengerOutput::new(config, data_directory)?;
+                Ok(Box::new(output))
+            }
+            "file" => {
+                let output = file::FileOutput::new(config, kind, data_directory)?;
+                Ok(Box::new(output))
+            }
+            "file_activity" => {
+                let output = file_activity::FileActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "fitbit" => {
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Fitbit output"))?
+                    .to_path_buf();
+                let output = fitbit::FitbitOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "garmin" => {
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Garmin output"))?
+                    .to_path_buf();
+                let output = garmin::GarminOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "git_activity" => {
+                let output = git_activity::GitActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "irc" => {
+                let output = irc::IrcOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "lastfm" => {
+                let output = lastfm::LastfmOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "local_activity" => {
+                let output = local_activity::LocalActivityOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "matrix" => {
+                let output = matrix::MatrixOutput::new(config, data_directory)?;
+                Ok(Box::new(output))
+            }
+            "mqtt_presence" => {
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for mqtt_presence output"))?
+                    .to_path_buf();
+                let output = mqtt_presence::MqttPresenceOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "ntfy" => {
+                let output = ntfy::NtfyOutput::new(config, data_directory)?;
+                Ok(Box::new(output))
+            }
+            "pushover" => {
+                let output = pushover::PushoverOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "rocketchat" => {
+                let output = rocketchat::RocketChatOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "slack" => {
+                let output = slack::SlackOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "sms_twilio" => {
+                let output = sms_twilio::SmsTwilioOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "sns" => {
+                let output = sns::SnsOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "steam" => {
+                let output = steam::SteamOutput::new(config, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "syslog" => {
+                let output = syslog::SyslogOutput::new(config, kind)?;
+                Ok(Box::new(output))
+            }
+            "voice_twilio" => {
+                let output = voice_twilio::VoiceTwilioOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "webhook" => {
+                let output = webhook::WebhookOutput::new(config)?;
+                Ok(Box::new(output))
+            }
+            "whatsapp" => {
+                let output = whatsapp::WhatsAppOutput::new(config)?;
                 Ok(Box::new(output))
             }
             "whoop" => {
                 let data_dir = data_directory
                     .ok_or_else(|| anyhow::anyhow!("Data directory required for WHOOP output"))?
                     .to_path_buf();
-                let output = whoop::WhoopOutput::new(config, data_dir, max_time_since_last_checkin)?;
+                let output = whoop::WhoopOutput::new(config, data_dir, context)?;
+                Ok(Box::new(output))
+            }
+            "withings" => {
+                let data_dir = data_directory
+                    .ok_or_else(|| anyhow::anyhow!("Data directory required for Withings output"))?
+                    .to_path_buf();
+                let output = withings::WithingsOutput::new(config, data_dir, context.max_time_since_last_checkin)?;
+                Ok(Box::new(output))
+            }
+            "xmpp" => {
+                let output = xmpp::XmppOutput::new(config, data_directory)?;
                 Ok(Box::new(output))
             }
             _ => anyhow::bail!("Unknown output type: {}", output_type),
         }
     }
+
+    /// The config keys an output of this type reads, or `None` for unknown types
+    pub fn config_keys(output_type: &str, is_bidirectional: bool) -> Option<&'static [&'static str]> {
+        match output_type {
+            "apprise" => Some(apprise::AppriseOutput::CONFIG_KEYS),
+            "console" => Some(console::ConsoleOutput::CONFIG_KEYS),
+            "discord" => Some(discord::DiscordOutput::CONFIG_KEYS),
+            "email" if is_bidirectional => Some(email_bidirectional::BidirectionalEmailOutput::CONFIG_KEYS),
+            "email" => Some(email::EmailOutput::CONFIG_KEYS),
+            "email_mailgun" => Some(email_mailgun::EmailMailgunOutput::CONFIG_KEYS),
+            "email_ses" => Some(email_ses::EmailSesOutput::CONFIG_KEYS),
+            "exec" => Some(exec::ExecOutput::CONFIG_KEYS),
+            "facebook_messenger" => Some(facebook_messenger::FacebookMessengerOutput::CONFIG_KEYS),
+            "file" => Some(file::FileOutput::CONFIG_KEYS),
+            "file_activity" => Some(file_activity::FileActivityOutput::CONFIG_KEYS),
+            "fitbit" => Some(fitbit::FitbitOutput::CONFIG_KEYS),
+            "garmin" => Some(garmin::GarminOutput::CONFIG_KEYS),
+            "git_activity" => Some(git_activity::GitActivityOutput::CONFIG_KEYS),
+            "irc" => Some(irc::IrcOutput::CONFIG_KEYS),
+            "lastfm" => Some(lastfm::LastfmOutput::CONFIG_KEYS),
+            "local_activity" => Some(local_activity::LocalActivityOutput::CONFIG_KEYS),
+            "matrix" => Some(matrix::MatrixOutput::CONFIG_KEYS),
+            "mqtt_presence" => Some(mqtt_presence::MqttPresenceOutput::CONFIG_KEYS),
+            "ntfy" => Some(ntfy::NtfyOutput::CONFIG_KEYS),
+            "pushover" => Some(pushover::PushoverOutput::CONFIG_KEYS),
+            "rocketchat" => Some(rocketchat::RocketChatOutput::CONFIG_KEYS),
+            "slack" => Some(slack::SlackOutput::CONFIG_KEYS),
+            "sms_twilio" => Some(sms_twilio::SmsTwilioOutput::CONFIG_KEYS),
+            "sns" => Some(sns::SnsOutput::CONFIG_KEYS),
+            "steam" => Some(steam::SteamOutput::CONFIG_KEYS),
+            "syslog" => Some(syslog::SyslogOutput::CONFIG_KEYS),
+            "voice_twilio" => Some(voice_twilio::VoiceTwilioOutput::CONFIG_KEYS),
+            "webhook" => Some(webhook::WebhookOutput::CONFIG_KEYS),
+            "whatsapp" => Some(whatsapp::WhatsAppOutput::CONFIG_KEYS),
+            "whoop" => Some(whoop::WhoopOutput::CONFIG_KEYS),
+            "withings" => Some(withings::WithingsOutput::CONFIG_KEYS),
+            "xmpp" => Some(xmpp::XmppOutput::CONFIG_KEYS),
+            _ => None,
+        }
+    }
+
+    /// Whether an output of this type reads `key` as one of a family of
+    /// prefixed keys, such as a webhook's `header_Authorization`
+    pub fn accepts_prefixed_key(output_type: &str, key: &str) -> bool {
+        match output_type {
+            "webhook" => key.starts_with(webhook::HEADER_PREFIX),
+            _ => false,
+        }
+    }
 }
 
-pub async fn process_outputs_with_fallback(
-    outputs: &[Box<dyn Output>],
+/// The minimal view of an output the dispatch code needs. Both `Output` and
+/// `BidirectionalOutput` provide it, so check-in requests and last signals go
+/// through the same send logic.
+#[async_trait]
+pub trait Sendable: Send + Sync {
+    async fn send(&self, message: &str) -> Result<OutputResult>;
+    async fn is_healthy(&self) -> Result<bool>;
+    fn name(&self) -> &str;
+}
+
+#[async_trait]
+impl Sendable for dyn Output {
+    async fn send(&self, message: &str) -> Result<OutputResult> {
+        self.send_message(message).await
+    }
+
+    async fn is_healthy(&self) -> Result<bool> {
+        self.health_check().await
+    }
+
+    fn name(&self) -> &str {
+        self.get_name()
+    }
+}
+
+#[async_trait]
+impl Sendable for dyn bidirectional::BidirectionalOutput {
+    async fn send(&self, message: &str) -> Result<OutputResult> {
+        self.send_message(message).await
+    }
+
+    async fn is_healthy(&self) -> Result<bool> {
+        self.health_check().await
+    }
+
+    fn name(&self) -> &str {
+        self.get_name()
+    }
+}
+
+/// Runs an output's health check, logging why it is being skipped if not healthy
+async fn passes_health_check<S: Sendable + ?Sized>(output: &S) -> bool {
+    match output.is_healthy().await {
+        Ok(true) => true,
+        Ok(false) => {
+            tracing::warn!("Health check failed for {}, skipping", output.name());
+            false
+        }
+        Err(e) => {
+            tracing::warn!("Health check error for {}: {}, skipping", output.name(), e);
+            false
+        }
+    }
+}
+
+/// The order outputs are tried in at `local_time`: outputs within their
+/// `active_hours` first, then the rest, each group sorted by `priority` with
+/// ties kept in config order. Outputs without a config count as priority 0
+/// and always active.
+pub fn dispatch_order(output_count: usize, output_configs: &[OutputConfig], local_time: NaiveTime) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..output_count).collect();
+    order.sort_by_key(|&i| {
+        let output_config = output_configs.get(i);
+        (!in_active_hours(output_config, local_time), output_config.map_or(0, |output_config| output_config.priority))
+    });
+    order
+}
+
+fn in_active_hours(output_config: Option<&OutputConfig>, local_time: NaiveTime) -> bool {
+    output_config
+        .and_then(|output_config| output_config.active_hours)
+        .is_none_or(|active_hours| active_hours.contains(local_time))
+}
+
+/// Sends the message through the outputs in `dispatch_order` until one succeeds.
+/// `output_configs` lines up with `outputs` and supplies per-output settings
+/// such as `message_prefix`; outputs without a config get the message as is.
+pub async fn process_outputs_with_fallback<S: Sendable + ?Sized>(
+    outputs: &[Box<S>],
     message: &str,
-    _retry_delay_hours: u32,
+    output_configs: &[OutputConfig],
+    local_time: NaiveTime,
 ) -> Result<OutputResult> {
     if outputs.is_empty() {
         return Ok(OutputResult::Failed("No outputs configured".to_string()));
     }
 
-    for (i, output) in outputs.iter().enumerate() {
-        tracing::info!("Attempting to send message via {}", output.get_name());
-        
-        let health_ok = match output.health_check().await {
-            Ok(healthy) => {
-                if !healthy {
-                    tracing::warn!("Health check failed for {}, skipping", output.get_name());
-                    false
-                } else {
-                    true
-                }
-            }
-            Err(e) => {
-                tracing::warn!("Health check error for {}: {}, skipping", output.get_name(), e);
-                false
-            }
-        };
+    let order = dispatch_order(outputs.len(), output_configs, local_time);
+    let mut persistent_failure = None;
+    for (attempt, &i) in order.iter().enumerate() {
+        let output = &outputs[i];
+        if in_active_hours(output_configs.get(i), local_time) {
+            tracing::info!("Attempting to send message via {}", output.name());
+        } else {
+            tracing::info!("Attempting to send message via {} outside its active hours, no in-hours output succeeded", output.name());
+        }
 
-        if !health_ok {
+        if !passes_health_check(output.as_ref()).await {
             continue;
         }
 
-        match output.send_message(message).await {
+        let message = match output_configs.get(i) {
+            Some(output_config) => wrap_message(message, output_config),
+            None => message.to_string(),
+        };
+
+        match output.send(&message).await {
             Ok(OutputResult::Success) => {
-                tracing::info!("Message sent successfully via {}", output.get_name());
+                tracing::info!("Message sent successfully via {}", output.name());
                 return Ok(OutputResult::Success);
             }
-            Ok(OutputResult::Failed(error)) => {
-                tracing::warn!("Failed to send message via {}: {}", output.get_name(), error);
+            Ok(result @ OutputResult::Failed(_)) => {
+                tracing::warn!("Failed to send message via {}: {}", output.name(), result.error_message().unwrap_or_default());
+                if result.is_persistent_failure() {
+                    persistent_failure = Some(result);
+                }
             }
             Ok(OutputResult::Skipped(reason)) => {
-                tracing::info!("Message sending skipped via {}: {}", output.get_name(), reason);
+                tracing::info!("Message sending skipped via {}: {}", output.name(), reason);
                 return Ok(OutputResult::Skipped(reason));
             }
             Err(e) => {
-                tracing::error!("Error sending message via {}: {}", output.get_name(), e);
+                tracing::error!("Error sending message via {}: {}", output.name(), e);
             }
         }
 
-        if i < outputs.len() - 1 {
+        if attempt < order.len() - 1 {
             tracing::info!("Trying next output immediately due to failure");
         }
     }
 
-    Ok(OutputResult::Failed("All outputs failed".to_string()))
+    // Surface a failure that needs fixing rather than the generic one
+    Ok(persistent_failure.unwrap_or_else(|| OutputResult::Failed("All outputs failed".to_string())))
 }
 
 /// Processes all outputs, sending the message to every configured recipient.
 /// Unlike process_outputs_with_fallback, this continues after the first success
 /// to ensure all recipients receive the message (used for emergency last signals).
+#[allow(dead_code)]
 pub async fn process_outputs_to_all(
     outputs: &[Box<dyn Output>],
     message: &str,
@@ -143,20 +511,7 @@ pub async fn process_outputs_to_all(
         tracing::info!("Attempting to send message via {}", output_name);
         
         // Check health first
-        let health_ok = match output.health_check().await {
-            Ok(healthy) => {
-                if !healthy {
-                    tracing::warn!("Health check failed for {}, skipping", output_name);
-                    false
-                } else {
-                    true
-                }
-            }
-            Err(e) => {
-                tracing::warn!("Health check error for {}: {}, skipping", output_name, e);
-                false
-            }
-        };
+        let health_ok = passes_health_check(output.as_ref()).await;
 
         let result = if !health_ok {
             OutputResult::Skipped("Health check failed".to_string())
@@ -190,39 +545,307 @@ pub async fn process_outputs_to_all(
     Ok(results)
 }
 
+/// Config keys every output type accepts, handled by the dispatch code rather
+/// than the output itself
+pub const COMMON_CONFIG_KEYS: &[&str] = &["message_prefix", "message_suffix", "encrypt_to", "format"];
+
+/// Applies an output's optional `message_prefix` / `message_suffix` around a
+/// message. Template variables such as `{timestamp}` work inside both.
+pub fn wrap_message(message: &str, output_config: &OutputConfig) -> String {
+    let prefix = output_config.config.get("message_prefix");
+    let suffix = output_config.config.get("message_suffix");
+    if prefix.is_none() && suffix.is_none() {
+        return message.to_string();
+    }
+
+    format!(
+        "{}{}{}",
+        prefix.map(|p| render_template_variables(p)).unwrap_or_default(),
+        message,
+        suffix.map(|s| render_template_variables(s)).unwrap_or_default(),
+    )
+}
+
+/// Identifies a webhook URL whose secret part can't be picked out: its host and
+/// a short hash of the whole URL, so two such webhooks never share an ID
+pub fn url_fingerprint(url: &str) -> String {
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string());
+    format!("{}#{}", host, &hex::encode(Sha256::digest(url.as_bytes()))[..12])
+}
+
 /// Generates a unique identifier for an output recipient based on type and config.
 /// This is used to track which recipients have already been successfully notified.
 pub fn generate_recipient_id(output_config: &OutputConfig) -> String {
     match output_config.output_type.as_str() {
+        "apprise" => {
+            // The URLs hold credentials, so only their schemes identify the output
+            match output_config.config.get("urls") {
+                Some(urls) => {
+                    let schemes: Vec<&str> = urls.split(',')
+                        .filter_map(|url| url.trim().split_once("://").map(|(scheme, _)| scheme))
+                        .collect();
+                    format!("apprise:{}", schemes.join("+"))
+                }
+                None => "apprise:unknown".to_string(),
+            }
+        }
+        "console" => {
+            let name = output_config.config.get("name").map(String::as_str).unwrap_or("console");
+            format!("console:{}", name)
+        }
+        "discord" => {
+            if let Some(webhook_url) = output_config.config.get("webhook_url") {
+                format!("discord:{}", discord::webhook_label(webhook_url))
+            } else {
+                "discord:unknown".to_string()
+            }
+        }
         "email" => {
             if let Some(to) = output_config.config.get("to") {
                 format!("email:{}", to)
             } else {
-                format!("email:unknown")
+                "email:unknown".to_string()
+            }
+        }
+        "email_mailgun" => {
+            if let Some(to) = output_config.config.get("to") {
+                format!("email_mailgun:{}", to)
+            } else {
+                "email_mailgun:unknown".to_string()
+            }
+        }
+        "email_ses" => {
+            if let Some(to) = output_config.config.get("to") {
+                format!("email_ses:{}", to)
+            } else {
+                "email_ses:unknown".to_string()
+            }
+        }
+        "exec" => {
+            if let Some(command) = output_config.config.get("command") {
+                format!("exec:{}", command)
+            } else {
+                "exec:unknown".to_string()
             }
         }
         "facebook_messenger" => {
             if let Some(user_id) = output_config.config.get("user_id") {
                 format!("facebook_messenger:{}", user_id)
             } else {
-                format!("facebook_messenger:unknown")
+                "facebook_messenger:unknown".to_string()
+            }
+        }
+        "file" => {
+            if let Some(path) = output_config.config.get("path") {
+                format!("file:{}", path)
+            } else {
+                "file:unknown".to_string()
+            }
+        }
+        "file_activity" => {
+            if let Some(paths) = output_config.config.get("paths") {
+                format!("file_activity:{}", paths)
+            } else {
+                "file_activity:unknown".to_string()
+            }
+        }
+        "fitbit" => {
+            // Fitbit doesn't send messages, but include for completeness
+            "fitbit:device".to_string()
+        }
+        "garmin" => {
+            // Garmin doesn't send messages, but include for completeness
+            "garmin:device".to_string()
+        }
+        "git_activity" => {
+            if let Some(repos) = output_config.config.get("repos") {
+                format!("git_activity:{}", repos)
+            } else {
+                "git_activity:unknown".to_string()
+            }
+        }
+        "irc" => {
+            match (output_config.config.get("server"), output_config.config.get("target")) {
+                (Some(server), Some(target)) => format!("irc:{}/{}", server, target),
+                _ => "irc:unknown".to_string(),
+            }
+        }
+        "lastfm" => {
+            if let Some(username) = output_config.config.get("username") {
+                format!("lastfm:{}", username)
+            } else {
+                "lastfm:unknown".to_string()
+            }
+        }
+        "local_activity" => {
+            // Local activity doesn't send messages, but include for completeness
+            "local_activity:this-computer".to_string()
+        }
+        "matrix" => {
+            if let Some(room_id) = output_config.config.get("room_id") {
+                format!("matrix:{}", room_id)
+            } else {
+                "matrix:unknown".to_string()
+            }
+        }
+        "mqtt_presence" => {
+            match (output_config.config.get("broker"), output_config.config.get("topic")) {
+                (Some(broker), Some(topic)) => format!("mqtt_presence:{}/{}", broker, topic),
+                _ => "mqtt_presence:unknown".to_string(),
+            }
+        }
+        "ntfy" => {
+            match (output_config.config.get("server_url"), output_config.config.get("topic")) {
+                (Some(server_url), Some(topic)) => {
+                    let host = reqwest::Url::parse(server_url).ok()
+                        .and_then(|url| url.host_str().map(str::to_string))
+                        .unwrap_or_else(|| "unknown".to_string());
+                    format!("ntfy:{}/{}", host, topic)
+                }
+                _ => "ntfy:unknown".to_string(),
+            }
+        }
+        "pushover" => {
+            // One Pushover user key can reach several devices, so the key is the recipient
+            if let Some(user_key) = output_config.config.get("user_key") {
+                format!("pushover:{}", user_key)
+            } else {
+                "pushover:unknown".to_string()
+            }
+        }
+        "rocketchat" => {
+            // The webhook path holds its token, so the host and channel identify it
+            let host = output_config.config.get("webhook_url")
+                .and_then(|url| reqwest::Url::parse(url).ok())
+                .and_then(|url| url.host_str().map(str::to_string));
+            match (host, output_config.config.get("channel")) {
+                (Some(host), Some(channel)) => format!("rocketchat:{}/{}", host, channel),
+                (Some(host), None) => format!("rocketchat:{}", host),
+                (None, _) => "rocketchat:unknown".to_string(),
+            }
+        }
+        "slack" => {
+            if let Some(webhook_url) = output_config.config.get("webhook_url") {
+                format!("slack:{}", slack::webhook_label(webhook_url))
+            } else {
+                "slack:unknown".to_string()
+            }
+        }
+        "sms_twilio" => {
+            if let Some(to_number) = output_config.config.get("to_number") {
+                format!("sms_twilio:{}", to_number)
+            } else {
+                "sms_twilio:unknown".to_string()
+            }
+        }
+        "sns" => {
+            if let Some(topic_arn) = output_config.config.get("topic_arn") {
+                format!("sns:{}", topic_arn)
+            } else {
+                "sns:unknown".to_string()
+            }
+        }
+        "steam" => {
+            if let Some(steam_id) = output_config.config.get("steam_id") {
+                format!("steam:{}", steam_id)
+            } else {
+                "steam:unknown".to_string()
+            }
+        }
+        "syslog" => {
+            let facility = output_config.config.get("facility").map(String::as_str).unwrap_or("daemon");
+            format!("syslog:{}", facility)
+        }
+        "voice_twilio" => {
+            if let Some(to_number) = output_config.config.get("to_number") {
+                format!("voice_twilio:{}", to_number)
+            } else {
+                "voice_twilio:unknown".to_string()
+            }
+        }
+        "webhook" => {
+            // The query string may hold a secret, so only the host and path identify it
+            match output_config.config.get("url").and_then(|url| reqwest::Url::parse(url).ok()) {
+                Some(url) => format!("webhook:{}{}", url.host_str().unwrap_or("unknown"), url.path()),
+                None => "webhook:unknown".to_string(),
+            }
+        }
+        "whatsapp" => {
+            if let Some(to_number) = output_config.config.get("to_number") {
+                format!("whatsapp:{}", to_number)
+            } else {
+                "whatsapp:unknown".to_string()
             }
         }
         "whoop" => {
             // WHOOP doesn't send messages, but include for completeness
             "whoop:device".to_string()
         }
+        "withings" => {
+            // Withings doesn't send messages, but include for completeness
+            "withings:device".to_string()
+        }
+        "xmpp" => {
+            if let Some(to_jid) = output_config.config.get("to_jid") {
+                format!("xmpp:{}", to_jid)
+            } else {
+                "xmpp:unknown".to_string()
+            }
+        }
         _ => format!("{}:unknown", output_config.output_type)
     }
 }
 
+/// The people an output reaches, one id each. An email output with several
+/// `to` addresses sends one message, but each address is tracked on its own.
+pub fn generate_recipient_ids(output_config: &OutputConfig) -> Vec<String> {
+    if output_config.output_type == "email"
+        && let Some(to) = output_config.config.get("to")
+    {
+        let ids: Vec<String> = to
+            .split(',')
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| format!("email:{}", address))
+            .collect();
+        if !ids.is_empty() {
+            return ids;
+        }
+    }
+    vec![generate_recipient_id(output_config)]
+}
+
+/// Which recipient tracking a last signal send reads and updates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LastSignalRun {
+    /// The real last signal, deduplicated against `last_signal_recipients_notified`
+    Live {
+        min_renotify_interval: ConfigDuration,
+        force_renotify: bool,
+        /// How long a recipient who wasn't reached waits before the next
+        /// attempt. `None` for `lastsignal fire`, which tries everyone now.
+        output_retry_delay: Option<ConfigDuration>,
+    },
+    /// `lastsignal rehearse` to the canary outputs, recorded in
+    /// `canary_recipients_notified` only
+    Rehearsal,
+}
+
 /// Processes last signal outputs with recipient tracking to prevent duplicate notifications.
-/// Only sends to recipients who haven't already been successfully notified.
+/// Only sends to recipients who haven't already been successfully notified or
+/// acknowledged it. `{ack_url}` expands to each recipient's own link, or to
+/// nothing without `ack_links` or when one message goes to several recipients.
 pub async fn process_last_signal_outputs(
     output_configs: &[OutputConfig],
     outputs: &[Box<dyn Output>],
     message: &str,
+    format: MessageFormat,
     state_manager: &mut StateManager,
+    run: LastSignalRun,
+    ack_links: Option<&AckLinks>,
 ) -> Result<Vec<(String, String, OutputResult)>> {
     if outputs.is_empty() {
         return Ok(vec![]);
@@ -230,78 +853,161 @@ pub async fn process_last_signal_outputs(
 
     let mut results = Vec::new();
     
-    for (i, (output_config, output)) in output_configs.iter().zip(outputs.iter()).enumerate() {
-        let recipient_id = generate_recipient_id(output_config);
+    for (output_config, output) in output_configs.iter().zip(outputs.iter()) {
         let output_name = output.get_name().to_string();
-        
-        // Skip if already notified
-        if state_manager.get_state().is_last_signal_recipient_already_notified(&recipient_id) {
-            tracing::info!("Skipping {} - recipient {} already notified", output_name, recipient_id);
-            results.push((
-                output_name,
-                recipient_id,
-                OutputResult::Skipped("Recipient already notified".to_string())
-            ));
+
+        let mut pending = Vec::new();
+        for recipient_id in generate_recipient_ids(output_config) {
+            if let LastSignalRun::Live { min_renotify_interval, force_renotify, output_retry_delay } = run
+                && let Some(reason) = live_skip_reason(state_manager.get_state(), &recipient_id, min_renotify_interval, force_renotify, output_retry_delay)
+            {
+                tracing::info!("Skipping {} - recipient {} {}", output_name, recipient_id, reason);
+                results.push((output_name.clone(), recipient_id, OutputResult::Skipped(reason)));
+            } else {
+                pending.push(recipient_id);
+            }
+        }
+        if pending.is_empty() {
             continue;
         }
-        
-        tracing::info!("Attempting to send last signal via {} to {}", output_name, recipient_id);
-        
-        // Check health first
-        let health_ok = match output.health_check().await {
-            Ok(healthy) => {
-                if !healthy {
-                    tracing::warn!("Health check failed for {}, skipping", output_name);
-                    false
-                } else {
-                    true
+
+        // One message reaches all of the output's recipients
+        let recipients = pending.join(", ");
+        tracing::info!("Attempting to send last signal via {} to {}", output_name, recipients);
+        let ack_url = match (ack_links, pending.as_slice()) {
+            (Some(links), [recipient_id]) => links.url_for(recipient_id),
+            _ => String::new(),
+        };
+        let message = message.replace("{ack_url}", &ack_url);
+        let result = send_last_signal(output_config, output.as_ref(), &message, format, &recipients).await;
+        for recipient_id in pending {
+            if matches!(run, LastSignalRun::Live { .. })
+                && let Err(e) = state_manager.record_last_signal_attempt(&recipient_id)
+            {
+                tracing::error!("Failed to record last signal attempt: {}", e);
+            }
+            if result.is_success() {
+                let recorded = match run {
+                    LastSignalRun::Live { .. } => state_manager.record_last_signal_recipient_notified(&recipient_id),
+                    LastSignalRun::Rehearsal => state_manager.record_canary_recipient_notified(&recipient_id),
+                };
+                if let Err(e) = recorded {
+                    tracing::error!("Failed to record recipient notification: {}", e);
                 }
             }
+            results.push((output_name.clone(), recipient_id, result.clone()));
+        }
+    }
+
+    Ok(results)
+}
+
+/// Why a live last signal should not go to this recipient now, if it shouldn't
+fn live_skip_reason(
+    state: &AppState,
+    recipient_id: &str,
+    min_renotify_interval: ConfigDuration,
+    force_renotify: bool,
+    output_retry_delay: Option<ConfigDuration>,
+) -> Option<String> {
+    if state.is_last_signal_recipient_already_notified(recipient_id) {
+        return Some("Recipient already notified".to_string());
+    }
+
+    // A recipient who confirmed they saw it doesn't need it again
+    if let Some(acknowledged_at) = state.last_signal_acknowledgements.get(recipient_id) {
+        return Some(format!("acknowledged at {}", acknowledged_at.format("%Y-%m-%d %H:%M:%S UTC")));
+    }
+
+    // A recipient the last attempt didn't reach waits for output_retry_delay
+    if let Some(output_retry_delay) = output_retry_delay
+        && let Some(retry_at) = state.last_signal_retry_waiting_until(recipient_id, output_retry_delay)
+    {
+        return Some(format!("not reached yet, retrying after {}", retry_at.format("%Y-%m-%d %H:%M:%S UTC")));
+    }
+
+    // Never re-send to the same recipient faster than the configured floor
+    if !force_renotify
+        && let Some(sent_at) = state.recently_notified_at(recipient_id, min_renotify_interval)
+    {
+        return Some(format!(
+            "recently notified at {}, suppressed by min_renotify_interval",
+            sent_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+
+    None
+}
+
+/// Renders the last signal in the output's own `format` when it has one,
+/// overriding the message file's. A Markdown message sent to an output with
+/// `format = "plain"` has its markup stripped first.
+fn render_for_output(message: &str, format: MessageFormat, output_config: &OutputConfig) -> (String, MessageFormat) {
+    // Config validation rejects values that don't parse
+    match output_config.config.get("format").and_then(|format| format.parse().ok()) {
+        Some(MessageFormat::Plain) => (formatting::to_plain_text(message, format), MessageFormat::Plain),
+        Some(MessageFormat::Markdown) => (message.to_string(), MessageFormat::Markdown),
+        None => (message.to_string(), format),
+    }
+}
+
+/// Encrypts the last signal if the recipient has a key, wraps it in the
+/// output's prefix and suffix, then sends it if the output is healthy
+async fn send_last_signal(
+    output_config: &OutputConfig,
+    output: &(dyn Output + 'static),
+    message: &str,
+    format: MessageFormat,
+    recipient_id: &str,
+) -> OutputResult {
+    let output_name = output.get_name();
+    let (message, format) = render_for_output(message, format, output_config);
+
+    // Recipients with a key get ciphertext, never the plaintext
+    let (message, format) = match output_config.config.get("encrypt_to") {
+        Some(encrypt_to) => match encryption::encrypt_message(&message, encrypt_to) {
+            Ok(encrypted) => (encrypted, MessageFormat::Plain),
             Err(e) => {
-                tracing::warn!("Health check error for {}: {}, skipping", output_name, e);
-                false
+                tracing::error!("Failed to encrypt last signal for {}: {:#}", recipient_id, e);
+                return OutputResult::Failed(format!("Encryption failed: {:#}", e));
             }
-        };
+        },
+        None => (message, format),
+    };
+    // Outside the ciphertext, so the recipient's filters and eyes can see it
+    let message = wrap_message(&message, output_config);
 
-        let result = if !health_ok {
-            OutputResult::Skipped("Health check failed".to_string())
-        } else {
-            match output.send_message(message).await {
-                Ok(result) => {
-                    match &result {
-                        OutputResult::Success => {
-                            tracing::info!("Last signal sent successfully via {} to {}", output_name, recipient_id);
-                            // Record successful notification
-                            if let Err(e) = state_manager.record_last_signal_recipient_notified(&recipient_id) {
-                                tracing::error!("Failed to record recipient notification: {}", e);
-                            }
-                        }
-                        OutputResult::Failed(error) => {
-                            tracing::warn!("Failed to send last signal via {} to {}: {}", output_name, recipient_id, error);
-                        }
-                        OutputResult::Skipped(reason) => {
-                            tracing::info!("Last signal sending skipped via {} to {}: {}", output_name, recipient_id, reason);
-                        }
-                    }
-                    result
+    // Check health first
+    if !passes_health_check(output).await {
+        return OutputResult::Skipped("Health check failed".to_string());
+    }
+
+    match output.send_formatted(&message, format).await {
+        Ok(result) => {
+            match &result {
+                OutputResult::Success => {
+                    tracing::info!("Last signal sent successfully via {} to {}", output_name, recipient_id);
                 }
-                Err(e) => {
-                    let error_msg = format!("Error sending last signal: {}", e);
-                    tracing::error!("Error sending last signal via {} to {}: {}", output_name, recipient_id, e);
-                    OutputResult::Failed(error_msg)
+                OutputResult::Failed(error) => {
+                    tracing::warn!("Failed to send last signal via {} to {}: {}", output_name, recipient_id, error);
+                }
+                OutputResult::Skipped(reason) => {
+                    tracing::info!("Last signal sending skipped via {} to {}: {}", output_name, recipient_id, reason);
                 }
             }
-        };
-        
-        results.push((output_name, recipient_id, result));
+            result
+        }
+        Err(e) => {
+            tracing::error!("Error sending last signal via {} to {}: {}", output_name, recipient_id, e);
+            OutputResult::Failed(format!("Error sending last signal: {}", e))
+        }
     }
-
-    Ok(results)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
 
     struct MockOutput {
         name: String,
@@ -345,7 +1051,7 @@ mod tests {
             Box::new(MockOutput::new("second", false, true)),
         ];
 
-        let result = process_outputs_with_fallback(&outputs, "test message", 1).await.unwrap();
+        let result = process_outputs_with_fallback(&outputs, "test message", &[], NaiveTime::MIN).await.unwrap();
         assert!(result.is_success());
     }
 
@@ -356,7 +1062,7 @@ mod tests {
             Box::new(MockOutput::new("second", true, true)),
         ];
 
-        let result = process_outputs_with_fallback(&outputs, "test message", 1).await.unwrap();
+        let result = process_outputs_with_fallback(&outputs, "test message", &[], NaiveTime::MIN).await.unwrap();
         assert!(result.is_success());
     }
 
@@ -367,7 +1073,7 @@ mod tests {
             Box::new(MockOutput::new("healthy", true, true)),
         ];
 
-        let result = process_outputs_with_fallback(&outputs, "test message", 1).await.unwrap();
+        let result = process_outputs_with_fallback(&outputs, "test message", &[], NaiveTime::MIN).await.unwrap();
         assert!(result.is_success());
     }
 
@@ -378,7 +1084,7 @@ mod tests {
             Box::new(MockOutput::new("second", false, true)),
         ];
 
-        let result = process_outputs_with_fallback(&outputs, "test message", 1).await.unwrap();
+        let result = process_outputs_with_fallback(&outputs, "test message", &[], NaiveTime::MIN).await.unwrap();
         assert!(!result.is_success());
         assert!(result.error_message().unwrap().contains("All outputs failed"));
     }
@@ -439,4 +1145,563 @@ mod tests {
         assert!(matches!(results[0].1, OutputResult::Success));
         assert!(matches!(results[1].1, OutputResult::Skipped(_)));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_last_signal_respects_min_renotify_interval() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), "a@example.com".to_string());
+        let output_configs = vec![OutputConfig {
+            output_type: "email".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }];
+        let outputs: Vec<Box<dyn Output>> = vec![Box::new(MockOutput::new("email", true, true))];
+        let one_day = ConfigDuration::from_hours(24);
+
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "msg", MessageFormat::Plain, &mut state_manager, live(one_day, false), None,
+        ).await.unwrap();
+        assert!(matches!(results[0].2, OutputResult::Success));
+
+        // A check-in resets the episode, but the recipient was only just notified
+        state_manager.clear_last_signal_recipient_tracking().unwrap();
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "msg", MessageFormat::Plain, &mut state_manager, live(one_day, false), None,
+        ).await.unwrap();
+        match &results[0].2 {
+            OutputResult::Skipped(reason) => {
+                assert!(reason.starts_with("recently notified at"));
+                assert!(reason.ends_with("suppressed by min_renotify_interval"));
+            }
+            other => panic!("expected skip, got {:?}", other),
+        }
+
+        // Forcing bypasses the floor
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "msg", MessageFormat::Plain, &mut state_manager, live(one_day, true), None,
+        ).await.unwrap();
+        assert!(matches!(results[0].2, OutputResult::Success));
+    }
+
+    struct RecordingOutput(Arc<Mutex<Vec<(String, MessageFormat)>>>);
+
+    #[async_trait]
+    impl Output for RecordingOutput {
+        async fn send_message(&self, message: &str) -> Result<OutputResult> {
+            self.send_formatted(message, MessageFormat::Plain).await
+        }
+
+        async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+            self.0.lock().unwrap().push((message.to_string(), format));
+            Ok(OutputResult::Success)
+        }
+
+        async fn health_check(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn get_name(&self) -> &str {
+            "recording"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_encrypted_for_recipients_with_a_key() {
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+        let identity = age::x25519::Identity::generate();
+
+        let mut config = HashMap::new();
+        config.insert("path".to_string(), "sent.log".to_string());
+        config.insert("encrypt_to".to_string(), identity.to_public().to_string());
+        config.insert("message_prefix".to_string(), "LASTSIGNAL: ".to_string());
+        let output_configs = vec![OutputConfig {
+            output_type: "file".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }];
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![Box::new(RecordingOutput(sent.clone()))];
+
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "**secret** message", MessageFormat::Markdown, &mut state_manager, live(ConfigDuration::from_hours(24), false), None,
+        ).await.unwrap();
+        assert!(matches!(results[0].2, OutputResult::Success));
+
+        let sent = sent.lock().unwrap();
+        let (message, format) = &sent[0];
+        assert!(!message.contains("secret"));
+        assert!(message.contains("-----BEGIN AGE ENCRYPTED FILE-----"));
+        assert_eq!(*format, MessageFormat::Plain);
+
+        // The prefix is readable in the clear, before the armor
+        assert!(message.starts_with("LASTSIGNAL: This message from LastSignal is encrypted"), "{}", message);
+        let armor = message.find("-----BEGIN AGE ENCRYPTED FILE-----").unwrap();
+        assert!(!message[armor..].contains("LASTSIGNAL"));
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_output_format_overrides_the_message_format() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+
+        let output_configs: Vec<OutputConfig> = [None, Some("plain")].iter()
+            .enumerate()
+            .map(|(i, format)| OutputConfig {
+                output_type: "file".to_string(),
+                config: [("path".to_string(), format!("sent-{}.log", i))].into_iter()
+                    .chain(format.map(|format| ("format".to_string(), format.to_string())))
+                    .collect(),
+                bidirectional: false,
+                priority: 0,
+                active_hours: None,
+                additional_imap_accounts: Vec::new(),
+            })
+            .collect();
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![
+            Box::new(RecordingOutput(sent.clone())),
+            Box::new(RecordingOutput(sent.clone())),
+        ];
+
+        process_last_signal_outputs(
+            &output_configs, &outputs, "Call **555-0100**", MessageFormat::Markdown, &mut state_manager, live(ConfigDuration::from_hours(24), false), None,
+        ).await.unwrap();
+
+        assert_eq!(*sent.lock().unwrap(), vec![
+            ("Call **555-0100**".to_string(), MessageFormat::Markdown),
+            ("Call 555-0100".to_string(), MessageFormat::Plain),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_ack_links_and_acknowledged_recipients() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+
+        let output_configs: Vec<OutputConfig> = ["a@example.com", "b@example.com"].iter()
+            .map(|to| OutputConfig {
+                output_type: "email".to_string(),
+                config: HashMap::from([("to".to_string(), to.to_string())]),
+                bidirectional: false,
+                priority: 0,
+                active_hours: None,
+                additional_imap_accounts: Vec::new(),
+            })
+            .collect();
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![
+            Box::new(RecordingOutput(sent.clone())),
+            Box::new(RecordingOutput(sent.clone())),
+        ];
+        state_manager.record_last_signal_acknowledged("email:b@example.com", chrono::Utc::now()).unwrap();
+
+        let links = crate::acknowledgement::AcknowledgementsConfig {
+            listen: Some("127.0.0.1:8787".to_string()),
+            public_url: Some("https://example.com".to_string()),
+            secret: Some("0123456789abcdef".to_string()),
+        }.links(chrono::Utc::now());
+
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "Confirm: {ack_url}", MessageFormat::Plain, &mut state_manager, live(ConfigDuration::from_hours(24), false), links.as_ref(),
+        ).await.unwrap();
+
+        assert!(matches!(results[0].2, OutputResult::Success));
+        assert!(matches!(&results[1].2, OutputResult::Skipped(reason) if reason.starts_with("acknowledged at")));
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].0.starts_with("Confirm: https://example.com/ack?r=email%3Aa%40example.com&t="));
+    }
+
+    #[tokio::test]
+    async fn test_last_signal_to_several_addresses_tracks_each() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+        let output_configs = vec![OutputConfig {
+            output_type: "email".to_string(),
+            config: HashMap::from([("to".to_string(), "a@example.com, b@example.com".to_string())]),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }];
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![Box::new(RecordingOutput(sent.clone()))];
+        let links = crate::acknowledgement::AcknowledgementsConfig {
+            listen: Some("127.0.0.1:8787".to_string()),
+            public_url: Some("https://example.com".to_string()),
+            secret: Some("0123456789abcdef".to_string()),
+        }.links(chrono::Utc::now());
+
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "Confirm: {ack_url}", MessageFormat::Plain, &mut state_manager, live(ConfigDuration::from_hours(24), false), links.as_ref(),
+        ).await.unwrap();
+
+        // One message, with no link since it couldn't say who acknowledged
+        assert_eq!(sent.lock().unwrap().len(), 1);
+        assert_eq!(sent.lock().unwrap()[0].0, "Confirm: ");
+        let recipients: Vec<&str> = results.iter().map(|(_, recipient_id, _)| recipient_id.as_str()).collect();
+        assert_eq!(recipients, ["email:a@example.com", "email:b@example.com"]);
+        assert!(results.iter().all(|(_, _, result)| result.is_success()));
+        let notified = &state_manager.get_state().last_signal_recipients_notified;
+        assert!(notified.contains_key("email:a@example.com") && notified.contains_key("email:b@example.com"));
+
+        // Once both are notified, nothing more is sent
+        let results = process_last_signal_outputs(
+            &output_configs, &outputs, "Confirm: {ack_url}", MessageFormat::Plain, &mut state_manager, live(ConfigDuration::from_hours(24), false), links.as_ref(),
+        ).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, _, result)| matches!(result, OutputResult::Skipped(_))));
+        assert_eq!(sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rehearsal_leaves_live_tracking_untouched() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut state_manager = StateManager::new(temp_dir.path()).unwrap();
+        let output_configs = vec![OutputConfig {
+            output_type: "email".to_string(),
+            config: HashMap::from([("to".to_string(), "me+canary@example.com".to_string())]),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }];
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![Box::new(RecordingOutput(sent.clone()))];
+
+        // Rehearsals ignore the re-notification floor, so they can be repeated
+        for _ in 0..2 {
+            let results = process_last_signal_outputs(
+                &output_configs, &outputs, "msg {ack_url}", MessageFormat::Plain, &mut state_manager, LastSignalRun::Rehearsal, None,
+            ).await.unwrap();
+            assert!(matches!(results[0].2, OutputResult::Success));
+        }
+
+        assert_eq!(sent.lock().unwrap().len(), 2);
+        assert_eq!(sent.lock().unwrap()[0].0, "msg ");
+        let state = state_manager.get_state();
+        assert!(state.canary_recipients_notified.contains_key("email:me+canary@example.com"));
+        assert!(state.last_signal_recipients_notified.is_empty());
+        assert!(state.last_signal_recipient_history.is_empty());
+        assert!(state.last_signal_fired.is_none());
+    }
+
+    #[test]
+    fn test_activity_checkin_uses_the_window() {
+        let now = Utc::now();
+        let window = ConfigDuration::from_hours(24);
+        let checkin = |age: chrono::Duration| activity_checkin("test", now - age, window, now, "Step count".to_string(), "Tracker".to_string());
+
+        let [CheckinResponse::Found { timestamp, subject, from }] = checkin(chrono::Duration::hours(23)).try_into().unwrap();
+        assert_eq!((timestamp, subject.as_str(), from.as_str()), (now - chrono::Duration::hours(23), "Step count", "Tracker"));
+        assert!(checkin(chrono::Duration::hours(25)).is_empty());
+
+        // Windows shorter than an hour, or not a whole number of hours, aren't rounded down
+        let checkin = |window: &str, age: chrono::Duration| {
+            activity_checkin("test", now - age, window.parse().unwrap(), now, "Step count".to_string(), "Tracker".to_string())
+        };
+        assert_eq!(checkin("30m", chrono::Duration::minutes(20)).len(), 1);
+        assert!(checkin("30m", chrono::Duration::minutes(40)).is_empty());
+        assert_eq!(checkin("90m", chrono::Duration::minutes(80)).len(), 1);
+        assert!(checkin("90m", chrono::Duration::minutes(100)).is_empty());
+    }
+
+    #[test]
+    fn test_wrap_message_applies_prefix_suffix_and_templates() {
+        let mut config = HashMap::new();
+        config.insert("message_prefix".to_string(), "LASTSIGNAL: ".to_string());
+        config.insert("message_suffix".to_string(), " (sent {timestamp})".to_string());
+        let output_config = OutputConfig {
+            output_type: "email".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+
+        let wrapped = wrap_message("hello", &output_config);
+        assert!(wrapped.starts_with("LASTSIGNAL: hello (sent "));
+        assert!(wrapped.ends_with("UTC)"));
+        assert!(!wrapped.contains("{timestamp}"));
+    }
+
+    #[test]
+    fn test_wrap_message_without_keys_is_untouched() {
+        let output_config = OutputConfig {
+            output_type: "email".to_string(),
+            config: HashMap::new(),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        assert_eq!(wrap_message("hello", &output_config), "hello");
+    }
+
+    #[test]
+    fn test_sms_recipient_id_uses_to_number() {
+        let mut config = HashMap::new();
+        config.insert("to_number".to_string(), "+447700900123".to_string());
+        let output_config = OutputConfig {
+            output_type: "sms_twilio".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        assert_eq!(generate_recipient_id(&output_config), "sms_twilio:+447700900123");
+    }
+
+    #[test]
+    fn test_pushover_recipient_id_uses_user_key() {
+        let pushover = |user_key: &str| OutputConfig {
+            output_type: "pushover".to_string(),
+            config: [("user_key", user_key), ("api_token", "shared_app_token")].iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        assert_eq!(generate_recipient_id(&pushover("uAlice")), "pushover:uAlice");
+        assert_ne!(generate_recipient_id(&pushover("uAlice")), generate_recipient_id(&pushover("uBob")));
+    }
+
+    #[test]
+    fn test_slack_recipient_id_per_webhook() {
+        let slack = |webhook_url: &str| OutputConfig {
+            output_type: "slack".to_string(),
+            config: HashMap::from([("webhook_url".to_string(), webhook_url.to_string())]),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        // Workflow triggers don't follow the incoming webhook layout
+        let first = generate_recipient_id(&slack("https://hooks.slack.com/triggers/T00000000/111/first-secret"));
+        let second = generate_recipient_id(&slack("https://hooks.slack.com/triggers/T00000000/222/second-secret"));
+        assert_ne!(first, second);
+        assert!(first.starts_with("slack:hooks.slack.com#"));
+        assert!(!first.contains("first-secret"));
+        assert_eq!(first, generate_recipient_id(&slack("https://hooks.slack.com/triggers/T00000000/111/first-secret")));
+    }
+
+    #[test]
+    fn test_discord_recipient_id_per_webhook() {
+        let discord = |webhook_url: &str| OutputConfig {
+            output_type: "discord".to_string(),
+            config: HashMap::from([("webhook_url".to_string(), webhook_url.to_string())]),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        assert_eq!(
+            generate_recipient_id(&discord("https://discord.com/api/webhooks/123456789012345678/token")),
+            "discord:123456789012345678"
+        );
+        // A proxy in front of Discord has no webhook ID in its path
+        let first = generate_recipient_id(&discord("https://relay.example.com/hook/first-secret"));
+        let second = generate_recipient_id(&discord("https://relay.example.com/hook/second-secret"));
+        assert_ne!(first, second);
+        assert!(first.starts_with("discord:relay.example.com#"));
+        assert!(!first.contains("first-secret"));
+    }
+
+    #[test]
+    fn test_email_recipient_ids_one_per_address() {
+        let email = |to: &str| OutputConfig {
+            output_type: "email".to_string(),
+            config: HashMap::from([("to".to_string(), to.to_string()), ("cc".to_string(), "c@example.com".to_string())]),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+        assert_eq!(generate_recipient_ids(&email("a@example.com")), ["email:a@example.com"]);
+        assert_eq!(
+            generate_recipient_ids(&email("a@example.com, Bob <b@example.com>,")),
+            ["email:a@example.com", "email:Bob <b@example.com>"]
+        );
+        assert_eq!(generate_recipient_ids(&email(" , ")), ["email: , "]);
+
+        // Other outputs have one recipient each
+        let mut mailgun = email("a@example.com, b@example.com");
+        mailgun.output_type = "email_mailgun".to_string();
+        assert_eq!(generate_recipient_ids(&mailgun), ["email_mailgun:a@example.com, b@example.com"]);
+    }
+
+    fn live(min_renotify_interval: ConfigDuration, force_renotify: bool) -> LastSignalRun {
+        LastSignalRun::Live { min_renotify_interval, force_renotify, output_retry_delay: None }
+    }
+
+    fn mock_outputs() -> Vec<MockOutput> {
+        vec![
+            MockOutput::new("unhealthy", true, false),
+            MockOutput::new("failing", false, true),
+            MockOutput::new("working", true, true),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_checkin_and_last_signal_outputs_share_fallback_semantics() {
+        use bidirectional::{BidirectionalOutput, BidirectionalWrapper};
+
+        let plain: Vec<Box<dyn Output>> = mock_outputs()
+            .into_iter()
+            .map(|output| Box::new(output) as Box<dyn Output>)
+            .collect();
+        let wrapped: Vec<Box<dyn BidirectionalOutput>> = mock_outputs()
+            .into_iter()
+            .map(|output| Box::new(BidirectionalWrapper::new(output)) as Box<dyn BidirectionalOutput>)
+            .collect();
+
+        let plain_result = process_outputs_with_fallback(&plain, "test message", &[], NaiveTime::MIN).await.unwrap();
+        let wrapped_result = process_outputs_with_fallback(&wrapped, "test message", &[], NaiveTime::MIN).await.unwrap();
+        assert!(plain_result.is_success());
+        assert!(wrapped_result.is_success());
+
+        // Without the working output both give up with the same result
+        let plain_result = process_outputs_with_fallback(&plain[..2], "test message", &[], NaiveTime::MIN).await.unwrap();
+        let wrapped_result = process_outputs_with_fallback(&wrapped[..2], "test message", &[], NaiveTime::MIN).await.unwrap();
+        assert_eq!(plain_result.error_message(), Some("All outputs failed"));
+        assert_eq!(wrapped_result.error_message(), plain_result.error_message());
+    }
+
+    #[tokio::test]
+    async fn test_process_outputs_with_fallback_applies_output_config() {
+        use std::sync::{Arc, Mutex};
+
+        struct RecordingOutput(Arc<Mutex<Vec<String>>>);
+
+        #[async_trait]
+        impl Output for RecordingOutput {
+            async fn send_message(&self, message: &str) -> Result<OutputResult> {
+                self.0.lock().unwrap().push(message.to_string());
+                Ok(OutputResult::Success)
+            }
+
+            async fn health_check(&self) -> Result<bool> {
+                Ok(true)
+            }
+
+            fn get_name(&self) -> &str {
+                "recording"
+            }
+        }
+
+        let mut config = HashMap::new();
+        config.insert("message_prefix".to_string(), "LASTSIGNAL: ".to_string());
+        let output_configs = vec![OutputConfig {
+            output_type: "file".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }];
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let outputs: Vec<Box<dyn Output>> = vec![Box::new(RecordingOutput(sent.clone()))];
+
+        process_outputs_with_fallback(&outputs, "hello", &output_configs, NaiveTime::MIN).await.unwrap();
+        assert_eq!(*sent.lock().unwrap(), vec!["LASTSIGNAL: hello".to_string()]);
+    }
+
+    fn scheduled_output(output_type: &str, priority: i32, active_hours: Option<&str>) -> OutputConfig {
+        OutputConfig {
+            output_type: output_type.to_string(),
+            config: HashMap::new(),
+            bidirectional: false,
+            priority,
+            active_hours: active_hours.map(|hours| hours.parse().unwrap()),
+            additional_imap_accounts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_order_by_priority_then_active_hours() {
+        let output_configs = vec![
+            scheduled_output("email", 2, None),
+            scheduled_output("telegram", 1, Some("07:00-23:00")),
+            scheduled_output("facebook_messenger", 1, Some("09:00-17:00")),
+            scheduled_output("file", 2, None),
+        ];
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let evening = NaiveTime::from_hms_opt(20, 0, 0).unwrap();
+        let night = NaiveTime::from_hms_opt(2, 0, 0).unwrap();
+
+        assert_eq!(dispatch_order(4, &output_configs, noon), vec![1, 2, 0, 3]);
+        assert_eq!(dispatch_order(4, &output_configs, evening), vec![1, 0, 3, 2]);
+        assert_eq!(dispatch_order(4, &output_configs, night), vec![0, 3, 1, 2]);
+        // Outputs without a config are priority 0 and always active
+        assert_eq!(dispatch_order(5, &output_configs, night), vec![4, 0, 3, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_out_of_hours_output_used_only_as_fallback() {
+        let output_configs = vec![
+            scheduled_output("email", 0, Some("23:00-07:00")),
+            scheduled_output("telegram", 0, Some("07:00-23:00")),
+        ];
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+        let outputs: Vec<Box<dyn Output>> = vec![
+            Box::new(MockOutput::new("overnight", true, true)),
+            Box::new(MockOutput::new("daytime", true, false)),
+        ];
+        let result = process_outputs_with_fallback(&outputs, "test message", &output_configs, noon).await.unwrap();
+        assert!(matches!(result, OutputResult::Success));
+
+        let outputs: Vec<Box<dyn Output>> = vec![
+            Box::new(MockOutput::new("overnight", true, false)),
+            Box::new(MockOutput::new("daytime", true, false)),
+        ];
+        let result = process_outputs_with_fallback(&outputs, "test message", &output_configs, noon).await.unwrap();
+        assert!(matches!(result, OutputResult::Failed(_)));
+    }
+
+    struct FullMailboxOutput;
+
+    #[async_trait]
+    impl Output for FullMailboxOutput {
+        async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+            Ok(OutputResult::quota_exceeded("552 5.2.2 Mailbox full"))
+        }
+
+        async fn health_check(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn get_name(&self) -> &str {
+            "full"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fallback_surfaces_persistent_failure() {
+        let outputs: Vec<Box<dyn Output>> = vec![
+            Box::new(FullMailboxOutput),
+            Box::new(MockOutput::new("flaky", false, true)),
+        ];
+
+        let result = process_outputs_with_fallback(&outputs, "test message", &[], NaiveTime::MIN).await.unwrap();
+        assert!(result.is_persistent_failure());
+        assert!(result.error_message().unwrap().contains("5.2.2"));
+        assert!(!OutputResult::Failed("Connection refused".to_string()).is_persistent_failure());
+    }
+}
@@ -0,0 +1,625 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::RwLock;
+
+const DEFAULT_PORT: u16 = 1883;
+const DEFAULT_TLS_PORT: u16 = 8883;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sent in CONNECT. A PINGREQ goes out every half of this, and a connection
+/// silent for twice this is assumed dead.
+const KEEP_ALIVE: Duration = Duration::from_secs(60);
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(300);
+
+/// Larger packets are refused rather than buffered
+const MAX_PACKET_BYTES: usize = 1024 * 1024;
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const DISCONNECT: u8 = 14;
+
+/// Where and what to subscribe to
+#[derive(Debug, Clone)]
+pub struct MqttSettings {
+    broker: String,
+    port: u16,
+    tls: bool,
+    topic: String,
+    username: Option<String>,
+    password: Option<String>,
+    /// Configured, or `None` for a random one per connection
+    client_id: Option<String>,
+}
+
+impl MqttSettings {
+    pub fn parse(config: &HashMap<String, String>) -> Result<Self> {
+        let broker = config
+            .get("broker")
+            .context("Missing 'broker' field in mqtt_presence config")?
+            .clone();
+        let topic = config
+            .get("topic")
+            .context("Missing 'topic' field in mqtt_presence config")?
+            .clone();
+        if topic.is_empty() {
+            anyhow::bail!("mqtt_presence 'topic' is empty");
+        }
+
+        let tls = match config.get("tls").map(|tls| tls.trim().to_ascii_lowercase()) {
+            None => false,
+            Some(tls) if tls == "true" => true,
+            Some(tls) if tls == "false" => false,
+            Some(tls) => anyhow::bail!("mqtt_presence 'tls' must be true or false, got '{}'", tls),
+        };
+        let port = match config.get("port") {
+            Some(port) => port.parse().with_context(|| format!("Invalid mqtt_presence port '{}'", port))?,
+            None if tls => DEFAULT_TLS_PORT,
+            None => DEFAULT_PORT,
+        };
+
+        let client_id = config.get("client_id").cloned();
+        if client_id.as_ref().is_some_and(|id| id.is_empty() || id.len() > 23) {
+            anyhow::bail!("mqtt_presence 'client_id' must be 1 to 23 characters");
+        }
+
+        Ok(MqttSettings {
+            broker,
+            port,
+            tls,
+            topic,
+            username: config.get("username").cloned(),
+            password: config.get("password").cloned(),
+            client_id,
+        })
+    }
+
+    /// The client ID for one connection. Health checks need their own, or the
+    /// broker would disconnect the subscriber to make room.
+    fn connection_client_id(&self, purpose: &str) -> String {
+        match &self.client_id {
+            Some(id) if purpose.is_empty() => id.clone(),
+            Some(id) => format!("{}-{}", id, purpose).chars().take(23).collect(),
+            None => format!("lastsignal-{}", hex::encode(rand::random::<[u8; 4]>())),
+        }
+    }
+}
+
+/// Treats messages on an MQTT topic, such as a phone publishing its location
+/// with OwnTracks, as signs of life
+#[derive(Debug)]
+pub struct MqttPresenceOutput {
+    settings: MqttSettings,
+    last_seen: Arc<RwLock<Option<DateTime<Utc>>>>,
+    max_time_since_last_checkin: ConfigDuration,
+    subscriber_task: tokio::task::JoinHandle<()>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Packet {
+    ConnAck { return_code: u8 },
+    SubAck { return_codes: Vec<u8> },
+    Publish { topic: String, payload: Vec<u8>, retain: bool },
+    PingResp,
+    Other(u8),
+}
+
+trait MqttStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> MqttStream for S {}
+
+fn encode_remaining_length(mut length: usize, buf: &mut Vec<u8>) {
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if length == 0 {
+            break;
+        }
+    }
+}
+
+fn push_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+fn packet(packet_type: u8, flags: u8, body: &[u8]) -> Vec<u8> {
+    let mut buf = vec![(packet_type << 4) | flags];
+    encode_remaining_length(body.len(), &mut buf);
+    buf.extend_from_slice(body);
+    buf
+}
+
+fn connect_packet(settings: &MqttSettings, client_id: &str) -> Vec<u8> {
+    // Clean session: nothing is queued for us while we are away
+    let mut flags = 0x02;
+    if settings.username.is_some() {
+        flags |= 0x80;
+    }
+    if settings.password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    push_string(&mut body, "MQTT");
+    body.push(4); // Protocol level 3.1.1
+    body.push(flags);
+    body.extend_from_slice(&(KEEP_ALIVE.as_secs() as u16).to_be_bytes());
+    push_string(&mut body, client_id);
+    if let Some(username) = &settings.username {
+        push_string(&mut body, username);
+    }
+    if let Some(password) = &settings.password {
+        push_string(&mut body, password);
+    }
+    packet(CONNECT, 0, &body)
+}
+
+fn subscribe_packet(packet_id: u16, topic: &str) -> Vec<u8> {
+    let mut body = packet_id.to_be_bytes().to_vec();
+    push_string(&mut body, topic);
+    body.push(0); // QoS 0
+    packet(SUBSCRIBE, 0x02, &body)
+}
+
+fn parse_packet(header: u8, body: &[u8]) -> Result<Packet> {
+    let packet_type = header >> 4;
+    match packet_type {
+        CONNACK => {
+            let return_code = *body.get(1).context("Short CONNACK")?;
+            Ok(Packet::ConnAck { return_code })
+        }
+        SUBACK => Ok(Packet::SubAck { return_codes: body.get(2..).context("Short SUBACK")?.to_vec() }),
+        PUBLISH => {
+            let qos = (header >> 1) & 0x03;
+            let topic_length = u16::from_be_bytes([*body.first().context("Short PUBLISH")?, *body.get(1).context("Short PUBLISH")?]) as usize;
+            let topic = body.get(2..2 + topic_length).context("Short PUBLISH")?;
+            // QoS 1 and 2 carry a packet ID before the payload
+            let payload_start = 2 + topic_length + if qos > 0 { 2 } else { 0 };
+            Ok(Packet::Publish {
+                topic: String::from_utf8_lossy(topic).into_owned(),
+                payload: body.get(payload_start..).context("Short PUBLISH")?.to_vec(),
+                retain: header & 0x01 != 0,
+            })
+        }
+        PINGRESP => Ok(Packet::PingResp),
+        other => Ok(Packet::Other(other)),
+    }
+}
+
+async fn read_packet<R: AsyncRead + Unpin>(stream: &mut R) -> Result<Packet> {
+    let header = stream.read_u8().await.context("Broker closed the connection")?;
+    let mut length = 0usize;
+    for shift in 0..4 {
+        let byte = stream.read_u8().await.context("Broker closed the connection")?;
+        length |= ((byte & 0x7f) as usize) << (7 * shift);
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if shift == 3 {
+            anyhow::bail!("Malformed packet length from broker");
+        }
+    }
+    if length > MAX_PACKET_BYTES {
+        anyhow::bail!("Broker sent a {} byte packet, more than the {} allowed", length, MAX_PACKET_BYTES);
+    }
+    let mut body = vec![0u8; length];
+    stream.read_exact(&mut body).await.context("Broker closed the connection")?;
+    parse_packet(header, &body)
+}
+
+fn connack_error(return_code: u8) -> &'static str {
+    match return_code {
+        1 => "unacceptable protocol version",
+        2 => "client ID rejected",
+        3 => "server unavailable",
+        4 => "bad username or password",
+        5 => "not authorized",
+        _ => "unknown error",
+    }
+}
+
+/// Connects and waits for the broker to accept the session
+async fn open(settings: &MqttSettings, client_id: &str) -> Result<Box<dyn MqttStream>> {
+    let tcp = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((settings.broker.as_str(), settings.port)))
+        .await
+        .with_context(|| format!("Timed out connecting to {}:{}", settings.broker, settings.port))?
+        .with_context(|| format!("Failed to connect to {}:{}", settings.broker, settings.port))?;
+
+    let mut stream: Box<dyn MqttStream> = if settings.tls {
+        let connector = tokio_native_tls::TlsConnector::from(
+            tokio_native_tls::native_tls::TlsConnector::new().context("Failed to set up TLS")?,
+        );
+        let tls = tokio::time::timeout(CONNECT_TIMEOUT, connector.connect(&settings.broker, tcp))
+            .await
+            .context("Timed out during TLS handshake")?
+            .context("TLS handshake failed")?;
+        Box::new(tls)
+    } else {
+        Box::new(tcp)
+    };
+
+    stream.write_all(&connect_packet(settings, client_id)).await.context("Failed to write to broker")?;
+    match tokio::time::timeout(CONNECT_TIMEOUT, read_packet(&mut stream)).await.context("Timed out waiting for CONNACK")?? {
+        Packet::ConnAck { return_code: 0 } => Ok(stream),
+        Packet::ConnAck { return_code } => anyhow::bail!("Broker refused the connection: {}", connack_error(return_code)),
+        other => anyhow::bail!("Expected CONNACK from broker, got {:?}", other),
+    }
+}
+
+/// When a message shows the publisher was alive. OwnTracks and similar put
+/// a `tst` Unix timestamp in a JSON payload, which is used when present. A
+/// live message without one is timed by its arrival, but a retained one could
+/// be any age so it is ignored.
+fn message_time(payload: &[u8], retained: bool, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let stamped = serde_json::from_slice::<Value>(payload)
+        .ok()
+        .and_then(|payload| payload.get("tst").and_then(Value::as_i64))
+        .and_then(|seconds| DateTime::from_timestamp(seconds, 0));
+    match stamped {
+        // A publisher with a fast clock shouldn't buy time
+        Some(timestamp) => Some(timestamp.min(now)),
+        None if retained => None,
+        None => Some(now),
+    }
+}
+
+fn state_file(data_directory: &Path, settings: &MqttSettings) -> PathBuf {
+    let key = format!("{}:{}/{}", settings.broker, settings.port, settings.topic);
+    let digest = Sha256::digest(key.as_bytes());
+    data_directory.join(format!("mqtt_presence_{}.json", hex::encode(&digest[..4])))
+}
+
+fn load_last_seen(path: &Path) -> Option<DateTime<Utc>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let state: Value = serde_json::from_str(&content).ok()?;
+    state.get("last_seen")?.as_str()?.parse().ok()
+}
+
+/// Records `timestamp` if it is the newest yet, in memory and on disk
+async fn record(last_seen: &RwLock<Option<DateTime<Utc>>>, state_file: &Path, timestamp: DateTime<Utc>) {
+    let mut last_seen = last_seen.write().await;
+    if last_seen.is_some_and(|existing| existing >= timestamp) {
+        return;
+    }
+    *last_seen = Some(timestamp);
+    let state = serde_json::json!({ "last_seen": timestamp });
+    if let Err(e) = std::fs::write(state_file, state.to_string()) {
+        tracing::warn!("mqtt_presence: failed to save last seen time to {}: {}", state_file.display(), e);
+    }
+}
+
+/// One subscription, until the connection fails. `on_subscribed` runs once
+/// the broker accepts the subscription.
+async fn listen(
+    settings: &MqttSettings,
+    last_seen: &RwLock<Option<DateTime<Utc>>>,
+    state_file: &Path,
+    on_subscribed: &mut (dyn FnMut() + Send),
+) -> Result<()> {
+    let mut stream = open(settings, &settings.connection_client_id("")).await?;
+    stream.write_all(&subscribe_packet(1, &settings.topic)).await.context("Failed to write to broker")?;
+    let (mut reader, mut writer) = tokio::io::split(stream);
+
+    let pinger = async {
+        let mut interval = tokio::time::interval(KEEP_ALIVE / 2);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            writer.write_all(&packet(PINGREQ, 0, &[])).await.context("Failed to ping broker")?;
+        }
+    };
+
+    let reader = async {
+        loop {
+            let packet = tokio::time::timeout(KEEP_ALIVE * 2, read_packet(&mut reader))
+                .await
+                .context("Broker stopped responding")??;
+            match packet {
+                Packet::SubAck { return_codes } => {
+                    if return_codes.contains(&0x80) {
+                        anyhow::bail!("Broker refused the subscription to '{}'", settings.topic);
+                    }
+                    tracing::info!("mqtt_presence: subscribed to '{}' on {}", settings.topic, settings.broker);
+                    on_subscribed();
+                }
+                Packet::Publish { topic, payload, retain } => {
+                    match message_time(&payload, retain, Utc::now()) {
+                        Some(timestamp) => {
+                            tracing::debug!("mqtt_presence: message on '{}' at {}", topic, timestamp);
+                            record(last_seen, state_file, timestamp).await;
+                        }
+                        None => tracing::debug!("mqtt_presence: ignoring undated retained message on '{}'", topic),
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    tokio::select! {
+        result = pinger => result,
+        result = reader => result,
+    }
+}
+
+/// Keeps a subscription open for the life of the output, reconnecting with
+/// backoff when the broker goes away
+async fn subscribe_forever(settings: MqttSettings, last_seen: Arc<RwLock<Option<DateTime<Utc>>>>, state_file: PathBuf) {
+    let mut delay = RECONNECT_DELAY;
+    loop {
+        let mut subscribed = false;
+        let result = listen(&settings, &last_seen, &state_file, &mut || subscribed = true).await;
+        if subscribed {
+            delay = RECONNECT_DELAY;
+        }
+        if let Err(e) = result {
+            tracing::warn!("mqtt_presence: {}: {:#}, reconnecting in {}s", settings.broker, e, delay.as_secs());
+        }
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+}
+
+impl MqttPresenceOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["broker", "port", "tls", "topic", "username", "password", "client_id"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let settings = MqttSettings::parse(config)?;
+        let state_file = state_file(&data_directory, &settings);
+
+        // A restart shouldn't forget a message that arrived just before it
+        let last_seen = Arc::new(RwLock::new(load_last_seen(&state_file)));
+        let subscriber_task = tokio::spawn(subscribe_forever(settings.clone(), Arc::clone(&last_seen), state_file));
+
+        Ok(MqttPresenceOutput {
+            settings,
+            last_seen,
+            max_time_since_last_checkin,
+            subscriber_task,
+        })
+    }
+}
+
+impl Drop for MqttPresenceOutput {
+    fn drop(&mut self) {
+        self.subscriber_task.abort();
+    }
+}
+
+#[async_trait]
+impl Output for MqttPresenceOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // MQTT presence is used purely for checking if the user is alive
+        Ok(OutputResult::Skipped("mqtt_presence is a check-only adapter".to_string()))
+    }
+
+    /// The broker accepts a connection with these credentials
+    async fn health_check(&self) -> Result<bool> {
+        match open(&self.settings, &self.settings.connection_client_id("check")).await {
+            Ok(mut stream) => {
+                let _ = stream.write_all(&packet(DISCONNECT, 0, &[])).await;
+                Ok(true)
+            }
+            Err(e) => {
+                tracing::warn!("mqtt_presence health check failed: {:#}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "mqtt_presence"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for MqttPresenceOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let Some(last_seen) = *self.last_seen.read().await else {
+            tracing::debug!("mqtt_presence: no messages seen on '{}' yet", self.settings.topic);
+            return Ok(vec![]);
+        };
+
+        Ok(super::activity_checkin(
+            "mqtt_presence",
+            last_seen,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            format!("MQTT message on {}", self.settings.topic),
+            format!("MQTT broker {}", self.settings.broker),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn config(port: u16) -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("broker".to_string(), "127.0.0.1".to_string());
+        config.insert("port".to_string(), port.to_string());
+        config.insert("topic".to_string(), "owntracks/me/phone".to_string());
+        config
+    }
+
+    fn publish(topic: &str, payload: &str, retain: bool) -> Vec<u8> {
+        let mut body = Vec::new();
+        push_string(&mut body, topic);
+        body.extend_from_slice(payload.as_bytes());
+        packet(PUBLISH, retain as u8, &body)
+    }
+
+    #[test]
+    fn test_settings() {
+        let settings = MqttSettings::parse(&config(1883)).unwrap();
+        assert_eq!((settings.port, settings.tls), (1883, false));
+        assert!(settings.connection_client_id("").starts_with("lastsignal-"));
+
+        let mut tls = config(1883);
+        tls.remove("port");
+        tls.insert("tls".to_string(), "true".to_string());
+        assert_eq!(MqttSettings::parse(&tls).unwrap().port, DEFAULT_TLS_PORT);
+
+        let mut named = config(1883);
+        named.insert("client_id".to_string(), "phone-watcher".to_string());
+        let settings = MqttSettings::parse(&named).unwrap();
+        assert_eq!(settings.connection_client_id(""), "phone-watcher");
+        assert_eq!(settings.connection_client_id("check"), "phone-watcher-check");
+
+        for (key, value) in [("tls", "yes"), ("port", "mqtt"), ("client_id", "a-client-id-longer-than-23")] {
+            let mut invalid = config(1883);
+            invalid.insert(key.to_string(), value.to_string());
+            assert!(MqttSettings::parse(&invalid).is_err(), "{}={}", key, value);
+        }
+        let mut missing = config(1883);
+        missing.remove("topic");
+        assert!(MqttSettings::parse(&missing).is_err());
+    }
+
+    #[test]
+    fn test_packets() {
+        let mut length = Vec::new();
+        encode_remaining_length(321, &mut length);
+        assert_eq!(length, vec![0xc1, 0x02]);
+
+        let mut settings = MqttSettings::parse(&config(1883)).unwrap();
+        settings.username = Some("me".to_string());
+        settings.password = Some("pw".to_string());
+        let connect = connect_packet(&settings, "id");
+        assert_eq!(connect[0], 0x10);
+        assert_eq!(&connect[2..9], b"\x00\x04MQTT\x04");
+        assert_eq!(connect[9], 0xc2);
+        assert!(connect.ends_with(b"\x00\x02id\x00\x02me\x00\x02pw"));
+
+        assert_eq!(subscribe_packet(1, "a/#"), b"\x82\x08\x00\x01\x00\x03a/#\x00".to_vec());
+
+        let published = publish("a/b", "{}", true);
+        assert_eq!(
+            parse_packet(published[0], &published[2..]).unwrap(),
+            Packet::Publish { topic: "a/b".to_string(), payload: b"{}".to_vec(), retain: true }
+        );
+        // A QoS 1 publish has a packet ID to skip
+        assert_eq!(
+            parse_packet(0x32, b"\x00\x01t\x00\x07hi").unwrap(),
+            Packet::Publish { topic: "t".to_string(), payload: b"hi".to_vec(), retain: false }
+        );
+        assert!(parse_packet(0x30, b"\x00\x09t").is_err());
+    }
+
+    #[test]
+    fn test_message_time() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+        let owntracks = br#"{"_type": "location", "batt": 84, "lat": 51.5, "lon": -0.1, "tst": 1760520600}"#;
+        assert_eq!(message_time(owntracks, true, now).unwrap().timestamp(), 1_760_520_600);
+        assert_eq!(message_time(br#"{"tst": 9999999999}"#, false, now), Some(now));
+        assert_eq!(message_time(b"84", false, now), Some(now));
+        assert_eq!(message_time(b"84", true, now), None);
+    }
+
+    #[tokio::test]
+    async fn test_messages_from_broker_are_checkins() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let broker = tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+            assert!(matches!(read_packet(&mut client).await.unwrap(), Packet::Other(CONNECT)));
+            client.write_all(&packet(CONNACK, 0, &[0, 0])).await.unwrap();
+            assert!(matches!(read_packet(&mut client).await.unwrap(), Packet::Other(SUBSCRIBE)));
+            client.write_all(&packet(SUBACK, 0, &[0, 1, 0])).await.unwrap();
+            // An undated retained message, then a live one
+            client.write_all(&publish("owntracks/me/phone", "84", true)).await.unwrap();
+            client.write_all(&publish("owntracks/me/phone", "83", false)).await.unwrap();
+            client
+        });
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = MqttPresenceOutput::new(&config(port), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        let _client = broker.await.unwrap();
+
+        let mut responses = Vec::new();
+        for _ in 0..50 {
+            responses = output.check_for_responses(None).await.unwrap();
+            if !responses.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(matches!(responses.as_slice(), [CheckinResponse::Found { .. }]));
+
+        // A restarted daemon remembers the message
+        let seen = *output.last_seen.read().await;
+        drop(output);
+        let restarted = MqttPresenceOutput::new(&config(port), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(*restarted.last_seen.read().await, seen);
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = MqttPresenceOutput::new(&config(port), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+
+        // The subscriber connects first, then two health checks, the second refused
+        let broker = tokio::spawn(async move {
+            let mut clients = Vec::new();
+            for return_code in [0, 0, 4] {
+                let (mut client, _) = listener.accept().await.unwrap();
+                read_packet(&mut client).await.unwrap();
+                client.write_all(&packet(CONNACK, 0, &[0, return_code])).await.unwrap();
+                clients.push(client);
+            }
+            clients
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+        assert!(!<dyn Output>::health_check(&output).await.unwrap());
+        broker.await.unwrap();
+
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(_)));
+    }
+}
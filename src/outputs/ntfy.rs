@@ -0,0 +1,421 @@
+use super::bidirectional::{BidirectionalOutput, CheckinResponse};
+use super::{Output, OutputResult};
+use crate::formatting;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// ntfy turns bodies over 4096 bytes into attachments, which many servers don't allow
+const MAX_MESSAGE_CHARS: usize = 4000;
+
+/// Tag on every message LastSignal publishes, so its own reminders are never
+/// mistaken for check-in replies
+const OWN_MESSAGE_TAG: &str = "lastsignal";
+
+#[derive(Debug)]
+pub struct NtfyOutput {
+    server_url: Url,
+    topic: String,
+    /// Topic polled for check-in replies, `topic` when not set
+    response_topic: String,
+    auth: NtfyAuth,
+    priority: Option<String>,
+    title: Option<String>,
+    /// Where the timestamp of the last processed reply is kept
+    cursor_file: Option<PathBuf>,
+    client: Client,
+}
+
+#[derive(Debug)]
+enum NtfyAuth {
+    None,
+    Token(String),
+    Basic { username: String, password: String },
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct NtfyCursor {
+    last_processed: Option<DateTime<Utc>>,
+}
+
+/// One line of a `/json?poll=1` response
+#[derive(Debug, Deserialize)]
+struct NtfyEvent {
+    event: String,
+    /// Seconds since the Unix epoch
+    time: i64,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl NtfyOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &[
+        "server_url", "topic", "response_topic", "auth_token", "username", "password", "priority", "title",
+    ];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: Option<&Path>) -> Result<Self> {
+        let server_url = config
+            .get("server_url")
+            .context("Missing 'server_url' field in ntfy config")?;
+        let server_url = parse_server_url(server_url)?;
+
+        let topic = config
+            .get("topic")
+            .context("Missing 'topic' field in ntfy config")?
+            .clone();
+        validate_topic(&topic)?;
+
+        let response_topic = config.get("response_topic").cloned().unwrap_or_else(|| topic.clone());
+        validate_topic(&response_topic)?;
+
+        let auth = match (config.get("auth_token"), config.get("username"), config.get("password")) {
+            (Some(token), None, None) => NtfyAuth::Token(token.clone()),
+            (None, Some(username), Some(password)) => NtfyAuth::Basic { username: username.clone(), password: password.clone() },
+            (None, None, None) => NtfyAuth::None,
+            _ => anyhow::bail!("ntfy config needs either 'auth_token' or both 'username' and 'password'"),
+        };
+
+        let priority = config.get("priority").map(|priority| parse_priority(priority)).transpose()?;
+
+        let cursor_file = data_directory.map(|dir| dir.join(cursor_file_name(&server_url, &response_topic)));
+
+        Ok(NtfyOutput {
+            server_url,
+            topic,
+            response_topic,
+            auth,
+            priority,
+            title: config.get("title").cloned(),
+            cursor_file,
+            client: Client::new(),
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.server_url.as_str().trim_end_matches('/'), path)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.auth {
+            NtfyAuth::None => request,
+            NtfyAuth::Token(token) => request.bearer_auth(token),
+            NtfyAuth::Basic { username, password } => request.basic_auth(username, Some(password)),
+        }
+    }
+
+    async fn publish(&self, message: &str) -> Result<OutputResult> {
+        let mut request = self.authorize(self.client.post(self.url(&self.topic)))
+            .header("X-Tags", OWN_MESSAGE_TAG)
+            .body(message.to_string());
+        if let Some(priority) = &self.priority {
+            request = request.header("X-Priority", priority);
+        }
+        if let Some(title) = &self.title {
+            request = request.header("X-Title", title);
+        }
+
+        let response = match request.send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string()))));
+            }
+        };
+
+        let status_code = response.status();
+        if status_code.is_success() {
+            return Ok(OutputResult::Success);
+        }
+
+        match response.text().await {
+            Ok(text) => Ok(OutputResult::Failed(format!("ntfy HTTP {}: {}", status_code, text))),
+            Err(e) => Ok(OutputResult::Failed(format!("ntfy HTTP {} (failed to read response: {})", status_code, e))),
+        }
+    }
+
+    fn load_cursor(&self) -> Result<NtfyCursor> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(NtfyCursor::default());
+        };
+        if !cursor_file.exists() {
+            return Ok(NtfyCursor::default());
+        }
+
+        let content = std::fs::read_to_string(cursor_file)
+            .with_context(|| format!("Failed to read ntfy cursor file: {:?}", cursor_file))?;
+        serde_json::from_str(&content).context("Failed to parse ntfy cursor file")
+    }
+
+    fn save_cursor(&self, cursor: &NtfyCursor) -> Result<()> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(());
+        };
+
+        let content = serde_json::to_string_pretty(cursor).context("Failed to serialize ntfy cursor")?;
+        std::fs::write(cursor_file, content)
+            .with_context(|| format!("Failed to write ntfy cursor file: {:?}", cursor_file))
+    }
+
+    async fn poll_messages(&self, after: Option<DateTime<Utc>>) -> Result<String> {
+        let since = after.map(|after| after.timestamp().to_string()).unwrap_or_else(|| "all".to_string());
+        let response = self
+            .authorize(self.client.get(self.url(&format!("{}/json", self.response_topic))))
+            .query(&[("poll", "1"), ("since", &since)])
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("ntfy poll request failed: {}", redact::redact(&e.to_string())))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("ntfy poll HTTP {}: {}", status, text);
+        }
+
+        response.text().await.context("Failed to read ntfy poll response")
+    }
+}
+
+/// The server's base URL, e.g. `https://ntfy.sh` or `https://ntfy.example.com/`
+pub fn parse_server_url(server_url: &str) -> Result<Url> {
+    let url = Url::parse(server_url).context("Invalid 'server_url' in ntfy config")?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("ntfy 'server_url' must be an http(s) URL");
+    }
+    if url.host_str().is_none() {
+        anyhow::bail!("ntfy 'server_url' has no host");
+    }
+    Ok(url)
+}
+
+/// ntfy topics are 1-64 characters of letters, digits, `_` and `-`
+fn validate_topic(topic: &str) -> Result<()> {
+    let valid = !topic.is_empty()
+        && topic.len() <= 64
+        && topic.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !valid {
+        anyhow::bail!("Invalid ntfy topic '{}': use 1-64 letters, digits, '_' or '-'", topic);
+    }
+    Ok(())
+}
+
+/// Accepts ntfy's priority numbers 1-5 or names (min, low, default, high, urgent)
+pub fn parse_priority(priority: &str) -> Result<String> {
+    let priority = priority.trim().to_ascii_lowercase();
+    match priority.as_str() {
+        "1" | "2" | "3" | "4" | "5" | "min" | "low" | "default" | "high" | "urgent" | "max" => Ok(priority),
+        _ => anyhow::bail!("Invalid ntfy priority '{}': use 1-5 or min, low, default, high, urgent", priority),
+    }
+}
+
+/// One cursor file per server and topic, e.g. `ntfy_ntfy.sh_checkins.json`
+fn cursor_file_name(server_url: &Url, topic: &str) -> String {
+    let host: String = server_url
+        .host_str()
+        .unwrap_or("unknown")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("ntfy_{}_{}.json", host, topic)
+}
+
+/// Messages in a poll response sent after `after`, other than LastSignal's own
+fn replies_from(body: &str, after: Option<DateTime<Utc>>) -> Vec<CheckinResponse> {
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<NtfyEvent>(line) {
+            Ok(event) => Some(event),
+            Err(e) => {
+                tracing::debug!("Skipping unreadable ntfy event: {}", e);
+                None
+            }
+        })
+        .filter(|event| event.event == "message" && !event.tags.iter().any(|tag| tag == OWN_MESSAGE_TAG))
+        .filter_map(|event| {
+            let timestamp = DateTime::from_timestamp(event.time, 0)?;
+            if after.is_some_and(|after| timestamp <= after) {
+                return None;
+            }
+            Some(CheckinResponse::Found {
+                timestamp,
+                subject: event.title.unwrap_or(event.message),
+                from: "ntfy".to_string(),
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Output for NtfyOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let parts = formatting::split_message(message, MAX_MESSAGE_CHARS);
+        let total = parts.len();
+
+        for (i, part) in parts.iter().enumerate() {
+            match self.publish(part).await? {
+                OutputResult::Success => {}
+                OutputResult::Failed(error) if total > 1 => {
+                    return Ok(OutputResult::Failed(format!("Part {}/{}: {}", i + 1, total, error)));
+                }
+                other => return Ok(other),
+            }
+        }
+
+        Ok(OutputResult::Success)
+    }
+
+    /// The server reports itself healthy and, with credentials, still accepts them for the topic
+    async fn health_check(&self) -> Result<bool> {
+        match self.client.get(self.url("v1/health")).send().await {
+            Ok(resp) if resp.status().is_success() => {}
+            Ok(resp) => {
+                tracing::debug!("ntfy health check: /v1/health returned {}", resp.status());
+                return Ok(false);
+            }
+            Err(e) => {
+                tracing::debug!("ntfy health check HTTP error: {}", e);
+                return Ok(false);
+            }
+        }
+
+        if matches!(self.auth, NtfyAuth::None) {
+            return Ok(true);
+        }
+
+        match self.authorize(self.client.get(self.url(&format!("{}/auth", self.topic)))).send().await {
+            Ok(resp) if resp.status().is_success() => Ok(true),
+            Ok(resp) => {
+                tracing::debug!("ntfy health check: topic auth returned {}", resp.status());
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("ntfy health check HTTP error: {}", redact::redact(&e.to_string()));
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "ntfy"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for NtfyOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        Output::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Output::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        Output::get_name(self)
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let after = since.max(self.load_cursor()?.last_processed);
+        let body = self.poll_messages(after).await?;
+        Ok(replies_from(&body, after))
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        let mut cursor = self.load_cursor()?;
+        if cursor.last_processed.is_some_and(|processed| processed >= timestamp) {
+            return Ok(());
+        }
+        cursor.last_processed = Some(timestamp);
+        self.save_cursor(&cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("server_url".to_string(), "https://ntfy.example.com/".to_string());
+        config.insert("topic".to_string(), "lastsignal-checkins".to_string());
+        config
+    }
+
+    #[test]
+    fn test_ntfy_output_creation() {
+        let output = NtfyOutput::new(&test_config(), None).unwrap();
+        assert_eq!(output.url(&output.topic), "https://ntfy.example.com/lastsignal-checkins");
+        assert_eq!(output.response_topic, "lastsignal-checkins");
+        assert!(matches!(output.auth, NtfyAuth::None));
+
+        let mut config = test_config();
+        config.insert("response_topic".to_string(), "lastsignal-replies".to_string());
+        config.insert("auth_token".to_string(), "tk_secret".to_string());
+        config.insert("priority".to_string(), "High".to_string());
+        let output = NtfyOutput::new(&config, None).unwrap();
+        assert_eq!(output.response_topic, "lastsignal-replies");
+        assert!(matches!(output.auth, NtfyAuth::Token(_)));
+        assert_eq!(output.priority.as_deref(), Some("high"));
+    }
+
+    #[test]
+    fn test_ntfy_output_rejects_invalid_config() {
+        for key in ["server_url", "topic"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(NtfyOutput::new(&config, None).is_err(), "missing {} should be an error", key);
+        }
+
+        let mut config = test_config();
+        config.insert("topic".to_string(), "not/a/topic".to_string());
+        assert!(NtfyOutput::new(&config, None).is_err());
+
+        let mut config = test_config();
+        config.insert("username".to_string(), "me".to_string());
+        assert!(NtfyOutput::new(&config, None).is_err());
+
+        assert!(parse_priority("6").is_err());
+        assert!(parse_server_url("ftp://ntfy.example.com").is_err());
+    }
+
+    #[test]
+    fn test_replies_skip_own_messages_and_old_ones() {
+        let body = [
+            r#"{"id":"a","time":1750000300,"event":"message","topic":"t","message":"ok"}"#,
+            r#"{"id":"b","time":1750000200,"event":"message","topic":"t","message":"Please check in","tags":["lastsignal"]}"#,
+            r#"{"id":"c","time":1750000250,"event":"keepalive","topic":"t"}"#,
+            r#"{"id":"d","time":1750000000,"event":"message","topic":"t","message":"old"}"#,
+        ].join("\n");
+
+        let replies = replies_from(&body, DateTime::from_timestamp(1_750_000_100, 0));
+        assert_eq!(replies.len(), 1);
+        match &replies[0] {
+            CheckinResponse::Found { timestamp, subject, .. } => {
+                assert_eq!(timestamp.timestamp(), 1_750_000_300);
+                assert_eq!(subject, "ok");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mark_processed_until_persists_latest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = NtfyOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(output.cursor_file, Some(temp_dir.path().join("ntfy_ntfy.example.com_lastsignal-checkins.json")));
+
+        let later = Utc::now();
+        output.mark_processed_until(later).await.unwrap();
+        output.mark_processed_until(later - chrono::Duration::hours(1)).await.unwrap();
+
+        let reloaded = NtfyOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(reloaded.load_cursor().unwrap().last_processed, Some(later));
+    }
+}
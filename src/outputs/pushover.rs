@@ -0,0 +1,254 @@
+use super::{Output, OutputResult};
+use crate::formatting;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const API_BASE_URL: &str = "https://api.pushover.net/1";
+
+/// Pushover rejects messages longer than this
+const MAX_MESSAGE_CHARS: usize = 1024;
+
+/// Pushover's emergency priority, which repeats until acknowledged on the device
+const EMERGENCY_PRIORITY: i8 = 2;
+
+/// How often an emergency notification repeats, in seconds. Pushover's minimum is 30.
+const DEFAULT_RETRY_SECS: u32 = 60;
+
+/// How long an emergency notification keeps repeating, in seconds. Pushover's maximum is 10800.
+const DEFAULT_EXPIRE_SECS: u32 = 3600;
+
+#[derive(Debug, Clone)]
+pub struct PushoverOutput {
+    user_key: String,
+    api_token: String,
+    priority: Option<i8>,
+    retry: u32,
+    expire: u32,
+    client: Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct PushoverResponse {
+    status: i32,
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+impl PushoverOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["user_key", "api_token", "priority", "retry", "expire"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let user_key = config
+            .get("user_key")
+            .context("Missing 'user_key' field in pushover config")?
+            .clone();
+
+        let api_token = config
+            .get("api_token")
+            .context("Missing 'api_token' field in pushover config")?
+            .clone();
+
+        let priority = config.get("priority").map(|priority| parse_priority(priority)).transpose()?;
+
+        let retry = match config.get("retry") {
+            Some(retry) => retry.parse().context("Invalid 'retry' in pushover config")?,
+            None => DEFAULT_RETRY_SECS,
+        };
+        if retry < 30 {
+            anyhow::bail!("Pushover 'retry' must be at least 30 seconds");
+        }
+
+        let expire = match config.get("expire") {
+            Some(expire) => expire.parse().context("Invalid 'expire' in pushover config")?,
+            None => DEFAULT_EXPIRE_SECS,
+        };
+        if expire > 10800 {
+            anyhow::bail!("Pushover 'expire' must be at most 10800 seconds (3 hours)");
+        }
+
+        Ok(PushoverOutput {
+            user_key,
+            api_token,
+            priority,
+            retry,
+            expire,
+            client: Client::new(),
+        })
+    }
+
+    /// Form fields for one message. Emergency priority needs `retry` and `expire`.
+    fn form(&self, message: &str) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("token", self.api_token.clone()),
+            ("user", self.user_key.clone()),
+            ("message", message.to_string()),
+        ];
+        if let Some(priority) = self.priority {
+            form.push(("priority", priority.to_string()));
+            if priority == EMERGENCY_PRIORITY {
+                form.push(("retry", self.retry.to_string()));
+                form.push(("expire", self.expire.to_string()));
+            }
+        }
+        form
+    }
+
+    async fn post_message(&self, message: &str) -> Result<OutputResult> {
+        let response = match self
+            .client
+            .post(format!("{}/messages.json", API_BASE_URL))
+            .form(&self.form(message))
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string()))));
+            }
+        };
+
+        let status_code = response.status();
+        let text = match response.text().await {
+            Ok(text) => text,
+            Err(e) => return Ok(OutputResult::Failed(format!("Pushover HTTP {} (failed to read response: {})", status_code, e))),
+        };
+
+        match serde_json::from_str::<PushoverResponse>(&text) {
+            Ok(body) if status_code.is_success() && body.status == 1 => Ok(OutputResult::Success),
+            Ok(body) if !body.errors.is_empty() => {
+                Ok(OutputResult::Failed(format!("Pushover HTTP {}: {}", status_code, body.errors.join("; "))))
+            }
+            _ => Ok(OutputResult::Failed(format!("Pushover HTTP {}: {}", status_code, text))),
+        }
+    }
+}
+
+/// Pushover priorities run from -2 (no notification) to 2 (emergency)
+pub fn parse_priority(priority: &str) -> Result<i8> {
+    let priority: i8 = priority.trim().parse()
+        .with_context(|| format!("Invalid pushover priority '{}'", priority))?;
+    if !(-2..=EMERGENCY_PRIORITY).contains(&priority) {
+        anyhow::bail!("Pushover priority must be between -2 and 2, got {}", priority);
+    }
+    Ok(priority)
+}
+
+#[async_trait]
+impl Output for PushoverOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let parts = formatting::split_message(message, MAX_MESSAGE_CHARS);
+        let total = parts.len();
+
+        for (i, part) in parts.iter().enumerate() {
+            match self.post_message(part).await? {
+                OutputResult::Success => {}
+                OutputResult::Failed(error) if total > 1 => {
+                    return Ok(OutputResult::Failed(format!("Part {}/{}: {}", i + 1, total, error)));
+                }
+                other => return Ok(other),
+            }
+        }
+
+        Ok(OutputResult::Success)
+    }
+
+    /// Confirms the API token is valid and the user key has an active device
+    async fn health_check(&self) -> Result<bool> {
+        let form = [("token", self.api_token.as_str()), ("user", self.user_key.as_str())];
+        let response = match self
+            .client
+            .post(format!("{}/users/validate.json", API_BASE_URL))
+            .form(&form)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::debug!("Pushover health check HTTP error: {}", redact::redact(&e.to_string()));
+                return Ok(false);
+            }
+        };
+
+        let status_code = response.status();
+        match response.json::<PushoverResponse>().await {
+            Ok(body) if status_code.is_success() && body.status == 1 => Ok(true),
+            Ok(body) => {
+                tracing::debug!("Pushover health check failed: HTTP {}: {}", status_code, body.errors.join("; "));
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("Pushover health check HTTP {}: unreadable response: {}", status_code, e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "pushover"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("user_key".to_string(), "uQiRzpo4DXghDmr9QzzfQu27cmVRsG".to_string());
+        config.insert("api_token".to_string(), "azGDORePK8gMaC0QOYAMyEEuzJnyUi".to_string());
+        config
+    }
+
+    fn field<'a>(form: &'a [(&'static str, String)], name: &str) -> Option<&'a str> {
+        form.iter().find(|(key, _)| *key == name).map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_pushover_output_creation() {
+        let output = PushoverOutput::new(&test_config()).unwrap();
+        let form = output.form("Please check in");
+        assert_eq!(field(&form, "user"), Some("uQiRzpo4DXghDmr9QzzfQu27cmVRsG"));
+        assert_eq!(field(&form, "message"), Some("Please check in"));
+        assert_eq!(field(&form, "priority"), None);
+
+        for key in ["user_key", "api_token"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(PushoverOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+    }
+
+    #[test]
+    fn test_emergency_priority_retries_until_expiry() {
+        let mut config = test_config();
+        config.insert("priority".to_string(), "1".to_string());
+        let form = PushoverOutput::new(&config).unwrap().form("hi");
+        assert_eq!(field(&form, "priority"), Some("1"));
+        assert_eq!(field(&form, "retry"), None);
+
+        config.insert("priority".to_string(), "2".to_string());
+        let form = PushoverOutput::new(&config).unwrap().form("hi");
+        assert_eq!(field(&form, "priority"), Some("2"));
+        assert_eq!(field(&form, "retry"), Some("60"));
+        assert_eq!(field(&form, "expire"), Some("3600"));
+
+        config.insert("retry".to_string(), "10".to_string());
+        assert!(PushoverOutput::new(&config).is_err());
+        config.insert("retry".to_string(), "30".to_string());
+        config.insert("expire".to_string(), "86400".to_string());
+        assert!(PushoverOutput::new(&config).is_err());
+    }
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!(parse_priority("-2").unwrap(), -2);
+        assert_eq!(parse_priority(" 2 ").unwrap(), 2);
+        assert!(parse_priority("3").is_err());
+        assert!(parse_priority("high").is_err());
+    }
+}
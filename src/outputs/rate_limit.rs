@@ -0,0 +1,396 @@
+use super::bidirectional::{BidirectionalOutput, CheckinResponse, MailboxUsage};
+use super::{Output, OutputResult};
+use crate::config::OutputConfig;
+use crate::formatting::MessageFormat;
+use crate::shutdown::Shutdown;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RateLimitConfig {
+    /// Most messages sent through one provider in any rolling hour
+    #[serde(default = "default_max_sends_per_hour")]
+    pub max_sends_per_hour: usize,
+    /// Per-provider overrides of `max_sends_per_hour`, keyed by host
+    #[serde(default)]
+    pub providers: HashMap<String, usize>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_sends_per_hour: default_max_sends_per_hour(),
+            providers: HashMap::new(),
+        }
+    }
+}
+
+fn default_max_sends_per_hour() -> usize {
+    30
+}
+
+impl RateLimitConfig {
+    pub fn limit_for(&self, provider: &str) -> usize {
+        self.providers.get(provider).copied().unwrap_or(self.max_sends_per_hour).max(1)
+    }
+}
+
+/// The account or host a send goes through, so outputs sharing one SMTP
+/// account or API share its limit. `None` for outputs that don't leave the machine.
+pub fn provider_key(output_config: &OutputConfig) -> Option<String> {
+    let config = &output_config.config;
+    let host_of = |key: &str| {
+        config.get(key)
+            .and_then(|url| reqwest::Url::parse(url).ok())
+            .and_then(|url| url.host_str().map(str::to_string))
+    };
+
+    match output_config.output_type.as_str() {
+        "apprise" => host_of("server_url"),
+        "email" => config.get("smtp_host").cloned(),
+        "email_mailgun" => host_of("base_url").or_else(|| Some("api.mailgun.net".to_string())),
+        "email_ses" => config.get("region").map(|region| format!("email.{}.amazonaws.com", region)),
+        "discord" | "rocketchat" | "slack" => host_of("webhook_url"),
+        "facebook_messenger" => Some("graph.facebook.com".to_string()),
+        "irc" => config.get("server").cloned(),
+        "matrix" => host_of("homeserver_url"),
+        "ntfy" => host_of("server_url"),
+        "pushover" => Some("api.pushover.net".to_string()),
+        "sns" => config.get("region").map(|region| format!("sns.{}.amazonaws.com", region)),
+        "sms_twilio" | "voice_twilio" => Some("api.twilio.com".to_string()),
+        "webhook" => host_of("url"),
+        "whatsapp" => match config.get("provider").map(String::as_str) {
+            Some("twilio") => Some("api.twilio.com".to_string()),
+            Some("cloud_api") => Some("graph.facebook.com".to_string()),
+            _ => None,
+        },
+        // The server the account logs in to, or the account's own domain
+        "xmpp" => match config.get("server") {
+            Some(server) => Some(server.rsplit_once(':').map_or(server.as_str(), |(host, _)| host).to_string()),
+            None => config.get("jid")
+                .and_then(|jid| tokio_xmpp::jid::Jid::new(jid).ok())
+                .map(|jid| jid.domain().to_string()),
+        },
+        _ => None,
+    }
+}
+
+/// Rolling-hour send counts per provider, shared by every output. Sends over
+/// the limit wait their turn, in the order they arrived, rather than being
+/// dropped, until the daemon is asked to shut down.
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    window: Duration,
+    sends: Mutex<HashMap<String, VecDeque<DateTime<Utc>>>>,
+    queues: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    shutdown: Mutex<Shutdown>,
+}
+
+impl RateLimiter {
+    /// Starts from `history`, the send times saved by an earlier run
+    pub fn new(config: &RateLimitConfig, history: &HashMap<String, Vec<DateTime<Utc>>>) -> Self {
+        Self::with_window(config, history, Duration::hours(1))
+    }
+
+    fn with_window(config: &RateLimitConfig, history: &HashMap<String, Vec<DateTime<Utc>>>, window: Duration) -> Self {
+        let sends = history.iter()
+            .map(|(provider, times)| (provider.clone(), times.iter().copied().collect()))
+            .collect();
+        Self {
+            config: config.clone(),
+            window,
+            sends: Mutex::new(sends),
+            queues: Mutex::new(HashMap::new()),
+            shutdown: Mutex::new(Shutdown::never()),
+        }
+    }
+
+    /// Stops throttled sends waiting once `shutdown` is requested
+    pub fn set_shutdown(&self, shutdown: Shutdown) {
+        *self.shutdown.lock().expect("rate limiter lock poisoned") = shutdown;
+    }
+
+    /// Send times within the window, to be saved in state
+    pub fn history(&self, now: DateTime<Utc>) -> HashMap<String, Vec<DateTime<Utc>>> {
+        let mut sends = self.sends.lock().expect("rate limiter lock poisoned");
+        sends.iter_mut()
+            .map(|(provider, times)| {
+                prune(times, now - self.window);
+                (provider.clone(), times.iter().copied().collect::<Vec<_>>())
+            })
+            .filter(|(_, times)| !times.is_empty())
+            .collect()
+    }
+
+    /// Waits until `provider` is under its limit, then counts one send. `false`
+    /// if shutdown was requested first, in which case nothing is counted.
+    pub async fn acquire(&self, provider: &str) -> bool {
+        let mut shutdown = self.shutdown.lock().expect("rate limiter lock poisoned").clone();
+        tokio::select! {
+            // A send under the limit goes ahead even during shutdown
+            biased;
+            _ = self.wait_for_turn(provider) => true,
+            _ = shutdown.requested() => {
+                tracing::warn!("Shutting down while sends through {} are throttled", provider);
+                false
+            }
+        }
+    }
+
+    async fn wait_for_turn(&self, provider: &str) {
+        let queue = self.queues.lock().expect("rate limiter lock poisoned")
+            .entry(provider.to_string())
+            .or_default()
+            .clone();
+        // tokio's Mutex is fair, so queued sends go out in arrival order
+        let _turn = queue.lock().await;
+
+        loop {
+            let wait = {
+                let now = Utc::now();
+                let mut sends = self.sends.lock().expect("rate limiter lock poisoned");
+                let times = sends.entry(provider.to_string()).or_default();
+                prune(times, now - self.window);
+
+                let limit = self.config.limit_for(provider);
+                if times.len() < limit {
+                    times.push_back(now);
+                    return;
+                }
+                times.front().map(|oldest| *oldest + self.window - now).unwrap_or_default()
+            };
+
+            tracing::warn!(
+                "Throttling sends through {}: {} sent in the last {}, next send in {}s",
+                provider,
+                self.config.limit_for(provider),
+                crate::duration_parser::DurationStyle::Compact.format(self.window),
+                wait.num_seconds()
+            );
+            tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+        }
+    }
+}
+
+fn prune(times: &mut VecDeque<DateTime<Utc>>, cutoff: DateTime<Utc>) {
+    while times.front().is_some_and(|time| *time <= cutoff) {
+        times.pop_front();
+    }
+}
+
+/// Providers at their limit according to `history`, with their send counts
+pub fn throttled_providers(
+    config: &RateLimitConfig,
+    history: &HashMap<String, Vec<DateTime<Utc>>>,
+    now: DateTime<Utc>,
+) -> Vec<(String, usize)> {
+    let mut throttled: Vec<(String, usize)> = history.iter()
+        .map(|(provider, times)| (provider.clone(), times.iter().filter(|time| **time > now - Duration::hours(1)).count()))
+        .filter(|(provider, count)| *count >= config.limit_for(provider))
+        .collect();
+    throttled.sort();
+    throttled
+}
+
+/// Wraps an output so its sends count against its provider's limit
+pub struct RateLimitedOutput<O: ?Sized> {
+    inner: Box<O>,
+    provider: String,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<O: ?Sized> RateLimitedOutput<O> {
+    pub fn new(inner: Box<O>, provider: String, limiter: Arc<RateLimiter>) -> Self {
+        Self { inner, provider, limiter }
+    }
+
+    fn shutting_down(&self) -> OutputResult {
+        OutputResult::Skipped(format!("shutting down while sends through {} are throttled", self.provider))
+    }
+}
+
+#[async_trait]
+impl Output for RateLimitedOutput<dyn Output> {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        if !self.limiter.acquire(&self.provider).await {
+            return Ok(self.shutting_down());
+        }
+        self.inner.send_message(message).await
+    }
+
+    async fn send_formatted(&self, message: &str, format: MessageFormat) -> Result<OutputResult> {
+        if !self.limiter.acquire(&self.provider).await {
+            return Ok(self.shutting_down());
+        }
+        self.inner.send_formatted(message, format).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    fn get_name(&self) -> &str {
+        self.inner.get_name()
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for RateLimitedOutput<dyn BidirectionalOutput> {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        if !self.limiter.acquire(&self.provider).await {
+            return Ok(self.shutting_down());
+        }
+        self.inner.send_message(message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.inner.health_check().await
+    }
+
+    fn get_name(&self) -> &str {
+        self.inner.get_name()
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        self.inner.check_for_responses(since).await
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        self.inner.mark_processed_until(timestamp).await
+    }
+
+    async fn mailbox_usage(&self) -> Result<Option<MailboxUsage>> {
+        self.inner.mailbox_usage().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Recording {
+        name: String,
+        sent: Arc<Mutex<Vec<(String, std::time::Instant)>>>,
+    }
+
+    #[async_trait]
+    impl Output for Recording {
+        async fn send_message(&self, message: &str) -> Result<OutputResult> {
+            self.sent.lock().unwrap().push((format!("{}:{}", self.name, message), std::time::Instant::now()));
+            Ok(OutputResult::Success)
+        }
+
+        async fn health_check(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn get_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn output_config(output_type: &str, config: &[(&str, &str)]) -> OutputConfig {
+        OutputConfig {
+            output_type: output_type.to_string(),
+            config: config.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_provider_key() {
+        assert_eq!(provider_key(&output_config("email", &[("smtp_host", "smtp.gmail.com")])).as_deref(), Some("smtp.gmail.com"));
+        assert_eq!(
+            provider_key(&output_config("slack", &[("webhook_url", "https://hooks.slack.com/services/T0/B0/X")])).as_deref(),
+            Some("hooks.slack.com")
+        );
+        assert_eq!(provider_key(&output_config("file", &[("path", "out.log")])), None);
+    }
+
+    #[tokio::test]
+    async fn test_burst_is_queued_in_order_and_delivered() {
+        let config = RateLimitConfig { max_sends_per_hour: 2, providers: HashMap::new() };
+        let limiter = Arc::new(RateLimiter::with_window(&config, &HashMap::new(), Duration::milliseconds(300)));
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let outputs: Vec<RateLimitedOutput<dyn Output>> = ["a", "b"].iter()
+            .map(|name| RateLimitedOutput::new(
+                Box::new(Recording { name: name.to_string(), sent: sent.clone() }) as Box<dyn Output>,
+                "smtp.example.com".to_string(),
+                limiter.clone(),
+            ))
+            .collect();
+
+        let started = std::time::Instant::now();
+        let messages: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let sends = messages.iter().enumerate().map(|(i, message)| outputs[i % 2].send_message(message));
+        let results = futures_util::future::join_all(sends).await;
+        assert!(results.iter().all(|result| result.as_ref().unwrap().is_success()));
+
+        let sent = sent.lock().unwrap();
+        let messages: Vec<&str> = sent.iter().map(|(message, _)| message.as_str()).collect();
+        assert_eq!(messages, ["a:0", "b:1", "a:2", "b:3", "a:4"]);
+
+        // Two fit in the first window, two in the second, the last in the third
+        assert!(sent[1].1 - started < std::time::Duration::from_millis(150));
+        assert!(sent[2].1 - started >= std::time::Duration::from_millis(300));
+        assert!(sent[4].1 - started >= std::time::Duration::from_millis(600));
+    }
+
+    #[tokio::test]
+    async fn test_providers_are_limited_separately_and_history_is_kept() {
+        let mut config = RateLimitConfig { max_sends_per_hour: 1, providers: HashMap::new() };
+        config.providers.insert("api.twilio.com".to_string(), 5);
+        let limiter = RateLimiter::new(&config, &HashMap::new());
+
+        assert!(limiter.acquire("smtp.example.com").await);
+        for _ in 0..3 {
+            assert!(limiter.acquire("api.twilio.com").await);
+        }
+
+        let now = Utc::now();
+        let history = limiter.history(now);
+        assert_eq!(history["smtp.example.com"].len(), 1);
+        assert_eq!(history["api.twilio.com"].len(), 3);
+        assert_eq!(throttled_providers(&config, &history, now), vec![("smtp.example.com".to_string(), 1)]);
+
+        // History older than an hour no longer counts
+        assert!(throttled_providers(&config, &history, now + Duration::hours(2)).is_empty());
+        let restored = RateLimiter::new(&config, &history);
+        assert!(restored.history(now + Duration::hours(2)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_interrupts_a_throttled_send() {
+        let config = RateLimitConfig { max_sends_per_hour: 1, providers: HashMap::new() };
+        let limiter = Arc::new(RateLimiter::new(&config, &HashMap::new()));
+        let (trigger, shutdown) = Shutdown::channel();
+        limiter.set_shutdown(shutdown);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let output = RateLimitedOutput::new(
+            Box::new(Recording { name: "a".to_string(), sent: sent.clone() }) as Box<dyn Output>,
+            "smtp.example.com".to_string(),
+            limiter.clone(),
+        );
+        assert!(output.send_message("first").await.unwrap().is_success());
+
+        // The second send would wait out the hour
+        let (result, ()) = tokio::join!(
+            tokio::time::timeout(std::time::Duration::from_secs(5), output.send_message("second")),
+            async {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                trigger.send(true).unwrap();
+            }
+        );
+        assert!(matches!(result.unwrap().unwrap(), OutputResult::Skipped(reason) if reason.contains("smtp.example.com")));
+        assert_eq!(sent.lock().unwrap().len(), 1);
+        assert_eq!(limiter.history(Utc::now())["smtp.example.com"].len(), 1);
+    }
+}
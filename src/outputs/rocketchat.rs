@@ -0,0 +1,181 @@
+use super::{Output, OutputResult};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Posts to a Rocket.Chat or Mattermost incoming webhook. Both accept the same
+/// `{"text": ...}` payload and large messages, so nothing is split.
+#[derive(Debug, Clone)]
+pub struct RocketChatOutput {
+    webhook_url: Url,
+    channel: Option<String>,
+    username: Option<String>,
+    health_url: Option<Url>,
+    client: Client,
+}
+
+impl RocketChatOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["webhook_url", "channel", "username", "health_url"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let webhook_url = config
+            .get("webhook_url")
+            .context("Missing 'webhook_url' field in rocketchat config")?;
+        let webhook_url = parse_url(webhook_url).context("Invalid 'webhook_url' in rocketchat config")?;
+
+        let health_url = config.get("health_url")
+            .map(|url| parse_url(url).context("Invalid 'health_url' in rocketchat config"))
+            .transpose()?;
+
+        Ok(RocketChatOutput {
+            webhook_url,
+            channel: config.get("channel").cloned(),
+            username: config.get("username").cloned(),
+            health_url,
+            client: Client::new(),
+        })
+    }
+
+    fn payload(&self, message: &str) -> Value {
+        let mut payload = json!({ "text": message });
+        if let Some(channel) = &self.channel {
+            payload["channel"] = json!(channel);
+        }
+        if let Some(username) = &self.username {
+            // Mattermost reads `username`, Rocket.Chat reads `alias`
+            payload["username"] = json!(username);
+            payload["alias"] = json!(username);
+        }
+        payload
+    }
+}
+
+/// Self-hosted servers are often on a LAN, so plain http is allowed
+fn parse_url(url: &str) -> Result<Url> {
+    let url = Url::parse(url).context("Not an absolute URL")?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("URL must use http or https");
+    }
+    if url.host_str().is_none() {
+        anyhow::bail!("URL has no host");
+    }
+    Ok(url)
+}
+
+/// Rocket.Chat answers `{"success": false}` for some rejected posts even with HTTP 200
+fn reports_failure(body: &str) -> bool {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|body| body.get("success").and_then(Value::as_bool))
+        == Some(false)
+}
+
+#[async_trait]
+impl Output for RocketChatOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let response = match self
+            .client
+            .post(self.webhook_url.clone())
+            .json(&self.payload(message))
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                // The URL's path holds the webhook token, so leave it out
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", e.without_url())));
+            }
+        };
+
+        let status_code = response.status();
+        match response.text().await {
+            Ok(text) if status_code.is_success() && !reports_failure(&text) => Ok(OutputResult::Success),
+            Ok(text) => Ok(OutputResult::Failed(format!("Rocket.Chat HTTP {}: {}", status_code, text))),
+            Err(e) => Ok(OutputResult::Failed(format!("Rocket.Chat HTTP {} (failed to read response: {})", status_code, e))),
+        }
+    }
+
+    /// GETs `health_url` when set. Otherwise only checks that the webhook's
+    /// host resolves, since any post would show up in the channel.
+    async fn health_check(&self) -> Result<bool> {
+        if let Some(health_url) = &self.health_url {
+            return match self.client.get(health_url.clone()).send().await {
+                Ok(resp) if resp.status().is_success() => Ok(true),
+                Ok(resp) => {
+                    tracing::debug!("Rocket.Chat health check: health_url returned {}", resp.status());
+                    Ok(false)
+                }
+                Err(e) => {
+                    tracing::debug!("Rocket.Chat health check HTTP error: {}", e.without_url());
+                    Ok(false)
+                }
+            };
+        }
+
+        let Some(host) = self.webhook_url.host_str() else {
+            return Ok(false);
+        };
+        let port = self.webhook_url.port_or_known_default().unwrap_or(443);
+
+        match tokio::net::lookup_host((host, port)).await {
+            Ok(mut addresses) => Ok(addresses.next().is_some()),
+            Err(e) => {
+                tracing::debug!("Rocket.Chat health check could not resolve {}: {}", host, e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "rocketchat"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("webhook_url".to_string(), "https://chat.example.com/hooks/abc123/secrettoken".to_string());
+        config
+    }
+
+    #[test]
+    fn test_rocketchat_payload() {
+        let output = RocketChatOutput::new(&test_config()).unwrap();
+        let long_message = "x".repeat(20_000);
+        assert_eq!(output.payload(&long_message), json!({ "text": long_message }));
+
+        let mut config = test_config();
+        config.insert("channel".to_string(), "#family".to_string());
+        config.insert("username".to_string(), "LastSignal".to_string());
+        let output = RocketChatOutput::new(&config).unwrap();
+        assert_eq!(
+            output.payload("hi"),
+            json!({ "text": "hi", "channel": "#family", "username": "LastSignal", "alias": "LastSignal" })
+        );
+    }
+
+    #[test]
+    fn test_rocketchat_output_rejects_invalid_config() {
+        assert!(RocketChatOutput::new(&HashMap::new()).is_err());
+
+        for (key, value) in [("webhook_url", "chat.example.com/hooks/abc"), ("webhook_url", "ftp://chat.example.com/hooks"), ("health_url", "/api/info")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(RocketChatOutput::new(&config).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_reports_failure() {
+        assert!(reports_failure(r#"{"success": false, "error": "error-invalid-channel"}"#));
+        assert!(!reports_failure(r#"{"success": true}"#));
+        // Mattermost answers with plain text
+        assert!(!reports_failure("ok"));
+    }
+}
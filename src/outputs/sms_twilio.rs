@@ -0,0 +1,184 @@
+use super::{Output, OutputResult};
+use crate::formatting;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use std::collections::HashMap;
+
+const API_BASE_URL: &str = "https://api.twilio.com/2010-04-01";
+
+/// Twilio rejects message bodies longer than this
+const MAX_MESSAGE_CHARS: usize = 1600;
+
+#[derive(Debug, Clone)]
+pub struct SmsTwilioOutput {
+    account_sid: String,
+    auth_token: String,
+    from_number: String,
+    to_number: String,
+    client: Client,
+}
+
+impl SmsTwilioOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["account_sid", "auth_token", "from_number", "to_number"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let account_sid = config
+            .get("account_sid")
+            .context("Missing 'account_sid' field in sms_twilio config")?
+            .clone();
+
+        let auth_token = config
+            .get("auth_token")
+            .context("Missing 'auth_token' field in sms_twilio config")?
+            .clone();
+
+        let from_number = config
+            .get("from_number")
+            .context("Missing 'from_number' field in sms_twilio config")?
+            .clone();
+
+        let to_number = config
+            .get("to_number")
+            .context("Missing 'to_number' field in sms_twilio config")?
+            .clone();
+
+        Ok(SmsTwilioOutput {
+            account_sid,
+            auth_token,
+            from_number,
+            to_number,
+            client: Client::new(),
+        })
+    }
+
+    fn get_account_url(&self) -> String {
+        format!("{}/Accounts/{}.json", API_BASE_URL, self.account_sid)
+    }
+
+    fn get_messages_url(&self) -> String {
+        format!("{}/Accounts/{}/Messages.json", API_BASE_URL, self.account_sid)
+    }
+
+    async fn send_text(&self, message: &str) -> Result<OutputResult> {
+        let form = [
+            ("To", self.to_number.as_str()),
+            ("From", self.from_number.as_str()),
+            ("Body", message),
+        ];
+
+        let response = match self
+            .client
+            .post(self.get_messages_url())
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .form(&form)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string()))));
+            }
+        };
+
+        let status_code = response.status();
+        if status_code.is_success() {
+            return Ok(OutputResult::Success);
+        }
+
+        match response.text().await {
+            Ok(text) => Ok(OutputResult::Failed(format!("Twilio HTTP {}: {}", status_code, text))),
+            Err(e) => Ok(OutputResult::Failed(format!("Twilio HTTP {} (failed to read response: {})", status_code, e))),
+        }
+    }
+}
+
+#[async_trait]
+impl Output for SmsTwilioOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let parts = formatting::split_message(message, MAX_MESSAGE_CHARS);
+        let total = parts.len();
+
+        for (i, part) in parts.iter().enumerate() {
+            match self.send_text(part).await? {
+                OutputResult::Success => {}
+                OutputResult::Failed(error) if total > 1 => {
+                    return Ok(OutputResult::Failed(format!("Part {}/{}: {}", i + 1, total, error)));
+                }
+                other => return Ok(other),
+            }
+        }
+
+        Ok(OutputResult::Success)
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        let response = match self
+            .client
+            .get(self.get_account_url())
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::debug!("Twilio health check HTTP error: {}", redact::redact(&e.to_string()));
+                return Ok(false);
+            }
+        };
+
+        if response.status().is_success() {
+            Ok(true)
+        } else {
+            tracing::debug!("Twilio health check HTTP error: {}", response.status());
+            Ok(false)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "sms_twilio"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("account_sid".to_string(), "AC123".to_string());
+        config.insert("auth_token".to_string(), "secret".to_string());
+        config.insert("from_number".to_string(), "+15005550006".to_string());
+        config.insert("to_number".to_string(), "+447700900123".to_string());
+        config
+    }
+
+    #[test]
+    fn test_sms_twilio_output_creation() {
+        let output = SmsTwilioOutput::new(&test_config()).unwrap();
+        assert_eq!(output.account_sid, "AC123");
+        assert_eq!(output.to_number, "+447700900123");
+        assert_eq!(output.get_messages_url(), "https://api.twilio.com/2010-04-01/Accounts/AC123/Messages.json");
+        assert_eq!(output.get_account_url(), "https://api.twilio.com/2010-04-01/Accounts/AC123.json");
+    }
+
+    #[test]
+    fn test_sms_twilio_output_missing_config() {
+        for key in SmsTwilioOutput::CONFIG_KEYS {
+            let mut config = test_config();
+            config.remove(*key);
+            assert!(SmsTwilioOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+    }
+
+    #[test]
+    fn test_long_messages_split_into_segments() {
+        let message = "word ".repeat(700);
+        let parts = formatting::split_message(&message, MAX_MESSAGE_CHARS);
+
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|part| part.chars().count() <= MAX_MESSAGE_CHARS));
+    }
+}
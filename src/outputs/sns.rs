@@ -0,0 +1,232 @@
+use super::aws::{self, Credentials};
+use super::{Output, OutputResult};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// Used by email subscriptions, and ignored by the others
+const SUBJECT: &str = "LastSignal Notification";
+
+const API_VERSION: &str = "2010-03-31";
+
+const SIGNING_SERVICE: &str = "sns";
+
+#[derive(Debug, Clone)]
+pub struct SnsOutput {
+    credentials: Credentials,
+    region: String,
+    topic_arn: String,
+    client: Client,
+}
+
+impl SnsOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["access_key_id", "secret_access_key", "region", "topic_arn"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let access_key_id = config
+            .get("access_key_id")
+            .context("Missing 'access_key_id' field in sns config")?
+            .clone();
+
+        let secret_access_key = config
+            .get("secret_access_key")
+            .context("Missing 'secret_access_key' field in sns config")?
+            .clone();
+
+        let region = config
+            .get("region")
+            .context("Missing 'region' field in sns config")?
+            .clone();
+        aws::validate_region(&region).context("Invalid 'region' in sns config")?;
+
+        let topic_arn = config
+            .get("topic_arn")
+            .context("Missing 'topic_arn' field in sns config")?
+            .clone();
+        validate_topic_arn(&topic_arn, &region).context("Invalid 'topic_arn' in sns config")?;
+
+        Ok(SnsOutput {
+            credentials: Credentials { access_key_id, secret_access_key },
+            region,
+            topic_arn,
+            client: Client::new(),
+        })
+    }
+
+    fn host(&self) -> String {
+        format!("sns.{}.amazonaws.com", self.region)
+    }
+
+    fn publish_params(&self, message: &str) -> Vec<(&'static str, String)> {
+        vec![
+            ("Action", "Publish".to_string()),
+            ("Version", API_VERSION.to_string()),
+            ("TopicArn", self.topic_arn.clone()),
+            ("Subject", SUBJECT.to_string()),
+            ("Message", message.to_string()),
+        ]
+    }
+
+    /// Calls a Query API action. `Ok(Err)` carries SNS's own error, decoded.
+    async fn call(&self, params: &[(&str, String)]) -> Result<std::result::Result<String, String>> {
+        let params: Vec<(&str, &str)> = params.iter().map(|(key, value)| (*key, value.as_str())).collect();
+        let body = aws::form_body(&params);
+        let host = self.host();
+
+        let mut request = self
+            .client
+            .post(format!("https://{}/", host))
+            .header("Content-Type", aws::FORM_CONTENT_TYPE)
+            .body(body.clone());
+        for (name, value) in aws::sign_form_post(&self.credentials, &self.region, SIGNING_SERVICE, &host, &body, Utc::now()) {
+            request = request.header(name, value);
+        }
+
+        let response = match request.send().await {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Err(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        let text = response.text().await.context("Failed to read SNS response")?;
+        if status_code.is_success() {
+            Ok(Ok(text))
+        } else {
+            Ok(Err(format!("SNS HTTP {}: {}", status_code, aws::error_message(&text))))
+        }
+    }
+}
+
+/// `arn:aws:sns:<region>:<account>:<name>`, in the configured region since
+/// SNS only accepts a topic at its own regional endpoint
+fn validate_topic_arn(topic_arn: &str, region: &str) -> Result<()> {
+    let parts: Vec<&str> = topic_arn.split(':').collect();
+    let [arn, partition, service, arn_region, account, name] = parts.as_slice() else {
+        anyhow::bail!("'{}' is not an SNS topic ARN such as arn:aws:sns:us-east-1:123456789012:lastsignal", topic_arn);
+    };
+    if *arn != "arn" || !partition.starts_with("aws") || *service != "sns" || name.is_empty() {
+        anyhow::bail!("'{}' is not an SNS topic ARN such as arn:aws:sns:us-east-1:123456789012:lastsignal", topic_arn);
+    }
+    if account.len() != 12 || !account.chars().all(|c| c.is_ascii_digit()) {
+        anyhow::bail!("'{}' has an invalid account ID", topic_arn);
+    }
+    if arn_region != &region {
+        anyhow::bail!("Topic is in {} but 'region' is {}", arn_region, region);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Output for SnsOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        match self.call(&self.publish_params(message)).await? {
+            Ok(body) if aws::xml_text(&body, "MessageId").is_some_and(|id| !id.is_empty()) => Ok(OutputResult::Success),
+            Ok(body) => Ok(OutputResult::Failed(format!("SNS accepted the publish without a MessageId: {}", body.trim()))),
+            Err(error) => Ok(OutputResult::Failed(error)),
+        }
+    }
+
+    /// The keys are valid and can see the topic
+    async fn health_check(&self) -> Result<bool> {
+        let params = [
+            ("Action", "GetTopicAttributes".to_string()),
+            ("Version", API_VERSION.to_string()),
+            ("TopicArn", self.topic_arn.clone()),
+        ];
+        match self.call(&params).await {
+            Ok(Ok(_)) => Ok(true),
+            Ok(Err(error)) => {
+                tracing::debug!("SNS health check failed: {}", error);
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("SNS health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "sns"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("access_key_id".to_string(), "AKIDEXAMPLE".to_string());
+        config.insert("secret_access_key".to_string(), "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string());
+        config.insert("region".to_string(), "eu-west-2".to_string());
+        config.insert("topic_arn".to_string(), "arn:aws:sns:eu-west-2:123456789012:lastsignal".to_string());
+        config
+    }
+
+    #[test]
+    fn test_sns_output_creation() {
+        let output = SnsOutput::new(&test_config()).unwrap();
+        assert_eq!(output.host(), "sns.eu-west-2.amazonaws.com");
+
+        for key in SnsOutput::CONFIG_KEYS {
+            let mut config = test_config();
+            config.remove(*key);
+            assert!(SnsOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+
+        for topic_arn in [
+            "lastsignal",
+            "arn:aws:sqs:eu-west-2:123456789012:lastsignal",
+            "arn:aws:sns:eu-west-2:1234:lastsignal",
+            "arn:aws:sns:us-east-1:123456789012:lastsignal",
+        ] {
+            let mut config = test_config();
+            config.insert("topic_arn".to_string(), topic_arn.to_string());
+            assert!(SnsOutput::new(&config).is_err(), "{} should be an error", topic_arn);
+        }
+    }
+
+    #[test]
+    fn test_publish_body() {
+        let output = SnsOutput::new(&test_config()).unwrap();
+        let params = output.publish_params("Check in & reply");
+        let params: Vec<(&str, &str)> = params.iter().map(|(key, value)| (*key, value.as_str())).collect();
+        assert_eq!(
+            aws::form_body(&params),
+            "Action=Publish&Version=2010-03-31&TopicArn=arn%3Aaws%3Asns%3Aeu-west-2%3A123456789012%3Alastsignal\
+             &Subject=LastSignal%20Notification&Message=Check%20in%20%26%20reply"
+        );
+    }
+
+    #[test]
+    fn test_publish_signature() {
+        let output = SnsOutput::new(&test_config()).unwrap();
+        let now = "2015-08-30T12:36:00Z".parse().unwrap();
+        let [(_, amz_date), (_, authorization)] =
+            aws::sign_form_post(&output.credentials, &output.region, SIGNING_SERVICE, &output.host(), "Action=Publish", now);
+        assert_eq!(amz_date, "20150830T123600Z");
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/eu-west-2/sns/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature="
+        ));
+    }
+
+    #[test]
+    fn test_publish_response() {
+        let body = r#"<PublishResponse xmlns="https://sns.amazonaws.com/doc/2010-03-31/">
+  <PublishResult>
+    <MessageId>567910cd-659e-55d4-8ccb-5aaf14679dc0</MessageId>
+  </PublishResult>
+</PublishResponse>"#;
+        assert_eq!(aws::xml_text(body, "MessageId"), Some("567910cd-659e-55d4-8ccb-5aaf14679dc0"));
+
+        let error = r#"<ErrorResponse xmlns="https://sns.amazonaws.com/doc/2010-03-31/">
+  <Error><Type>Sender</Type><Code>NotFound</Code><Message>Topic does not exist</Message></Error>
+</ErrorResponse>"#;
+        assert_eq!(aws::error_message(error), "NotFound: Topic does not exist");
+    }
+}
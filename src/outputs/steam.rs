@@ -0,0 +1,276 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::duration_parser::ConfigDuration;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const API_URL: &str = "https://api.steampowered.com";
+
+/// `communityvisibilitystate` of a profile whose details anyone can see
+const VISIBILITY_PUBLIC: i64 = 3;
+
+/// Treats being online or playing games on Steam as a sign of life
+#[derive(Debug, Clone)]
+pub struct SteamOutput {
+    client: Client,
+    api_key: String,
+    steam_id: String,
+    max_time_since_last_checkin: ConfigDuration,
+}
+
+/// The player in a `GetPlayerSummaries` response. Private profiles still
+/// appear, but without `lastlogoff` or game details, so they are an error
+/// rather than a profile that never shows activity.
+fn public_player<'a>(response: &'a Value, steam_id: &str) -> Result<&'a Value> {
+    let player = response
+        .get("response")
+        .and_then(|response| response.get("players"))
+        .and_then(Value::as_array)
+        .and_then(|players| players.first())
+        .with_context(|| format!("Steam has no profile for steam_id {}", steam_id))?;
+
+    let visibility = player.get("communityvisibilitystate").and_then(Value::as_i64);
+    if visibility != Some(VISIBILITY_PUBLIC) {
+        anyhow::bail!(
+            "Steam profile {} is private. Set 'My profile' and 'Game details' to Public in Steam's privacy settings",
+            steam_id
+        );
+    }
+    Ok(player)
+}
+
+/// When the player was last seen. Someone online or in a game right now
+/// counts as `now`, otherwise it is their last log off.
+fn last_seen(player: &Value, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let online = player.get("personastate").and_then(Value::as_i64).is_some_and(|state| state > 0);
+    if online || player.get("gameid").is_some() {
+        return Some(now);
+    }
+    player
+        .get("lastlogoff")
+        .and_then(Value::as_i64)
+        .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+}
+
+/// The latest `rtime_last_played` in a `GetRecentlyPlayedGames` response.
+/// Games without it only say they were played in the last two weeks, which
+/// can't be placed in the window.
+fn last_played(response: &Value) -> Option<DateTime<Utc>> {
+    response
+        .get("response")
+        .and_then(|response| response.get("games"))
+        .and_then(Value::as_array)?
+        .iter()
+        .filter_map(|game| game.get("rtime_last_played").and_then(Value::as_i64))
+        .filter(|&seconds| seconds > 0)
+        .filter_map(|seconds| DateTime::from_timestamp(seconds, 0))
+        .max()
+}
+
+impl SteamOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["api_key", "steam_id"];
+
+    pub fn new(config: &HashMap<String, String>, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        let api_key = config
+            .get("api_key")
+            .context("Missing 'api_key' field in steam config")?
+            .clone();
+        let steam_id = config
+            .get("steam_id")
+            .context("Missing 'steam_id' field in steam config")?
+            .clone();
+        if steam_id.len() != 17 || !steam_id.chars().all(|c| c.is_ascii_digit()) {
+            anyhow::bail!("Invalid 'steam_id' in steam config: expected the 17-digit SteamID64, got '{}'", steam_id);
+        }
+
+        Ok(SteamOutput {
+            client: Client::new(),
+            api_key,
+            steam_id,
+            max_time_since_last_checkin,
+        })
+    }
+
+    async fn call(&self, method: &str, query: &[(&str, &str)]) -> Result<Value> {
+        let response = self
+            .client
+            .get(format!("{}/{}", API_URL, method))
+            .query(&[("key", self.api_key.as_str()), ("format", "json")])
+            .query(query)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Steam request failed: {}", e.without_url()))?;
+
+        let status = response.status();
+        let text = response.text().await.context("Failed to read Steam response")?;
+        redact::log_payload(&format!("Steam {} response", method), status, &text);
+
+        if status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::UNAUTHORIZED {
+            anyhow::bail!("Steam rejected the API key (HTTP {})", status);
+        }
+        if !status.is_success() {
+            anyhow::bail!("Steam returned HTTP {}", status);
+        }
+        serde_json::from_str(&text).with_context(|| format!("Failed to parse Steam {} response", method))
+    }
+
+    async fn player_summary(&self) -> Result<Value> {
+        self.call("ISteamUser/GetPlayerSummaries/v2/", &[("steamids", self.steam_id.as_str())]).await
+    }
+
+    /// The most recent of being online and playing a game
+    async fn latest_activity(&self, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        let summary = self.player_summary().await?;
+        let player = public_player(&summary, &self.steam_id)?;
+        let seen = last_seen(player, now);
+
+        let played = match self
+            .call("IPlayerService/GetRecentlyPlayedGames/v1/", &[("steamid", self.steam_id.as_str())])
+            .await
+        {
+            Ok(games) => last_played(&games),
+            Err(e) => {
+                // The summary alone is still a useful answer
+                tracing::warn!("Steam: could not read recently played games: {:#}", e);
+                None
+            }
+        };
+
+        Ok(seen.max(played))
+    }
+}
+
+#[async_trait]
+impl Output for SteamOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Steam is used purely for checking if the user is alive via gaming activity
+        Ok(OutputResult::Skipped("Steam is a check-only adapter".to_string()))
+    }
+
+    /// The API key works and the profile is public
+    async fn health_check(&self) -> Result<bool> {
+        let result = match self.player_summary().await {
+            Ok(summary) => public_player(&summary, &self.steam_id).map(|_| ()),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => Ok(true),
+            Err(e) => {
+                tracing::warn!("Steam health check failed: {:#}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "steam"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for SteamOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let now = Utc::now();
+        let Some(latest) = self.latest_activity(now).await? else {
+            tracing::debug!("Steam: no activity recorded for {}", self.steam_id);
+            return Ok(vec![]);
+        };
+
+        Ok(super::activity_checkin(
+            "Steam",
+            latest,
+            self.max_time_since_last_checkin,
+            now,
+            "Steam Activity Detected".to_string(),
+            format!("Steam user {}", self.steam_id),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const STEAM_ID: &str = "76561197960435530";
+
+    #[test]
+    fn test_steam_output_config() {
+        let mut config = HashMap::new();
+        config.insert("api_key".to_string(), "0123456789ABCDEF".to_string());
+        assert!(SteamOutput::new(&config, ConfigDuration::from_hours(24)).is_err());
+
+        config.insert("steam_id".to_string(), "gabelogannewell".to_string());
+        assert!(SteamOutput::new(&config, ConfigDuration::from_hours(24)).is_err());
+
+        config.insert("steam_id".to_string(), STEAM_ID.to_string());
+        let output = SteamOutput::new(&config, ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(<dyn Output>::get_name(&output), "steam");
+    }
+
+    #[test]
+    fn test_public_player() {
+        let public = json!({"response": {"players": [
+            {"steamid": STEAM_ID, "communityvisibilitystate": 3, "personastate": 0, "lastlogoff": 1760520600}
+        ]}});
+        assert!(public_player(&public, STEAM_ID).is_ok());
+
+        let private = json!({"response": {"players": [{"steamid": STEAM_ID, "communityvisibilitystate": 1}]}});
+        let error = public_player(&private, STEAM_ID).unwrap_err().to_string();
+        assert!(error.contains("private"), "{}", error);
+
+        assert!(public_player(&json!({"response": {"players": []}}), STEAM_ID).is_err());
+    }
+
+    #[test]
+    fn test_last_seen() {
+        let now: DateTime<Utc> = "2026-10-15T09:30:00Z".parse().unwrap();
+
+        let offline = json!({"personastate": 0, "lastlogoff": 1760520600});
+        assert_eq!(last_seen(&offline, now).unwrap().timestamp(), 1_760_520_600);
+
+        let online = json!({"personastate": 1, "lastlogoff": 1760520600});
+        assert_eq!(last_seen(&online, now), Some(now));
+
+        // Invisible players appear offline, but the game they are in still shows
+        let in_game = json!({"personastate": 0, "gameid": "570", "lastlogoff": 1760520600});
+        assert_eq!(last_seen(&in_game, now), Some(now));
+
+        assert_eq!(last_seen(&json!({"personastate": 0}), now), None);
+    }
+
+    #[test]
+    fn test_last_played() {
+        let games = json!({"response": {"total_count": 3, "games": [
+            {"appid": 570, "playtime_2weeks": 120, "rtime_last_played": 1760436000},
+            {"appid": 620, "playtime_2weeks": 30, "rtime_last_played": 1760520600},
+            {"appid": 440, "playtime_2weeks": 10}
+        ]}});
+        assert_eq!(last_played(&games).unwrap().timestamp(), 1_760_520_600);
+
+        assert_eq!(last_played(&json!({"response": {"total_count": 0}})), None);
+        assert_eq!(last_played(&json!({"response": {}})), None);
+    }
+}
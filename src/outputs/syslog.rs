@@ -0,0 +1,285 @@
+use super::{MessageKind, Output, OutputResult};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// journald's native protocol socket, which keeps multi-line messages intact
+const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+
+/// Where syslog daemons listen on Linux, macOS and the BSDs
+const SYSLOG_SOCKETS: &[&str] = &["/dev/log", "/var/run/syslog", "/var/run/log"];
+
+const DEFAULT_IDENT: &str = "lastsignal";
+
+const FACILITIES: &[(&str, u8)] = &[
+    ("kern", 0), ("user", 1), ("mail", 2), ("daemon", 3), ("auth", 4), ("syslog", 5),
+    ("lpr", 6), ("news", 7), ("uucp", 8), ("cron", 9), ("authpriv", 10), ("ftp", 11),
+    ("local0", 16), ("local1", 17), ("local2", 18), ("local3", 19),
+    ("local4", 20), ("local5", 21), ("local6", 22), ("local7", 23),
+];
+
+const SEVERITIES: &[(&str, u8)] = &[
+    ("emerg", 0), ("alert", 1), ("crit", 2), ("err", 3),
+    ("warning", 4), ("notice", 5), ("info", 6), ("debug", 7),
+];
+
+/// Records every message in the local system log, as an audit trail
+/// alongside the outputs that actually reach people
+#[derive(Debug, Clone)]
+pub struct SyslogOutput {
+    facility: u8,
+    severity: u8,
+    ident: String,
+    /// A syslog socket to use instead of looking for journald or `/dev/log`
+    socket: Option<PathBuf>,
+}
+
+/// Where a message is written, and in which protocol
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Destination {
+    Journald(PathBuf),
+    Syslog(PathBuf),
+}
+
+impl SyslogOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["facility", "severity", "ident", "socket"];
+
+    pub fn new(config: &HashMap<String, String>, kind: MessageKind) -> Result<Self> {
+        let facility = match config.get("facility") {
+            Some(name) => lookup(FACILITIES, name).with_context(|| {
+                format!("Unknown syslog facility '{}': use one of {}", name, names(FACILITIES))
+            })?,
+            None => lookup(FACILITIES, "daemon").expect("daemon is a facility"),
+        };
+
+        // Last signals are the record that matters, so they stand out by default
+        let default_severity = match kind {
+            MessageKind::LastSignal => "alert",
+            MessageKind::Checkin => "info",
+        };
+        let severity_name = config.get("severity").map(String::as_str).unwrap_or(default_severity);
+        let severity = lookup(SEVERITIES, severity_name).with_context(|| {
+            format!("Unknown syslog severity '{}': use one of {}", severity_name, names(SEVERITIES))
+        })?;
+
+        let ident = config.get("ident").cloned().unwrap_or_else(|| DEFAULT_IDENT.to_string());
+        if ident.is_empty() || ident.contains(|c: char| c.is_whitespace() || c == ':' || c == '[') {
+            anyhow::bail!("Invalid syslog 'ident' '{}': it can't be empty or contain spaces, ':' or '['", ident);
+        }
+
+        Ok(SyslogOutput {
+            facility,
+            severity,
+            ident,
+            socket: config.get("socket").map(PathBuf::from),
+        })
+    }
+
+    fn priority(&self) -> u8 {
+        self.facility * 8 + self.severity
+    }
+
+    /// journald when it is running, otherwise the first syslog socket that exists
+    fn destination(&self) -> Option<Destination> {
+        if let Some(socket) = &self.socket {
+            return Some(Destination::Syslog(socket.clone()));
+        }
+        if std::path::Path::new(JOURNALD_SOCKET).exists() {
+            return Some(Destination::Journald(PathBuf::from(JOURNALD_SOCKET)));
+        }
+        SYSLOG_SOCKETS
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| path.exists())
+            .map(Destination::Syslog)
+    }
+
+    /// An RFC 3164 line, as `logger` would send it to a local daemon
+    fn syslog_datagram(&self, message: &str) -> Vec<u8> {
+        format!(
+            "<{}>{} {}[{}]: {}",
+            self.priority(),
+            chrono::Local::now().format("%b %e %H:%M:%S"),
+            self.ident,
+            std::process::id(),
+            message
+        )
+        .into_bytes()
+    }
+
+    /// journald's native `KEY=value` format. Values with newlines are sent
+    /// length-prefixed so the message stays one entry.
+    fn journald_datagram(&self, message: &str) -> Vec<u8> {
+        let fields = [
+            ("MESSAGE", message.to_string()),
+            ("PRIORITY", self.severity.to_string()),
+            ("SYSLOG_FACILITY", self.facility.to_string()),
+            ("SYSLOG_IDENTIFIER", self.ident.clone()),
+            ("SYSLOG_PID", std::process::id().to_string()),
+        ];
+
+        let mut datagram = Vec::new();
+        for (key, value) in fields {
+            datagram.extend_from_slice(key.as_bytes());
+            if value.contains('\n') {
+                datagram.push(b'\n');
+                datagram.extend_from_slice(&(value.len() as u64).to_le_bytes());
+            } else {
+                datagram.push(b'=');
+            }
+            datagram.extend_from_slice(value.as_bytes());
+            datagram.push(b'\n');
+        }
+        datagram
+    }
+}
+
+fn lookup(table: &[(&str, u8)], name: &str) -> Option<u8> {
+    table.iter().find(|(known, _)| known.eq_ignore_ascii_case(name)).map(|(_, code)| *code)
+}
+
+fn names(table: &[(&str, u8)]) -> String {
+    table.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(unix)]
+fn socket_for(path: &std::path::Path) -> std::io::Result<tokio::net::UnixDatagram> {
+    let socket = tokio::net::UnixDatagram::unbound()?;
+    socket.connect(path)?;
+    Ok(socket)
+}
+
+#[cfg(unix)]
+async fn send_datagram(path: &std::path::Path, datagram: &[u8]) -> std::io::Result<()> {
+    socket_for(path)?.send(datagram).await.map(|_| ())
+}
+
+#[async_trait]
+impl Output for SyslogOutput {
+    #[cfg(unix)]
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let result = match self.destination() {
+            Some(Destination::Journald(path)) => {
+                match send_datagram(&path, &self.journald_datagram(message)).await {
+                    Ok(()) => Ok(()),
+                    // Very large entries need journald's memfd protocol, so hand
+                    // those to the syslog socket, which journald also reads
+                    Err(e) => match SYSLOG_SOCKETS.iter().map(std::path::Path::new).find(|path| path.exists()) {
+                        Some(fallback) => {
+                            tracing::debug!("journald rejected the entry ({}), using {:?}", e, fallback);
+                            send_datagram(fallback, &self.syslog_datagram(message)).await
+                                .map_err(|e| format!("{:?}: {}", fallback, e))
+                        }
+                        None => Err(format!("{}: {}", JOURNALD_SOCKET, e)),
+                    },
+                }
+            }
+            Some(Destination::Syslog(path)) => send_datagram(&path, &self.syslog_datagram(message)).await
+                .map_err(|e| format!("{:?}: {}", path, e)),
+            None => return Ok(OutputResult::Failed("No journald or syslog socket found".to_string())),
+        };
+
+        match result {
+            Ok(()) => Ok(OutputResult::Success),
+            Err(error) => Ok(OutputResult::Failed(format!("Failed to write to the system log at {}", error))),
+        }
+    }
+
+    #[cfg(not(unix))]
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        Ok(OutputResult::Skipped("syslog output is only supported on Unix".to_string()))
+    }
+
+    /// Connects to the socket without sending anything, which fails if it
+    /// is missing or not writable by this user
+    #[cfg(unix)]
+    async fn health_check(&self) -> Result<bool> {
+        let path = match self.destination() {
+            Some(Destination::Journald(path) | Destination::Syslog(path)) => path,
+            None => {
+                tracing::debug!("Syslog health check: no journald or syslog socket found");
+                return Ok(false);
+            }
+        };
+        match socket_for(&path) {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                tracing::debug!("Syslog health check could not connect to {:?}: {}", path, e);
+                Ok(false)
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    async fn health_check(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn get_name(&self) -> &str {
+        "syslog"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syslog_output_config() {
+        let output = SyslogOutput::new(&HashMap::new(), MessageKind::LastSignal).unwrap();
+        assert_eq!((output.facility, output.severity), (3, 1));
+        let output = SyslogOutput::new(&HashMap::new(), MessageKind::Checkin).unwrap();
+        assert_eq!(output.priority(), 3 * 8 + 6);
+
+        let mut config = HashMap::new();
+        config.insert("facility".to_string(), "local3".to_string());
+        config.insert("severity".to_string(), "CRIT".to_string());
+        let output = SyslogOutput::new(&config, MessageKind::Checkin).unwrap();
+        assert_eq!(output.priority(), 19 * 8 + 2);
+
+        for (key, value) in [("facility", "local9"), ("severity", "panic"), ("ident", "last signal")] {
+            let mut config = HashMap::new();
+            config.insert(key.to_string(), value.to_string());
+            assert!(SyslogOutput::new(&config, MessageKind::Checkin).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_journald_datagram() {
+        let output = SyslogOutput::new(&HashMap::new(), MessageKind::LastSignal).unwrap();
+        let datagram = output.journald_datagram("Line one\nLine two");
+
+        let mut expected = b"MESSAGE\n".to_vec();
+        expected.extend_from_slice(&17u64.to_le_bytes());
+        expected.extend_from_slice(b"Line one\nLine two\nPRIORITY=1\nSYSLOG_FACILITY=3\nSYSLOG_IDENTIFIER=lastsignal\n");
+        assert!(datagram.starts_with(&expected));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_syslog_output_writes_to_socket() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("log");
+        let server = tokio::net::UnixDatagram::bind(&path).unwrap();
+
+        let mut config = HashMap::new();
+        config.insert("socket".to_string(), path.to_str().unwrap().to_string());
+        config.insert("ident".to_string(), "lastsignal-test".to_string());
+        let output = SyslogOutput::new(&config, MessageKind::LastSignal).unwrap();
+
+        assert!(output.health_check().await.unwrap());
+        assert!(matches!(output.send_message("Last signal sent").await.unwrap(), OutputResult::Success));
+
+        let mut buf = vec![0; 1024];
+        let len = server.recv(&mut buf).await.unwrap();
+        let line = String::from_utf8_lossy(&buf[..len]);
+        assert!(line.starts_with("<25>"), "{}", line);
+        assert!(line.ends_with(&format!(" lastsignal-test[{}]: Last signal sent", std::process::id())), "{}", line);
+
+        drop(server);
+        std::fs::remove_file(&path).unwrap();
+        assert!(!output.health_check().await.unwrap());
+    }
+}
@@ -0,0 +1,350 @@
+use super::{Output, OutputResult};
+use crate::duration_parser::ConfigDuration;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const API_BASE_URL: &str = "https://api.twilio.com/2010-04-01";
+
+/// Roughly how fast Twilio's `<Say>` voices speak, used to fit the message
+/// into `max_duration`
+const WORDS_PER_MINUTE: u64 = 150;
+
+const DEFAULT_MAX_DURATION_SECS: u64 = 120;
+
+/// How long the call status is polled for after the call is placed
+const STATUS_WINDOW: Duration = Duration::from_secs(90);
+
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct VoiceTwilioOutput {
+    account_sid: String,
+    auth_token: String,
+    from_number: String,
+    to_number: String,
+    max_duration: Duration,
+    client: Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct CallResource {
+    sid: String,
+    status: String,
+}
+
+/// Where a call is in Twilio's lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CallProgress {
+    /// Answered, so the message is being or has been read out
+    Answered,
+    /// Queued, initiated or ringing
+    Pending,
+    /// Busy, no answer, failed or canceled
+    NotAnswered,
+}
+
+fn call_progress(status: &str) -> CallProgress {
+    match status {
+        "in-progress" | "completed" => CallProgress::Answered,
+        "queued" | "initiated" | "ringing" => CallProgress::Pending,
+        _ => CallProgress::NotAnswered,
+    }
+}
+
+impl VoiceTwilioOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["account_sid", "auth_token", "from_number", "to_number", "max_duration"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let account_sid = config
+            .get("account_sid")
+            .context("Missing 'account_sid' field in voice_twilio config")?
+            .clone();
+        validate_account_sid(&account_sid)?;
+
+        let auth_token = config
+            .get("auth_token")
+            .context("Missing 'auth_token' field in voice_twilio config")?
+            .clone();
+        if auth_token.trim().is_empty() {
+            anyhow::bail!("voice_twilio 'auth_token' is empty");
+        }
+
+        let from_number = config
+            .get("from_number")
+            .context("Missing 'from_number' field in voice_twilio config")?
+            .clone();
+        validate_phone_number(&from_number).context("Invalid 'from_number' in voice_twilio config")?;
+
+        let to_number = config
+            .get("to_number")
+            .context("Missing 'to_number' field in voice_twilio config")?
+            .clone();
+        validate_phone_number(&to_number).context("Invalid 'to_number' in voice_twilio config")?;
+
+        let max_duration = match config.get("max_duration") {
+            Some(duration) => duration.parse::<ConfigDuration>()
+                .context("Invalid 'max_duration' in voice_twilio config")?
+                .as_duration(),
+            None => Duration::from_secs(DEFAULT_MAX_DURATION_SECS),
+        };
+
+        Ok(VoiceTwilioOutput {
+            account_sid,
+            auth_token,
+            from_number,
+            to_number,
+            max_duration,
+            client: Client::new(),
+        })
+    }
+
+    fn get_account_url(&self) -> String {
+        format!("{}/Accounts/{}.json", API_BASE_URL, self.account_sid)
+    }
+
+    fn get_calls_url(&self) -> String {
+        format!("{}/Accounts/{}/Calls.json", API_BASE_URL, self.account_sid)
+    }
+
+    fn get_call_url(&self, call_sid: &str) -> String {
+        format!("{}/Accounts/{}/Calls/{}.json", API_BASE_URL, self.account_sid, call_sid)
+    }
+
+    fn twiml(&self, message: &str) -> String {
+        let max_words = (self.max_duration.as_secs() * WORDS_PER_MINUTE / 60).max(1) as usize;
+        format!("<Response><Say>{}</Say></Response>", escape_xml(&truncate_words(message, max_words)))
+    }
+
+    async fn place_call(&self, message: &str) -> Result<std::result::Result<CallResource, String>> {
+        // Leave a little time after the message for the call to connect
+        let time_limit = (self.max_duration.as_secs() + 30).to_string();
+        let twiml = self.twiml(message);
+        let form = [
+            ("To", self.to_number.as_str()),
+            ("From", self.from_number.as_str()),
+            ("Twiml", twiml.as_str()),
+            ("TimeLimit", time_limit.as_str()),
+        ];
+
+        let response = match self
+            .client
+            .post(self.get_calls_url())
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .form(&form)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Err(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        if !status_code.is_success() {
+            return Ok(Err(match response.text().await {
+                Ok(text) => format!("Twilio HTTP {}: {}", status_code, text),
+                Err(e) => format!("Twilio HTTP {} (failed to read response: {})", status_code, e),
+            }));
+        }
+
+        let call = response.json().await.context("Failed to parse Twilio call response")?;
+        Ok(Ok(call))
+    }
+
+    async fn fetch_call_status(&self, call_sid: &str) -> Result<String> {
+        let response = self
+            .client
+            .get(self.get_call_url(call_sid))
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Twilio call status request failed: {}", redact::redact(&e.to_string())))?;
+
+        let status_code = response.status();
+        if !status_code.is_success() {
+            anyhow::bail!("Twilio call status HTTP {}", status_code);
+        }
+
+        let call: CallResource = response.json().await.context("Failed to parse Twilio call status")?;
+        Ok(call.status)
+    }
+}
+
+/// Twilio account SIDs are `AC` followed by 32 hex characters
+pub fn validate_account_sid(account_sid: &str) -> Result<()> {
+    let valid = account_sid.len() == 34
+        && account_sid.starts_with("AC")
+        && account_sid[2..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        anyhow::bail!("Twilio 'account_sid' should be AC followed by 32 hex characters");
+    }
+    Ok(())
+}
+
+/// Phone numbers must be in E.164 form, e.g. `+447700900123`
+pub fn validate_phone_number(number: &str) -> Result<()> {
+    let digits = number.strip_prefix('+').unwrap_or_default();
+    if !(8..=15).contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) || digits.starts_with('0') {
+        anyhow::bail!("'{}' is not an E.164 phone number such as +447700900123", number);
+    }
+    Ok(())
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML at all
+            c if c.is_control() && !matches!(c, '\n' | '\t') => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Keeps the first `max_words` words, so the call ends within the time limit
+fn truncate_words(message: &str, max_words: usize) -> String {
+    let words: Vec<&str> = message.split_whitespace().collect();
+    if words.len() <= max_words {
+        return words.join(" ");
+    }
+    tracing::warn!("Voice message truncated from {} to {} words to fit max_duration", words.len(), max_words);
+    format!("{} ...", words[..max_words].join(" "))
+}
+
+#[async_trait]
+impl Output for VoiceTwilioOutput {
+    /// Places the call, then waits for it to be answered. Only an answered
+    /// call counts as delivered.
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let call = match self.place_call(message).await? {
+            Ok(call) => call,
+            Err(error) => return Ok(OutputResult::Failed(error)),
+        };
+        tracing::info!("Placed Twilio call {} to {}", call.sid, self.to_number);
+
+        let deadline = tokio::time::Instant::now() + STATUS_WINDOW;
+        let mut status = call.status;
+        loop {
+            match call_progress(&status) {
+                CallProgress::Answered => return Ok(OutputResult::Success),
+                CallProgress::NotAnswered => return Ok(OutputResult::Failed(format!("Call ended with status '{}'", status))),
+                CallProgress::Pending if tokio::time::Instant::now() >= deadline => {
+                    return Ok(OutputResult::Failed(format!(
+                        "Call still '{}' after {}s",
+                        status,
+                        STATUS_WINDOW.as_secs()
+                    )));
+                }
+                CallProgress::Pending => {}
+            }
+
+            tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+            match self.fetch_call_status(&call.sid).await {
+                Ok(latest) => status = latest,
+                Err(e) => tracing::debug!("Failed to poll Twilio call {}: {}", call.sid, e),
+            }
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        let response = match self
+            .client
+            .get(self.get_account_url())
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::debug!("Twilio health check HTTP error: {}", redact::redact(&e.to_string()));
+                return Ok(false);
+            }
+        };
+
+        if response.status().is_success() {
+            Ok(true)
+        } else {
+            tracing::debug!("Twilio health check HTTP error: {}", response.status());
+            Ok(false)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "voice_twilio"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_SID: &str = "AC0123456789abcdef0123456789abcdef";
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("account_sid".to_string(), ACCOUNT_SID.to_string());
+        config.insert("auth_token".to_string(), "secret".to_string());
+        config.insert("from_number".to_string(), "+15005550006".to_string());
+        config.insert("to_number".to_string(), "+447700900123".to_string());
+        config
+    }
+
+    #[test]
+    fn test_voice_twilio_output_creation() {
+        let output = VoiceTwilioOutput::new(&test_config()).unwrap();
+        assert_eq!(output.max_duration, Duration::from_secs(120));
+        assert_eq!(
+            output.get_call_url("CA1"),
+            format!("https://api.twilio.com/2010-04-01/Accounts/{}/Calls/CA1.json", ACCOUNT_SID)
+        );
+
+        for key in ["account_sid", "auth_token", "from_number", "to_number"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(VoiceTwilioOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+    }
+
+    #[test]
+    fn test_credentials_and_numbers_are_validated() {
+        assert!(validate_account_sid("AC123").is_err());
+        assert!(validate_account_sid("XX0123456789abcdef0123456789abcdef").is_err());
+        assert!(validate_phone_number("+447700900123").is_ok());
+        assert!(validate_phone_number("07700900123").is_err());
+        assert!(validate_phone_number("+44 7700 900123").is_err());
+    }
+
+    #[test]
+    fn test_twiml_is_escaped_and_truncated() {
+        let mut config = test_config();
+        config.insert("max_duration".to_string(), "2s".to_string());
+        let output = VoiceTwilioOutput::new(&config).unwrap();
+
+        // 2s at 150 words per minute is 5 words
+        let twiml = output.twiml("Tom & Jerry <said> \"it's\" fine, really, truly");
+        assert_eq!(
+            twiml,
+            "<Response><Say>Tom &amp; Jerry &lt;said&gt; &quot;it&apos;s&quot; ...</Say></Response>"
+        );
+    }
+
+    #[test]
+    fn test_call_progress() {
+        assert_eq!(call_progress("completed"), CallProgress::Answered);
+        assert_eq!(call_progress("in-progress"), CallProgress::Answered);
+        assert_eq!(call_progress("ringing"), CallProgress::Pending);
+        assert_eq!(call_progress("no-answer"), CallProgress::NotAnswered);
+        assert_eq!(call_progress("busy"), CallProgress::NotAnswered);
+    }
+}
@@ -0,0 +1,271 @@
+use super::{Output, OutputResult};
+use crate::duration_parser::ConfigDuration;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::{Client, Method, Url};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Body sent when no `body` template is configured
+const DEFAULT_BODY: &str = r#"{"message": "{message}", "timestamp": "{timestamp}"}"#;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Config keys starting with this become request headers, e.g. `header_Authorization`
+pub const HEADER_PREFIX: &str = "header_";
+
+#[derive(Debug, Clone)]
+pub struct WebhookOutput {
+    url: Url,
+    method: Method,
+    /// JSON body with `{message}` and `{timestamp}` placeholders in its strings
+    body: Value,
+    headers: Vec<(String, String)>,
+    success_status: RangeInclusive<u16>,
+    health_url: Option<Url>,
+    client: Client,
+}
+
+impl WebhookOutput {
+    /// Config keys this output reads, besides those starting with `header_`
+    pub const CONFIG_KEYS: &'static [&'static str] = &["url", "method", "body", "success_status", "timeout", "health_url"];
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let url = config
+            .get("url")
+            .context("Missing 'url' field in webhook config")?;
+        let url = parse_url(url).context("Invalid 'url' in webhook config")?;
+
+        let method = match config.get("method") {
+            Some(method) => Method::from_bytes(method.trim().to_ascii_uppercase().as_bytes())
+                .with_context(|| format!("Invalid 'method' '{}' in webhook config", method))?,
+            None => Method::POST,
+        };
+
+        let body = serde_json::from_str(config.get("body").map(String::as_str).unwrap_or(DEFAULT_BODY))
+            .context("Webhook 'body' must be valid JSON")?;
+
+        let mut headers: Vec<(String, String)> = config.iter()
+            .filter_map(|(key, value)| key.strip_prefix(HEADER_PREFIX).map(|name| (name.to_string(), value.clone())))
+            .collect();
+        headers.sort();
+        for (name, value) in &headers {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("Invalid webhook header name '{}'", name))?;
+            reqwest::header::HeaderValue::from_str(value)
+                .with_context(|| format!("Invalid value for webhook header '{}'", name))?;
+        }
+
+        let success_status = match config.get("success_status") {
+            Some(range) => parse_status_range(range)?,
+            None => 200..=299,
+        };
+
+        let timeout = match config.get("timeout") {
+            Some(timeout) => timeout.parse::<ConfigDuration>()
+                .context("Invalid 'timeout' in webhook config")?
+                .as_duration(),
+            None => std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+        };
+
+        let health_url = config.get("health_url")
+            .map(|url| parse_url(url).context("Invalid 'health_url' in webhook config"))
+            .transpose()?;
+
+        Ok(WebhookOutput {
+            url,
+            method,
+            body,
+            headers,
+            success_status,
+            health_url,
+            client: Client::builder().timeout(timeout).build().context("Failed to build webhook HTTP client")?,
+        })
+    }
+
+    fn render_body(&self, message: &str) -> Value {
+        let timestamp = Utc::now().to_rfc3339();
+        substitute(&self.body, &[("{message}", message), ("{timestamp}", &timestamp)])
+    }
+}
+
+/// An absolute http(s) URL
+pub fn parse_url(url: &str) -> Result<Url> {
+    let url = Url::parse(url).with_context(|| format!("'{}' is not an absolute URL", redact::redact(url)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("Webhook URLs must use http or https");
+    }
+    if url.host_str().is_none() {
+        anyhow::bail!("Webhook URL has no host");
+    }
+    Ok(url)
+}
+
+/// `"200-299"` or a single status such as `"204"`
+fn parse_status_range(range: &str) -> Result<RangeInclusive<u16>> {
+    let parse = |status: &str| -> Result<u16> {
+        let status: u16 = status.trim().parse()
+            .with_context(|| format!("Invalid status code '{}' in webhook 'success_status'", status))?;
+        if !(100..=599).contains(&status) {
+            anyhow::bail!("Webhook 'success_status' codes must be between 100 and 599");
+        }
+        Ok(status)
+    };
+
+    let (low, high) = match range.split_once('-') {
+        Some((low, high)) => (parse(low)?, parse(high)?),
+        None => {
+            let status = parse(range)?;
+            (status, status)
+        }
+    };
+    if low > high {
+        anyhow::bail!("Webhook 'success_status' range '{}' is backwards", range);
+    }
+    Ok(low..=high)
+}
+
+/// Replaces placeholders in every string of the template. Substituting after
+/// parsing keeps the body valid JSON whatever the message contains.
+fn substitute(template: &Value, replacements: &[(&str, &str)]) -> Value {
+    match template {
+        Value::String(text) => Value::String(
+            replacements.iter().fold(text.clone(), |text, (placeholder, value)| text.replace(placeholder, value)),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(|item| substitute(item, replacements)).collect()),
+        Value::Object(fields) => Value::Object(
+            fields.iter().map(|(key, value)| (key.clone(), substitute(value, replacements))).collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[async_trait]
+impl Output for WebhookOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let mut request = self.client
+            .request(self.method.clone(), self.url.clone())
+            .json(&self.render_body(message));
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        let response = match request.send().await {
+            Ok(resp) => resp,
+            Err(e) => {
+                // The URL may carry a secret, so leave it out
+                return Ok(OutputResult::Failed(format!("HTTP request failed: {}", e.without_url())));
+            }
+        };
+
+        let status_code = response.status();
+        if self.success_status.contains(&status_code.as_u16()) {
+            return Ok(OutputResult::Success);
+        }
+
+        match response.text().await {
+            Ok(text) => Ok(OutputResult::Failed(format!("Webhook HTTP {}: {}", status_code, text))),
+            Err(e) => Ok(OutputResult::Failed(format!("Webhook HTTP {} (failed to read response: {})", status_code, e))),
+        }
+    }
+
+    /// GETs `health_url` when set, otherwise sends a HEAD request to the webhook URL.
+    /// Any response other than a server error counts, since many webhooks reject HEAD.
+    async fn health_check(&self) -> Result<bool> {
+        let mut request = match &self.health_url {
+            Some(health_url) => self.client.get(health_url.clone()),
+            None => self.client.head(self.url.clone()),
+        };
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        match request.send().await {
+            Ok(resp) if self.health_url.is_some() => {
+                if !resp.status().is_success() {
+                    tracing::debug!("Webhook health check: health_url returned {}", resp.status());
+                }
+                Ok(resp.status().is_success())
+            }
+            Ok(resp) => {
+                if resp.status().is_server_error() {
+                    tracing::debug!("Webhook health check: HEAD returned {}", resp.status());
+                }
+                Ok(!resp.status().is_server_error())
+            }
+            Err(e) => {
+                tracing::debug!("Webhook health check HTTP error: {}", e.without_url());
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "webhook"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("url".to_string(), "https://hooks.example.com/lastsignal".to_string());
+        config
+    }
+
+    #[test]
+    fn test_webhook_output_defaults() {
+        let output = WebhookOutput::new(&test_config()).unwrap();
+        assert_eq!(output.method, Method::POST);
+        assert_eq!(output.success_status, 200..=299);
+        assert!(output.headers.is_empty());
+
+        let body = output.render_body("Please check in");
+        assert_eq!(body["message"], "Please check in");
+        assert!(body["timestamp"].as_str().unwrap().parse::<chrono::DateTime<Utc>>().is_ok());
+    }
+
+    #[test]
+    fn test_body_template_keeps_json_valid() {
+        let mut config = test_config();
+        config.insert("body".to_string(), r#"{"content": {"text": "Alert: {message}"}, "tags": ["{message}"], "level": 5}"#.to_string());
+        config.insert("header_Authorization".to_string(), "Bearer abc".to_string());
+        config.insert("method".to_string(), "put".to_string());
+        let output = WebhookOutput::new(&config).unwrap();
+        assert_eq!(output.method, Method::PUT);
+        assert_eq!(output.headers, vec![("Authorization".to_string(), "Bearer abc".to_string())]);
+
+        let message = "He said \"help\"\nthen {left}";
+        assert_eq!(output.render_body(message), json!({
+            "content": { "text": format!("Alert: {}", message) },
+            "tags": [message],
+            "level": 5,
+        }));
+    }
+
+    #[test]
+    fn test_webhook_output_rejects_invalid_config() {
+        assert!(WebhookOutput::new(&HashMap::new()).is_err());
+        assert!(parse_url("/relative/path").is_err());
+        assert!(parse_url("ftp://example.com/hook").is_err());
+
+        for (key, value) in [("body", "{not json"), ("success_status", "299-200"), ("timeout", "soon"), ("header_Bad Name", "x")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(WebhookOutput::new(&config).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_parse_status_range() {
+        assert_eq!(parse_status_range("200-204").unwrap(), 200..=204);
+        assert_eq!(parse_status_range("202").unwrap(), 202..=202);
+        assert!(parse_status_range("99").is_err());
+    }
+}
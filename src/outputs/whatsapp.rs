@@ -0,0 +1,515 @@
+use super::voice_twilio::{validate_account_sid, validate_phone_number};
+use super::{Output, OutputResult};
+use crate::formatting;
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const TWILIO_API_BASE_URL: &str = "https://api.twilio.com/2010-04-01";
+
+const CLOUD_API_BASE_URL: &str = "https://graph.facebook.com/v18.0";
+
+/// Twilio rejects WhatsApp bodies longer than this
+const TWILIO_MAX_MESSAGE_CHARS: usize = 1600;
+
+/// The Cloud API rejects text bodies longer than this
+const CLOUD_API_MAX_MESSAGE_CHARS: usize = 4096;
+
+/// Meta's limit on a template body parameter
+const MAX_TEMPLATE_PARAMETER_CHARS: usize = 1024;
+
+/// Twilio's error code for a free-form message outside the 24-hour session window
+const TWILIO_OUTSIDE_WINDOW_ERROR: i64 = 63016;
+
+/// Cloud API error code for a free-form message outside the 24-hour session window
+const CLOUD_API_OUTSIDE_WINDOW_ERROR: i64 = 131047;
+
+/// How long a Twilio message's status is polled for after sending
+const STATUS_WINDOW: Duration = Duration::from_secs(30);
+
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+const DEFAULT_TEMPLATE_LANGUAGE: &str = "en_US";
+
+#[derive(Debug, Clone)]
+enum WhatsAppProvider {
+    Twilio {
+        account_sid: String,
+        auth_token: String,
+        from_number: String,
+    },
+    CloudApi {
+        access_token: String,
+        phone_number_id: String,
+        template_language: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct WhatsAppOutput {
+    provider: WhatsAppProvider,
+    to_number: String,
+    /// A Twilio Content SID (`HX...`) or a Cloud API template name, with one
+    /// body variable that receives the message
+    template_name: Option<String>,
+    client: Client,
+}
+
+/// How a free-form send went
+#[derive(Debug, PartialEq, Eq)]
+enum Delivery {
+    Sent,
+    /// The recipient hasn't messaged in 24 hours, so only a template can reach them
+    OutsideWindow(String),
+    Failed(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct TwilioMessage {
+    sid: String,
+    status: String,
+    #[serde(default)]
+    error_code: Option<i64>,
+    #[serde(default)]
+    error_message: Option<String>,
+}
+
+impl WhatsAppOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &[
+        "provider", "to_number", "template_name",
+        "account_sid", "auth_token", "from_number",
+        "access_token", "phone_number_id", "template_language",
+    ];
+
+    /// Keys each provider requires, besides `provider` and `to_number`
+    pub fn required_keys(provider: &str) -> Option<&'static [&'static str]> {
+        match provider {
+            "twilio" => Some(&["account_sid", "auth_token", "from_number"]),
+            "cloud_api" => Some(&["access_token", "phone_number_id"]),
+            _ => None,
+        }
+    }
+
+    pub fn new(config: &HashMap<String, String>) -> Result<Self> {
+        let provider_name = config
+            .get("provider")
+            .context("Missing 'provider' field in whatsapp config")?;
+        let required = Self::required_keys(provider_name)
+            .with_context(|| format!("Unknown whatsapp provider '{}': use twilio or cloud_api", provider_name))?;
+        let get = |key: &str| -> Result<String> {
+            config.get(key)
+                .cloned()
+                .with_context(|| format!("Missing '{}' field in whatsapp config for provider {}", key, provider_name))
+        };
+        for key in required {
+            get(key)?;
+        }
+
+        let to_number = get("to_number")?;
+        validate_phone_number(&to_number).context("Invalid 'to_number' in whatsapp config")?;
+
+        let template_name = config.get("template_name").cloned();
+
+        let provider = match provider_name.as_str() {
+            "twilio" => {
+                let account_sid = get("account_sid")?;
+                validate_account_sid(&account_sid)?;
+                let from_number = get("from_number")?;
+                validate_phone_number(&from_number).context("Invalid 'from_number' in whatsapp config")?;
+                if template_name.as_ref().is_some_and(|sid| !sid.starts_with("HX")) {
+                    anyhow::bail!("With provider twilio, whatsapp 'template_name' must be a Content SID starting with HX");
+                }
+                WhatsAppProvider::Twilio { account_sid, auth_token: get("auth_token")?, from_number }
+            }
+            _ => WhatsAppProvider::CloudApi {
+                access_token: get("access_token")?,
+                phone_number_id: get("phone_number_id")?,
+                template_language: config.get("template_language").cloned().unwrap_or_else(|| DEFAULT_TEMPLATE_LANGUAGE.to_string()),
+            },
+        };
+
+        Ok(WhatsAppOutput {
+            provider,
+            to_number,
+            template_name,
+            client: Client::new(),
+        })
+    }
+
+    fn max_message_chars(&self) -> usize {
+        match self.provider {
+            WhatsAppProvider::Twilio { .. } => TWILIO_MAX_MESSAGE_CHARS,
+            WhatsAppProvider::CloudApi { .. } => CLOUD_API_MAX_MESSAGE_CHARS,
+        }
+    }
+
+    fn cloud_api_text_payload(&self, message: &str) -> Value {
+        json!({
+            "messaging_product": "whatsapp",
+            "to": self.to_number.trim_start_matches('+'),
+            "type": "text",
+            "text": { "body": message },
+        })
+    }
+
+    fn cloud_api_template_payload(&self, template_name: &str, language: &str, message: &str) -> Value {
+        json!({
+            "messaging_product": "whatsapp",
+            "to": self.to_number.trim_start_matches('+'),
+            "type": "template",
+            "template": {
+                "name": template_name,
+                "language": { "code": language },
+                "components": [{
+                    "type": "body",
+                    "parameters": [{ "type": "text", "text": template_parameter(message) }],
+                }],
+            },
+        })
+    }
+
+    async fn send_text(&self, message: &str) -> Result<Delivery> {
+        match &self.provider {
+            WhatsAppProvider::Twilio { .. } => {
+                self.send_twilio(&[("Body", message.to_string())]).await
+            }
+            WhatsAppProvider::CloudApi { access_token, phone_number_id, .. } => {
+                self.send_cloud_api(access_token, phone_number_id, &self.cloud_api_text_payload(message)).await
+            }
+        }
+    }
+
+    async fn send_template(&self, template_name: &str, message: &str) -> Result<Delivery> {
+        match &self.provider {
+            WhatsAppProvider::Twilio { .. } => {
+                let variables = json!({ "1": template_parameter(message) }).to_string();
+                self.send_twilio(&[("ContentSid", template_name.to_string()), ("ContentVariables", variables)]).await
+            }
+            WhatsAppProvider::CloudApi { access_token, phone_number_id, template_language } => {
+                let payload = self.cloud_api_template_payload(template_name, template_language, message);
+                self.send_cloud_api(access_token, phone_number_id, &payload).await
+            }
+        }
+    }
+
+    /// Sends through Twilio, then follows the message's status, since Twilio
+    /// accepts every message and only reports a closed session window afterwards
+    async fn send_twilio(&self, content: &[(&str, String)]) -> Result<Delivery> {
+        let WhatsAppProvider::Twilio { account_sid, auth_token, from_number } = &self.provider else {
+            unreachable!("send_twilio is only called for the twilio provider");
+        };
+
+        let mut form = vec![
+            ("To", format!("whatsapp:{}", self.to_number)),
+            ("From", format!("whatsapp:{}", from_number)),
+        ];
+        form.extend(content.iter().map(|(key, value)| (*key, value.clone())));
+
+        let response = match self
+            .client
+            .post(format!("{}/Accounts/{}/Messages.json", TWILIO_API_BASE_URL, account_sid))
+            .basic_auth(account_sid, Some(auth_token))
+            .form(&form)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Delivery::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        let text = response.text().await.context("Failed to read Twilio response")?;
+        if !status_code.is_success() {
+            return Ok(Delivery::Failed(format!("Twilio HTTP {}: {}", status_code, text)));
+        }
+        let mut message: TwilioMessage = serde_json::from_str(&text).context("Failed to parse Twilio message response")?;
+
+        let deadline = tokio::time::Instant::now() + STATUS_WINDOW;
+        loop {
+            match twilio_delivery(&message) {
+                Some(delivery) => return Ok(delivery),
+                None if tokio::time::Instant::now() >= deadline => {
+                    // Still queued with Twilio, which has accepted it
+                    tracing::warn!("WhatsApp message {} still '{}' after {}s", message.sid, message.status, STATUS_WINDOW.as_secs());
+                    return Ok(Delivery::Sent);
+                }
+                None => {}
+            }
+
+            tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+            match self.fetch_twilio_message(account_sid, auth_token, &message.sid).await {
+                Ok(latest) => message = latest,
+                Err(e) => tracing::debug!("Failed to poll WhatsApp message {}: {}", message.sid, e),
+            }
+        }
+    }
+
+    async fn fetch_twilio_message(&self, account_sid: &str, auth_token: &str, message_sid: &str) -> Result<TwilioMessage> {
+        let response = self
+            .client
+            .get(format!("{}/Accounts/{}/Messages/{}.json", TWILIO_API_BASE_URL, account_sid, message_sid))
+            .basic_auth(account_sid, Some(auth_token))
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Twilio message status request failed: {}", redact::redact(&e.to_string())))?;
+
+        let status_code = response.status();
+        if !status_code.is_success() {
+            anyhow::bail!("Twilio message status HTTP {}", status_code);
+        }
+        response.json().await.context("Failed to parse Twilio message status")
+    }
+
+    async fn send_cloud_api(&self, access_token: &str, phone_number_id: &str, payload: &Value) -> Result<Delivery> {
+        let response = match self
+            .client
+            .post(format!("{}/{}/messages", CLOUD_API_BASE_URL, phone_number_id))
+            .bearer_auth(access_token)
+            .json(payload)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Delivery::Failed(format!("HTTP request failed: {}", redact::redact(&e.to_string())))),
+        };
+
+        let status_code = response.status();
+        let text = response.text().await.context("Failed to read WhatsApp Cloud API response")?;
+        if status_code.is_success() {
+            return Ok(Delivery::Sent);
+        }
+
+        let error = format!("WhatsApp Cloud API HTTP {}: {}", status_code, text);
+        if cloud_api_error_code(&text) == Some(CLOUD_API_OUTSIDE_WINDOW_ERROR) {
+            Ok(Delivery::OutsideWindow(error))
+        } else {
+            Ok(Delivery::Failed(error))
+        }
+    }
+
+    /// Free-form text, split to the provider's limit
+    async fn send_text_parts(&self, message: &str) -> Result<Delivery> {
+        let parts = formatting::split_message(message, self.max_message_chars());
+        let total = parts.len();
+
+        for (i, part) in parts.iter().enumerate() {
+            match self.send_text(part).await? {
+                Delivery::Sent => {}
+                Delivery::Failed(error) if total > 1 => return Ok(Delivery::Failed(format!("Part {}/{}: {}", i + 1, total, error))),
+                // Nothing reached the recipient yet, so the template can still carry the whole message
+                Delivery::OutsideWindow(error) if i == 0 => return Ok(Delivery::OutsideWindow(error)),
+                Delivery::OutsideWindow(error) if total > 1 => return Ok(Delivery::Failed(format!("Part {}/{}: {}", i + 1, total, error))),
+                other => return Ok(other),
+            }
+        }
+
+        Ok(Delivery::Sent)
+    }
+}
+
+/// `None` while Twilio is still working on the message
+fn twilio_delivery(message: &TwilioMessage) -> Option<Delivery> {
+    match message.status.as_str() {
+        "sent" | "delivered" | "read" => Some(Delivery::Sent),
+        "failed" | "undelivered" => {
+            let error = format!(
+                "Twilio message {} {}: error {} {}",
+                message.sid,
+                message.status,
+                message.error_code.map(|code| code.to_string()).unwrap_or_else(|| "unknown".to_string()),
+                message.error_message.as_deref().unwrap_or_default(),
+            );
+            let error = error.trim_end().to_string();
+            if message.error_code == Some(TWILIO_OUTSIDE_WINDOW_ERROR) {
+                Some(Delivery::OutsideWindow(error))
+            } else {
+                Some(Delivery::Failed(error))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn cloud_api_error_code(body: &str) -> Option<i64> {
+    serde_json::from_str::<Value>(body).ok()?.get("error")?.get("code")?.as_i64()
+}
+
+/// Template parameters can't contain newlines, tabs or runs of spaces, and
+/// are limited to 1024 characters
+fn template_parameter(message: &str) -> String {
+    let flattened = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flattened.chars().count() <= MAX_TEMPLATE_PARAMETER_CHARS {
+        return flattened;
+    }
+    let truncated: String = flattened.chars().take(MAX_TEMPLATE_PARAMETER_CHARS - 3).collect();
+    format!("{}...", truncated)
+}
+
+#[async_trait]
+impl Output for WhatsAppOutput {
+    /// Sends free-form text. Outside the 24-hour session window, falls back
+    /// to `template_name`. With the Cloud API and a template, the template is
+    /// always used, as Meta reports a closed window only after accepting the message.
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let delivery = match (&self.provider, &self.template_name) {
+            (WhatsAppProvider::CloudApi { .. }, Some(template_name)) => {
+                return match self.send_template(template_name, message).await? {
+                    Delivery::Sent => Ok(OutputResult::Success),
+                    Delivery::OutsideWindow(error) | Delivery::Failed(error) => {
+                        Ok(OutputResult::Failed(format!("WhatsApp template '{}' failed: {}", template_name, error)))
+                    }
+                };
+            }
+            _ => self.send_text_parts(message).await?,
+        };
+
+        match delivery {
+            Delivery::Sent => Ok(OutputResult::Success),
+            Delivery::Failed(error) => Ok(OutputResult::Failed(error)),
+            Delivery::OutsideWindow(error) => {
+                let Some(template_name) = &self.template_name else {
+                    return Ok(OutputResult::Failed(format!(
+                        "Outside the 24-hour WhatsApp session window and no 'template_name' is configured: {}",
+                        error
+                    )));
+                };
+                tracing::info!("Outside the WhatsApp session window for {}, sending template {}", self.to_number, template_name);
+                match self.send_template(template_name, message).await? {
+                    Delivery::Sent => Ok(OutputResult::Success),
+                    Delivery::OutsideWindow(template_error) | Delivery::Failed(template_error) => Ok(OutputResult::Failed(format!(
+                        "Outside the 24-hour WhatsApp session window ({}), and the fallback template '{}' also failed: {}",
+                        error, template_name, template_error
+                    ))),
+                }
+            }
+        }
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        let request = match &self.provider {
+            WhatsAppProvider::Twilio { account_sid, auth_token, .. } => self
+                .client
+                .get(format!("{}/Accounts/{}.json", TWILIO_API_BASE_URL, account_sid))
+                .basic_auth(account_sid, Some(auth_token)),
+            WhatsAppProvider::CloudApi { access_token, phone_number_id, .. } => self
+                .client
+                .get(format!("{}/{}", CLOUD_API_BASE_URL, phone_number_id))
+                .query(&[("fields", "display_phone_number")])
+                .bearer_auth(access_token),
+        };
+
+        match request.send().await {
+            Ok(resp) if resp.status().is_success() => Ok(true),
+            Ok(resp) => {
+                tracing::debug!("WhatsApp health check HTTP error: {}", resp.status());
+                Ok(false)
+            }
+            Err(e) => {
+                tracing::debug!("WhatsApp health check HTTP error: {}", redact::redact(&e.to_string()));
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "whatsapp"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn twilio_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("provider".to_string(), "twilio".to_string());
+        config.insert("account_sid".to_string(), "AC0123456789abcdef0123456789abcdef".to_string());
+        config.insert("auth_token".to_string(), "secret".to_string());
+        config.insert("from_number".to_string(), "+14155238886".to_string());
+        config.insert("to_number".to_string(), "+447700900123".to_string());
+        config
+    }
+
+    fn cloud_api_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("provider".to_string(), "cloud_api".to_string());
+        config.insert("access_token".to_string(), "EAAG...".to_string());
+        config.insert("phone_number_id".to_string(), "106540352242922".to_string());
+        config.insert("to_number".to_string(), "+447700900123".to_string());
+        config
+    }
+
+    #[test]
+    fn test_whatsapp_output_requires_provider_keys() {
+        assert!(WhatsAppOutput::new(&twilio_config()).is_ok());
+        assert!(WhatsAppOutput::new(&cloud_api_config()).is_ok());
+
+        for key in ["provider", "account_sid", "auth_token", "from_number", "to_number"] {
+            let mut config = twilio_config();
+            config.remove(key);
+            assert!(WhatsAppOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+        for key in ["access_token", "phone_number_id"] {
+            let mut config = cloud_api_config();
+            config.remove(key);
+            assert!(WhatsAppOutput::new(&config).is_err(), "missing {} should be an error", key);
+        }
+
+        let mut config = twilio_config();
+        config.insert("provider".to_string(), "signal".to_string());
+        assert!(WhatsAppOutput::new(&config).is_err());
+
+        let mut config = twilio_config();
+        config.insert("template_name".to_string(), "lastsignal_reminder".to_string());
+        assert!(WhatsAppOutput::new(&config).is_err());
+    }
+
+    #[test]
+    fn test_cloud_api_payloads() {
+        let output = WhatsAppOutput::new(&cloud_api_config()).unwrap();
+        assert_eq!(
+            output.cloud_api_text_payload("Please check in"),
+            json!({ "messaging_product": "whatsapp", "to": "447700900123", "type": "text", "text": { "body": "Please check in" } })
+        );
+
+        let payload = output.cloud_api_template_payload("lastsignal_reminder", "en_GB", "Please\n\ncheck   in");
+        assert_eq!(payload["template"]["name"], "lastsignal_reminder");
+        assert_eq!(payload["template"]["language"]["code"], "en_GB");
+        assert_eq!(payload["template"]["components"][0]["parameters"][0]["text"], "Please check in");
+    }
+
+    #[test]
+    fn test_template_parameter_is_capped() {
+        let parameter = template_parameter(&"word ".repeat(500));
+        assert_eq!(parameter.chars().count(), MAX_TEMPLATE_PARAMETER_CHARS);
+        assert!(parameter.ends_with("..."));
+    }
+
+    #[test]
+    fn test_twilio_status_detects_closed_window() {
+        let message = |status: &str, error_code: Option<i64>| TwilioMessage {
+            sid: "SM1".to_string(),
+            status: status.to_string(),
+            error_code,
+            error_message: None,
+        };
+
+        assert_eq!(twilio_delivery(&message("queued", None)), None);
+        assert_eq!(twilio_delivery(&message("delivered", None)), Some(Delivery::Sent));
+        assert!(matches!(twilio_delivery(&message("failed", Some(63016))), Some(Delivery::OutsideWindow(_))));
+        assert!(matches!(twilio_delivery(&message("undelivered", Some(63024))), Some(Delivery::Failed(error)) if error.contains("63024")));
+    }
+
+    #[test]
+    fn test_cloud_api_error_code() {
+        let body = r#"{"error":{"message":"(#131047) Re-engagement message","type":"OAuthException","code":131047}}"#;
+        assert_eq!(cloud_api_error_code(body), Some(CLOUD_API_OUTSIDE_WINDOW_ERROR));
+        assert_eq!(cloud_api_error_code("Bad Gateway"), None);
+    }
+}
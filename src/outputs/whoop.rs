@@ -1,24 +1,49 @@
-use super::{Output, OutputResult};
+use super::{Output, OutputContext, OutputResult};
 use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
-use crate::oauth::WhoopOAuth;
-use crate::duration_parser::ConfigDuration;
+use crate::outputs::whoop_webhook;
+use crate::oauth::{OAuthClient, WHOOP};
+use crate::duration_parser::{ConfigDuration, DurationStyle};
+use crate::redact;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, StatusCode};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::Instant;
+
+const DEFAULT_BASE_URL: &str = "https://api.prod.whoop.com/developer/v1";
+
+/// Longest `Retry-After` worth waiting for within a check
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Rate limited retries of one request before giving up
+const MAX_RATE_LIMIT_RETRIES: usize = 3;
 
 /// WHOOP API client for checking device activity
 #[derive(Debug)]
 pub struct WhoopOutput {
     client: Client,
-    oauth_client: Arc<RwLock<WhoopOAuth>>,
-    max_time_since_last_checkin: ConfigDuration,
+    oauth_client: Arc<RwLock<OAuthClient>>,
+    /// Activity within this long counts as a check-in
+    activity_window: ConfigDuration,
+    base_url: String,
+    /// How long a fetched activity timestamp is reused, so the health check
+    /// and the check for responses in one cycle share a fetch
+    cache_ttl: Duration,
+    activity_cache: Mutex<Option<(Instant, DateTime<Utc>)>>,
+    /// Completed workouts count as activity, as they show up sooner than
+    /// cycles, sleep and recovery
+    include_workouts: bool,
+    /// Where the check-in server records webhook events, when `webhook` is on
+    webhook_directory: Option<std::path::PathBuf>,
     name: String,
-    _refresh_task_handle: tokio::task::JoinHandle<()>,
+    /// Only for long-running outputs, and aborted when the output is dropped
+    refresh_task_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -57,6 +82,25 @@ struct WhoopSleep {
     updated_at: String,
 }
 
+/// A workout, minus the sport and score fields we don't need
+#[derive(Deserialize, Debug)]
+struct WhoopWorkoutResponse {
+    records: Vec<WhoopWorkout>,
+}
+
+#[derive(Deserialize, Debug)]
+struct WhoopWorkout {
+    #[allow(dead_code)]
+    id: u32,
+    #[allow(dead_code)]
+    start: String,
+    #[allow(dead_code)]
+    end: String,
+    #[allow(dead_code)]
+    created_at: String,
+    updated_at: String,
+}
+
 #[derive(Deserialize, Debug)]
 struct WhoopRecoveryResponse {
     records: Vec<WhoopRecovery>,
@@ -74,125 +118,227 @@ struct WhoopRecovery {
 }
 
 impl WhoopOutput {
-    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["client_id", "client_secret", "redirect_uri", "activity_window", "cache_ttl", "include_workouts", "api_base_url", "token_file", "webhook"];
+
+    /// `activity_window` defaults to `max_time_since_last_checkin`, so activity
+    /// anywhere before the last signal deadline counts unless it's set, and
+    /// `cache_ttl` to `check_interval`
+    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, context: OutputContext) -> Result<Self> {
 
         let client = Client::new();
         let name = "WHOOP".to_string();
 
+        let activity_window = match config.get("activity_window") {
+            Some(window) => window.parse::<ConfigDuration>()
+                .context("Invalid 'activity_window' in WHOOP config")?,
+            None => context.max_time_since_last_checkin,
+        };
+        let cache_ttl = match config.get("cache_ttl") {
+            Some(ttl) => ttl.parse::<ConfigDuration>()
+                .context("Invalid 'cache_ttl' in WHOOP config")?,
+            None => context.check_interval,
+        };
+        let include_workouts = match config.get("include_workouts").map(String::as_str) {
+            None | Some("true") => true,
+            Some("false") => false,
+            Some(other) => anyhow::bail!("Invalid 'include_workouts' in WHOOP config: '{}', expected true or false", other),
+        };
+        let webhook_directory = whoop_webhook::webhook_enabled(config)?.then(|| data_directory.clone());
+        let base_url = parse_base_url(config.get("api_base_url").map(String::as_str).unwrap_or(DEFAULT_BASE_URL))?;
+
         // Get OAuth credentials from config, fallback to dummy values for backward compatibility
         let client_id = config.get("client_id").cloned().unwrap_or_else(|| "dummy".to_string());
         let client_secret = config.get("client_secret").cloned().unwrap_or_else(|| "dummy".to_string());
         let redirect_uri = config.get("redirect_uri").cloned().unwrap_or_else(|| "dummy".to_string());
 
         // Create OAuth client for token management
-        let oauth_client = Arc::new(RwLock::new(WhoopOAuth::new(
+        let mut oauth_client = OAuthClient::new(
+            WHOOP,
             client_id.clone(),
             client_secret.clone(),
             redirect_uri,
             data_directory.clone(),
-        )));
+        );
+        if let Some(token_file) = config.get("token_file") {
+            oauth_client = oauth_client.with_token_file(token_file);
+        }
+        let oauth_client = Arc::new(RwLock::new(oauth_client));
 
-        // Spawn background task to refresh token every 30 minutes
-        let oauth_client_clone = Arc::clone(&oauth_client);
+        // Spawn background task to refresh token every 30 minutes, unless this is a one-shot command
         let has_real_credentials = client_id != "dummy" && client_secret != "dummy";
-        let refresh_task_handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60 * 30)); // 30 minutes
-            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-            
-            loop {
-                interval.tick().await;
-                
-                // Skip refresh attempts if using dummy credentials
-                if !has_real_credentials {
-                    tracing::debug!("WHOOP: Skipping background token refresh - dummy credentials in use. Add client_id and client_secret to config for automatic refresh.");
-                    continue;
-                }
-                
-                // Attempt to refresh the token
-                let oauth_client = oauth_client_clone.read().await;
-                match oauth_client.load_tokens() {
-                    Ok(tokens) => {
-                        // Check if token needs refreshing (expires within next 35 minutes)
-                        let now = Utc::now();
-                        let buffer = chrono::Duration::minutes(35);
-                        
-                        if tokens.expires_at <= now + buffer {
-                            tracing::info!("WHOOP: Proactively refreshing access token in background");
-                            
-                            match oauth_client.refresh_token(&tokens.refresh_token).await {
-                                Ok(new_tokens) => {
-                                    if let Err(e) = oauth_client.save_tokens(&new_tokens) {
-                                        tracing::error!("WHOOP: Failed to save refreshed tokens: {}", e);
-                                    } else {
-                                        tracing::info!("WHOOP: Successfully refreshed access token in background");
-                                    }
-                                }
-                                Err(e) => {
-                                    tracing::error!("WHOOP: Failed to refresh token in background: {}", e);
-                                }
-                            }
-                        } else {
-                            tracing::debug!("WHOOP: Token still valid, no refresh needed");
-                        }
-                    }
-                    Err(e) => {
-                        tracing::warn!("WHOOP: Could not load tokens for background refresh: {}", e);
-                    }
-                }
-            }
-        });
+        let refresh_task_handle = context.long_running
+            .then(|| crate::oauth::spawn_background_refresh(Arc::clone(&oauth_client), has_real_credentials));
 
         Ok(Self {
             client,
             oauth_client,
-            max_time_since_last_checkin,
+            activity_window,
+            base_url,
+            cache_ttl: cache_ttl.as_duration(),
+            activity_cache: Mutex::new(None),
+            include_workouts,
+            webhook_directory,
             name,
-            _refresh_task_handle: refresh_task_handle,
+            refresh_task_handle,
         })
     }
 
+    /// Whether activity at `timestamp` falls within the activity window
+    fn is_recent(&self, timestamp: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now - timestamp <= chrono::Duration::seconds(self.activity_window.as_secs() as i64)
+    }
+
     async fn get_most_recent_activity_timestamp(&self) -> Result<DateTime<Utc>> {
-        let mut most_recent: Option<DateTime<Utc>> = None;
+        // Held across the fetch, so a concurrent caller waits for its result
+        let mut cache = self.activity_cache.lock().await;
+        if let Some((fetched_at, timestamp)) = cache.filter(|(fetched_at, _)| fetched_at.elapsed() < self.cache_ttl) {
+            tracing::debug!("WHOOP: Using activity fetched {}s ago", fetched_at.elapsed().as_secs());
+            return Ok(timestamp);
+        }
 
-        // Check most recent cycle data
-        if let Ok(timestamp) = self.get_most_recent_cycle_timestamp().await {
-            most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp)));
+        let mut most_recent: Option<DateTime<Utc>> = None;
+        for (what, result) in self.latest_timestamps().await {
+            match result {
+                Ok(timestamp) => most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp))),
+                Err(e) => tracing::debug!("WHOOP: No {} timestamp: {}", what, e),
+            }
         }
 
-        // Check most recent sleep data
-        if let Ok(timestamp) = self.get_most_recent_sleep_timestamp().await {
-            most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp)));
+        let most_recent = most_recent.context("No recent activity data found from WHOOP API")?;
+        *cache = Some((Instant::now(), most_recent));
+        Ok(most_recent)
+    }
+
+    /// The latest record's timestamp from each endpoint, fetched concurrently
+    async fn latest_timestamps(&self) -> Vec<(&'static str, Result<DateTime<Utc>>)> {
+        let (cycle, sleep, recovery, workout) = tokio::join!(
+            self.get_most_recent_cycle_timestamp(),
+            self.get_most_recent_sleep_timestamp(),
+            self.get_most_recent_recovery_timestamp(),
+            async {
+                if self.include_workouts {
+                    Some(self.get_most_recent_workout_timestamp().await)
+                } else {
+                    None
+                }
+            },
+        );
+
+        let mut timestamps = vec![("cycle", cycle), ("sleep", sleep), ("recovery", recovery)];
+        timestamps.extend(workout.map(|workout| ("workout", workout)));
+        timestamps
+    }
+
+    /// Prints this account's part of `lastsignal whoop-status`. Returns false
+    /// when its tokens are missing or expired.
+    async fn print_status(&self, now: DateTime<Utc>) -> bool {
+        let oauth_client = self.oauth_client.read().await;
+        println!("{} ({})", self.name, oauth_client.tokens_file().display());
+
+        let tokens = match oauth_client.load_tokens() {
+            Ok(tokens) => tokens,
+            Err(e) => {
+                println!("  ❌ {}", e);
+                return false;
+            }
+        };
+        if tokens.expires_at <= now {
+            println!(
+                "  ❌ Access token expired {} ({}). The daemon refreshes it while running; otherwise run 'lastsignal whoop-auth' again.",
+                DurationStyle::Verbose.relative(tokens.expires_at - now),
+                tokens.expires_at.format("%Y-%m-%d %H:%M UTC")
+            );
+            return false;
         }
+        println!(
+            "  Access token expires {} ({})",
+            DurationStyle::Verbose.relative(tokens.expires_at - now),
+            tokens.expires_at.format("%Y-%m-%d %H:%M UTC")
+        );
+        drop(oauth_client);
+
+        print!("{}", self.render_activity(&self.latest_timestamps().await, now));
+        true
+    }
 
-        // Check most recent recovery data
-        if let Ok(timestamp) = self.get_most_recent_recovery_timestamp().await {
-            most_recent = Some(most_recent.map_or(timestamp, |existing| existing.max(timestamp)));
+    /// Each endpoint's latest timestamp, and whether the most recent counts
+    /// as a check-in under the activity window
+    fn render_activity(&self, timestamps: &[(&str, Result<DateTime<Utc>>)], now: DateTime<Utc>) -> String {
+        let mut report = String::new();
+        for (what, result) in timestamps {
+            let line = match result {
+                Ok(timestamp) => format!(
+                    "updated {} ({})",
+                    DurationStyle::Verbose.relative(*timestamp - now),
+                    timestamp.format("%Y-%m-%d %H:%M UTC")
+                ),
+                Err(e) => format!("unavailable: {}", e),
+            };
+            report.push_str(&format!("  {:<9} {}\n", format!("{}:", what), line));
         }
 
-        most_recent.context("No recent activity data found from WHOOP API")
+        let most_recent = timestamps.iter().filter_map(|(_, result)| result.as_ref().ok()).max();
+        let verdict = match most_recent {
+            Some(timestamp) if self.is_recent(*timestamp, now) => format!(
+                "✅ Most recent activity, {}, is within the {} activity window, so it counts as a check-in",
+                DurationStyle::Verbose.relative(*timestamp - now),
+                self.activity_window
+            ),
+            Some(timestamp) => format!(
+                "⚠️ Most recent activity, {}, is outside the {} activity window, so it doesn't count as a check-in",
+                DurationStyle::Verbose.relative(*timestamp - now),
+                self.activity_window
+            ),
+            None => "⚠️ No activity found, so WHOOP can't check you in".to_string(),
+        };
+        report.push_str(&format!("  {}\n", verdict));
+        report
     }
 
-    async fn get_most_recent_cycle_timestamp(&self) -> Result<DateTime<Utc>> {
-        let oauth_client = self.oauth_client.read().await;
-        let access_token = oauth_client.get_valid_access_token().await?;
-        let url = "https://api.prod.whoop.com/developer/v1/cycle";
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&access_token)
-            .query(&[("limit", "1")])
-            .send()
-            .await
-            .context("Failed to fetch cycle data from WHOOP API")?;
-
-        if !response.status().is_success() {
-            anyhow::bail!("WHOOP API returned error: {}", response.status());
+    /// GETs the latest record from `path`, waiting out rate limits that say
+    /// how long to wait
+    async fn get_latest(&self, path: &str, what: &str) -> Result<String> {
+        let access_token = self.oauth_client.read().await.get_valid_access_token().await?;
+        let url = format!("{}{}", self.base_url, path);
+        let mut rate_limits = 0;
+
+        loop {
+            let response = self
+                .client
+                .get(&url)
+                .bearer_auth(&access_token)
+                .query(&[("limit", "1")])
+                .send()
+                .await
+                .with_context(|| format!("Failed to fetch {} data from WHOOP API", what))?;
+
+            let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS && rate_limits < MAX_RATE_LIMIT_RETRIES {
+                match retry_after(response.headers()) {
+                    Some(wait) if wait <= MAX_RATE_LIMIT_WAIT => {
+                        rate_limits += 1;
+                        tracing::info!("WHOOP rate limited, retrying {} request in {}s", what, wait.as_secs());
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            if !status.is_success() {
+                anyhow::bail!("WHOOP API returned error: {}", status);
+            }
+
+            let response_text = response.text().await
+                .context("Failed to read response text from WHOOP API")?;
+
+            redact::log_payload(&format!("WHOOP {} API response", what), status, &response_text);
+            return Ok(response_text);
         }
+    }
 
-        let response_text = response.text().await
-            .context("Failed to read response text from WHOOP API")?;
-        
-        tracing::debug!("WHOOP cycle API full response: {}", response_text);
+    async fn get_most_recent_cycle_timestamp(&self) -> Result<DateTime<Utc>> {
+        let response_text = self.get_latest("/cycle", "cycle").await?;
 
         let cycle_response: WhoopCycleResponse = serde_json::from_str(&response_text)
             .context("Failed to parse cycle response from WHOOP API")?;
@@ -210,26 +356,7 @@ impl WhoopOutput {
     }
 
     async fn get_most_recent_sleep_timestamp(&self) -> Result<DateTime<Utc>> {
-        let oauth_client = self.oauth_client.read().await;
-        let access_token = oauth_client.get_valid_access_token().await?;
-        let url = "https://api.prod.whoop.com/developer/v1/activity/sleep";
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&access_token)
-            .query(&[("limit", "1")])
-            .send()
-            .await
-            .context("Failed to fetch sleep data from WHOOP API")?;
-
-        if !response.status().is_success() {
-            anyhow::bail!("WHOOP API returned error: {}", response.status());
-        }
-
-        let response_text = response.text().await
-            .context("Failed to read response text from WHOOP API")?;
-        
-        tracing::debug!("WHOOP sleep API full response: {}", response_text);
+        let response_text = self.get_latest("/activity/sleep", "sleep").await?;
 
         let sleep_response: WhoopSleepResponse = serde_json::from_str(&response_text)
             .context("Failed to parse sleep response from WHOOP API")?;
@@ -247,26 +374,7 @@ impl WhoopOutput {
     }
 
     async fn get_most_recent_recovery_timestamp(&self) -> Result<DateTime<Utc>> {
-        let oauth_client = self.oauth_client.read().await;
-        let access_token = oauth_client.get_valid_access_token().await?;
-        let url = "https://api.prod.whoop.com/developer/v1/recovery";
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&access_token)
-            .query(&[("limit", "1")])
-            .send()
-            .await
-            .context("Failed to fetch recovery data from WHOOP API")?;
-
-        if !response.status().is_success() {
-            anyhow::bail!("WHOOP API returned error: {}", response.status());
-        }
-
-        let response_text = response.text().await
-            .context("Failed to read response text from WHOOP API")?;
-        
-        tracing::debug!("WHOOP recovery API full response: {}", response_text);
+        let response_text = self.get_latest("/recovery", "recovery").await?;
 
         let recovery_response: WhoopRecoveryResponse = serde_json::from_str(&response_text)
             .context("Failed to parse recovery response from WHOOP API")?;
@@ -282,6 +390,81 @@ impl WhoopOutput {
 
         Ok(timestamp)
     }
+
+    async fn get_most_recent_workout_timestamp(&self) -> Result<DateTime<Utc>> {
+        let response_text = self.get_latest("/activity/workout", "workout").await?;
+
+        let workout_response: WhoopWorkoutResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse workout response from WHOOP API")?;
+
+        if workout_response.records.is_empty() {
+            anyhow::bail!("No workout data found");
+        }
+
+        let most_recent_workout = &workout_response.records[0];
+        let timestamp = DateTime::parse_from_rfc3339(&most_recent_workout.updated_at)
+            .context("Failed to parse workout updated_at timestamp")?
+            .with_timezone(&Utc);
+
+        Ok(timestamp)
+    }
+}
+
+/// `lastsignal whoop-status`: reports each WHOOP checkin output's tokens and
+/// latest activity, failing when any account's tokens are missing or expired
+pub async fn run_whoop_status(config: &crate::config::Config) -> Result<()> {
+    let data_directory = config.get_data_directory()?;
+    let context = OutputContext {
+        max_time_since_last_checkin: config.recipient.max_time_since_last_checkin,
+        check_interval: config.app.check_interval,
+        long_running: false,
+    };
+
+    let whoop_configs: Vec<_> = config.checkin.outputs.iter()
+        .filter(|output| output.output_type == "whoop")
+        .collect();
+    if whoop_configs.is_empty() {
+        anyhow::bail!("No whoop output in [checkin] outputs");
+    }
+
+    let now = Utc::now();
+    let mut tokens_ok = true;
+    for (i, output_config) in whoop_configs.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let output = WhoopOutput::new(&output_config.config, data_directory.clone(), context)?;
+        tokens_ok &= output.print_status(now).await;
+    }
+
+    if !tokens_ok {
+        anyhow::bail!("WHOOP tokens are missing or expired");
+    }
+    Ok(())
+}
+
+/// Endpoint paths are appended to the base URL, so it keeps its API version
+/// but loses any trailing slash
+pub fn parse_base_url(base_url: &str) -> Result<String> {
+    let url = reqwest::Url::parse(base_url).context("Invalid 'api_base_url' in WHOOP config")?;
+    if !matches!(url.scheme(), "https" | "http") {
+        anyhow::bail!("WHOOP 'api_base_url' must be an http or https URL");
+    }
+    Ok(base_url.trim_end_matches('/').to_string())
+}
+
+/// The wait a 429 response asks for in its `Retry-After` header, in seconds
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers.get(RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+impl Drop for WhoopOutput {
+    fn drop(&mut self) {
+        if let Some(handle) = &self.refresh_task_handle {
+            handle.abort();
+        }
+    }
 }
 
 #[async_trait]
@@ -297,14 +480,13 @@ impl Output for WhoopOutput {
         match self.get_most_recent_activity_timestamp().await {
             Ok(timestamp) => {
                 let now = Utc::now();
-                let hours_since_activity = (now - timestamp).num_hours();
-                
+
                 tracing::info!(
-                    "WHOOP health check: most recent activity was {} hours ago",
-                    hours_since_activity
+                    "WHOOP health check: most recent activity was {}",
+                    DurationStyle::Verbose.relative(timestamp - now)
                 );
-                
-                Ok(hours_since_activity <= self.max_time_since_last_checkin.as_hours() as i64)
+
+                Ok(self.is_recent(timestamp, now))
             }
             Err(e) => {
                 tracing::warn!("WHOOP health check failed: {}", e);
@@ -333,14 +515,28 @@ impl BidirectionalOutput for WhoopOutput {
     }
 
     async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        // A recent webhook event saves polling the API
+        if let Some(data_directory) = &self.webhook_directory {
+            match whoop_webhook::last_event(data_directory) {
+                Ok(Some(event)) if self.is_recent(event.timestamp, Utc::now()) => {
+                    tracing::info!("WHOOP webhook reported {} at {}, treating as check-in", event.event_type, event.timestamp);
+                    return Ok(vec![CheckinResponse::Found {
+                        timestamp: event.timestamp,
+                        subject: format!("WHOOP Webhook Event: {}", event.event_type),
+                        from: "WHOOP Device".to_string(),
+                    }]);
+                }
+                Ok(_) => tracing::debug!("WHOOP: No recent webhook event, polling"),
+                Err(e) => tracing::warn!("WHOOP: Failed to read webhook event, polling: {}", e),
+            }
+        }
+
         // Check if there's been recent device activity that indicates the user is alive
         let most_recent_activity = self.get_most_recent_activity_timestamp().await?;
         
-        // Always use our configured max_time_since_last_checkin window, not the 'since' parameter
+        // Always use our configured activity window, not the 'since' parameter
         // WHOOP determines "aliveness" based on recent device activity within our configured window
-        let cutoff_time = Utc::now() - chrono::Duration::hours(self.max_time_since_last_checkin.as_hours() as i64);
-
-        if most_recent_activity > cutoff_time {
+        if self.is_recent(most_recent_activity, Utc::now()) {
             // Found recent activity - this counts as a "check-in"
             let response = CheckinResponse::Found {
                 timestamp: most_recent_activity,
@@ -356,8 +552,8 @@ impl BidirectionalOutput for WhoopOutput {
             Ok(vec![response])
         } else {
             tracing::debug!(
-                "WHOOP: No recent activity within {} hours. Most recent activity was at {}",
-                self.max_time_since_last_checkin.as_hours(),
+                "WHOOP: No recent activity within {}. Most recent activity was at {}",
+                self.activity_window,
                 most_recent_activity
             );
             Ok(vec![])
@@ -373,7 +569,67 @@ impl BidirectionalOutput for WhoopOutput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
+    use crate::oauth::OAuthTokens;
+    use axum::response::IntoResponse;
+    use axum::{routing::get, Json, Router};
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn one_shot(max_time_since_last_checkin: ConfigDuration) -> OutputContext {
+        OutputContext {
+            max_time_since_last_checkin,
+            check_interval: ConfigDuration::from_hours(1),
+            long_running: false,
+        }
+    }
+
+    /// Serves a record per endpoint, updated the given hours ago, answering the
+    /// first `rate_limited` requests with a 429. Returns the base URL and the
+    /// request count.
+    async fn mock_whoop(hours_ago: [i64; 4], rate_limited: usize) -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let rate_limited = Arc::new(AtomicUsize::new(rate_limited));
+        let updated_at = |hours: i64| (Utc::now() - chrono::Duration::hours(hours)).to_rfc3339();
+        let start = "2026-10-14T22:00:00Z";
+        let bodies = [
+            json!({"records": [{"id": 1, "start": start, "end": null, "created_at": start, "updated_at": updated_at(hours_ago[0])}]}),
+            json!({"records": [{"id": 2, "start": start, "end": start, "created_at": start, "updated_at": updated_at(hours_ago[1])}]}),
+            json!({"records": [{"cycle_id": 1, "sleep_id": 2, "created_at": start, "updated_at": updated_at(hours_ago[2])}]}),
+            json!({"records": [{"id": 3, "start": start, "end": start, "created_at": start, "updated_at": updated_at(hours_ago[3])}]}),
+        ];
+
+        let mut app = Router::new();
+        for (path, body) in ["/cycle", "/activity/sleep", "/recovery", "/activity/workout"].into_iter().zip(bodies) {
+            let requests = Arc::clone(&requests);
+            let rate_limited = Arc::clone(&rate_limited);
+            app = app.route(path, get(move || async move {
+                requests.fetch_add(1, Ordering::SeqCst);
+                if rate_limited.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1)).is_ok() {
+                    return (axum::http::StatusCode::TOO_MANY_REQUESTS, [("retry-after", "0")]).into_response();
+                }
+                Json(body).into_response()
+            }));
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{}", address), requests)
+    }
+
+    async fn mock_output(config: &HashMap<String, String>, data_directory: &std::path::Path, base_url: &str) -> WhoopOutput {
+        let mut config = config.clone();
+        config.insert("api_base_url".to_string(), base_url.to_string());
+        let output = WhoopOutput::new(&config, data_directory.to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        let tokens = OAuthTokens {
+            access_token: "test_token".to_string(),
+            refresh_token: "test_refresh".to_string(),
+            expires_at: Utc::now() + chrono::Duration::days(1),
+            token_type: "Bearer".to_string(),
+        };
+        output.oauth_client.read().await.save_tokens(&tokens).unwrap();
+        output
+    }
 
     #[tokio::test]
     async fn test_whoop_output_creation() {
@@ -381,15 +637,12 @@ mod tests {
         let config = HashMap::new();
         let max_time = ConfigDuration::from_hours(24);
 
-        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), max_time);
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(max_time));
         assert!(output.is_ok());
         
         let output = output.unwrap();
         assert_eq!(<dyn Output>::get_name(&output), "WHOOP");
-        assert_eq!(output.max_time_since_last_checkin.as_hours(), 24);
-        
-        // Give the background task a moment to start
-        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(output.activity_window.as_hours(), 24);
     }
 
     #[tokio::test]
@@ -397,14 +650,31 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
         let config = HashMap::new();
         let max_time = ConfigDuration::from_days(14); // Using system default
-        let result = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), max_time);
+        let result = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(max_time));
         assert!(result.is_ok());
         
         let output = result.unwrap();
-        assert_eq!(output.max_time_since_last_checkin.as_days(), 14); // default value
-        
-        // Give the background task a moment to start
-        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(output.activity_window.as_days(), 14); // default value
+    }
+
+    #[tokio::test]
+    async fn test_whoop_activity_window() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut config = HashMap::new();
+        config.insert("activity_window".to_string(), "2d".to_string());
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        assert_eq!(output.activity_window.as_days(), 2);
+
+        // The window, not the 14-day deadline, decides what counts
+        let now = Utc::now();
+        assert!(output.is_recent(now - chrono::Duration::hours(47), now));
+        assert!(output.is_recent(now - chrono::Duration::days(2), now));
+        assert!(!output.is_recent(now - chrono::Duration::days(3), now));
+
+        for invalid in ["soon", "0h"] {
+            config.insert("activity_window".to_string(), invalid.to_string());
+            assert!(WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).is_err(), "{}", invalid);
+        }
     }
 
 
@@ -415,7 +685,7 @@ mod tests {
         let config = HashMap::new();
         let max_time = ConfigDuration::from_hours(24);
 
-        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), max_time).unwrap();
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(max_time)).unwrap();
         let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
 
         match result {
@@ -424,8 +694,257 @@ mod tests {
             }
             _ => panic!("Expected Skipped result"),
         }
-        
-        // Give the background task a moment to start
-        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[tokio::test]
+    async fn test_whoop_activity_cached_within_cycle() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, requests) = mock_whoop([30, 5, 8, 12], 0).await;
+        let output = mock_output(&HashMap::new(), temp_dir.path(), &base_url).await;
+
+        assert!(<dyn Output>::health_check(&output).await.unwrap());
+        let responses = output.check_for_responses(None).await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        // The most recent of the endpoints counts
+        match &responses[..] {
+            [CheckinResponse::Found { timestamp, .. }] => {
+                let hours_ago = (Utc::now() - *timestamp).num_minutes() as f64 / 60.0;
+                assert!((4.9..5.1).contains(&hours_ago), "{}", hours_ago);
+            }
+            other => panic!("Expected one check-in, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_whoop_activity_cache_expires() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, requests) = mock_whoop([30, 5, 8, 12], 0).await;
+        let mut config = HashMap::new();
+        config.insert("cache_ttl".to_string(), "1s".to_string());
+        let output = mock_output(&config, temp_dir.path(), &base_url).await;
+
+        output.get_most_recent_activity_timestamp().await.unwrap();
+        output.get_most_recent_activity_timestamp().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        output.get_most_recent_activity_timestamp().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 8);
+    }
+
+    #[tokio::test]
+    async fn test_whoop_waits_out_rate_limits() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, requests) = mock_whoop([30, 5, 8, 12], 2).await;
+        let output = mock_output(&HashMap::new(), temp_dir.path(), &base_url).await;
+
+        let timestamp = output.get_most_recent_activity_timestamp().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 6);
+        assert!(output.is_recent(timestamp, Utc::now()));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+        headers.insert(RETRY_AFTER, "Wed, 21 Oct 2026 07:28:00 GMT".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_whoop_workouts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, requests) = mock_whoop([30, 20, 25, 2], 0).await;
+        let output = mock_output(&HashMap::new(), temp_dir.path(), &base_url).await;
+        let hours_ago = |timestamp: DateTime<Utc>| (Utc::now() - timestamp).num_minutes() as f64 / 60.0;
+
+        // A workout newer than everything else is the most recent activity
+        let timestamp = output.get_most_recent_activity_timestamp().await.unwrap();
+        assert!((1.9..2.1).contains(&hours_ago(timestamp)), "{}", hours_ago(timestamp));
+
+        let mut config = HashMap::new();
+        config.insert("include_workouts".to_string(), "false".to_string());
+        let output = mock_output(&config, temp_dir.path(), &base_url).await;
+        requests.store(0, Ordering::SeqCst);
+        let timestamp = output.get_most_recent_activity_timestamp().await.unwrap();
+        assert!((19.9..20.1).contains(&hours_ago(timestamp)), "{}", hours_ago(timestamp));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        config.insert("include_workouts".to_string(), "yes".to_string());
+        assert!(WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).is_err());
+    }
+
+    #[test]
+    fn test_parse_workout_response() {
+        // Captured from /developer/v1/activity/workout
+        let fixture = r#"{
+            "records": [{
+                "id": 1043,
+                "user_id": 9012,
+                "created_at": "2026-10-14T18:41:52.170Z",
+                "updated_at": "2026-10-14T18:44:07.338Z",
+                "start": "2026-10-14T17:30:11.502Z",
+                "end": "2026-10-14T18:38:40.201Z",
+                "timezone_offset": "+01:00",
+                "sport_id": 1,
+                "score_state": "SCORED",
+                "score": {
+                    "strain": 8.2463,
+                    "average_heart_rate": 123,
+                    "max_heart_rate": 146,
+                    "kilojoule": 1569.34033203125,
+                    "percent_recorded": 100,
+                    "distance_meter": 1772.77035916,
+                    "altitude_gain_meter": 46.64384460449,
+                    "altitude_change_meter": -0.781372010707855,
+                    "zone_duration": {
+                        "zone_zero_milli": 13458,
+                        "zone_one_milli": 389370,
+                        "zone_two_milli": 388367,
+                        "zone_three_milli": 71137,
+                        "zone_four_milli": 0,
+                        "zone_five_milli": 0
+                    }
+                }
+            }],
+            "next_token": "MTIzOjEyMzEyMw"
+        }"#;
+        let response: WhoopWorkoutResponse = serde_json::from_str(fixture).unwrap();
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].updated_at, "2026-10-14T18:44:07.338Z");
+
+        // Workouts still being scored have no score yet
+        let unscored = r#"{"records": [{"id": 1044, "user_id": 9012, "created_at": "2026-10-15T07:02:00Z", "updated_at": "2026-10-15T07:02:00Z",
+            "start": "2026-10-15T06:10:00Z", "end": "2026-10-15T07:00:00Z", "timezone_offset": "+01:00", "sport_id": 0, "score_state": "PENDING_SCORE"}]}"#;
+        assert!(serde_json::from_str::<WhoopWorkoutResponse>(unscored).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_whoop_check_for_responses_end_to_end() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, _) = mock_whoop([30, 5, 8, 12], 0).await;
+
+        // Two accounts share the data directory, each with its own token file
+        for (token_file, access_token) in [("whoop_alice.json", "alice_token"), ("whoop_bob.json", "bob_token")] {
+            let client = OAuthClient::new(WHOOP, "id".to_string(), "secret".to_string(), "http://localhost".to_string(), temp_dir.path().to_path_buf())
+                .with_token_file(token_file);
+            let tokens = OAuthTokens {
+                access_token: access_token.to_string(),
+                refresh_token: "test_refresh".to_string(),
+                expires_at: Utc::now() + chrono::Duration::days(1),
+                token_type: "Bearer".to_string(),
+            };
+            client.save_tokens(&tokens).unwrap();
+        }
+
+        let mut config = HashMap::new();
+        config.insert("api_base_url".to_string(), format!("{}/", base_url));
+        config.insert("token_file".to_string(), "whoop_alice.json".to_string());
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        assert_eq!(output.base_url, base_url);
+        assert_eq!(output.oauth_client.read().await.get_valid_access_token().await.unwrap(), "alice_token");
+
+        let responses = output.check_for_responses(None).await.unwrap();
+        assert!(matches!(&responses[..], [CheckinResponse::Found { from, .. }] if from == "WHOOP Device"), "{:?}", responses);
+
+        // Activity older than the window isn't a check-in
+        config.insert("activity_window".to_string(), "2h".to_string());
+        config.insert("token_file".to_string(), "whoop_bob.json".to_string());
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+
+        // Without tokens in the configured file, the error names it
+        config.insert("token_file".to_string(), "whoop_carol.json".to_string());
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        let error = output.check_for_responses(None).await.unwrap_err().to_string();
+        assert!(error.contains("No recent activity"), "{}", error);
+        let error = output.oauth_client.read().await.load_tokens().unwrap_err().to_string();
+        assert!(error.contains("'lastsignal whoop-auth --token-file whoop_carol.json'"), "{}", error);
+    }
+
+    #[test]
+    fn test_parse_base_url() {
+        assert_eq!(parse_base_url(DEFAULT_BASE_URL).unwrap(), DEFAULT_BASE_URL);
+        assert_eq!(parse_base_url("https://api.prod.whoop.com/developer/v2/").unwrap(), "https://api.prod.whoop.com/developer/v2");
+        assert_eq!(parse_base_url("http://127.0.0.1:8080").unwrap(), "http://127.0.0.1:8080");
+        assert!(parse_base_url("api.prod.whoop.com").is_err());
+        assert!(parse_base_url("ftp://api.prod.whoop.com").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_whoop_refresh_task_lifetime() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = HashMap::new();
+
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        assert!(output.refresh_task_handle.is_none());
+
+        let daemon = OutputContext { long_running: true, ..one_shot(ConfigDuration::from_days(14)) };
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), daemon).unwrap();
+        let refresh_task = output.refresh_task_handle.as_ref().unwrap().abort_handle();
+        tokio::task::yield_now().await;
+        assert!(!refresh_task.is_finished());
+
+        drop(output);
+        tokio::task::yield_now().await;
+        assert!(refresh_task.is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_whoop_render_activity() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut config = HashMap::new();
+        config.insert("activity_window".to_string(), "2d".to_string());
+        let output = WhoopOutput::new(&config, temp_dir.path().to_path_buf(), one_shot(ConfigDuration::from_days(14))).unwrap();
+        let now: DateTime<Utc> = "2026-10-15T12:00:00Z".parse().unwrap();
+        let at = |timestamp: &str| Ok(timestamp.parse::<DateTime<Utc>>().unwrap());
+
+        let report = output.render_activity(&[
+            ("cycle", at("2026-10-14T07:00:00Z")),
+            ("sleep", at("2026-10-15T07:00:00Z")),
+            ("recovery", Err(anyhow::anyhow!("No recovery data found"))),
+        ], now);
+        let expected = [
+            "  cycle:    updated 1 day and 5 hours ago (2026-10-14 07:00 UTC)",
+            "  sleep:    updated 5 hours ago (2026-10-15 07:00 UTC)",
+            "  recovery: unavailable: No recovery data found",
+            "  ✅ Most recent activity, 5 hours ago, is within the 2d activity window, so it counts as a check-in",
+        ];
+        assert_eq!(report.lines().collect::<Vec<_>>(), expected);
+
+        let report = output.render_activity(&[("cycle", at("2026-10-12T07:00:00Z"))], now);
+        assert!(report.ends_with("⚠️ Most recent activity, 3 days and 5 hours ago, is outside the 2d activity window, so it doesn't count as a check-in\n"), "{}", report);
+
+        let report = output.render_activity(&[("cycle", Err(anyhow::anyhow!("WHOOP API returned error: 401 Unauthorized")))], now);
+        assert!(report.ends_with("⚠️ No activity found, so WHOOP can't check you in\n"), "{}", report);
+    }
+
+    #[tokio::test]
+    async fn test_whoop_webhook_event_before_polling() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (base_url, requests) = mock_whoop([30, 25, 28, 40], 0).await;
+        let mut config = HashMap::new();
+        config.insert("activity_window".to_string(), "2h".to_string());
+        config.insert("webhook".to_string(), "true".to_string());
+        let output = mock_output(&config, temp_dir.path(), &base_url).await;
+
+        // Without an event it polls, and the polled activity is too old
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        let event = whoop_webhook::RecordedEvent { timestamp: Utc::now() - chrono::Duration::minutes(10), event_type: "sleep.updated".to_string() };
+        std::fs::write(temp_dir.path().join(whoop_webhook::WEBHOOK_EVENT_FILE), serde_json::to_string(&event).unwrap()).unwrap();
+        let responses = output.check_for_responses(None).await.unwrap();
+        assert!(matches!(&responses[..], [CheckinResponse::Found { timestamp, subject, .. }]
+            if *timestamp == event.timestamp && subject == "WHOOP Webhook Event: sleep.updated"), "{:?}", responses);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        // Outputs without `webhook` ignore the file
+        config.remove("webhook");
+        let output = mock_output(&config, temp_dir.path(), &base_url).await;
+        assert!(output.check_for_responses(None).await.unwrap().is_empty());
     }
 }
@@ -0,0 +1,216 @@
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use hmac::Mac;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::OutputConfig;
+use crate::signing::hmac_sha256;
+
+/// The latest webhook event, read by `WhoopOutput` before it polls
+pub const WEBHOOK_EVENT_FILE: &str = "whoop_webhook_event.json";
+
+/// How far an event's signed timestamp may be from now before it's treated
+/// as a replay
+const MAX_TIMESTAMP_SKEW_MINUTES: i64 = 5;
+
+/// Receives WHOOP's webhook events on the check-in server, for a WHOOP
+/// checkin output with `webhook = "true"`
+#[derive(Debug, Clone)]
+pub struct WhoopWebhook {
+    client_secret: String,
+    data_directory: PathBuf,
+}
+
+/// The body WHOOP posts, e.g. `{"user_id": 10129, "id": 10235, "type": "workout.updated"}`.
+/// Ids are numbers in v1 and UUIDs in v2, and aren't needed.
+#[derive(Debug, Deserialize)]
+struct WebhookEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+}
+
+/// An event that counts as activity, as kept in `WEBHOOK_EVENT_FILE`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// When WHOOP signed the event
+    pub timestamp: DateTime<Utc>,
+    pub event_type: String,
+}
+
+impl WhoopWebhook {
+    pub fn new(client_secret: &str, data_directory: &Path) -> Self {
+        Self {
+            client_secret: client_secret.to_string(),
+            data_directory: data_directory.to_path_buf(),
+        }
+    }
+
+    /// `None` unless a WHOOP checkin output opts in with `webhook = "true"`
+    pub fn from_checkin_outputs(outputs: &[OutputConfig], data_directory: &Path) -> Result<Option<Self>> {
+        let Some(output) = outputs.iter().find(|output| output.output_type == "whoop" && webhook_enabled(&output.config).unwrap_or(false)) else {
+            return Ok(None);
+        };
+        let client_secret = output.config.get("client_secret")
+            .context("WHOOP 'webhook' needs 'client_secret' to check signatures")?;
+        Ok(Some(Self::new(client_secret, data_directory)))
+    }
+
+    /// Checks an event's signature and age, and records it when it's new
+    /// data. Returns the recorded event, or `None` for deletions.
+    pub fn receive(&self, timestamp: Option<&str>, signature: Option<&str>, body: &[u8], now: DateTime<Utc>) -> Result<Option<RecordedEvent>> {
+        let timestamp = verify_signature(
+            &self.client_secret,
+            timestamp.context("Missing X-WHOOP-Signature-Timestamp header")?,
+            signature.context("Missing X-WHOOP-Signature header")?,
+            body,
+            now,
+        )?;
+        let event: WebhookEvent = serde_json::from_slice(body).context("Invalid WHOOP webhook event")?;
+
+        // Deleting a record isn't evidence of anything new
+        if !event.event_type.ends_with(".updated") {
+            tracing::debug!("WHOOP webhook: Ignoring {} event", event.event_type);
+            return Ok(None);
+        }
+
+        let recorded = RecordedEvent { timestamp, event_type: event.event_type };
+        let path = self.data_directory.join(WEBHOOK_EVENT_FILE);
+        let json = serde_json::to_string_pretty(&recorded).context("Failed to serialize WHOOP webhook event")?;
+        std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+        tracing::info!("WHOOP webhook: {} at {}", recorded.event_type, recorded.timestamp);
+        Ok(Some(recorded))
+    }
+}
+
+pub fn webhook_enabled(config: &HashMap<String, String>) -> Result<bool> {
+    match config.get("webhook").map(String::as_str) {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(other) => bail!("Invalid 'webhook' in WHOOP config: '{}', expected true or false", other),
+    }
+}
+
+/// The latest recorded event, if any
+pub fn last_event(data_directory: &Path) -> Result<Option<RecordedEvent>> {
+    let path = data_directory.join(WEBHOOK_EVENT_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let json = std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let event = serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(event))
+}
+
+/// WHOOP signs `<timestamp><body>` with HMAC-SHA256 keyed by the client
+/// secret, base64 encoded. The timestamp is in milliseconds since the epoch,
+/// and one more than a few minutes from now is refused as a replay. Returns
+/// the signed timestamp.
+fn verify_signature(client_secret: &str, timestamp: &str, signature: &str, body: &[u8], now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let signature = base64::engine::general_purpose::STANDARD
+        .decode(signature.trim())
+        .context("Invalid WHOOP webhook signature encoding")?;
+    let mut mac = hmac_sha256(client_secret.as_bytes(), timestamp.as_bytes());
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| anyhow::anyhow!("WHOOP webhook signature does not match"))?;
+
+    let millis: i64 = timestamp.trim().parse().context("Invalid WHOOP webhook timestamp")?;
+    let timestamp = DateTime::from_timestamp_millis(millis).context("WHOOP webhook timestamp out of range")?;
+    if (now - timestamp).abs() > chrono::Duration::minutes(MAX_TIMESTAMP_SKEW_MINUTES) {
+        bail!("Stale WHOOP webhook timestamp {}", timestamp);
+    }
+    Ok(timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whoop_client_secret";
+    const BODY: &str = r#"{"user_id":10129,"id":10235,"type":"workout.updated","trace_id":"d3c1e3a0-3f42-4f5e-8d5e-2b1c5e3a9f10"}"#;
+    /// 2026-10-15T09:30:00Z
+    const TIMESTAMP: &str = "1792056600000";
+    /// base64(HMAC-SHA256(SECRET, TIMESTAMP + BODY))
+    const SIGNATURE: &str = "EBgBAczLw5sNvttM1XSSRjkC+0YM5H9HhtXgwhXCoSM=";
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-15T09:30:00Z".parse().unwrap()
+    }
+
+    fn webhook(data_directory: &Path) -> WhoopWebhook {
+        WhoopWebhook::new(SECRET, data_directory)
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        assert_eq!(verify_signature(SECRET, TIMESTAMP, SIGNATURE, BODY.as_bytes(), now()).unwrap(), now());
+
+        let tampered = BODY.replace("workout", "sleep");
+        assert!(verify_signature(SECRET, TIMESTAMP, SIGNATURE, tampered.as_bytes(), now()).is_err());
+        assert!(verify_signature("another_secret", TIMESTAMP, SIGNATURE, BODY.as_bytes(), now()).is_err());
+        assert!(verify_signature(SECRET, "1792056600001", SIGNATURE, BODY.as_bytes(), now()).is_err());
+        assert!(verify_signature(SECRET, TIMESTAMP, "not base64!", BODY.as_bytes(), now()).is_err());
+    }
+
+    #[test]
+    fn test_stale_timestamps_are_rejected() {
+        let minutes = chrono::Duration::minutes;
+        assert!(verify_signature(SECRET, TIMESTAMP, SIGNATURE, BODY.as_bytes(), now() + minutes(5)).is_ok());
+        assert!(verify_signature(SECRET, TIMESTAMP, SIGNATURE, BODY.as_bytes(), now() - minutes(5)).is_ok());
+
+        // A captured request replayed later, or one dated in the future
+        let error = verify_signature(SECRET, TIMESTAMP, SIGNATURE, BODY.as_bytes(), now() + minutes(6)).unwrap_err();
+        assert!(error.to_string().contains("Stale"), "{}", error);
+        assert!(verify_signature(SECRET, TIMESTAMP, SIGNATURE, BODY.as_bytes(), now() - minutes(6)).is_err());
+    }
+
+    #[test]
+    fn test_receive_records_updates() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let webhook = webhook(temp_dir.path());
+        assert_eq!(last_event(temp_dir.path()).unwrap(), None);
+
+        assert!(webhook.receive(None, Some(SIGNATURE), BODY.as_bytes(), now()).is_err());
+        assert!(webhook.receive(Some(TIMESTAMP), None, BODY.as_bytes(), now()).is_err());
+        assert_eq!(last_event(temp_dir.path()).unwrap(), None);
+
+        let recorded = webhook.receive(Some(TIMESTAMP), Some(SIGNATURE), BODY.as_bytes(), now()).unwrap().unwrap();
+        assert_eq!(recorded, RecordedEvent { timestamp: now(), event_type: "workout.updated".to_string() });
+        assert_eq!(last_event(temp_dir.path()).unwrap(), Some(recorded));
+    }
+
+    #[test]
+    fn test_receive_ignores_deletions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let body = r#"{"user_id":10129,"id":"ecfc6a15-4661-442f-a9a4-f160dd7afae8","type":"sleep.deleted","trace_id":"e4a1b0c2"}"#;
+        let mut mac = hmac_sha256(SECRET.as_bytes(), TIMESTAMP.as_bytes());
+        mac.update(body.as_bytes());
+        let signature = base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+
+        assert_eq!(webhook(temp_dir.path()).receive(Some(TIMESTAMP), Some(&signature), body.as_bytes(), now()).unwrap(), None);
+        assert_eq!(last_event(temp_dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_from_checkin_outputs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = |config: &[(&str, &str)]| OutputConfig {
+            output_type: "whoop".to_string(),
+            config: config.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        };
+
+        assert!(WhoopWebhook::from_checkin_outputs(&[output(&[("client_secret", SECRET)])], temp_dir.path()).unwrap().is_none());
+        assert!(WhoopWebhook::from_checkin_outputs(&[output(&[("webhook", "true")])], temp_dir.path()).is_err());
+        let webhook = WhoopWebhook::from_checkin_outputs(&[output(&[("webhook", "true"), ("client_secret", SECRET)])], temp_dir.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(webhook.client_secret, SECRET);
+    }
+}
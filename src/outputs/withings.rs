@@ -0,0 +1,277 @@
+use super::{Output, OutputResult};
+use crate::outputs::bidirectional::{BidirectionalOutput, CheckinResponse};
+use crate::oauth::{OAuthClient, WITHINGS};
+use crate::duration_parser::{ConfigDuration, DurationStyle};
+use crate::redact;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const API_BASE: &str = "https://wbsapi.withings.net";
+
+/// Only used for the authorization step, which `withings-auth` does itself
+const DEFAULT_REDIRECT_URI: &str = "http://127.0.0.1:3000/auth/withings/callback";
+
+/// How far back to ask for measurements and sleep, so the health check can
+/// report how long ago the last one was
+const LOOKBACK_DAYS: i64 = 30;
+
+/// `category` of a real measurement, as opposed to a user objective
+const CATEGORY_MEASURE: i64 = 1;
+
+/// Withings API client for checking scale measurements and sleep records
+#[derive(Debug)]
+pub struct WithingsOutput {
+    client: Client,
+    oauth_client: Arc<RwLock<OAuthClient>>,
+    max_time_since_last_checkin: ConfigDuration,
+    name: String,
+    _refresh_task_handle: tokio::task::JoinHandle<()>,
+}
+
+/// The `body` of a Withings API response. Errors come back with HTTP 200 and
+/// a nonzero `status`.
+fn api_body(response: &Value) -> Result<&Value> {
+    let status = response.get("status").and_then(Value::as_i64).context("Withings response has no status")?;
+    if status != 0 {
+        let error = response.get("error").and_then(Value::as_str).unwrap_or("unknown error");
+        anyhow::bail!("Withings API error {}: {}", status, error);
+    }
+    response.get("body").context("Withings response has no body")
+}
+
+/// The newest measurement in a `getmeas` body, such as stepping on the scale
+fn latest_measurement(body: &Value) -> Option<DateTime<Utc>> {
+    body.get("measuregrps")
+        .and_then(Value::as_array)?
+        .iter()
+        .filter(|group| group.get("category").and_then(Value::as_i64) == Some(CATEGORY_MEASURE))
+        .filter_map(|group| group.get("date").and_then(Value::as_i64))
+        .filter_map(|seconds| DateTime::from_timestamp(seconds, 0))
+        .max()
+}
+
+/// The end of the newest night in a sleep `getsummary` body
+fn latest_wake_up(body: &Value) -> Option<DateTime<Utc>> {
+    body.get("series")
+        .and_then(Value::as_array)?
+        .iter()
+        .filter_map(|night| night.get("enddate").and_then(Value::as_i64))
+        .filter_map(|seconds| DateTime::from_timestamp(seconds, 0))
+        .max()
+}
+
+impl WithingsOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["client_id", "client_secret"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: std::path::PathBuf, max_time_since_last_checkin: ConfigDuration) -> Result<Self> {
+        // Withings refresh tokens change on every refresh, which needs the client credentials
+        let client_id = config
+            .get("client_id")
+            .context("Missing 'client_id' field in withings config")?
+            .clone();
+        let client_secret = config
+            .get("client_secret")
+            .context("Missing 'client_secret' field in withings config")?
+            .clone();
+
+        let oauth_client = Arc::new(RwLock::new(OAuthClient::new(
+            WITHINGS,
+            client_id,
+            client_secret,
+            DEFAULT_REDIRECT_URI.to_string(),
+            data_directory,
+        )));
+
+        let refresh_task_handle = crate::oauth::spawn_background_refresh(Arc::clone(&oauth_client), true);
+
+        Ok(Self {
+            client: Client::new(),
+            oauth_client,
+            max_time_since_last_checkin,
+            name: "Withings".to_string(),
+            _refresh_task_handle: refresh_task_handle,
+        })
+    }
+
+    async fn call(&self, path: &str, params: &[(&str, &str)], description: &str) -> Result<Value> {
+        let oauth_client = self.oauth_client.read().await;
+        let access_token = oauth_client.get_valid_access_token().await?;
+        let response = self
+            .client
+            .post(format!("{}{}", API_BASE, path))
+            .bearer_auth(&access_token)
+            .form(params)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch {} from Withings API: {}", description, e.without_url()))?;
+
+        let status = response.status();
+        let response_text = response.text().await
+            .context("Failed to read response text from Withings API")?;
+
+        redact::log_payload(&format!("Withings {} API response", description), status, &response_text);
+
+        if !status.is_success() {
+            anyhow::bail!("Withings API returned error: {}", status);
+        }
+
+        let response: Value = serde_json::from_str(&response_text)
+            .with_context(|| format!("Failed to parse {} response from Withings API", description))?;
+        Ok(api_body(&response)?.clone())
+    }
+
+    /// The latest measurement or wake-up. Either source failing is logged,
+    /// so a sleep analyzer outage doesn't hide this morning's weigh-in.
+    async fn get_most_recent_activity_timestamp(&self) -> Result<DateTime<Utc>> {
+        let since = (Utc::now() - chrono::Duration::days(LOOKBACK_DAYS)).timestamp().to_string();
+
+        let measured = match self.call("/measure", &[("action", "getmeas"), ("lastupdate", since.as_str())], "measurements").await {
+            Ok(body) => latest_measurement(&body),
+            Err(e) => {
+                tracing::warn!("Withings: could not read measurements: {:#}", e);
+                None
+            }
+        };
+        let slept = match self.call("/v2/sleep", &[("action", "getsummary"), ("lastupdate", since.as_str())], "sleep").await {
+            Ok(body) => latest_wake_up(&body),
+            Err(e) => {
+                tracing::warn!("Withings: could not read sleep: {:#}", e);
+                None
+            }
+        };
+
+        measured
+            .max(slept)
+            .with_context(|| format!("No measurements or sleep records from Withings in the last {} days", LOOKBACK_DAYS))
+    }
+}
+
+#[async_trait]
+impl Output for WithingsOutput {
+    async fn send_message(&self, _message: &str) -> Result<OutputResult> {
+        // Withings is used purely for checking if the user is alive via measurements
+        Ok(OutputResult::Skipped("Withings is a check-only adapter".to_string()))
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        match self.get_most_recent_activity_timestamp().await {
+            Ok(timestamp) => {
+                let now = Utc::now();
+                let hours_since_activity = (now - timestamp).num_hours();
+
+                tracing::info!(
+                    "Withings health check: most recent measurement was {}",
+                    DurationStyle::Verbose.relative(timestamp - now)
+                );
+
+                Ok(hours_since_activity <= self.max_time_since_last_checkin.as_hours() as i64)
+            }
+            Err(e) => {
+                tracing::warn!("Withings health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for WithingsOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        <Self as Output>::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        <Self as Output>::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        <Self as Output>::get_name(self)
+    }
+
+    async fn check_for_responses(&self, _since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let most_recent_activity = self.get_most_recent_activity_timestamp().await?;
+
+        Ok(super::activity_checkin(
+            "Withings",
+            most_recent_activity,
+            self.max_time_since_last_checkin,
+            Utc::now(),
+            "Withings Measurement Detected".to_string(),
+            "Withings Device".to_string(),
+        ))
+    }
+
+    async fn mark_processed_until(&self, _timestamp: DateTime<Utc>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("client_id".to_string(), "withings_client".to_string());
+        config.insert("client_secret".to_string(), "0123456789abcdef".to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn test_withings_output_creation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = WithingsOutput::new(&test_config(), temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).unwrap();
+        assert_eq!(<dyn Output>::get_name(&output), "Withings");
+        let result = <dyn Output>::send_message(&output, "test message").await.unwrap();
+        assert!(matches!(result, OutputResult::Skipped(reason) if reason.contains("check-only adapter")));
+
+        for key in ["client_id", "client_secret"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(WithingsOutput::new(&config, temp_dir.path().to_path_buf(), ConfigDuration::from_hours(24)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_api_body() {
+        let ok = json!({"status": 0, "body": {"measuregrps": []}});
+        assert!(api_body(&ok).unwrap().get("measuregrps").is_some());
+
+        let error = json!({"status": 401, "body": {}, "error": "XRequestID: Not provided invalid_token"});
+        assert!(api_body(&error).unwrap_err().to_string().contains("401"));
+    }
+
+    #[test]
+    fn test_latest_measurement() {
+        // A goal weight (category 2) set later isn't a weigh-in
+        let body = json!({"updatetime": 1760520600, "timezone": "Europe/London", "measuregrps": [
+            {"grpid": 1, "attrib": 0, "date": 1760436000, "created": 1760436005, "category": 1, "measures": [{"value": 72000, "type": 1, "unit": -3}]},
+            {"grpid": 2, "attrib": 0, "date": 1760520600, "created": 1760520605, "category": 1, "measures": [{"value": 71800, "type": 1, "unit": -3}]},
+            {"grpid": 3, "attrib": 0, "date": 1760530000, "created": 1760530000, "category": 2, "measures": [{"value": 70000, "type": 1, "unit": -3}]}
+        ]});
+        assert_eq!(latest_measurement(&body).unwrap().timestamp(), 1_760_520_600);
+        assert_eq!(latest_measurement(&json!({"measuregrps": []})), None);
+    }
+
+    #[test]
+    fn test_latest_wake_up() {
+        let body = json!({"series": [
+            {"startdate": 1760400000, "enddate": 1760428800, "date": "2025-10-14"},
+            {"startdate": 1760486400, "enddate": 1760515200, "date": "2025-10-15"}
+        ], "more": false, "offset": 0});
+        assert_eq!(latest_wake_up(&body).unwrap().timestamp(), 1_760_515_200);
+        assert_eq!(latest_wake_up(&json!({"series": []})), None);
+    }
+}
@@ -0,0 +1,419 @@
+use super::bidirectional::{BidirectionalOutput, CheckinResponse};
+use super::{Output, OutputResult};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio_xmpp::connect::DnsConfig;
+use tokio_xmpp::jid::{BareJid, Jid};
+use tokio_xmpp::parsers::delay::Delay;
+use tokio_xmpp::parsers::message::{Lang, Message, MessageType};
+use tokio_xmpp::parsers::presence::Presence;
+use tokio_xmpp::parsers::roster::Roster;
+use tokio_xmpp::xmlstream::Timeouts;
+use tokio_xmpp::{Client, Event, IqRequest, IqResponse, Stanza};
+
+const DEFAULT_PORT: u16 = 5222;
+
+/// How long connecting and logging in may take. The client reconnects on its
+/// own, so a bad password can otherwise retry forever.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for a sent stanza or an IQ response
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How long to stay online collecting messages, including any the server held
+/// while LastSignal was offline
+const COLLECT_WINDOW: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub struct XmppOutput {
+    jid: Jid,
+    password: String,
+    /// `host` and port to connect to, instead of the SRV records of the JID's domain
+    server: Option<(String, u16)>,
+    to_jid: BareJid,
+    /// Where the timestamp of the last processed reply is kept
+    cursor_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct XmppCursor {
+    last_processed: Option<DateTime<Utc>>,
+}
+
+/// A chat message with a body, as far as check-ins are concerned
+#[derive(Debug)]
+struct ReceivedMessage {
+    from: BareJid,
+    body: String,
+    timestamp: DateTime<Utc>,
+}
+
+impl XmppOutput {
+    /// Config keys this output reads
+    pub const CONFIG_KEYS: &'static [&'static str] = &["jid", "password", "server", "to_jid"];
+
+    pub fn new(config: &HashMap<String, String>, data_directory: Option<&Path>) -> Result<Self> {
+        let jid = config
+            .get("jid")
+            .context("Missing 'jid' field in xmpp config")?;
+        let jid = Jid::new(jid).with_context(|| format!("Invalid 'jid' '{}' in xmpp config", jid))?;
+        if jid.node().is_none() {
+            anyhow::bail!("xmpp 'jid' must be an account such as lastsignal@example.com");
+        }
+
+        let password = config
+            .get("password")
+            .context("Missing 'password' field in xmpp config")?
+            .clone();
+
+        let server = config.get("server").map(|server| parse_server(server)).transpose()?;
+
+        let to_jid = config
+            .get("to_jid")
+            .context("Missing 'to_jid' field in xmpp config")?;
+        let to_jid = BareJid::new(to_jid).with_context(|| format!("Invalid 'to_jid' '{}' in xmpp config", to_jid))?;
+
+        let cursor_file = data_directory.map(|dir| dir.join(cursor_file_name(&to_jid)));
+
+        Ok(XmppOutput {
+            jid,
+            password,
+            server,
+            to_jid,
+            cursor_file,
+        })
+    }
+
+    /// Logs in and waits until the session is ready
+    async fn connect(&self) -> Result<Client> {
+        let mut client = match &self.server {
+            Some((host, port)) => Client::new_starttls(
+                self.jid.clone(),
+                self.password.clone(),
+                DnsConfig::no_srv(host, *port),
+                Timeouts::default(),
+            ),
+            None => Client::new(self.jid.clone(), self.password.clone()),
+        };
+
+        let online = tokio::time::timeout(LOGIN_TIMEOUT, async {
+            while let Some(event) = client.next().await {
+                match event {
+                    Event::Online { .. } => return Ok(()),
+                    Event::Disconnected(e) => anyhow::bail!("XMPP login failed: {}", e),
+                    Event::Stanza(_) => {}
+                }
+            }
+            anyhow::bail!("XMPP connection closed before login")
+        })
+        .await;
+
+        match online {
+            Ok(Ok(())) => Ok(client),
+            Ok(Err(e)) => Err(e),
+            Err(_) => anyhow::bail!("Timed out after {}s logging in to XMPP as {}", LOGIN_TIMEOUT.as_secs(), self.jid),
+        }
+    }
+
+    fn load_cursor(&self) -> Result<XmppCursor> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(XmppCursor::default());
+        };
+        if !cursor_file.exists() {
+            return Ok(XmppCursor::default());
+        }
+
+        let content = std::fs::read_to_string(cursor_file)
+            .with_context(|| format!("Failed to read xmpp cursor file: {:?}", cursor_file))?;
+        serde_json::from_str(&content).context("Failed to parse xmpp cursor file")
+    }
+
+    fn save_cursor(&self, cursor: &XmppCursor) -> Result<()> {
+        let Some(cursor_file) = &self.cursor_file else {
+            return Ok(());
+        };
+
+        let content = serde_json::to_string_pretty(cursor).context("Failed to serialize xmpp cursor")?;
+        std::fs::write(cursor_file, content)
+            .with_context(|| format!("Failed to write xmpp cursor file: {:?}", cursor_file))
+    }
+
+    /// Goes online so the server delivers held messages, then collects
+    /// everything that arrives within `COLLECT_WINDOW`
+    async fn collect_messages(&self) -> Result<Vec<ReceivedMessage>> {
+        let mut client = self.connect().await?;
+        tokio::time::timeout(REQUEST_TIMEOUT, client.send_stanza(Presence::available().into()))
+            .await
+            .context("Timed out sending XMPP presence")?
+            .context("Failed to send XMPP presence")?;
+
+        let mut messages = Vec::new();
+        let deadline = tokio::time::Instant::now() + COLLECT_WINDOW;
+        while let Ok(Some(event)) = tokio::time::timeout_at(deadline, client.next()).await {
+            match event {
+                Event::Stanza(Stanza::Message(message)) => messages.extend(received_message(message, Utc::now())),
+                Event::Disconnected(e) => tracing::debug!("XMPP connection dropped while collecting messages: {}", e),
+                _ => {}
+            }
+        }
+
+        if let Err(e) = client.send_end().await {
+            tracing::debug!("Failed to close XMPP connection: {}", e);
+        }
+        Ok(messages)
+    }
+}
+
+/// `host` or `host:port`, for servers without SRV records
+fn parse_server(server: &str) -> Result<(String, u16)> {
+    let (host, port) = match server.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port.parse().with_context(|| format!("Invalid port in xmpp 'server' '{}'", server))?;
+            (host, port)
+        }
+        None => (server, DEFAULT_PORT),
+    };
+    if host.trim().is_empty() {
+        anyhow::bail!("xmpp 'server' has no host");
+    }
+    Ok((host.trim().to_string(), port))
+}
+
+/// One cursor file per contact, e.g. `xmpp_alice_example.com.json`
+fn cursor_file_name(to_jid: &BareJid) -> String {
+    let name: String = to_jid
+        .as_str()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("xmpp_{}.json", name)
+}
+
+/// Messages held by the server carry a delay stamp of when they were sent;
+/// anything else arrived live, at `now`
+fn received_message(mut message: Message, now: DateTime<Utc>) -> Option<ReceivedMessage> {
+    if message.type_ == MessageType::Error {
+        return None;
+    }
+    let from = message.from.as_ref()?.to_bare();
+    let (_, body) = message.get_best_body_cloned(vec![])?;
+    let timestamp = match message.extract_payload::<Delay>() {
+        Ok(Some(delay)) => delay.stamp.0.with_timezone(&Utc),
+        _ => now,
+    };
+    Some(ReceivedMessage { from, body, timestamp })
+}
+
+/// Messages from `to_jid` sent after `after`
+fn replies_from(messages: Vec<ReceivedMessage>, to_jid: &BareJid, after: Option<DateTime<Utc>>) -> Vec<CheckinResponse> {
+    messages
+        .into_iter()
+        .filter(|message| &message.from == to_jid)
+        .filter(|message| after.is_none_or(|after| message.timestamp > after))
+        .map(|message| CheckinResponse::Found {
+            timestamp: message.timestamp,
+            subject: message.body,
+            from: message.from.to_string(),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Output for XmppOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        let mut client = match self.connect().await {
+            Ok(client) => client,
+            Err(e) => return Ok(OutputResult::Failed(e.to_string())),
+        };
+
+        let chat = Message::chat(Some(Jid::from(self.to_jid.clone()))).with_body(Lang::default(), message.to_string());
+        let result = match tokio::time::timeout(REQUEST_TIMEOUT, client.send_stanza(chat.into())).await {
+            Ok(Ok(_)) => OutputResult::Success,
+            Ok(Err(e)) => OutputResult::Failed(format!("Failed to send XMPP message: {}", e)),
+            Err(_) => OutputResult::Failed(format!("Timed out after {}s sending XMPP message", REQUEST_TIMEOUT.as_secs())),
+        };
+
+        if let Err(e) = client.send_end().await {
+            tracing::debug!("Failed to close XMPP connection: {}", e);
+        }
+        Ok(result)
+    }
+
+    /// Logs in and fetches the roster
+    async fn health_check(&self) -> Result<bool> {
+        let mut client = match self.connect().await {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::debug!("XMPP health check: {}", e);
+                return Ok(false);
+            }
+        };
+
+        let roster = Roster { ver: None, items: Vec::new() };
+        let roster_fetch = async {
+            let mut response = client.send_iq(None, IqRequest::Get(roster.into())).await;
+            // IQ responses are only routed while the client is being polled
+            loop {
+                tokio::select! {
+                    result = &mut response => return result,
+                    event = client.next() => {
+                        if event.is_none() {
+                            return (&mut response).await;
+                        }
+                    }
+                }
+            }
+        };
+
+        let healthy = match tokio::time::timeout(REQUEST_TIMEOUT, roster_fetch).await {
+            Ok(Ok(IqResponse::Result(_))) => true,
+            Ok(Ok(IqResponse::Error(e))) => {
+                tracing::debug!("XMPP health check: roster fetch failed: {:?}", e.defined_condition);
+                false
+            }
+            Ok(Err(e)) => {
+                tracing::debug!("XMPP health check: roster fetch failed: {}", e);
+                false
+            }
+            Err(_) => {
+                tracing::debug!("XMPP health check: roster fetch timed out");
+                false
+            }
+        };
+
+        if let Err(e) = client.send_end().await {
+            tracing::debug!("Failed to close XMPP connection: {}", e);
+        }
+        Ok(healthy)
+    }
+
+    fn get_name(&self) -> &str {
+        "xmpp"
+    }
+}
+
+#[async_trait]
+impl BidirectionalOutput for XmppOutput {
+    async fn send_message(&self, message: &str) -> Result<OutputResult> {
+        Output::send_message(self, message).await
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Output::health_check(self).await
+    }
+
+    fn get_name(&self) -> &str {
+        Output::get_name(self)
+    }
+
+    async fn check_for_responses(&self, since: Option<DateTime<Utc>>) -> Result<Vec<CheckinResponse>> {
+        let after = since.max(self.load_cursor()?.last_processed);
+        let messages = self.collect_messages().await?;
+        Ok(replies_from(messages, &self.to_jid, after))
+    }
+
+    async fn mark_processed_until(&self, timestamp: DateTime<Utc>) -> Result<()> {
+        let mut cursor = self.load_cursor()?;
+        if cursor.last_processed.is_some_and(|processed| processed >= timestamp) {
+            return Ok(());
+        }
+        cursor.last_processed = Some(timestamp);
+        self.save_cursor(&cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_xmpp::minidom::Element;
+
+    fn test_config() -> HashMap<String, String> {
+        let mut config = HashMap::new();
+        config.insert("jid".to_string(), "lastsignal@example.com".to_string());
+        config.insert("password".to_string(), "secret".to_string());
+        config.insert("to_jid".to_string(), "alice@example.org".to_string());
+        config
+    }
+
+    fn parse_message(xml: &str) -> Message {
+        Message::try_from(xml.parse::<Element>().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_xmpp_output_creation() {
+        let output = XmppOutput::new(&test_config(), None).unwrap();
+        assert_eq!(output.to_jid.as_str(), "alice@example.org");
+        assert_eq!(output.server, None);
+
+        let mut config = test_config();
+        config.insert("server".to_string(), "xmpp.example.com".to_string());
+        assert_eq!(XmppOutput::new(&config, None).unwrap().server, Some(("xmpp.example.com".to_string(), 5222)));
+        config.insert("server".to_string(), "xmpp.example.com:5223".to_string());
+        assert_eq!(XmppOutput::new(&config, None).unwrap().server, Some(("xmpp.example.com".to_string(), 5223)));
+    }
+
+    #[test]
+    fn test_xmpp_output_rejects_invalid_config() {
+        for key in ["jid", "password", "to_jid"] {
+            let mut config = test_config();
+            config.remove(key);
+            assert!(XmppOutput::new(&config, None).is_err(), "missing {} should be an error", key);
+        }
+
+        for (key, value) in [("jid", "example.com"), ("to_jid", "not a jid@"), ("server", "xmpp.example.com:port")] {
+            let mut config = test_config();
+            config.insert(key.to_string(), value.to_string());
+            assert!(XmppOutput::new(&config, None).is_err(), "{} = {} should be an error", key, value);
+        }
+    }
+
+    #[test]
+    fn test_replies_only_from_contact_after_watermark() {
+        let now = Utc::now();
+        let messages = [
+            // Held by the server while offline
+            r#"<message xmlns="jabber:client" from="alice@example.org/phone" type="chat"><body>ok</body><delay xmlns="urn:xmpp:delay" stamp="2025-06-15T12:00:00Z"/></message>"#,
+            r#"<message xmlns="jabber:client" from="alice@example.org/phone" type="chat"><body>old</body><delay xmlns="urn:xmpp:delay" stamp="2025-06-01T12:00:00Z"/></message>"#,
+            r#"<message xmlns="jabber:client" from="mallory@example.net/pc" type="chat"><body>ok</body></message>"#,
+            // Typing notifications have no body
+            r#"<message xmlns="jabber:client" from="alice@example.org/phone" type="chat"><composing xmlns="http://jabber.org/protocol/chatstates"/></message>"#,
+            r#"<message xmlns="jabber:client" from="alice@example.org/laptop" type="chat"><body>still here</body></message>"#,
+        ]
+        .into_iter()
+        .filter_map(|xml| received_message(parse_message(xml), now))
+        .collect();
+
+        let to_jid = BareJid::new("alice@example.org").unwrap();
+        let after = "2025-06-10T00:00:00Z".parse::<DateTime<Utc>>().ok();
+        let replies = replies_from(messages, &to_jid, after);
+        assert_eq!(replies.len(), 2);
+        match &replies[0] {
+            CheckinResponse::Found { timestamp, subject, from } => {
+                assert_eq!(*timestamp, "2025-06-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap());
+                assert_eq!(subject, "ok");
+                assert_eq!(from, "alice@example.org");
+            }
+        }
+        assert!(matches!(&replies[1], CheckinResponse::Found { timestamp, .. } if *timestamp == now));
+    }
+
+    #[tokio::test]
+    async fn test_mark_processed_until_persists_latest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = XmppOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(output.cursor_file, Some(temp_dir.path().join("xmpp_alice_example.org.json")));
+
+        let later = Utc::now();
+        output.mark_processed_until(later).await.unwrap();
+        output.mark_processed_until(later - chrono::Duration::hours(1)).await.unwrap();
+
+        let reloaded = XmppOutput::new(&test_config(), Some(temp_dir.path())).unwrap();
+        assert_eq!(reloaded.load_cursor().unwrap().last_processed, Some(later));
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set once at startup from `app.log_sensitive_payloads`
+static LOG_SENSITIVE_PAYLOADS: AtomicBool = AtomicBool::new(false);
+
+/// Field and query parameter names whose values are never written to logs
+const SECRET_KEYS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "password",
+    "token",
+    "code",
+];
+
+const REDACTED: &str = "[REDACTED]";
+
+pub fn set_log_sensitive_payloads(enabled: bool) {
+    LOG_SENSITIVE_PAYLOADS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether full payloads may be logged: only when opted in and tracing at trace level
+pub fn sensitive_payloads_enabled() -> bool {
+    LOG_SENSITIVE_PAYLOADS.load(Ordering::Relaxed) && tracing::enabled!(tracing::Level::TRACE)
+}
+
+/// Logs an API response body. By default only its size is logged, since
+/// bodies can hold health data, email metadata or tokens. With
+/// `log_sensitive_payloads` and trace logging the redacted body is logged.
+pub fn log_payload(label: &str, status: impl std::fmt::Display, body: &str) {
+    if sensitive_payloads_enabled() {
+        tracing::trace!("{} ({}): {}", label, status, redact(body));
+    } else {
+        tracing::debug!("{} ({}): {} bytes", label, status, body.len());
+    }
+}
+
+/// Replaces bearer tokens, secret query parameters and secret JSON fields with a placeholder
+pub fn redact(text: &str) -> String {
+    let mut redacted = redact_after(text, "Bearer ");
+    for key in SECRET_KEYS {
+        redacted = redact_after(&redacted, &format!("{}=", key));
+        redacted = redact_json_field(&redacted, key);
+    }
+    redacted
+}
+
+fn is_value_end(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '&' | '"' | '\'' | ',' | ';' | '}' | ')')
+}
+
+/// Whether `text[..index]` ends in the middle of a longer identifier, so
+/// `token=` doesn't match inside `refresh_token=`
+fn continues_identifier(text: &str, index: usize) -> bool {
+    text[..index].chars().next_back().is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Redacts the value following each occurrence of `marker`
+fn redact_after(text: &str, marker: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find(marker) {
+        let value_start = index + marker.len();
+        result.push_str(&rest[..value_start]);
+        let value_len = rest[value_start..].find(is_value_end).unwrap_or(rest.len() - value_start);
+
+        if value_len == 0 || continues_identifier(rest, index) || rest[value_start..].starts_with(REDACTED) {
+            result.push_str(&rest[value_start..value_start + value_len]);
+        } else {
+            result.push_str(REDACTED);
+        }
+        rest = &rest[value_start + value_len..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Redacts string values of `"key": "value"` pairs
+fn redact_json_field(text: &str, key: &str) -> String {
+    let quoted_key = format!("\"{}\"", key);
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find(&quoted_key) {
+        let after_key = index + quoted_key.len();
+        result.push_str(&rest[..after_key]);
+        rest = &rest[after_key..];
+
+        let trimmed = rest.trim_start();
+        let Some(after_colon) = trimmed.strip_prefix(':') else {
+            continue;
+        };
+        let Some(value) = after_colon.trim_start().strip_prefix('"') else {
+            continue;
+        };
+        let Some(value_len) = find_string_end(value) else {
+            continue;
+        };
+
+        let prefix_len = rest.len() - value.len();
+        result.push_str(&rest[..prefix_len]);
+        result.push_str(REDACTED);
+        rest = &value[value_len..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Byte length of a JSON string body up to (not including) its closing quote
+fn find_string_end(value: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_token_in_url() {
+        assert_eq!(
+            redact("GET https://graph.facebook.com/v18.0/me?access_token=EAAB123xyz&fields=name failed"),
+            "GET https://graph.facebook.com/v18.0/me?access_token=[REDACTED]&fields=name failed"
+        );
+    }
+
+    #[test]
+    fn test_redacts_bearer_and_form_secrets() {
+        assert_eq!(
+            redact("Authorization: Bearer abc.def.ghi"),
+            "Authorization: Bearer [REDACTED]"
+        );
+        assert_eq!(
+            redact("grant_type=refresh_token&client_secret=s3cret&refresh_token=r1"),
+            "grant_type=refresh_token&client_secret=[REDACTED]&refresh_token=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_redacts_json_secret_fields() {
+        let body = r#"{"access_token": "a\"b", "refresh_token":"r1", "expires_in": 3600, "scope": "offline"}"#;
+        assert_eq!(
+            redact(body),
+            r#"{"access_token": "[REDACTED]", "refresh_token":"[REDACTED]", "expires_in": 3600, "scope": "offline"}"#
+        );
+    }
+
+    #[test]
+    fn test_leaves_ordinary_text_alone() {
+        let text = "Checkin reply from someone@example.com: I'm fine, no token here";
+        assert_eq!(redact(text), text);
+    }
+
+    #[test]
+    fn test_payloads_not_logged_by_default() {
+        assert!(!sensitive_payloads_enabled());
+    }
+}
@@ -0,0 +1,471 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Days, Duration, LocalResult, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::duration_parser::ConfigDuration;
+use crate::state::AppState;
+
+// Durations are applied exactly, to the second. Nothing here rounds to days.
+
+fn to_chrono(duration: ConfigDuration) -> Duration {
+    i64::try_from(duration.as_secs())
+        .ok()
+        .and_then(Duration::try_seconds)
+        .unwrap_or(Duration::MAX)
+}
+
+/// Adds a configured duration to a point in time, saturating instead of overflowing
+pub fn add_duration(time: DateTime<Utc>, duration: ConfigDuration) -> DateTime<Utc> {
+    time.checked_add_signed(to_chrono(duration))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// How the check-in and last signal durations are laid onto the calendar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Anchor {
+    /// "7d" is exactly 7 × 24 hours later, so the local time shifts by an
+    /// hour across a DST change
+    #[default]
+    Elapsed,
+    /// "7d" is the same local time 7 days later. Any part of a duration
+    /// short of a whole day is still added as elapsed time.
+    WallClock,
+}
+
+/// An `Anchor` with the time zone wall-clock anchoring works in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Anchoring {
+    pub anchor: Anchor,
+    pub timezone: Tz,
+}
+
+impl Anchoring {
+    #[cfg(test)]
+    pub const ELAPSED: Anchoring = Anchoring { anchor: Anchor::Elapsed, timezone: Tz::UTC };
+
+    /// Adds a configured duration to a point in time according to the anchor
+    pub fn add(&self, time: DateTime<Utc>, duration: ConfigDuration) -> DateTime<Utc> {
+        match self.anchor {
+            Anchor::Elapsed => add_duration(time, duration),
+            Anchor::WallClock => add_wall_clock(time, duration, self.timezone),
+        }
+    }
+}
+
+impl fmt::Display for Anchoring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.anchor {
+            Anchor::Elapsed => write!(f, "elapsed"),
+            Anchor::WallClock => write!(f, "wall_clock ({})", self.timezone),
+        }
+    }
+}
+
+/// Adds the whole days of `duration` as calendar days in `timezone`, then the
+/// rest as elapsed time. A local time skipped by a spring-forward change
+/// lands the same elapsed distance past the change, and one repeated by a
+/// fall-back change resolves to its first occurrence.
+fn add_wall_clock(time: DateTime<Utc>, duration: ConfigDuration, timezone: Tz) -> DateTime<Utc> {
+    const DAY_SECS: u64 = 24 * 60 * 60;
+    let days = duration.as_secs() / DAY_SECS;
+    let rest = ConfigDuration::from_seconds(duration.as_secs() % DAY_SECS);
+
+    let Some(local) = time.with_timezone(&timezone).naive_local().checked_add_days(Days::new(days)) else {
+        return DateTime::<Utc>::MAX_UTC;
+    };
+    let shifted = match timezone.from_local_datetime(&local) {
+        LocalResult::Single(shifted) | LocalResult::Ambiguous(shifted, _) => Some(shifted.with_timezone(&Utc)),
+        // DST gaps are at most a few hours, so an hour before is a real local time
+        LocalResult::None => timezone.from_local_datetime(&(local - Duration::hours(1)))
+            .earliest()
+            .map(|before| before.with_timezone(&Utc) + Duration::hours(1)),
+    };
+
+    shifted
+        .map(|shifted| add_duration(shifted, rest))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// When the next check-in request becomes due. `None` means there has never
+/// been a check-in, so a request is due immediately.
+pub fn next_request_due(state: &AppState, duration_between_checkins: ConfigDuration, anchoring: Anchoring) -> Option<DateTime<Utc>> {
+    state.last_checkin
+        .map(|checkin| anchoring.add(checkin, duration_between_checkins))
+}
+
+/// When a check-in request that failed on every output is tried again.
+/// `None` unless one is waiting to be retried.
+pub fn checkin_request_retry_due(state: &AppState, output_retry_delay: ConfigDuration) -> Option<DateTime<Utc>> {
+    state.checkin_request_failed_at
+        .map(|failed| add_duration(failed, output_retry_delay))
+}
+
+/// A message asking the admin to check in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckinRequest {
+    /// A scheduled check-in request
+    Initial,
+    /// An escalating reminder after an unanswered request, numbered from 1
+    Reminder(usize),
+}
+
+/// The escalating reminder due at `now`: the latest level whose offset from
+/// `first_request` has passed, unless it's been sent. Levels missed while
+/// LastSignal wasn't running are skipped rather than sent late.
+pub fn escalation_level_due(first_request: DateTime<Utc>, escalation: &[ConfigDuration], sent: &BTreeSet<usize>, anchoring: Anchoring, now: DateTime<Utc>) -> Option<usize> {
+    let level = escalation.iter()
+        .rposition(|offset| is_reached(anchoring.add(first_request, *offset), now))?
+        + 1;
+    let already_sent = sent.last().is_some_and(|highest| *highest >= level);
+    (!already_sent).then_some(level)
+}
+
+/// The next escalating reminder still to come after `now`, and when
+pub fn next_escalation(first_request: DateTime<Utc>, escalation: &[ConfigDuration], sent: &BTreeSet<usize>, anchoring: Anchoring, now: DateTime<Utc>) -> Option<(usize, DateTime<Utc>)> {
+    let highest_sent = sent.last().copied().unwrap_or(0);
+    escalation.iter()
+        .enumerate()
+        .skip(highest_sent)
+        .map(|(index, offset)| (index + 1, anchoring.add(first_request, *offset)))
+        .find(|(_, due)| !is_reached(*due, now))
+}
+
+/// When the last signal fires if nobody checks in. Measured from the last
+/// check-in, or from the first unanswered request if there has never been one.
+/// `None` means there is nothing to measure from yet, so it cannot fire.
+pub fn last_signal_deadline(state: &AppState, max_time_since_last_checkin: ConfigDuration, anchoring: Anchoring) -> Option<DateTime<Utc>> {
+    state.last_checkin
+        .or(state.last_checkin_request)
+        .map(|anchor| anchoring.add(anchor, max_time_since_last_checkin))
+}
+
+/// When the warning that the last signal is about to fire goes out,
+/// `warn_before` ahead of `deadline`
+pub fn last_signal_warning_at(deadline: DateTime<Utc>, warn_before: ConfigDuration) -> DateTime<Utc> {
+    deadline.checked_sub_signed(to_chrono(warn_before))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+/// The most recent sign that someone looked at the configuration: the config
+/// file being edited, `lastsignal reviewed`, or a successful `test --send`
+pub fn last_configuration_review(state: &AppState, config_modified_at: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    [config_modified_at, state.last_reviewed, state.last_test_send]
+        .into_iter()
+        .flatten()
+        .max()
+}
+
+/// Whether the configuration has gone unreviewed for longer than `review_reminder_interval`.
+/// With nothing to go on we don't nag.
+pub fn review_due(last_review: Option<DateTime<Utc>>, review_reminder_interval: ConfigDuration, now: DateTime<Utc>) -> bool {
+    last_review.is_some_and(|reviewed| is_reached(add_duration(reviewed, review_reminder_interval), now))
+}
+
+/// Time left until `deadline`, or zero once it has passed
+pub fn time_remaining(deadline: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+    deadline.signed_duration_since(now).max(Duration::zero())
+}
+
+/// Whether `deadline` has been reached at `now`
+pub fn is_reached(deadline: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now >= deadline
+}
+
+/// Parses a user-supplied point in time: either RFC 3339, or `HH:MM` meaning
+/// that time today in `timezone`
+pub fn parse_local_time(input: &str, timezone: Tz, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let time = NaiveTime::parse_from_str(input, "%H:%M")
+        .with_context(|| format!("Invalid time '{}', expected HH:MM or RFC 3339", input))?;
+    let local = now.with_timezone(&timezone).date_naive().and_time(time);
+    timezone.from_local_datetime(&local)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+        .with_context(|| format!("{} does not exist in {} today", input, timezone))
+}
+
+/// A daily window such as "07:00-23:00", in local time. A window that ends
+/// before it starts wraps past midnight, so "23:00-07:00" covers the night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveHours {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl ActiveHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl FromStr for ActiveHours {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (start, end) = s.split_once('-')
+            .with_context(|| format!("Invalid active_hours '{}', expected a range like \"07:00-23:00\"", s))?;
+        let parse_time = |time: &str| NaiveTime::parse_from_str(time.trim(), "%H:%M")
+            .with_context(|| format!("Invalid time '{}' in active_hours, expected HH:MM", time.trim()));
+        let (start, end) = (parse_time(start)?, parse_time(end)?);
+
+        if start == end {
+            anyhow::bail!("active_hours '{}' starts and ends at the same time", s);
+        }
+
+        Ok(ActiveHours { start, end })
+    }
+}
+
+impl fmt::Display for ActiveHours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start.format("%H:%M"), self.end.format("%H:%M"))
+    }
+}
+
+impl Serialize for ActiveHours {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ActiveHours {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const YEAR_SECS: i64 = 365 * 24 * 60 * 60;
+
+    fn state_with(last_checkin: Option<DateTime<Utc>>, last_checkin_request: Option<DateTime<Utc>>) -> AppState {
+        AppState {
+            last_checkin,
+            last_checkin_request,
+            ..AppState::default()
+        }
+    }
+
+    #[test]
+    fn test_no_checkin_means_request_due_immediately() {
+        let state = AppState::default();
+        assert!(next_request_due(&state, ConfigDuration::from_days(7), Anchoring::ELAPSED).is_none());
+        assert!(last_signal_deadline(&state, ConfigDuration::from_days(14), Anchoring::ELAPSED).is_none());
+    }
+
+    #[test]
+    fn test_deadline_falls_back_to_first_request() {
+        let now = Utc::now();
+        let state = state_with(None, Some(now));
+        assert_eq!(
+            last_signal_deadline(&state, ConfigDuration::from_days(14), Anchoring::ELAPSED),
+            Some(now + Duration::days(14))
+        );
+    }
+
+    #[test]
+    fn test_sub_day_durations_are_exact() {
+        let now = Utc::now();
+        let state = state_with(Some(now - Duration::minutes(20)), None);
+        let due = next_request_due(&state, ConfigDuration::from_minutes(30), Anchoring::ELAPSED).unwrap();
+
+        assert!(!is_reached(due, now));
+        assert_eq!(time_remaining(due, now), Duration::minutes(10));
+    }
+
+    #[test]
+    fn test_escalation_levels() {
+        let first_request = Utc::now() - Duration::days(30);
+        let escalation = [ConfigDuration::from_days(2), ConfigDuration::from_days(4), ConfigDuration::from_days(6)];
+        let after = |days: i64| first_request + Duration::days(days);
+        let mut sent = BTreeSet::new();
+
+        assert_eq!(escalation_level_due(first_request, &escalation, &sent, Anchoring::ELAPSED, after(1)), None);
+        assert_eq!(next_escalation(first_request, &escalation, &sent, Anchoring::ELAPSED, after(1)), Some((1, after(2))));
+        assert_eq!(escalation_level_due(first_request, &escalation, &sent, Anchoring::ELAPSED, after(2)), Some(1));
+
+        sent.insert(1);
+        assert_eq!(escalation_level_due(first_request, &escalation, &sent, Anchoring::ELAPSED, after(3)), None);
+        assert_eq!(next_escalation(first_request, &escalation, &sent, Anchoring::ELAPSED, after(3)), Some((2, after(4))));
+
+        // A level missed while LastSignal was down is skipped for the latest one
+        assert_eq!(escalation_level_due(first_request, &escalation, &sent, Anchoring::ELAPSED, after(7)), Some(3));
+        sent.insert(3);
+        assert_eq!(escalation_level_due(first_request, &escalation, &sent, Anchoring::ELAPSED, after(7)), None);
+        assert_eq!(next_escalation(first_request, &escalation, &sent, Anchoring::ELAPSED, after(7)), None);
+    }
+
+    #[test]
+    fn test_review_due_uses_most_recent_review() {
+        let now = Utc::now();
+        let interval = ConfigDuration::from_days(180);
+        let mut state = AppState::default();
+        let old_config = Some(now - Duration::days(400));
+
+        assert!(!review_due(last_configuration_review(&state, None), interval, now));
+        assert!(review_due(last_configuration_review(&state, old_config), interval, now));
+
+        state.last_reviewed = Some(now - Duration::days(10));
+        assert!(!review_due(last_configuration_review(&state, old_config), interval, now));
+
+        state.last_reviewed = None;
+        state.last_test_send = Some(now - Duration::days(181));
+        assert!(review_due(last_configuration_review(&state, old_config), interval, now));
+    }
+
+    #[test]
+    fn test_active_hours() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        let day: ActiveHours = "07:00-23:00".parse().unwrap();
+        assert!(day.contains(time(7, 0)));
+        assert!(day.contains(time(22, 59)));
+        assert!(!day.contains(time(23, 0)));
+        assert!(!day.contains(time(3, 0)));
+
+        let night: ActiveHours = "23:00-07:00".parse().unwrap();
+        assert!(night.contains(time(23, 30)));
+        assert!(night.contains(time(6, 0)));
+        assert!(!night.contains(time(12, 0)));
+        assert_eq!(night.to_string(), "23:00-07:00");
+
+        assert!("07:00".parse::<ActiveHours>().is_err());
+        assert!("7am-11pm".parse::<ActiveHours>().is_err());
+        assert!("09:00-09:00".parse::<ActiveHours>().is_err());
+    }
+
+    #[test]
+    fn test_parse_local_time() {
+        let now = DateTime::parse_from_rfc3339("2025-06-21T10:00:00Z").unwrap().with_timezone(&Utc);
+
+        let london = parse_local_time("18:00", chrono_tz::Europe::London, now).unwrap();
+        assert_eq!(london.to_rfc3339(), "2025-06-21T17:00:00+00:00");
+
+        let exact = parse_local_time("2025-01-01T08:30:00+01:00", Tz::UTC, now).unwrap();
+        assert_eq!(exact.to_rfc3339(), "2025-01-01T07:30:00+00:00");
+
+        assert!(parse_local_time("6pm", Tz::UTC, now).is_err());
+    }
+
+    #[test]
+    fn test_add_duration_saturates() {
+        let huge = ConfigDuration::from_seconds(u64::MAX);
+        assert_eq!(add_duration(Utc::now(), huge), DateTime::<Utc>::MAX_UTC);
+    }
+
+    fn utc(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().with_timezone(&Utc)
+    }
+
+    const LONDON_WALL_CLOCK: Anchoring = Anchoring { anchor: Anchor::WallClock, timezone: chrono_tz::Europe::London };
+
+    #[test]
+    fn test_wall_clock_keeps_local_time_across_spring_forward() {
+        // 09:00 GMT the Sunday before the clocks go forward on 2025-03-30
+        let checkin = utc("2025-03-23T09:00:00Z");
+        let week = ConfigDuration::from_days(7);
+
+        assert_eq!(LONDON_WALL_CLOCK.add(checkin, week), utc("2025-03-30T08:00:00Z"));
+        assert_eq!(Anchoring::ELAPSED.add(checkin, week), utc("2025-03-30T09:00:00Z"));
+    }
+
+    #[test]
+    fn test_wall_clock_keeps_local_time_across_fall_back() {
+        // 09:00 BST the Sunday before the clocks go back on 2025-10-26
+        let checkin = utc("2025-10-19T08:00:00Z");
+        let week = ConfigDuration::from_days(7);
+
+        assert_eq!(LONDON_WALL_CLOCK.add(checkin, week), utc("2025-10-26T09:00:00Z"));
+        assert_eq!(Anchoring::ELAPSED.add(checkin, week), utc("2025-10-26T08:00:00Z"));
+    }
+
+    #[test]
+    fn test_wall_clock_resolves_skipped_and_repeated_local_times() {
+        // 01:30 local does not exist on 2025-03-30; it lands at 02:30 BST
+        assert_eq!(
+            LONDON_WALL_CLOCK.add(utc("2025-03-29T01:30:00Z"), ConfigDuration::from_days(1)),
+            utc("2025-03-30T01:30:00Z")
+        );
+        // 01:30 local happens twice on 2025-10-26; the first (BST) one is used
+        assert_eq!(
+            LONDON_WALL_CLOCK.add(utc("2025-10-25T00:30:00Z"), ConfigDuration::from_days(1)),
+            utc("2025-10-26T00:30:00Z")
+        );
+    }
+
+    #[test]
+    fn test_wall_clock_adds_part_days_as_elapsed_time() {
+        // 36h from 20:00 GMT on 2025-03-29: 20:00 BST on 03-30, then 12 hours
+        let start = utc("2025-03-29T20:00:00Z");
+        assert_eq!(LONDON_WALL_CLOCK.add(start, ConfigDuration::from_hours(36)), utc("2025-03-31T07:00:00Z"));
+        assert_eq!(Anchoring::ELAPSED.add(start, ConfigDuration::from_hours(36)), utc("2025-03-31T08:00:00Z"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_checkin_pushes_deadline_forward(
+            checkin_ago in proptest::option::of(0..YEAR_SECS),
+            request_ago in proptest::option::of(0..YEAR_SECS),
+            max_secs in 1..(10 * YEAR_SECS as u64),
+        ) {
+            let now = Utc::now();
+            let max_time = ConfigDuration::from_seconds(max_secs);
+            let before = state_with(
+                checkin_ago.map(|secs| now - Duration::seconds(secs)),
+                request_ago.map(|secs| now - Duration::seconds(secs)),
+            );
+            let after = state_with(Some(now), before.last_checkin_request);
+
+            let new_deadline = last_signal_deadline(&after, max_time, Anchoring::ELAPSED).unwrap();
+            if let Some(old_deadline) = last_signal_deadline(&before, max_time, Anchoring::ELAPSED) {
+                prop_assert!(new_deadline >= old_deadline);
+            }
+            prop_assert!(!is_reached(new_deadline, now));
+        }
+
+        #[test]
+        fn prop_deadlines_monotone_in_duration(
+            checkin_ago in 0..YEAR_SECS,
+            a in 1..(10 * YEAR_SECS as u64),
+            b in 1..(10 * YEAR_SECS as u64),
+        ) {
+            let now = Utc::now();
+            let state = state_with(Some(now - Duration::seconds(checkin_ago)), None);
+            let (shorter, longer) = (ConfigDuration::from_seconds(a.min(b)), ConfigDuration::from_seconds(a.max(b)));
+
+            prop_assert!(last_signal_deadline(&state, shorter, Anchoring::ELAPSED) <= last_signal_deadline(&state, longer, Anchoring::ELAPSED));
+            prop_assert!(next_request_due(&state, shorter, Anchoring::ELAPSED) <= next_request_due(&state, longer, Anchoring::ELAPSED));
+        }
+
+        #[test]
+        fn prop_time_remaining_consistent_with_is_reached(
+            offset in -YEAR_SECS..YEAR_SECS,
+        ) {
+            let now = Utc::now();
+            let deadline = now + Duration::seconds(offset);
+            let remaining = time_remaining(deadline, now);
+
+            prop_assert!(remaining >= Duration::zero());
+            prop_assert_eq!(remaining == Duration::zero(), is_reached(deadline, now));
+            if !is_reached(deadline, now) {
+                prop_assert_eq!(now + remaining, deadline);
+            }
+        }
+    }
+}
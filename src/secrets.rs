@@ -0,0 +1,231 @@
+use age::secrecy::SecretString;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Set once at startup from the `[encryption]` section. Without it secret
+/// files are written in plaintext.
+static PASSPHRASE: OnceLock<SecretString> = OnceLock::new();
+
+/// scrypt cost for encrypting secret files. They're decrypted on every
+/// check, so this is lower than age's calibrated default of about a second.
+#[cfg(not(test))]
+const SCRYPT_WORK_FACTOR: u8 = 15;
+/// Unoptimized scrypt takes seconds at the real cost
+#[cfg(test)]
+const SCRYPT_WORK_FACTOR: u8 = 10;
+
+const ARMOR_HEADER: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// Encrypts token files in the data directory with a passphrase
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EncryptionConfig {
+    /// File holding the passphrase, e.g. on a tmpfs or from a secrets manager
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase_file: Option<String>,
+    /// Environment variable holding the passphrase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase_env: Option<String>,
+}
+
+impl EncryptionConfig {
+    pub fn validate(&self) -> Result<()> {
+        match (&self.passphrase_file, &self.passphrase_env) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => anyhow::bail!("[encryption] needs exactly one of passphrase_file or passphrase_env"),
+        }
+    }
+
+    pub fn passphrase(&self) -> Result<SecretString> {
+        let passphrase = match (&self.passphrase_file, &self.passphrase_env) {
+            (Some(path), None) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read encryption passphrase_file {}", path))?
+                .trim_end_matches(['\r', '\n'])
+                .to_string(),
+            (None, Some(name)) => std::env::var(name)
+                .with_context(|| format!("Encryption passphrase_env {} is not set", name))?,
+            _ => anyhow::bail!("[encryption] needs exactly one of passphrase_file or passphrase_env"),
+        };
+        if passphrase.is_empty() {
+            anyhow::bail!("The encryption passphrase is empty");
+        }
+        Ok(SecretString::from(passphrase))
+    }
+}
+
+pub fn set_passphrase(passphrase: SecretString) {
+    let _ = PASSPHRASE.set(passphrase);
+}
+
+/// Writes a secret file, encrypted when a passphrase is set
+pub fn write(path: &Path, contents: &str) -> Result<()> {
+    let contents = match PASSPHRASE.get() {
+        Some(passphrase) => encrypt(passphrase, contents)?,
+        None => contents.to_string(),
+    };
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {:?}", path))
+}
+
+/// Reads a secret file, decrypting it if it's encrypted. Plaintext files are
+/// read as they are, so they keep working until `migrate-secrets` runs.
+pub fn read(path: &Path) -> Result<String> {
+    let contents = std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+    if !is_encrypted(&contents) {
+        return Ok(contents);
+    }
+    let passphrase = PASSPHRASE.get().with_context(|| {
+        format!("{:?} is encrypted, but there's no [encryption] section in the config to decrypt it with", path)
+    })?;
+    decrypt(passphrase, &contents).with_context(|| {
+        format!("Failed to decrypt {:?}. Check that the [encryption] passphrase is the one it was encrypted with", path)
+    })
+}
+
+fn is_encrypted(contents: &str) -> bool {
+    contents.trim_start().starts_with(ARMOR_HEADER)
+}
+
+fn encrypt(passphrase: &SecretString, plaintext: &str) -> Result<String> {
+    let mut recipient = age::scrypt::Recipient::new(passphrase.clone());
+    recipient.set_work_factor(SCRYPT_WORK_FACTOR);
+    let encryptor = age::Encryptor::with_recipients(std::iter::once(&recipient as &dyn age::Recipient))
+        .context("Failed to set up encryption")?;
+
+    let mut ciphertext = Vec::new();
+    let armored = age::armor::ArmoredWriter::wrap_output(&mut ciphertext, age::armor::Format::AsciiArmor)
+        .context("Failed to armor encrypted file")?;
+    let mut writer = encryptor.wrap_output(armored).context("Failed to encrypt")?;
+    writer.write_all(plaintext.as_bytes()).context("Failed to encrypt")?;
+    writer.finish()
+        .and_then(|armored| armored.finish())
+        .context("Failed to finish encrypted file")?;
+    String::from_utf8(ciphertext).context("Armored file is not UTF-8")
+}
+
+fn decrypt(passphrase: &SecretString, armored: &str) -> Result<String> {
+    let decryptor = age::Decryptor::new(age::armor::ArmoredReader::new(armored.as_bytes()))
+        .context("Not a valid encrypted file")?;
+    let identity = age::scrypt::Identity::new(passphrase.clone());
+    let mut plaintext = String::new();
+    decryptor.decrypt(std::iter::once(&identity as &dyn age::Identity))
+        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .read_to_string(&mut plaintext)
+        .context("Failed to read decrypted contents")?;
+    Ok(plaintext)
+}
+
+/// What `migrate-secrets` did with one file
+#[derive(Debug, PartialEq)]
+pub enum Migration {
+    Encrypted,
+    AlreadyEncrypted,
+    Missing,
+}
+
+/// Encrypts `path` in place if it's plaintext
+fn migrate_file(passphrase: &SecretString, path: &Path) -> Result<Migration> {
+    if !path.exists() {
+        return Ok(Migration::Missing);
+    }
+    let contents = std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+    if is_encrypted(&contents) {
+        return Ok(Migration::AlreadyEncrypted);
+    }
+
+    // Write beside it and rename, so an interruption can't lose the tokens
+    let encrypted = encrypt(passphrase, &contents)?;
+    let temp_path = path.with_extension("encrypting");
+    std::fs::write(&temp_path, encrypted).with_context(|| format!("Failed to write {:?}", temp_path))?;
+    std::fs::rename(&temp_path, path).with_context(|| format!("Failed to replace {:?}", path))?;
+    Ok(Migration::Encrypted)
+}
+
+/// `lastsignal migrate-secrets`: encrypts each of `files` that is still plaintext
+pub fn run_migrate_secrets(encryption: Option<&EncryptionConfig>, files: &[PathBuf]) -> Result<()> {
+    let encryption = encryption.context("Add an [encryption] section to the config first, with passphrase_file or passphrase_env")?;
+    let passphrase = encryption.passphrase()?;
+
+    let mut encrypted = 0;
+    for path in files {
+        match migrate_file(&passphrase, path)? {
+            Migration::Encrypted => {
+                println!("🔒 Encrypted {}", path.display());
+                encrypted += 1;
+            }
+            Migration::AlreadyEncrypted => println!("✅ Already encrypted: {}", path.display()),
+            Migration::Missing => tracing::debug!("No {:?} to encrypt", path),
+        }
+    }
+    println!("Encrypted {} file(s).", encrypted);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use age::secrecy::ExposeSecret;
+
+    fn passphrase(value: &str) -> SecretString {
+        SecretString::from(value.to_string())
+    }
+
+    #[test]
+    fn test_encrypt_round_trip() {
+        let tokens = r#"{"access_token": "a", "refresh_token": "r"}"#;
+        let encrypted = encrypt(&passphrase("correct horse"), tokens).unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("refresh_token"));
+        assert_eq!(decrypt(&passphrase("correct horse"), &encrypted).unwrap(), tokens);
+
+        assert!(decrypt(&passphrase("battery staple"), &encrypted).is_err());
+        assert!(!is_encrypted(tokens));
+    }
+
+    #[test]
+    fn test_migrate_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("whoop_tokens.json");
+        let passphrase = passphrase("correct horse");
+        assert_eq!(migrate_file(&passphrase, &path).unwrap(), Migration::Missing);
+
+        std::fs::write(&path, r#"{"refresh_token": "r"}"#).unwrap();
+        assert_eq!(migrate_file(&passphrase, &path).unwrap(), Migration::Encrypted);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(decrypt(&passphrase, &contents).unwrap(), r#"{"refresh_token": "r"}"#);
+
+        assert_eq!(migrate_file(&passphrase, &path).unwrap(), Migration::AlreadyEncrypted);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), contents);
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_read_without_passphrase() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let plaintext = temp_dir.path().join("plain.json");
+        std::fs::write(&plaintext, "{}").unwrap();
+        assert_eq!(read(&plaintext).unwrap(), "{}");
+
+        // Tests never set the passphrase, so an encrypted file can't be read
+        let encrypted = temp_dir.path().join("gmail_tokens.json");
+        std::fs::write(&encrypted, encrypt(&passphrase("correct horse"), "{}").unwrap()).unwrap();
+        let error = format!("{:#}", read(&encrypted).unwrap_err());
+        assert!(error.contains("gmail_tokens.json") && error.contains("[encryption]"), "{}", error);
+    }
+
+    #[test]
+    fn test_encryption_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("passphrase");
+        std::fs::write(&path, "correct horse\n").unwrap();
+        let config = EncryptionConfig { passphrase_file: Some(path.display().to_string()), passphrase_env: None };
+        config.validate().unwrap();
+        assert_eq!(config.passphrase().unwrap().expose_secret(), "correct horse");
+
+        let config = EncryptionConfig { passphrase_file: None, passphrase_env: Some("LASTSIGNAL_TEST_UNSET_PASSPHRASE".to_string()) };
+        assert!(config.passphrase().unwrap_err().to_string().contains("LASTSIGNAL_TEST_UNSET_PASSPHRASE"));
+
+        assert!(EncryptionConfig { passphrase_file: None, passphrase_env: None }.validate().is_err());
+    }
+}
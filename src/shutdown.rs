@@ -0,0 +1,80 @@
+use anyhow::{Context, Result};
+use tokio::sync::watch;
+
+/// Tells the daemon to stop at its next safe point, once SIGINT or SIGTERM
+/// (Ctrl-C on Windows) arrives. Sends already under way are finished first.
+#[derive(Debug, Clone)]
+pub struct Shutdown {
+    requested: watch::Receiver<bool>,
+}
+
+impl Shutdown {
+    /// Listens for the signals that ask the daemon to stop
+    pub fn on_signals() -> Result<Self> {
+        let (sender, shutdown) = Self::channel();
+
+        #[cfg(unix)]
+        let mut terminate = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .context("Failed to listen for SIGTERM")?;
+        tokio::spawn(async move {
+            #[cfg(unix)]
+            let signal = tokio::select! {
+                _ = tokio::signal::ctrl_c() => "SIGINT",
+                _ = terminate.recv() => "SIGTERM",
+            };
+            #[cfg(not(unix))]
+            let signal = match tokio::signal::ctrl_c().await {
+                Ok(()) => "Ctrl-C",
+                Err(e) => {
+                    tracing::error!("Failed to listen for Ctrl-C: {}", e);
+                    return;
+                }
+            };
+            tracing::warn!("Received {}, shutting down after the current step", signal);
+            let _ = sender.send(true);
+        });
+        Ok(shutdown)
+    }
+
+    /// For commands that run once, which never stop early
+    pub fn never() -> Self {
+        Self::channel().1
+    }
+
+    /// A shutdown that's requested by sending `true`
+    pub fn channel() -> (watch::Sender<bool>, Self) {
+        let (sender, requested) = watch::channel(false);
+        (sender, Self { requested })
+    }
+
+    pub fn is_requested(&self) -> bool {
+        *self.requested.borrow()
+    }
+
+    /// Resolves once shutdown is requested, or never if it can't be
+    pub async fn requested(&mut self) {
+        if self.requested.wait_for(|requested| *requested).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_shutdown_channel() {
+        let (sender, mut shutdown) = Shutdown::channel();
+        assert!(!shutdown.is_requested());
+
+        sender.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), shutdown.requested()).await.unwrap();
+        assert!(shutdown.is_requested());
+
+        // Without a sender it waits forever
+        let mut never = Shutdown::never();
+        assert!(tokio::time::timeout(Duration::from_millis(50), never.requested()).await.is_err());
+    }
+}
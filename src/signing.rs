@@ -0,0 +1,35 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// HMAC-SHA256 of `data` keyed with `key`. Callers either finalize it or
+/// check a received signature with `verify_slice`, which compares in
+/// constant time, and can `update` it with more data first.
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Hmac<Sha256> {
+    // HMAC accepts keys of any length, so this cannot fail
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data);
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256_matches_rfc_4231() {
+        // RFC 4231 test case 2
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex::encode(mac.finalize().into_bytes()),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256_update_continues_the_data() {
+        let mut split = hmac_sha256(b"key", b"1700000000");
+        split.update(b"body");
+        let whole = hmac_sha256(b"key", b"1700000000body");
+        assert_eq!(split.finalize().into_bytes(), whole.finalize().into_bytes());
+    }
+}
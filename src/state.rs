@@ -1,10 +1,12 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 use crate::duration_parser::ConfigDuration;
+use crate::notifier::NotifierState;
+use crate::schedule::{self, Anchoring, CheckinRequest};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AppState {
@@ -18,6 +20,60 @@ pub struct AppState {
     /// Value is timestamp when successfully sent
     #[serde(default)]
     pub last_signal_recipients_notified: HashMap<String, DateTime<Utc>>,
+    /// Most recent time each recipient was sent a last signal. Unlike
+    /// `last_signal_recipients_notified` this survives check-ins, so the
+    /// re-notification floor holds across resets
+    #[serde(default)]
+    pub last_signal_recipient_history: HashMap<String, DateTime<Utc>>,
+    /// Recipients who confirmed they saw the last signal, by acknowledgement
+    /// link or email reply, and when. Cleared on check-in.
+    #[serde(default)]
+    pub last_signal_acknowledgements: HashMap<String, DateTime<Utc>>,
+    /// When the configuration was last confirmed as up to date with `lastsignal reviewed`
+    #[serde(default)]
+    pub last_reviewed: Option<DateTime<Utc>>,
+    /// When `lastsignal test --send` last delivered through every checkin output
+    #[serde(default)]
+    pub last_test_send: Option<DateTime<Utc>>,
+    /// Pending warnings and cooldowns for the internal warning notifier
+    #[serde(default)]
+    pub notifier: NotifierState,
+    /// SHA-256 of the config file as last loaded, to notice when it changes
+    #[serde(default)]
+    pub config_hash: Option<String>,
+    /// Description of a config change not yet mentioned in a check-in request
+    #[serde(default)]
+    pub unannounced_config_change: Option<String>,
+    /// Recent send times per provider, so rate limits hold across restarts
+    #[serde(default)]
+    pub provider_sends: HashMap<String, Vec<DateTime<Utc>>>,
+    /// Canary recipients sent the last signal by the latest rehearsal, and
+    /// when. Kept apart from `last_signal_recipients_notified`.
+    #[serde(default)]
+    pub canary_recipients_notified: HashMap<String, DateTime<Utc>>,
+    /// When a rehearsal last reached every canary recipient
+    #[serde(default)]
+    pub last_successful_rehearsal: Option<DateTime<Utc>>,
+    /// When a check-in request last failed on every output. It's retried once
+    /// `output_retry_delay` has passed, until one gets through.
+    #[serde(default)]
+    pub checkin_request_failed_at: Option<DateTime<Utc>>,
+    /// When the first check-in request since the last check-in was sent.
+    /// Escalating reminders are timed from it.
+    #[serde(default)]
+    pub first_unanswered_request: Option<DateTime<Utc>>,
+    /// Escalating reminders sent since the last check-in, numbered from 1
+    #[serde(default)]
+    pub escalation_levels_sent: BTreeSet<usize>,
+    /// When each recipient was last sent the last signal or tried, so one
+    /// that wasn't reached is retried after `recipient.output_retry_delay`.
+    /// Cleared on check-in.
+    #[serde(default)]
+    pub last_signal_recipient_attempts: HashMap<String, DateTime<Utc>>,
+    /// When the warning that the last signal is about to fire was sent.
+    /// Cleared on check-in, so each overdue period gets one.
+    #[serde(default)]
+    pub last_signal_warning_sent: Option<DateTime<Utc>>,
 }
 
 impl Default for AppState {
@@ -29,6 +85,21 @@ impl Default for AppState {
             checkin_request_count: 0,
             version: env!("CARGO_PKG_VERSION").to_string(),
             last_signal_recipients_notified: HashMap::new(),
+            last_signal_recipient_history: HashMap::new(),
+            last_signal_acknowledgements: HashMap::new(),
+            last_reviewed: None,
+            last_test_send: None,
+            notifier: NotifierState::default(),
+            config_hash: None,
+            unannounced_config_change: None,
+            provider_sends: HashMap::new(),
+            canary_recipients_notified: HashMap::new(),
+            last_successful_rehearsal: None,
+            checkin_request_failed_at: None,
+            first_unanswered_request: None,
+            escalation_levels_sent: BTreeSet::new(),
+            last_signal_recipient_attempts: HashMap::new(),
+            last_signal_warning_sent: None,
         }
     }
 }
@@ -68,12 +139,33 @@ impl AppState {
         tracing::info!("Recording checkin at {}", Utc::now());
         self.last_checkin = Some(Utc::now());
         self.checkin_request_count = 0;
+        self.checkin_request_failed_at = None;
+        self.first_unanswered_request = None;
+        self.escalation_levels_sent.clear();
+        self.last_signal_warning_sent = None;
     }
 
     pub fn record_checkin_request(&mut self) {
         tracing::info!("Recording checkin request at {}", Utc::now());
         self.last_checkin_request = Some(Utc::now());
         self.checkin_request_count += 1;
+        self.checkin_request_failed_at = None;
+        self.first_unanswered_request.get_or_insert(Utc::now());
+    }
+
+    pub fn record_escalation_reminder(&mut self, level: usize) {
+        self.record_checkin_request();
+        self.escalation_levels_sent.insert(level);
+    }
+
+    pub fn record_checkin_request_failed(&mut self) {
+        tracing::info!("Recording failed checkin request at {}", Utc::now());
+        self.checkin_request_failed_at = Some(Utc::now());
+    }
+
+    pub fn record_last_signal_warning(&mut self) {
+        tracing::info!("Recording last signal warning sent at {}", Utc::now());
+        self.last_signal_warning_sent = Some(Utc::now());
     }
 
     pub fn record_last_signal_fired(&mut self) {
@@ -85,6 +177,85 @@ impl AppState {
         let now = Utc::now();
         tracing::info!("Recording last signal sent to recipient {} at {}", recipient_id, now);
         self.last_signal_recipients_notified.insert(recipient_id.to_string(), now);
+        self.last_signal_recipient_history.insert(recipient_id.to_string(), now);
+    }
+
+    pub fn record_last_signal_attempt(&mut self, recipient_id: &str) {
+        self.last_signal_recipient_attempts.insert(recipient_id.to_string(), Utc::now());
+    }
+
+    /// When a recipient the last signal hasn't reached can be tried again,
+    /// if that's still to come
+    pub fn last_signal_retry_waiting_until(&self, recipient_id: &str, output_retry_delay: ConfigDuration) -> Option<DateTime<Utc>> {
+        self.last_signal_recipient_attempts.get(recipient_id)
+            .map(|attempted| schedule::add_duration(*attempted, output_retry_delay))
+            .filter(|retry_at| !schedule::is_reached(*retry_at, Utc::now()))
+    }
+
+    /// Whether any of `recipient_ids` still needs the last signal and is due
+    /// an attempt: never tried, or last tried `output_retry_delay` ago. A
+    /// recipient `min_renotify_interval` holds back isn't due until it ends.
+    pub fn last_signal_retry_due(&self, recipient_ids: &[String], output_retry_delay: ConfigDuration, min_renotify_interval: ConfigDuration) -> bool {
+        self.get_pending_last_signal_recipients(recipient_ids).iter()
+            .filter(|recipient_id| !self.last_signal_acknowledgements.contains_key(*recipient_id))
+            .filter(|recipient_id| self.recently_notified_at(recipient_id, min_renotify_interval).is_none())
+            .any(|recipient_id| self.last_signal_retry_waiting_until(recipient_id, output_retry_delay).is_none())
+    }
+
+    /// Returns when the recipient was last sent a last signal, if that was
+    /// less than `min_renotify_interval` ago
+    pub fn recently_notified_at(&self, recipient_id: &str, min_renotify_interval: ConfigDuration) -> Option<DateTime<Utc>> {
+        self.last_signal_recipient_history.get(recipient_id)
+            .copied()
+            .filter(|sent_at| {
+                Utc::now().signed_duration_since(*sent_at).num_seconds() < min_renotify_interval.as_secs() as i64
+            })
+    }
+
+    /// Records that the recipient acknowledged the last signal. Returns
+    /// `false` if they already had, keeping the first acknowledgement.
+    pub fn record_last_signal_acknowledged(&mut self, recipient_id: &str, at: DateTime<Utc>) -> bool {
+        if self.last_signal_acknowledgements.contains_key(recipient_id) {
+            return false;
+        }
+        tracing::info!("Recording last signal acknowledged by recipient {} at {}", recipient_id, at);
+        self.last_signal_acknowledgements.insert(recipient_id.to_string(), at);
+        true
+    }
+
+    /// A rehearsal notifies every canary afresh
+    pub fn start_rehearsal(&mut self) {
+        tracing::info!("Starting last signal rehearsal at {}", Utc::now());
+        self.canary_recipients_notified.clear();
+    }
+
+    pub fn record_canary_recipient_notified(&mut self, recipient_id: &str) {
+        let now = Utc::now();
+        tracing::info!("Recording rehearsal sent to canary recipient {} at {}", recipient_id, now);
+        self.canary_recipients_notified.insert(recipient_id.to_string(), now);
+    }
+
+    pub fn record_successful_rehearsal(&mut self) {
+        tracing::info!("Recording successful rehearsal at {}", Utc::now());
+        self.last_successful_rehearsal = Some(Utc::now());
+    }
+
+    pub fn record_reviewed(&mut self) {
+        tracing::info!("Recording configuration review at {}", Utc::now());
+        self.last_reviewed = Some(Utc::now());
+    }
+
+    pub fn record_test_send(&mut self) {
+        tracing::info!("Recording successful test send at {}", Utc::now());
+        self.last_test_send = Some(Utc::now());
+    }
+
+    pub fn record_config_change(&mut self, hash: String, description: Option<String>) {
+        tracing::info!("Recording configuration hash {}", hash);
+        self.config_hash = Some(hash);
+        if description.is_some() {
+            self.unannounced_config_change = description;
+        }
     }
 
     pub fn is_last_signal_recipient_already_notified(&self, recipient_id: &str) -> bool {
@@ -101,68 +272,57 @@ impl AppState {
     pub fn clear_last_signal_recipient_tracking(&mut self) {
         tracing::info!("Clearing last signal recipient tracking");
         self.last_signal_recipients_notified.clear();
+        self.last_signal_acknowledgements.clear();
+        self.last_signal_recipient_attempts.clear();
         self.last_signal_fired = None;
     }
 
-    pub fn days_since_last_checkin(&self) -> Option<i64> {
-        self.last_checkin.map(|checkin_time| {
-            let duration = Utc::now().signed_duration_since(checkin_time);
-            duration.num_days()
-        })
-    }
-
-    pub fn days_since_last_checkin_request(&self) -> Option<i64> {
-        self.last_checkin_request.map(|request_time| {
-            let duration = Utc::now().signed_duration_since(request_time);
-            duration.num_days()
-        })
-    }
-
-    pub fn days_since_last_signal_fired(&self) -> Option<i64> {
-        self.last_signal_fired.map(|signal_time| {
-            let duration = Utc::now().signed_duration_since(signal_time);
-            duration.num_days()
-        })
-    }
-
-    pub fn should_request_checkin(&self, duration_between_checkins: ConfigDuration) -> bool {
-        match self.last_checkin {
+    pub fn should_request_checkin(&self, duration_between_checkins: ConfigDuration, output_retry_delay: ConfigDuration, anchoring: Anchoring) -> bool {
+        // A request that failed everywhere is retried on its own schedule
+        if let Some(retry_due) = schedule::checkin_request_retry_due(self, output_retry_delay) {
+            return schedule::is_reached(retry_due, Utc::now());
+        }
+        match schedule::next_request_due(self, duration_between_checkins, anchoring) {
             None => true, // Never checked in before
-            Some(_) => {
-                let days_since = self.days_since_last_checkin().unwrap_or(0);
-                days_since >= duration_between_checkins.as_days() as i64
-            }
+            Some(due) => schedule::is_reached(due, Utc::now()),
         }
     }
 
-    pub fn should_fire_last_signal(&self, max_time_since_last_checkin: ConfigDuration) -> bool {
-        match self.last_checkin {
-            None => {
-                // If we've never had a checkin, we need to look at how long we've been running
-                // For now, we'll be conservative and only fire if we've explicitly been requesting checkins
-                match self.last_checkin_request {
-                    None => false,
-                    Some(_) => {
-                        let days_since_request = self.days_since_last_checkin_request().unwrap_or(0);
-                        days_since_request >= max_time_since_last_checkin.as_days() as i64
-                    }
-                }
-            }
-            Some(_) => {
-                let days_since_checkin = self.days_since_last_checkin().unwrap_or(0);
-                days_since_checkin >= max_time_since_last_checkin.as_days() as i64
-            }
+    /// What to send the admin this cycle. With `escalation` configured, the
+    /// first unanswered request is followed only by the escalating reminders
+    /// rather than a request every cycle.
+    pub fn checkin_request_due(&self, duration_between_checkins: ConfigDuration, output_retry_delay: ConfigDuration, escalation: &[ConfigDuration], anchoring: Anchoring) -> Option<CheckinRequest> {
+        let Some(first_request) = self.first_unanswered_request.filter(|_| !escalation.is_empty()) else {
+            return self.should_request_checkin(duration_between_checkins, output_retry_delay, anchoring)
+                .then_some(CheckinRequest::Initial);
+        };
+        let now = Utc::now();
+        if schedule::checkin_request_retry_due(self, output_retry_delay).is_some_and(|retry_due| !schedule::is_reached(retry_due, now)) {
+            return None;
         }
+        schedule::escalation_level_due(first_request, escalation, &self.escalation_levels_sent, anchoring, now)
+            .map(CheckinRequest::Reminder)
     }
 
-    pub fn has_fired_last_signal_recently(&self, max_time_since_last_checkin: ConfigDuration) -> bool {
-        match self.last_signal_fired {
-            None => false,
-            Some(_) => {
-                let days_since_signal = self.days_since_last_signal_fired().unwrap_or(i64::MAX);
-                days_since_signal < max_time_since_last_checkin.as_days() as i64
-            }
+    /// Whether to warn that the last signal is about to fire: within
+    /// `warn_before` of the deadline, and not yet warned this overdue period.
+    /// Once the deadline has passed there's nothing left to warn about.
+    pub fn should_warn_before_last_signal(&self, max_time_since_last_checkin: ConfigDuration, warn_before: ConfigDuration, anchoring: Anchoring) -> bool {
+        if self.last_signal_warning_sent.is_some() || self.last_signal_fired.is_some() {
+            return false;
         }
+        let now = Utc::now();
+        schedule::last_signal_deadline(self, max_time_since_last_checkin, anchoring)
+            .is_some_and(|deadline| {
+                schedule::is_reached(schedule::last_signal_warning_at(deadline, warn_before), now) && !schedule::is_reached(deadline, now)
+            })
+    }
+
+    pub fn should_fire_last_signal(&self, max_time_since_last_checkin: ConfigDuration, anchoring: Anchoring) -> bool {
+        // If we've never had a checkin, the deadline runs from the first checkin request;
+        // with neither there is nothing to measure from, so we never fire
+        schedule::last_signal_deadline(self, max_time_since_last_checkin, anchoring)
+            .is_some_and(|deadline| schedule::is_reached(deadline, Utc::now()))
     }
 }
 
@@ -186,6 +346,7 @@ impl StateManager {
         &self.state
     }
 
+    #[allow(dead_code)]
     pub fn get_state_mut(&mut self) -> &mut AppState {
         &mut self.state
     }
@@ -204,6 +365,21 @@ impl StateManager {
         self.save()
     }
 
+    pub fn record_escalation_reminder(&mut self, level: usize) -> Result<()> {
+        self.state.record_escalation_reminder(level);
+        self.save()
+    }
+
+    pub fn record_checkin_request_failed(&mut self) -> Result<()> {
+        self.state.record_checkin_request_failed();
+        self.save()
+    }
+
+    pub fn record_last_signal_warning(&mut self) -> Result<()> {
+        self.state.record_last_signal_warning();
+        self.save()
+    }
+
     pub fn record_last_signal_fired(&mut self) -> Result<()> {
         self.state.record_last_signal_fired();
         self.save()
@@ -214,10 +390,72 @@ impl StateManager {
         self.save()
     }
 
+    pub fn record_last_signal_attempt(&mut self, recipient_id: &str) -> Result<()> {
+        self.state.record_last_signal_attempt(recipient_id);
+        self.save()
+    }
+
     pub fn clear_last_signal_recipient_tracking(&mut self) -> Result<()> {
         self.state.clear_last_signal_recipient_tracking();
         self.save()
     }
+
+    pub fn record_last_signal_acknowledged(&mut self, recipient_id: &str, at: DateTime<Utc>) -> Result<bool> {
+        let recorded = self.state.record_last_signal_acknowledged(recipient_id, at);
+        if recorded {
+            self.save()?;
+        }
+        Ok(recorded)
+    }
+
+    pub fn start_rehearsal(&mut self) -> Result<()> {
+        self.state.start_rehearsal();
+        self.save()
+    }
+
+    pub fn record_canary_recipient_notified(&mut self, recipient_id: &str) -> Result<()> {
+        self.state.record_canary_recipient_notified(recipient_id);
+        self.save()
+    }
+
+    pub fn record_successful_rehearsal(&mut self) -> Result<()> {
+        self.state.record_successful_rehearsal();
+        self.save()
+    }
+
+    pub fn record_reviewed(&mut self) -> Result<()> {
+        self.state.record_reviewed();
+        self.save()
+    }
+
+    pub fn record_test_send(&mut self) -> Result<()> {
+        self.state.record_test_send();
+        self.save()
+    }
+
+    pub fn record_config_change(&mut self, hash: String, description: Option<String>) -> Result<()> {
+        self.state.record_config_change(hash, description);
+        self.save()
+    }
+
+    pub fn clear_unannounced_config_change(&mut self) -> Result<()> {
+        self.state.unannounced_config_change = None;
+        self.save()
+    }
+
+    pub fn record_provider_sends(&mut self, provider_sends: HashMap<String, Vec<DateTime<Utc>>>) -> Result<()> {
+        if self.state.provider_sends == provider_sends {
+            return Ok(());
+        }
+        self.state.provider_sends = provider_sends;
+        self.save()
+    }
+
+    pub fn update_notifier_state<R>(&mut self, update: impl FnOnce(&mut NotifierState) -> R) -> Result<R> {
+        let result = update(&mut self.state.notifier);
+        self.save()?;
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -260,21 +498,70 @@ mod tests {
     fn test_should_request_checkin() {
         let mut state = AppState::default();
         let seven_days = ConfigDuration::from_days(7);
+        let one_day = ConfigDuration::from_days(1);
         
         // Should request checkin if never checked in
-        assert!(state.should_request_checkin(seven_days));
+        assert!(state.should_request_checkin(seven_days, one_day, Anchoring::ELAPSED));
         
         // Record a checkin
         state.record_checkin();
         
         // Should not request immediately after checkin
-        assert!(!state.should_request_checkin(seven_days));
+        assert!(!state.should_request_checkin(seven_days, one_day, Anchoring::ELAPSED));
         
         // Simulate 8 days ago
         state.last_checkin = Some(Utc::now() - Duration::days(8));
         
         // Should request checkin after 7 days
-        assert!(state.should_request_checkin(seven_days));
+        assert!(state.should_request_checkin(seven_days, one_day, Anchoring::ELAPSED));
+    }
+
+    #[test]
+    fn test_failed_checkin_request_waits_for_retry_delay() {
+        let mut state = AppState::default();
+        let seven_days = ConfigDuration::from_days(7);
+        let one_day = ConfigDuration::from_days(1);
+        state.last_checkin = Some(Utc::now() - Duration::days(8));
+
+        state.record_checkin_request_failed();
+        assert!(state.last_checkin_request.is_none());
+        assert_eq!(state.checkin_request_count, 0);
+        assert!(!state.should_request_checkin(seven_days, one_day, Anchoring::ELAPSED));
+
+        state.checkin_request_failed_at = Some(Utc::now() - Duration::hours(25));
+        assert!(state.should_request_checkin(seven_days, one_day, Anchoring::ELAPSED));
+
+        // A request getting through, or a check-in, ends the retries
+        state.record_checkin_request();
+        assert!(state.checkin_request_failed_at.is_none());
+        state.record_checkin_request_failed();
+        state.record_checkin();
+        assert!(state.checkin_request_failed_at.is_none());
+    }
+
+    #[test]
+    fn test_warning_before_last_signal_boundaries() {
+        let fourteen_days = ConfigDuration::from_days(14);
+        let one_day = ConfigDuration::from_days(1);
+        let checked_in = |ago: Duration| AppState { last_checkin: Some(Utc::now() - ago), ..AppState::default() };
+
+        assert!(!AppState::default().should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+        assert!(!checked_in(Duration::days(13) - Duration::minutes(1)).should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+        assert!(checked_in(Duration::days(13) + Duration::minutes(1)).should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+        // Right up to the fire time, but not once it has come
+        assert!(checked_in(Duration::days(14) - Duration::seconds(5)).should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+        assert!(!checked_in(Duration::days(14)).should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+
+        // A warning window almost as long as the deadline opens straight after a check-in
+        let almost_all = ConfigDuration::from_seconds(fourteen_days.as_secs() - 60);
+        assert!(checked_in(Duration::minutes(2)).should_warn_before_last_signal(fourteen_days, almost_all, Anchoring::ELAPSED));
+
+        // Once per overdue period
+        let mut state = checked_in(Duration::days(13) + Duration::hours(1));
+        state.record_last_signal_warning();
+        assert!(!state.should_warn_before_last_signal(fourteen_days, one_day, Anchoring::ELAPSED));
+        state.record_checkin();
+        assert!(state.last_signal_warning_sent.is_none());
     }
 
     #[test]
@@ -283,25 +570,55 @@ mod tests {
         let fourteen_days = ConfigDuration::from_days(14);
         
         // Should not fire if no checkin requests made
-        assert!(!state.should_fire_last_signal(fourteen_days));
+        assert!(!state.should_fire_last_signal(fourteen_days, Anchoring::ELAPSED));
         
         // Record a checkin request 15 days ago
         state.last_checkin_request = Some(Utc::now() - Duration::days(15));
         
         // Should fire after 14 days of no checkin
-        assert!(state.should_fire_last_signal(fourteen_days));
+        assert!(state.should_fire_last_signal(fourteen_days, Anchoring::ELAPSED));
         
         // Record a checkin 
         state.record_checkin();
         
         // Should not fire immediately after checkin
-        assert!(!state.should_fire_last_signal(fourteen_days));
+        assert!(!state.should_fire_last_signal(fourteen_days, Anchoring::ELAPSED));
         
         // Simulate 15 days since last checkin
         state.last_checkin = Some(Utc::now() - Duration::days(15));
         
         // Should fire after 14 days
-        assert!(state.should_fire_last_signal(fourteen_days));
+        assert!(state.should_fire_last_signal(fourteen_days, Anchoring::ELAPSED));
+    }
+
+    #[test]
+    fn test_recipient_history_survives_clear() {
+        let mut state = AppState::default();
+        let one_day = ConfigDuration::from_hours(24);
+
+        state.record_last_signal_recipient_notified("email:a@example.com");
+        state.clear_last_signal_recipient_tracking();
+
+        assert!(!state.is_last_signal_recipient_already_notified("email:a@example.com"));
+        assert!(state.recently_notified_at("email:a@example.com", one_day).is_some());
+        assert!(state.recently_notified_at("email:b@example.com", one_day).is_none());
+
+        // Older sends fall outside the interval
+        state.last_signal_recipient_history.insert("email:a@example.com".to_string(), Utc::now() - Duration::hours(25));
+        assert!(state.recently_notified_at("email:a@example.com", one_day).is_none());
+    }
+
+    #[test]
+    fn test_acknowledgements_keep_first_and_clear_on_checkin() {
+        let mut state = AppState::default();
+        let first = Utc::now() - Duration::hours(1);
+
+        assert!(state.record_last_signal_acknowledged("email:a@example.com", first));
+        assert!(!state.record_last_signal_acknowledged("email:a@example.com", Utc::now()));
+        assert_eq!(state.last_signal_acknowledgements["email:a@example.com"], first);
+
+        state.clear_last_signal_recipient_tracking();
+        assert!(state.last_signal_acknowledgements.is_empty());
     }
 
     #[test]
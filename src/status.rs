@@ -0,0 +1,720 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::config::{Config, OutputConfig};
+use crate::duration_parser::{ConfigDuration, DurationStyle};
+use crate::outputs::{generate_recipient_ids, rate_limit};
+use crate::schedule;
+use crate::state::AppState;
+
+/// Snapshot of the system state and the actions it implies, shared by the
+/// human-readable and JSON forms of `status`
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusReport {
+    pub last_checkin: Option<DateTime<Utc>>,
+    pub last_checkin_request: Option<DateTime<Utc>>,
+    pub last_signal_fired: Option<DateTime<Utc>>,
+    pub checkin_request_count: u32,
+    /// `None` when there has never been a check-in, so a request is due now
+    pub next_checkin_request_due: Option<DateTime<Utc>>,
+    pub last_signal_deadline: Option<DateTime<Utc>>,
+    /// When the warning that the last signal is about to fire goes out, with
+    /// `warn_before_last_signal` configured
+    pub last_signal_warning_at: Option<DateTime<Utc>>,
+    /// When that warning was sent this overdue period
+    pub last_signal_warning_sent: Option<DateTime<Utc>>,
+    /// The next escalating reminder, while a check-in request is unanswered
+    pub next_escalation_reminder: Option<EscalationReminder>,
+    pub checkin_request_due: bool,
+    pub last_signal_due: bool,
+    pub last_configuration_review: Option<DateTime<Utc>>,
+    pub review_due: bool,
+    /// When `lastsignal rehearse` last reached every canary recipient
+    pub last_successful_rehearsal: Option<DateTime<Utc>>,
+    pub config_path: Option<PathBuf>,
+    pub data_directory: String,
+    pub configuration: ConfigurationSummary,
+    pub recipients: Vec<RecipientStatus>,
+    /// Providers at their hourly send limit, with sends in the last hour
+    pub throttled_providers: Vec<(String, usize)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EscalationReminder {
+    /// Numbered from 1, out of the configured `escalation` levels
+    pub level: usize,
+    pub due: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigurationSummary {
+    pub duration_between_checkins: ConfigDuration,
+    pub checkin_output_retry_delay: ConfigDuration,
+    /// Reminder times after the first unanswered check-in request
+    pub escalation: Vec<ConfigDuration>,
+    pub max_time_since_last_checkin: ConfigDuration,
+    pub last_signal_output_retry_delay: ConfigDuration,
+    /// How the durations above were applied to produce the shown times,
+    /// e.g. "wall_clock (Europe/London)"
+    pub anchoring: String,
+    pub checkin_outputs: usize,
+    pub last_signal_outputs: usize,
+    pub checkin_output_types: Vec<String>,
+    pub last_signal_output_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipientStatus {
+    pub recipient_id: String,
+    pub channel: String,
+    pub state: RecipientState,
+    pub notified_at: Option<DateTime<Utc>>,
+    /// When the recipient confirmed they saw the last signal
+    pub acknowledged_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientState {
+    /// The last signal was delivered to this recipient
+    Notified,
+    /// The last signal has not fired yet
+    Pending,
+    /// The last signal fired but this recipient has not been reached yet
+    Failed,
+    /// A tracking entry for a recipient that is no longer configured
+    Orphaned,
+}
+
+impl RecipientState {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            RecipientState::Notified => "notified",
+            RecipientState::Pending => "pending",
+            RecipientState::Failed => "not yet reached",
+            RecipientState::Orphaned => "orphaned (no longer configured)",
+        }
+    }
+}
+
+impl StatusReport {
+    pub fn new(config: &Config, state: &AppState) -> Self {
+        let max_time = config.recipient.max_time_since_last_checkin;
+        let anchoring = config.app.anchoring();
+        let last_configuration_review = schedule::last_configuration_review(state, config.modified_at());
+        let last_signal_deadline = schedule::last_signal_deadline(state, max_time, anchoring);
+        let recipient_ids: Vec<String> = config.recipient.last_signal_outputs.iter().flat_map(generate_recipient_ids).collect();
+
+        Self {
+            last_checkin: state.last_checkin,
+            last_checkin_request: state.last_checkin_request,
+            last_signal_fired: state.last_signal_fired,
+            checkin_request_count: state.checkin_request_count,
+            next_checkin_request_due: schedule::checkin_request_retry_due(state, config.checkin.output_retry_delay)
+                .or_else(|| schedule::next_request_due(state, config.checkin.duration_between_checkins, anchoring)),
+            last_signal_deadline,
+            last_signal_warning_at: config.recipient.warn_before_last_signal
+                .zip(last_signal_deadline)
+                .map(|(warn_before, deadline)| schedule::last_signal_warning_at(deadline, warn_before)),
+            last_signal_warning_sent: state.last_signal_warning_sent,
+            next_escalation_reminder: state.first_unanswered_request
+                .and_then(|first_request| schedule::next_escalation(first_request, &config.checkin.escalation, &state.escalation_levels_sent, anchoring, Utc::now()))
+                .map(|(level, due)| EscalationReminder { level, due }),
+            checkin_request_due: state.checkin_request_due(config.checkin.duration_between_checkins, config.checkin.output_retry_delay, &config.checkin.escalation, anchoring)
+                .is_some(),
+            last_signal_due: state.should_fire_last_signal(max_time, anchoring)
+                && state.last_signal_retry_due(&recipient_ids, config.recipient.output_retry_delay, config.recipient.min_renotify_interval),
+            last_configuration_review,
+            last_successful_rehearsal: state.last_successful_rehearsal,
+            review_due: schedule::review_due(last_configuration_review, config.app.review_reminder_interval, Utc::now()),
+            config_path: config.source_path.clone(),
+            data_directory: config.app.data_directory.clone(),
+            configuration: ConfigurationSummary {
+                duration_between_checkins: config.checkin.duration_between_checkins,
+                checkin_output_retry_delay: config.checkin.output_retry_delay,
+                escalation: config.checkin.escalation.clone(),
+                max_time_since_last_checkin: max_time,
+                last_signal_output_retry_delay: config.recipient.output_retry_delay,
+                anchoring: anchoring.to_string(),
+                checkin_outputs: config.checkin.outputs.len(),
+                last_signal_outputs: config.recipient.last_signal_outputs.len(),
+                checkin_output_types: config.checkin.outputs.iter().map(|output| output.output_type.clone()).collect(),
+                last_signal_output_types: config.recipient.last_signal_outputs.iter().map(|output| output.output_type.clone()).collect(),
+            },
+            recipients: recipient_statuses(&config.recipient.last_signal_outputs, state),
+            throttled_providers: rate_limit::throttled_providers(&config.app.rate_limit, &state.provider_sends, Utc::now()),
+        }
+    }
+}
+
+/// How `lastsignal status` prints the report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusView {
+    Text,
+    Json,
+    /// One line for terminal dashboards
+    Short,
+    /// A user template with `{field}` placeholders
+    Template(String),
+}
+
+/// The overall situation, as summarised by `status --short`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    NeverCheckedIn,
+    Ok,
+    /// The last signal deadline has passed and it has not fired yet
+    Overdue,
+    /// The last signal fired and nobody has checked in since
+    Fired,
+}
+
+impl Condition {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Condition::NeverCheckedIn => "❔",
+            Condition::Ok => "✅",
+            Condition::Overdue => "🚨",
+            Condition::Fired => "📨",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Condition::NeverCheckedIn => "never_checked_in",
+            Condition::Ok => "ok",
+            Condition::Overdue => "overdue",
+            Condition::Fired => "fired",
+        }
+    }
+}
+
+impl StatusReport {
+    pub fn condition(&self, now: DateTime<Utc>) -> Condition {
+        if let Some(fired) = self.last_signal_fired
+            && self.last_checkin.is_none_or(|checkin| checkin < fired)
+        {
+            return Condition::Fired;
+        }
+
+        if self.last_signal_deadline.is_some_and(|deadline| schedule::is_reached(deadline, now)) {
+            return Condition::Overdue;
+        }
+
+        match self.last_checkin {
+            Some(_) => Condition::Ok,
+            None => Condition::NeverCheckedIn,
+        }
+    }
+
+    fn recipients_notified(&self) -> String {
+        let configured: Vec<&RecipientStatus> = self.recipients.iter()
+            .filter(|recipient| recipient.state != RecipientState::Orphaned)
+            .collect();
+        let notified = configured.iter()
+            .filter(|recipient| recipient.state == RecipientState::Notified)
+            .count();
+        format!("{}/{}", notified, configured.len())
+    }
+
+    /// Values available to `status --format`, named after the JSON fields.
+    /// Timestamps also get a `_rel` form such as "2d ago" or "in 1d 5h".
+    pub fn template_fields(&self, now: DateTime<Utc>) -> Vec<(&'static str, String)> {
+        let absolute = |time: Option<DateTime<Utc>>, none: &str| {
+            time.map(|time| time.to_rfc3339()).unwrap_or_else(|| none.to_string())
+        };
+        let relative = |time: Option<DateTime<Utc>>, none: &str| {
+            time.map(|time| DurationStyle::Compact.relative(time - now)).unwrap_or_else(|| none.to_string())
+        };
+        let condition = self.condition(now);
+
+        vec![
+            ("icon", condition.icon().to_string()),
+            ("condition", condition.as_str().to_string()),
+            ("last_checkin", absolute(self.last_checkin, "never")),
+            ("last_checkin_rel", relative(self.last_checkin, "never")),
+            ("last_checkin_request", absolute(self.last_checkin_request, "never")),
+            ("last_checkin_request_rel", relative(self.last_checkin_request, "never")),
+            ("last_signal_fired", absolute(self.last_signal_fired, "never")),
+            ("last_signal_fired_rel", relative(self.last_signal_fired, "never")),
+            ("next_checkin_request_due", absolute(self.next_checkin_request_due, "now")),
+            ("next_checkin_request_due_rel", relative(self.next_checkin_request_due, "now")),
+            ("next_reminder_rel", relative(self.next_checkin_request_due, "now")),
+            ("next_escalation_reminder", absolute(self.next_escalation_reminder.map(|reminder| reminder.due), "none")),
+            ("next_escalation_reminder_rel", relative(self.next_escalation_reminder.map(|reminder| reminder.due), "none")),
+            ("last_signal_deadline", absolute(self.last_signal_deadline, "not started")),
+            ("last_signal_deadline_rel", relative(self.last_signal_deadline, "not started")),
+            ("deadline_rel", relative(self.last_signal_deadline, "not started")),
+            ("checkin_request_count", self.checkin_request_count.to_string()),
+            ("checkin_request_due", self.checkin_request_due.to_string()),
+            ("last_signal_due", self.last_signal_due.to_string()),
+            ("review_due", self.review_due.to_string()),
+            ("recipients_notified", self.recipients_notified()),
+        ]
+    }
+
+    /// Fills `{field}` placeholders from `template_fields`. Unknown placeholders are left as they are.
+    pub fn render_template(&self, template: &str, now: DateTime<Utc>) -> String {
+        let mut rendered = template.to_string();
+        for (name, value) in self.template_fields(now) {
+            rendered = rendered.replace(&format!("{{{}}}", name), &value);
+        }
+        rendered
+    }
+
+    /// The full human-readable report, shared by `status` and the daemon's startup log
+    pub fn render_text(&self, now: DateTime<Utc>) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_text(&mut out, now);
+        out
+    }
+
+    fn write_text(&self, out: &mut String, now: DateTime<Utc>) -> std::fmt::Result {
+        let format_time = |time: DateTime<Utc>| time.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+        let ago = |time: DateTime<Utc>| DurationStyle::Verbose.relative(time - now);
+
+        writeln!(out, "LastSignal Status:")?;
+        writeln!(out, "==================")?;
+
+        match self.last_checkin {
+            Some(checkin_time) => writeln!(out, "Last checkin: {} ({})", format_time(checkin_time), ago(checkin_time))?,
+            None => writeln!(out, "Last checkin: Never")?,
+        }
+
+        match self.last_checkin_request {
+            Some(request_time) => writeln!(out, "Last checkin request: {} ({})", format_time(request_time), ago(request_time))?,
+            None => writeln!(out, "Last checkin request: Never")?,
+        }
+
+        match self.last_signal_fired {
+            Some(signal_time) => writeln!(out, "Last signal fired: {} ({})", format_time(signal_time), ago(signal_time))?,
+            None => writeln!(out, "Last signal fired: Never")?,
+        }
+
+        writeln!(out, "Checkin request count: {}", self.checkin_request_count)?;
+
+        if let Some(rehearsal_time) = self.last_successful_rehearsal {
+            writeln!(out, "Last successful rehearsal: {} ({})", format_time(rehearsal_time), ago(rehearsal_time))?;
+        }
+
+        match self.next_checkin_request_due {
+            Some(due) => {
+                let remaining = schedule::time_remaining(due, now);
+                writeln!(out, "Next checkin request due: {} (in {})", format_time(due), DurationStyle::Verbose.format(remaining))?;
+            }
+            None => writeln!(out, "Next checkin request due: Now (never checked in)")?,
+        }
+
+        if let Some(reminder) = self.next_escalation_reminder {
+            writeln!(out, "Next escalation reminder: {} (level {} of {}, {})",
+                format_time(reminder.due), reminder.level, self.configuration.escalation.len(), DurationStyle::Verbose.until(reminder.due - now))?;
+        }
+
+        match self.last_signal_deadline {
+            Some(deadline) => {
+                writeln!(out, "Last signal deadline: {} ({})", format_time(deadline), DurationStyle::Verbose.until(deadline - now))?;
+            }
+            None => writeln!(out, "Last signal deadline: Not started (no checkin or checkin request yet)")?,
+        }
+
+        match (self.last_signal_warning_sent, self.last_signal_warning_at) {
+            (Some(sent), _) => writeln!(out, "Last signal warning: Sent {} ({})", format_time(sent), ago(sent))?,
+            (None, Some(warning_at)) => {
+                writeln!(out, "Last signal warning: {} ({})", format_time(warning_at), DurationStyle::Verbose.until(warning_at - now))?;
+            }
+            (None, None) => {}
+        }
+        writeln!(out)?;
+
+        let configuration = &self.configuration;
+        let config_path = self.config_path.as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "(unknown)".to_string());
+        writeln!(out, "Configuration:")?;
+        writeln!(out, "  Config file: {}", config_path)?;
+        writeln!(out, "  Data directory: {}", self.data_directory)?;
+        writeln!(out, "  Times are shown in UTC")?;
+        writeln!(out, "  Duration between checkins: {}", configuration.duration_between_checkins)?;
+        writeln!(out, "  Schedule anchoring: {}", configuration.anchoring)?;
+        writeln!(out, "  Output retry delay (checkin): {}", configuration.checkin_output_retry_delay)?;
+        if !configuration.escalation.is_empty() {
+            let escalation: Vec<String> = configuration.escalation.iter().map(ToString::to_string).collect();
+            writeln!(out, "  Escalation reminders: {} after the first unanswered request", escalation.join(", "))?;
+        }
+        writeln!(out, "  Max time since last checkin: {}", configuration.max_time_since_last_checkin)?;
+        writeln!(out, "  Output retry delay (last signal): {}", configuration.last_signal_output_retry_delay)?;
+        writeln!(out, "  Checkin outputs: {} ({})", configuration.checkin_outputs, configuration.checkin_output_types.join(", "))?;
+        writeln!(out, "  Last signal outputs: {} ({})", configuration.last_signal_outputs, configuration.last_signal_output_types.join(", "))?;
+        writeln!(out)?;
+
+        writeln!(out, "Last signal recipients:")?;
+        if self.recipients.is_empty() {
+            writeln!(out, "  (none configured)")?;
+        }
+        for recipient in &self.recipients {
+            let notified_at = recipient.notified_at
+                .map(|time| format!(" at {}", format_time(time)))
+                .unwrap_or_default();
+            let acknowledged_at = recipient.acknowledged_at
+                .map(|time| format!(", acknowledged at {}", format_time(time)))
+                .unwrap_or_default();
+            let marker = match recipient.state {
+                RecipientState::Orphaned => "⚠️  ",
+                _ => "  ",
+            };
+            writeln!(out, "{}{} [{}]: {}{}{}", marker, recipient.recipient_id, recipient.channel, recipient.state.describe(), notified_at, acknowledged_at)?;
+        }
+        writeln!(out)?;
+
+        // Show what actions would be taken
+        if self.checkin_request_due {
+            writeln!(out, "⚠️  Checkin request would be sent if running")?;
+        } else {
+            writeln!(out, "✅ Checkin is up to date")?;
+        }
+
+        if self.last_signal_due {
+            writeln!(out, "🚨 Last signal would be fired if running")?;
+        } else {
+            writeln!(out, "✅ Last signal not needed")?;
+        }
+
+        for (provider, sends) in &self.throttled_providers {
+            writeln!(out, "⏳ Throttling sends through {} ({} in the last hour)", provider, sends)?;
+        }
+
+        if self.review_due {
+            writeln!(out, "📝 Please review your LastSignal configuration, then run `lastsignal reviewed`")?;
+        }
+
+        Ok(())
+    }
+
+    /// A compact one-line summary for terminal dashboards
+    pub fn render_short(&self, now: DateTime<Utc>) -> String {
+        let condition = self.condition(now);
+        let checked_in = match self.last_checkin {
+            Some(checkin) => format!("checked in {}", DurationStyle::Compact.relative(checkin - now)),
+            None => "never checked in".to_string(),
+        };
+
+        let mut parts = vec![format!("{} {}", condition.icon(), checked_in)];
+        match condition {
+            Condition::Fired => {
+                let fired = self.last_signal_fired.map(|fired| DurationStyle::Compact.relative(fired - now)).unwrap_or_default();
+                parts = vec![
+                    format!("{} last signal sent {}", condition.icon(), fired),
+                    format!("{} recipients notified", self.recipients_notified()),
+                ];
+            }
+            Condition::Overdue => {
+                if let Some(deadline) = self.last_signal_deadline {
+                    parts.push(DurationStyle::Compact.until(deadline - now));
+                }
+            }
+            Condition::Ok | Condition::NeverCheckedIn => {
+                parts.push(format!("next reminder {}", due_phrase(self.next_checkin_request_due, now)));
+                match self.last_signal_deadline {
+                    Some(deadline) => parts.push(format!("deadline {}", due_phrase(Some(deadline), now))),
+                    None => parts.push("no deadline yet".to_string()),
+                }
+            }
+        }
+
+        parts.join(" · ")
+    }
+}
+
+fn due_phrase(due: Option<DateTime<Utc>>, now: DateTime<Utc>) -> String {
+    match due {
+        Some(due) if !schedule::is_reached(due, now) => DurationStyle::Compact.until(due - now),
+        _ => "due now".to_string(),
+    }
+}
+
+/// Builds one row per configured last signal recipient, followed by any
+/// tracking entries whose recipient has since been removed from the config.
+pub fn recipient_statuses(output_configs: &[OutputConfig], state: &AppState) -> Vec<RecipientStatus> {
+    let mut statuses: Vec<RecipientStatus> = output_configs
+        .iter()
+        .flat_map(|output_config| generate_recipient_ids(output_config).into_iter().map(move |recipient_id| (output_config, recipient_id)))
+        .map(|(output_config, recipient_id)| {
+            let notified_at = state.last_signal_recipients_notified.get(&recipient_id).copied();
+            let acknowledged_at = state.last_signal_acknowledgements.get(&recipient_id).copied();
+            let recipient_state = match (notified_at, state.last_signal_fired) {
+                (Some(_), _) => RecipientState::Notified,
+                (None, Some(_)) => RecipientState::Failed,
+                (None, None) => RecipientState::Pending,
+            };
+
+            RecipientStatus {
+                recipient_id,
+                channel: output_config.output_type.clone(),
+                state: recipient_state,
+                notified_at,
+                acknowledged_at,
+            }
+        })
+        .collect();
+
+    let mut orphaned: Vec<RecipientStatus> = state
+        .last_signal_recipients_notified
+        .iter()
+        .filter(|(id, _)| !statuses.iter().any(|status| &status.recipient_id == *id))
+        .map(|(id, notified_at)| RecipientStatus {
+            recipient_id: id.clone(),
+            channel: id.split(':').next().unwrap_or_default().to_string(),
+            state: RecipientState::Orphaned,
+            notified_at: Some(*notified_at),
+            acknowledged_at: state.last_signal_acknowledgements.get(id).copied(),
+        })
+        .collect();
+    orphaned.sort_by(|a, b| a.recipient_id.cmp(&b.recipient_id));
+
+    statuses.append(&mut orphaned);
+    statuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use std::collections::HashMap;
+
+    fn email_output(to: &str) -> OutputConfig {
+        let mut config = HashMap::new();
+        config.insert("to".to_string(), to.to_string());
+        OutputConfig {
+            output_type: "email".to_string(),
+            config,
+            bidirectional: false,
+            priority: 0,
+            active_hours: None,
+            additional_imap_accounts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_recipients_pending_before_fire() {
+        let state = AppState::default();
+        let statuses = recipient_statuses(&[email_output("a@example.com")], &state);
+
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].recipient_id, "email:a@example.com");
+        assert_eq!(statuses[0].channel, "email");
+        assert_eq!(statuses[0].state, RecipientState::Pending);
+        assert!(statuses[0].notified_at.is_none());
+    }
+
+    #[test]
+    fn test_recipients_notified_and_failed_after_fire() {
+        let mut state = AppState::default();
+        state.record_last_signal_fired();
+        state.record_last_signal_recipient_notified("email:a@example.com");
+
+        let outputs = [email_output("a@example.com"), email_output("b@example.com")];
+        let statuses = recipient_statuses(&outputs, &state);
+
+        assert_eq!(statuses[0].state, RecipientState::Notified);
+        assert!(statuses[0].notified_at.is_some());
+        assert_eq!(statuses[1].state, RecipientState::Failed);
+        assert!(statuses[1].notified_at.is_none());
+    }
+
+    #[test]
+    fn test_one_row_per_address_of_an_email_output() {
+        let mut state = AppState::default();
+        state.record_last_signal_fired();
+        state.record_last_signal_recipient_notified("email:b@example.com");
+
+        let statuses = recipient_statuses(&[email_output("a@example.com, b@example.com")], &state);
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!((statuses[0].recipient_id.as_str(), statuses[0].state), ("email:a@example.com", RecipientState::Failed));
+        assert_eq!((statuses[1].recipient_id.as_str(), statuses[1].state), ("email:b@example.com", RecipientState::Notified));
+    }
+
+    #[test]
+    fn test_orphaned_tracking_entries_are_flagged() {
+        let mut state = AppState::default();
+        state.record_last_signal_fired();
+        state.record_last_signal_recipient_notified("email:old@example.com");
+
+        let statuses = recipient_statuses(&[email_output("a@example.com")], &state);
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[1].recipient_id, "email:old@example.com");
+        assert_eq!(statuses[1].channel, "email");
+        assert_eq!(statuses[1].state, RecipientState::Orphaned);
+    }
+
+    fn report(state: &AppState) -> StatusReport {
+        StatusReport::new(&test_config(), state)
+    }
+
+    fn test_config() -> Config {
+        let mut config: Config = toml::from_str(r#"
+[checkin]
+duration_between_checkins = "7d"
+output_retry_delay = "24h"
+outputs = []
+
+[recipient]
+max_time_since_last_checkin = "14d"
+output_retry_delay = "12h"
+last_signal_outputs = []
+
+[last_signal]
+adapter_type = "file"
+message_file = "message.txt"
+
+[app]
+data_directory = "/tmp"
+log_level = "info"
+"#).unwrap();
+        config.recipient.last_signal_outputs = vec![email_output("a@example.com"), email_output("b@example.com")];
+        config
+    }
+
+    #[test]
+    fn test_short_status_never_checked_in() {
+        let now = Utc::now();
+        let report = report(&AppState::default());
+
+        assert_eq!(report.condition(now), Condition::NeverCheckedIn);
+        assert_eq!(report.render_short(now), "❔ never checked in · next reminder due now · no deadline yet");
+    }
+
+    #[test]
+    fn test_short_status_ok() {
+        let now = Utc::now();
+        let state = AppState {
+            last_checkin: Some(now - Duration::days(2) - Duration::minutes(1)),
+            ..AppState::default()
+        };
+        let report = report(&state);
+
+        assert_eq!(report.condition(now), Condition::Ok);
+        assert_eq!(report.render_short(now), "✅ checked in 2d ago · next reminder in 4d 23h · deadline in 11d 23h");
+    }
+
+    #[test]
+    fn test_short_status_overdue() {
+        let now = Utc::now();
+        let state = AppState {
+            last_checkin: Some(now - Duration::days(15) - Duration::minutes(1)),
+            ..AppState::default()
+        };
+        let report = report(&state);
+
+        assert_eq!(report.condition(now), Condition::Overdue);
+        assert_eq!(report.render_short(now), "🚨 checked in 15d ago · overdue by 1d");
+    }
+
+    #[test]
+    fn test_short_status_fired() {
+        let now = Utc::now();
+        let mut state = AppState {
+            last_checkin: Some(now - Duration::days(20)),
+            last_signal_fired: Some(now - Duration::hours(3) - Duration::minutes(1)),
+            ..AppState::default()
+        };
+        state.last_signal_recipients_notified.insert("email:a@example.com".to_string(), now);
+        let report = report(&state);
+
+        assert_eq!(report.condition(now), Condition::Fired);
+        assert_eq!(report.render_short(now), "📨 last signal sent 3h 1m ago · 1/2 recipients notified");
+    }
+
+    #[test]
+    fn test_render_template() {
+        let now = Utc::now();
+        let state = AppState {
+            last_checkin: Some(now - Duration::days(2) - Duration::minutes(1)),
+            checkin_request_count: 3,
+            ..AppState::default()
+        };
+        let report = report(&state);
+
+        assert_eq!(
+            report.render_template("{icon} {last_checkin_rel} {deadline_rel} ({checkin_request_count}) {unknown}", now),
+            "✅ 2d ago in 11d 23h (3) {unknown}"
+        );
+    }
+
+    #[test]
+    fn test_render_text_includes_configuration_and_recipients() {
+        let now = Utc::now();
+        let state = AppState {
+            last_checkin: Some(now - Duration::days(3)),
+            ..AppState::default()
+        };
+        let text = report(&state).render_text(now);
+
+        assert!(text.contains("Last checkin: "));
+        assert!(text.contains("(3 days ago)"));
+        assert!(text.contains("  Data directory: /tmp\n"));
+        assert!(text.contains("  Last signal outputs: 2 (email, email)\n"));
+        assert!(text.contains("  email:a@example.com [email]: pending\n"));
+        assert!(text.contains("✅ Checkin is up to date"));
+    }
+
+    #[test]
+    fn test_next_escalation_reminder() {
+        let now = Utc::now();
+        let mut config = test_config();
+        config.checkin.escalation = vec![ConfigDuration::from_days(2), ConfigDuration::from_days(4)];
+        let mut state = AppState {
+            last_checkin: Some(now - Duration::days(10)),
+            first_unanswered_request: Some(now - Duration::days(3)),
+            ..AppState::default()
+        };
+        state.escalation_levels_sent.insert(1);
+
+        let report = StatusReport::new(&config, &state);
+        let reminder = report.next_escalation_reminder.unwrap();
+        assert_eq!(reminder.level, 2);
+        assert_eq!(reminder.due, now - Duration::days(3) + Duration::days(4));
+        assert!(!report.checkin_request_due);
+
+        let text = report.render_text(now);
+        assert!(text.contains("(level 2 of 2, in 1 day)"), "{}", text);
+        assert!(text.contains("  Escalation reminders: 2d, 4d after the first unanswered request\n"));
+
+        // Nothing is scheduled until a request goes unanswered
+        let state = AppState { last_checkin: Some(now - Duration::days(1)), ..AppState::default() };
+        assert!(StatusReport::new(&config, &state).next_escalation_reminder.is_none());
+    }
+
+    #[test]
+    fn test_last_signal_warning() {
+        let now = Utc::now();
+        let mut config = test_config();
+        config.recipient.warn_before_last_signal = Some(ConfigDuration::from_hours(24));
+        let mut state = AppState {
+            last_checkin: Some(now - Duration::days(10)),
+            ..AppState::default()
+        };
+
+        let report = StatusReport::new(&config, &state);
+        assert_eq!(report.last_signal_warning_at, Some(now - Duration::days(10) + Duration::days(13)));
+        assert!(report.render_text(now).contains("Last signal warning: ") && report.render_text(now).contains("(in 3 days)"));
+
+        state.last_signal_warning_sent = Some(now - Duration::hours(2));
+        let text = StatusReport::new(&config, &state).render_text(now);
+        assert!(text.contains("Last signal warning: Sent ") && text.contains("(2 hours ago)"), "{}", text);
+
+        // Nothing to show without warn_before_last_signal
+        state.last_signal_warning_sent = None;
+        assert!(!StatusReport::new(&test_config(), &state).render_text(now).contains("Last signal warning"));
+    }
+
+    #[test]
+    fn test_recipient_state_serializes_snake_case() {
+        let json = serde_json::to_string(&RecipientState::Orphaned).unwrap();
+        assert_eq!(json, "\"orphaned\"");
+    }
+}